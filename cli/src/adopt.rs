@@ -0,0 +1,642 @@
+use std::{
+    collections::BTreeSet,
+    fs,
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result, bail};
+use clap::{Arg, ArgAction, ArgMatches, Command};
+use convert_case::{Case, Casing};
+use serde_json::{Value, to_string_pretty as json_to_string_pretty};
+use termcolor::{Color, ColorChoice, ColorSpec, StandardStream, WriteColor};
+use walkdir::WalkDir;
+
+use crate::{
+    CliCommand,
+    constants::{Database, ERROR_FAILED_TO_PARSE_MANIFEST, Infrastructure},
+    core::{
+        ast::infrastructure::{
+            env::find_env_var_references,
+            express_routes::{ExpressProjectAnalysis, analyze_express_project},
+        },
+        base_path::{RequiredLocation, find_app_root_path},
+        command::command,
+        manifest::{ProjectType, application::ApplicationManifestData},
+        name::validate_name,
+        rendered_template::{RenderedTemplate, RenderedTemplatesCache, write_rendered_templates},
+        sync::{
+            artifacts::{ArtifactType, ProjectSyncMetadata, sync_project_to_artifacts},
+            detection::detect_database_from_mikro_orm_config,
+        },
+    },
+};
+
+/// Directories that are build output or tooling state and are not copied
+/// when a project is adopted from outside the application.
+const SKIPPED_COPY_DIRS: &[&str] = &[
+    "node_modules",
+    "dist",
+    "build",
+    "coverage",
+    ".git",
+    ".turbo",
+];
+
+/// Driver packages and the database they imply, checked in order.
+const DATABASE_PACKAGES: &[(&str, Database)] = &[
+    ("@mikro-orm/postgresql", Database::PostgreSQL),
+    ("@mikro-orm/mysql", Database::MySQL),
+    ("@mikro-orm/mariadb", Database::MariaDB),
+    ("@mikro-orm/mssql", Database::MsSQL),
+    ("@mikro-orm/mongodb", Database::MongoDB),
+    ("@mikro-orm/libsql", Database::LibSQL),
+    ("@mikro-orm/sqlite", Database::SQLite),
+    ("@mikro-orm/better-sqlite", Database::BetterSQLite),
+    ("pg", Database::PostgreSQL),
+    ("postgres", Database::PostgreSQL),
+    ("mysql2", Database::MySQL),
+    ("mysql", Database::MySQL),
+    ("mariadb", Database::MariaDB),
+    ("mssql", Database::MsSQL),
+    ("tedious", Database::MsSQL),
+    ("mongodb", Database::MongoDB),
+    ("mongoose", Database::MongoDB),
+    ("@libsql/client", Database::LibSQL),
+    ("sqlite3", Database::SQLite),
+    ("better-sqlite3", Database::BetterSQLite),
+];
+
+const INFRASTRUCTURE_PACKAGES: &[(&str, Infrastructure)] = &[
+    ("ioredis", Infrastructure::Redis),
+    ("redis", Infrastructure::Redis),
+    ("@aws-sdk/client-s3", Infrastructure::S3),
+];
+
+/// ORMs the CLI does not manage; their models and migrations stay as they are.
+const UNMANAGED_ORMS: &[&str] = &[
+    "typeorm",
+    "prisma",
+    "@prisma/client",
+    "sequelize",
+    "drizzle-orm",
+    "knex",
+    "mongoose",
+    "objection",
+];
+
+/// Queue clients that would normally live in a forklaunch worker.
+const QUEUE_PACKAGES: &[&str] = &["kafkajs", "bullmq", "bull", "amqplib"];
+
+#[derive(Debug)]
+pub(crate) struct AdoptCommand;
+
+impl AdoptCommand {
+    pub(crate) fn new() -> Self {
+        Self {}
+    }
+}
+
+/// What could be inferred about a package before it is adopted.
+#[derive(Debug, Default)]
+struct AdoptionPlan {
+    http_framework: String,
+    database: Option<Database>,
+    infrastructure: Vec<Infrastructure>,
+    env_vars: BTreeSet<String>,
+    unmapped: Vec<String>,
+}
+
+fn package_dependencies(package_json: &Value) -> BTreeSet<String> {
+    ["dependencies", "devDependencies", "peerDependencies"]
+        .iter()
+        .filter_map(|key| package_json.get(key).and_then(Value::as_object))
+        .flat_map(|deps| deps.keys().cloned())
+        .collect()
+}
+
+fn detect_database_from_dependencies(dependencies: &BTreeSet<String>) -> Option<Database> {
+    DATABASE_PACKAGES
+        .iter()
+        .find(|(package, _)| dependencies.contains(*package))
+        .map(|(_, database)| *database)
+}
+
+fn detect_infrastructure_from_dependencies(dependencies: &BTreeSet<String>) -> Vec<Infrastructure> {
+    let mut infrastructure = vec![];
+    for (package, infra) in INFRASTRUCTURE_PACKAGES {
+        if dependencies.contains(*package) && !infrastructure.contains(infra) {
+            infrastructure.push(*infra);
+        }
+    }
+    infrastructure
+}
+
+/// Keys defined in the project's own `.env` files, which count as mapped.
+fn defined_env_keys(project_path: &Path) -> BTreeSet<String> {
+    [".env", ".env.local", ".env.example", ".env.template"]
+        .iter()
+        .filter_map(|file| fs::read_to_string(project_path.join(file)).ok())
+        .flat_map(|content| {
+            content
+                .lines()
+                .filter_map(|line| {
+                    let line = line.trim().trim_start_matches("export ");
+                    (!line.starts_with('#'))
+                        .then(|| line.split_once('='))
+                        .flatten()
+                        .map(|(key, _)| key.trim().to_string())
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+fn plan_adoption(
+    source_path: &Path,
+    package_json: &Value,
+    analysis: &ExpressProjectAnalysis,
+    manifest_data: &ApplicationManifestData,
+    database_override: Option<Database>,
+) -> Result<AdoptionPlan> {
+    let dependencies = package_dependencies(package_json);
+
+    let http_framework = if dependencies.contains("hyper-express") {
+        "hyper-express"
+    } else if dependencies.contains("express") {
+        "express"
+    } else {
+        bail!(
+            "{} does not depend on express or hyper-express",
+            source_path.join("package.json").display()
+        );
+    };
+
+    let mut plan = AdoptionPlan {
+        http_framework: http_framework.to_string(),
+        infrastructure: detect_infrastructure_from_dependencies(&dependencies),
+        ..Default::default()
+    };
+
+    if http_framework != manifest_data.http_framework {
+        plan.unmapped.push(format!(
+            "Project uses {} but the application is configured for {}",
+            http_framework, manifest_data.http_framework
+        ));
+    }
+
+    plan.database = match database_override {
+        Some(database) => Some(database),
+        None => detect_database_from_mikro_orm_config(source_path)?
+            .or_else(|| detect_database_from_dependencies(&dependencies)),
+    };
+    if plan.database.is_none() {
+        plan.unmapped.push(
+            "No database driver found, so no docker-compose service was added (re-run with --database to add one)"
+                .to_string(),
+        );
+    }
+
+    for orm in UNMANAGED_ORMS {
+        if dependencies.contains(*orm) {
+            plan.unmapped.push(format!(
+                "ORM '{}' is not managed by forklaunch; its models and migrations are left as they are",
+                orm
+            ));
+        }
+    }
+    for queue in QUEUE_PACKAGES {
+        if dependencies.contains(*queue) {
+            plan.unmapped.push(format!(
+                "Queue client '{}' is not wired as a forklaunch worker",
+                queue
+            ));
+        }
+    }
+
+    if analysis.entrypoints.is_empty() {
+        plan.unmapped
+            .push("No .listen(...) call found, so the server entrypoint is unknown".to_string());
+    }
+    for mount in &analysis.unresolved_mounts {
+        plan.unmapped
+            .push(format!("Could not resolve router mount {}", mount));
+    }
+    if !analysis.routes.is_empty() {
+        plan.unmapped.push(format!(
+            "{} route(s) are not declared with forklaunch contracts and are left out of the OpenAPI spec and SDK",
+            analysis.routes.len()
+        ));
+    }
+
+    plan.env_vars = find_env_var_references(source_path)?.into_iter().collect();
+    let defined = defined_env_keys(source_path);
+    let undefined: Vec<&String> = plan
+        .env_vars
+        .iter()
+        .filter(|var| !defined.contains(*var))
+        .collect();
+    if !undefined.is_empty() {
+        plan.unmapped.push(format!(
+            "Environment variables without a value in any .env file: {}",
+            undefined
+                .iter()
+                .map(|var| var.as_str())
+                .collect::<Vec<_>>()
+                .join(", ")
+        ));
+    }
+
+    if !source_path.join("tsconfig.json").exists() {
+        plan.unmapped
+            .push("No tsconfig.json, so the modules tsconfig reference will not build".to_string());
+    }
+
+    Ok(plan)
+}
+
+fn copy_project(source_path: &Path, target_path: &Path) -> Result<usize> {
+    let walker = WalkDir::new(source_path).into_iter().filter_entry(|entry| {
+        entry.depth() == 0
+            || !entry
+                .file_name()
+                .to_str()
+                .is_some_and(|name| SKIPPED_COPY_DIRS.contains(&name))
+    });
+
+    let mut copied = 0;
+    for entry in walker {
+        let entry = entry?;
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        let relative_path = entry.path().strip_prefix(source_path)?;
+        let destination = target_path.join(relative_path);
+        if let Some(parent) = destination.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create {}", parent.display()))?;
+        }
+        fs::copy(entry.path(), &destination)
+            .with_context(|| format!("Failed to copy {}", entry.path().display()))?;
+        copied += 1;
+    }
+
+    Ok(copied)
+}
+
+fn sources_export_sdk_client(project_path: &Path, project_name: &str) -> bool {
+    let sdk_client = format!("{}SdkClient", project_name.to_case(Case::Pascal));
+    WalkDir::new(project_path)
+        .into_iter()
+        .filter_entry(|entry| {
+            entry.depth() == 0
+                || !entry
+                    .file_name()
+                    .to_str()
+                    .is_some_and(|name| SKIPPED_COPY_DIRS.contains(&name))
+        })
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| {
+            entry.file_type().is_file()
+                && entry.path().extension().and_then(|ext| ext.to_str()) == Some("ts")
+        })
+        .any(|entry| {
+            fs::read_to_string(entry.path())
+                .is_ok_and(|content| content.contains("export") && content.contains(&sdk_client))
+        })
+}
+
+impl CliCommand for AdoptCommand {
+    fn command(&self) -> Command {
+        command(
+            "adopt",
+            "Adopt an existing Express or Hyper-Express project into the application",
+        )
+        .arg(
+            Arg::new("source")
+                .help("Path to the existing project (the folder containing package.json)")
+                .value_name("PROJECT_PATH")
+                .required(true),
+        )
+        .arg(
+            Arg::new("name")
+                .short('n')
+                .long("name")
+                .help("Name of the project in the application (defaults to the package name)"),
+        )
+        .arg(
+            Arg::new("database")
+                .short('d')
+                .long("database")
+                .help("Database to provision when it cannot be detected")
+                .value_parser(Database::VARIANTS),
+        )
+        .arg(
+            Arg::new("base_path")
+                .short('p')
+                .long("path")
+                .help("The application path"),
+        )
+        .arg(
+            Arg::new("dryrun")
+                .long("dryrun")
+                .help("Report what would be adopted without changing any files")
+                .action(ArgAction::SetTrue),
+        )
+    }
+
+    fn handler(&self, matches: &ArgMatches) -> Result<()> {
+        let mut stdout = StandardStream::stdout(ColorChoice::Always);
+        let dryrun = matches.get_flag("dryrun");
+
+        let (app_root_path, _) = find_app_root_path(matches, RequiredLocation::Application)?;
+        let mut rendered_templates_cache = RenderedTemplatesCache::new();
+        let manifest_path = app_root_path.join(".forklaunch").join("manifest.toml");
+        let manifest_template = rendered_templates_cache
+            .get(&manifest_path)?
+            .context("Failed to read manifest")?;
+        let mut manifest_data: ApplicationManifestData =
+            toml::from_str(&manifest_template.content).context(ERROR_FAILED_TO_PARSE_MANIFEST)?;
+        let modules_path = app_root_path.join(&manifest_data.modules_path);
+
+        let source_path = PathBuf::from(matches.get_one::<String>("source").unwrap());
+        let source_path = source_path
+            .canonicalize()
+            .with_context(|| format!("Project not found: {}", source_path.display()))?;
+        let package_json_path = source_path.join("package.json");
+        let mut package_json: Value = serde_json::from_str(
+            &fs::read_to_string(&package_json_path)
+                .with_context(|| format!("Failed to read {}", package_json_path.display()))?,
+        )
+        .with_context(|| format!("Failed to parse {}", package_json_path.display()))?;
+
+        let project_name = match matches.get_one::<String>("name") {
+            Some(name) => name.clone(),
+            None => package_json
+                .get("name")
+                .and_then(Value::as_str)
+                .map(|name| name.rsplit('/').next().unwrap_or(name).to_string())
+                .or_else(|| {
+                    source_path
+                        .file_name()
+                        .map(|name| name.to_string_lossy().to_string())
+                })
+                .context("Could not determine a project name, pass --name")?
+                .to_case(Case::Kebab),
+        };
+        if !validate_name(&project_name) {
+            bail!(
+                "Invalid project name '{}': use letters, '-' and '_' only (pass --name)",
+                project_name
+            );
+        }
+        if manifest_data
+            .projects
+            .iter()
+            .any(|p| p.name == project_name)
+        {
+            bail!("Project '{}' already exists in the manifest", project_name);
+        }
+
+        let target_path = modules_path.join(&project_name);
+        let in_place = source_path.parent() == modules_path.canonicalize().ok().as_deref();
+        if in_place && source_path.file_name() != Some(project_name.as_ref()) {
+            bail!(
+                "{} is already in the modules directory; its folder name must match the project name '{}'",
+                source_path.display(),
+                project_name
+            );
+        }
+        if !in_place && target_path.exists() {
+            bail!("{} already exists", target_path.display());
+        }
+
+        stdout.set_color(ColorSpec::new().set_fg(Some(Color::Cyan)))?;
+        writeln!(
+            stdout,
+            "[INFO] Analyzing {} as '{}'...",
+            source_path.display(),
+            project_name
+        )?;
+        stdout.reset()?;
+
+        let analysis = analyze_express_project(&source_path)?;
+        let database_override = matches
+            .get_one::<String>("database")
+            .map(|database| database.parse::<Database>())
+            .transpose()?;
+        let mut plan = plan_adoption(
+            &source_path,
+            &package_json,
+            &analysis,
+            &manifest_data,
+            database_override,
+        )?;
+
+        writeln!(stdout, "[INFO] Framework: {}", plan.http_framework)?;
+        for entrypoint in &analysis.entrypoints {
+            writeln!(stdout, "[INFO] Entrypoint: {}", entrypoint.display())?;
+        }
+        writeln!(stdout, "[INFO] Routes: {}", analysis.routes.len())?;
+        for route in &analysis.routes {
+            writeln!(
+                stdout,
+                "  {:<7} {} ({})",
+                route.method,
+                route.path,
+                route.file.display()
+            )?;
+        }
+        if let Some(database) = plan.database {
+            writeln!(stdout, "[INFO] Database: {}", database.to_string())?;
+        }
+        if !plan.infrastructure.is_empty() {
+            writeln!(
+                stdout,
+                "[INFO] Infrastructure: {}",
+                plan.infrastructure
+                    .iter()
+                    .map(|infra| infra.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )?;
+        }
+        if !plan.env_vars.is_empty() {
+            writeln!(
+                stdout,
+                "[INFO] Environment variables: {}",
+                plan.env_vars.iter().cloned().collect::<Vec<_>>().join(", ")
+            )?;
+        }
+
+        let client_sdk_path = modules_path.join("client-sdk").join("clientSdk.ts");
+        let wire_client_sdk =
+            client_sdk_path.exists() && sources_export_sdk_client(&source_path, &project_name);
+        if client_sdk_path.exists() && !wire_client_sdk {
+            plan.unmapped.push(format!(
+                "No exported {}SdkClient type, so the project was not added to the client SDK",
+                project_name.to_case(Case::Pascal)
+            ));
+        }
+
+        if dryrun {
+            writeln!(stdout)?;
+            writeln!(
+                stdout,
+                "[INFO] Dry run: would adopt into {}",
+                target_path.display()
+            )?;
+        } else {
+            if !in_place {
+                let copied = copy_project(&source_path, &target_path)?;
+                stdout.set_color(ColorSpec::new().set_fg(Some(Color::Green)))?;
+                writeln!(
+                    stdout,
+                    "[OK] Copied {} file(s) to {}",
+                    copied,
+                    target_path.display()
+                )?;
+                stdout.reset()?;
+            }
+
+            let package_name = format!(
+                "@{}/{}",
+                manifest_data.app_name.to_case(Case::Kebab),
+                project_name.to_case(Case::Kebab)
+            );
+            package_json["name"] = Value::String(package_name);
+            let target_package_json_path = target_path.join("package.json");
+            rendered_templates_cache.insert(
+                target_package_json_path.to_string_lossy().to_string(),
+                RenderedTemplate {
+                    path: target_package_json_path,
+                    content: json_to_string_pretty(&package_json)?,
+                    context: Some("Failed to write package.json".to_string()),
+                },
+            );
+
+            let mut artifacts = vec![ArtifactType::Manifest];
+            if plan.database.is_some() {
+                artifacts.push(ArtifactType::DockerCompose);
+            }
+            artifacts.push(ArtifactType::Runtime);
+            if wire_client_sdk {
+                artifacts.push(ArtifactType::ClientSdk);
+            }
+            artifacts.push(ArtifactType::ModulesTsconfig);
+
+            let description = package_json
+                .get("description")
+                .and_then(Value::as_str)
+                .filter(|description| !description.is_empty())
+                .map(str::to_string)
+                .unwrap_or_else(|| format!("Adopted from {}", source_path.display()));
+
+            sync_project_to_artifacts(
+                &mut rendered_templates_cache,
+                &mut manifest_data,
+                &ProjectSyncMetadata {
+                    project_type: ProjectType::Service,
+                    project_name: project_name.clone(),
+                    description,
+                    database: plan.database,
+                    infrastructure: plan.infrastructure.clone(),
+                    worker_type: None,
+                },
+                &artifacts,
+                &app_root_path,
+                &modules_path,
+                &mut stdout,
+            )
+            .inspect_err(|_| {
+                // Nothing else has been written yet, so drop the copy to
+                // leave the application as it was.
+                if !in_place {
+                    let _ = fs::remove_dir_all(&target_path);
+                }
+            })?;
+
+            rendered_templates_cache.insert(
+                manifest_path.to_string_lossy().to_string(),
+                RenderedTemplate {
+                    path: manifest_path.clone(),
+                    content: toml::to_string_pretty(&manifest_data)
+                        .context("Failed to serialize manifest")?,
+                    context: Some("Failed to write manifest".to_string()),
+                },
+            );
+
+            let rendered_templates: Vec<_> = rendered_templates_cache
+                .drain()
+                .map(|(_, template)| template)
+                .collect();
+            write_rendered_templates(&rendered_templates, false, &mut stdout)?;
+
+            stdout.set_color(ColorSpec::new().set_fg(Some(Color::Green)))?;
+            writeln!(stdout, "[OK] Adopted '{}'", project_name)?;
+            stdout.reset()?;
+        }
+
+        if !plan.unmapped.is_empty() {
+            writeln!(stdout)?;
+            stdout.set_color(ColorSpec::new().set_fg(Some(Color::Yellow)))?;
+            writeln!(stdout, "[WARN] Not mapped automatically:")?;
+            stdout.reset()?;
+            for item in &plan.unmapped {
+                writeln!(stdout, "  - {}", item)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use tempfile::TempDir;
+
+    use super::*;
+
+    #[test]
+    fn test_detect_from_dependencies() {
+        let dependencies = package_dependencies(&json!({
+            "dependencies": { "express": "^4.19.0", "pg": "^8.11.0", "ioredis": "^5.0.0" },
+            "devDependencies": { "@aws-sdk/client-s3": "^3.0.0", "redis": "^4.0.0" }
+        }));
+
+        assert_eq!(
+            detect_database_from_dependencies(&dependencies),
+            Some(Database::PostgreSQL)
+        );
+        assert_eq!(
+            detect_infrastructure_from_dependencies(&dependencies),
+            vec![Infrastructure::Redis, Infrastructure::S3]
+        );
+        assert_eq!(
+            detect_database_from_dependencies(&package_dependencies(&json!({}))),
+            None
+        );
+    }
+
+    #[test]
+    fn test_copy_project_skips_build_output() {
+        let temp_dir = TempDir::new().unwrap();
+        let source = temp_dir.path().join("legacy");
+        fs::create_dir_all(source.join("src")).unwrap();
+        fs::create_dir_all(source.join("node_modules/express")).unwrap();
+        fs::create_dir_all(source.join("dist")).unwrap();
+        fs::write(source.join("package.json"), "{}").unwrap();
+        fs::write(source.join("src/server.ts"), "").unwrap();
+        fs::write(source.join("node_modules/express/index.js"), "").unwrap();
+        fs::write(source.join("dist/server.js"), "").unwrap();
+
+        let target = temp_dir.path().join("modules/legacy");
+        assert_eq!(copy_project(&source, &target).unwrap(), 2);
+
+        assert!(target.join("package.json").exists());
+        assert!(target.join("src/server.ts").exists());
+        assert!(!target.join("node_modules").exists());
+        assert!(!target.join("dist").exists());
+    }
+}
@@ -1,12 +1,16 @@
 #[macro_use]
 pub(crate) mod ast;
+pub(crate) mod architecture;
 pub(crate) mod base_path;
 pub(crate) mod choices;
 pub(crate) mod client_sdk;
 pub(crate) mod command;
 pub(crate) mod database;
+pub(crate) mod dependency_licenses;
 pub(crate) mod docker;
 pub(crate) mod env;
+pub(crate) mod env_generate;
+pub(crate) mod env_schema;
 pub(crate) mod env_scope;
 pub(crate) mod env_template;
 pub(crate) mod format;
@@ -17,7 +21,10 @@ pub(crate) mod husky;
 pub(crate) mod iam;
 pub(crate) mod library_scanner;
 pub(crate) mod license;
+pub(crate) mod lockfile;
 pub(crate) mod manifest;
+pub(crate) mod merge;
+pub(crate) mod mikro_orm;
 pub(crate) mod modules;
 pub(crate) mod move_template;
 pub(crate) mod mutable_enum;
@@ -28,6 +35,9 @@ pub(crate) mod pnpm_workspace;
 pub(crate) mod relative_path;
 pub(crate) mod removal_template;
 pub(crate) mod rendered_template;
+pub(crate) mod sbom;
+pub(crate) mod secrets;
+pub(crate) mod seed_data;
 pub(crate) mod static_analysis;
 pub(crate) mod string;
 pub(crate) mod symlink_template;
@@ -0,0 +1,813 @@
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap, VecDeque},
+    fmt,
+    fs::read_to_string,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Result, bail};
+use oxc_allocator::Allocator;
+use oxc_ast::ast::{
+    ExportNamedDeclaration, ImportDeclaration, ImportDeclarationSpecifier, Statement,
+};
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+use serde::{Deserialize, Serialize};
+use walkdir::WalkDir;
+
+use crate::core::{
+    library_scanner::{ImportScanner, resolve_local_import},
+    manifest::{ProjectEntry, ProjectType},
+};
+
+pub(crate) const SERVICE_SDK_ONLY: &str = "service-sdk-only";
+pub(crate) const WORKER_NO_CONTROLLERS: &str = "worker-no-controllers";
+pub(crate) const LIBRARY_NO_SERVICES: &str = "library-no-services";
+pub(crate) const NO_DEEP_PERSISTENCE: &str = "no-deep-persistence";
+pub(crate) const NO_CYCLES: &str = "no-cycles";
+
+/// Built-in rules and what they enforce.
+pub(crate) const BUILTIN_RULES: [(&str, &str); 5] = [
+    (
+        SERVICE_SDK_ONLY,
+        "Services and workers may only use another service through its SDK client",
+    ),
+    (
+        WORKER_NO_CONTROLLERS,
+        "Workers may not import api/controllers",
+    ),
+    (
+        LIBRARY_NO_SERVICES,
+        "Libraries may not depend on services or workers",
+    ),
+    (
+        NO_DEEP_PERSISTENCE,
+        "No imports into another project's persistence",
+    ),
+    (
+        NO_CYCLES,
+        "Projects may not depend on each other in a cycle",
+    ),
+];
+
+const SKIPPED_DIRECTORIES: [&str; 4] = ["node_modules", "dist", ".turbo", "coverage"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) enum RuleLevel {
+    Off,
+    Warn,
+    Error,
+}
+
+impl fmt::Display for RuleLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            RuleLevel::Off => "off",
+            RuleLevel::Warn => "warning",
+            RuleLevel::Error => "error",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// An extra import restriction, declared as `[[architecture.forbid]]`.
+/// It applies to imports that cross from one project into another.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct ForbiddenImport {
+    /// Rule name shown in diagnostics
+    pub(crate) name: String,
+    /// Importing projects: a project name, `service`, `worker`, `library` or `*`
+    pub(crate) from: String,
+    /// Imported projects, with the same selectors as `from`
+    #[serde(default = "any_project")]
+    pub(crate) to: String,
+    /// Only forbid imports of this path inside the imported project, e.g.
+    /// `domain/schemas`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) path: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) message: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) level: Option<RuleLevel>,
+}
+
+fn any_project() -> String {
+    "*".to_string()
+}
+
+/// Architecture lint settings, declared as `[architecture]` in the manifest.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub(crate) struct ArchitectureConfig {
+    /// Level per built-in rule, e.g. `no-cycles = "warn"`
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub(crate) rules: BTreeMap<String, RuleLevel>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(crate) forbid: Vec<ForbiddenImport>,
+    /// Files to skip, as paths relative to the application root
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(crate) ignore: Vec<String>,
+}
+
+impl ArchitectureConfig {
+    /// Rejects rule names that do not exist, so a typo does not silently
+    /// leave a rule on.
+    pub(crate) fn validate(&self) -> Result<()> {
+        for name in self.rules.keys() {
+            if !BUILTIN_RULES.iter().any(|(rule, _)| rule == name) {
+                bail!(
+                    "Unknown architecture rule '{}'. Known rules: {}",
+                    name,
+                    BUILTIN_RULES
+                        .iter()
+                        .map(|(rule, _)| *rule)
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                );
+            }
+        }
+        Ok(())
+    }
+
+    fn level(&self, rule: &str) -> RuleLevel {
+        self.rules.get(rule).copied().unwrap_or(RuleLevel::Error)
+    }
+}
+
+/// An import from one project's file into a project of the application.
+#[derive(Debug, Clone)]
+pub(crate) struct ProjectImport {
+    /// Importing file, relative to the application root
+    pub(crate) file: String,
+    pub(crate) line: usize,
+    pub(crate) specifier: String,
+    /// Imported names; empty for side-effect imports and `export *`
+    pub(crate) names: Vec<String>,
+    pub(crate) from_project: String,
+    pub(crate) to_project: String,
+    /// `/`-separated path inside the imported project: the resolved file
+    /// for relative imports, the package subpath otherwise
+    pub(crate) target_path: String,
+    pub(crate) is_relative: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct Diagnostic {
+    pub(crate) rule: String,
+    pub(crate) level: RuleLevel,
+    pub(crate) file: String,
+    pub(crate) line: usize,
+    pub(crate) message: String,
+}
+
+struct ModuleRequest {
+    specifier: String,
+    offset: u32,
+    names: Vec<String>,
+}
+
+fn import_names(import: &ImportDeclaration) -> Vec<String> {
+    import
+        .specifiers
+        .iter()
+        .flatten()
+        .map(|specifier| match specifier {
+            ImportDeclarationSpecifier::ImportSpecifier(spec) => spec.imported.name().to_string(),
+            ImportDeclarationSpecifier::ImportDefaultSpecifier(_) => "default".to_string(),
+            ImportDeclarationSpecifier::ImportNamespaceSpecifier(_) => "*".to_string(),
+        })
+        .collect()
+}
+
+fn export_names(export: &ExportNamedDeclaration) -> Vec<String> {
+    export
+        .specifiers
+        .iter()
+        .map(|specifier| specifier.local.name().to_string())
+        .collect()
+}
+
+/// Imports and re-exports of a TypeScript source.
+fn module_requests(source: &str, path: &Path) -> Vec<ModuleRequest> {
+    let allocator = Allocator::default();
+    let source_type = SourceType::from_path(path)
+        .unwrap_or_default()
+        .with_typescript(true);
+    let program = Parser::new(&allocator, source, source_type).parse().program;
+
+    program
+        .body
+        .iter()
+        .filter_map(|statement| match statement {
+            Statement::ImportDeclaration(import) => Some(ModuleRequest {
+                specifier: import.source.value.to_string(),
+                offset: import.span.start,
+                names: import_names(import),
+            }),
+            Statement::ExportNamedDeclaration(export) => {
+                export.source.as_ref().map(|source| ModuleRequest {
+                    specifier: source.value.to_string(),
+                    offset: export.span.start,
+                    names: export_names(export),
+                })
+            }
+            Statement::ExportAllDeclaration(export) => Some(ModuleRequest {
+                specifier: export.source.value.to_string(),
+                offset: export.span.start,
+                names: vec![],
+            }),
+            _ => None,
+        })
+        .collect()
+}
+
+fn line_of(source: &str, offset: u32) -> usize {
+    source[..(offset as usize).min(source.len())]
+        .matches('\n')
+        .count()
+        + 1
+}
+
+fn slash_path(path: &Path) -> String {
+    path.components()
+        .map(|component| component.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+fn is_source_file(path: &Path) -> bool {
+    let name = path.to_string_lossy();
+    ["ts", "tsx", "mts", "cts"]
+        .iter()
+        .any(|extension| path.extension().is_some_and(|e| e == *extension))
+        && !name.ends_with(".d.ts")
+}
+
+/// Package name of a project: the `name` in its package.json, or
+/// `@<app>/<project>` by convention.
+fn package_name(project_path: &Path, app_name: &str, project_name: &str) -> String {
+    read_to_string(project_path.join("package.json"))
+        .ok()
+        .and_then(|content| serde_json::from_str::<serde_json::Value>(&content).ok())
+        .and_then(|json| json.get("name")?.as_str().map(str::to_string))
+        .unwrap_or_else(|| format!("@{}/{}", app_name, project_name))
+}
+
+/// Collects every import between projects of the application, including
+/// imports within the same project.
+pub(crate) fn scan_project_imports(
+    app_root: &Path,
+    modules_path: &Path,
+    app_name: &str,
+    projects: &[ProjectEntry],
+    ignore: &[String],
+) -> Result<Vec<ProjectImport>> {
+    let app_root = app_root
+        .canonicalize()
+        .unwrap_or_else(|_| app_root.to_path_buf());
+    let project_paths: Vec<(String, PathBuf)> = projects
+        .iter()
+        .filter_map(|project| {
+            let path = modules_path.join(&project.name).canonicalize().ok()?;
+            Some((project.name.clone(), path))
+        })
+        .collect();
+    let packages: HashMap<String, String> = project_paths
+        .iter()
+        .map(|(name, path)| (package_name(path, app_name, name), name.clone()))
+        .collect();
+
+    // Longest match wins so nested project directories resolve correctly
+    let owner = |path: &Path| -> Option<(&str, String)> {
+        project_paths
+            .iter()
+            .filter(|(_, project_path)| path.starts_with(project_path))
+            .max_by_key(|(_, project_path)| project_path.components().count())
+            .map(|(name, project_path)| {
+                (
+                    name.as_str(),
+                    slash_path(path.strip_prefix(project_path).unwrap()),
+                )
+            })
+    };
+
+    let mut imports = vec![];
+    for (project_name, project_path) in &project_paths {
+        let files = WalkDir::new(project_path)
+            .into_iter()
+            .filter_entry(|entry| {
+                !SKIPPED_DIRECTORIES.contains(&entry.file_name().to_string_lossy().as_ref())
+            })
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.file_type().is_file() && is_source_file(entry.path()));
+
+        for entry in files {
+            let file_path = entry.path();
+            let relative_file = slash_path(file_path.strip_prefix(&app_root).unwrap_or(file_path));
+            if ignore
+                .iter()
+                .any(|prefix| relative_file.starts_with(prefix.trim_end_matches('/')))
+            {
+                continue;
+            }
+            let Ok(source) = read_to_string(file_path) else {
+                continue;
+            };
+            let directory = file_path.parent().unwrap();
+
+            for request in module_requests(&source, file_path) {
+                let is_relative = request.specifier.starts_with('.');
+                let target = if is_relative {
+                    resolve_local_import(directory, &request.specifier)
+                        .and_then(|resolved| owner(&resolved))
+                        .map(|(name, path)| (name.to_string(), path))
+                } else {
+                    let package = ImportScanner::normalize_scoped_package(&request.specifier);
+                    packages.get(&package).map(|name| {
+                        (
+                            name.clone(),
+                            request.specifier[package.len()..]
+                                .trim_start_matches('/')
+                                .to_string(),
+                        )
+                    })
+                };
+                let Some((to_project, target_path)) = target else {
+                    continue;
+                };
+                imports.push(ProjectImport {
+                    file: relative_file.clone(),
+                    line: line_of(&source, request.offset),
+                    specifier: request.specifier,
+                    names: request.names,
+                    from_project: project_name.clone(),
+                    to_project,
+                    target_path,
+                    is_relative,
+                });
+            }
+        }
+    }
+
+    Ok(imports)
+}
+
+/// Whether a `/`-separated path contains `segment` as whole components.
+fn has_segment(path: &str, segment: &str) -> bool {
+    format!("/{}/", path).contains(&format!("/{}/", segment.trim_matches('/')))
+}
+
+fn matches_selector(selector: &str, project: &ProjectEntry) -> bool {
+    match selector {
+        "*" => true,
+        "service" => project.r#type == ProjectType::Service,
+        "worker" => project.r#type == ProjectType::Worker,
+        "library" => project.r#type == ProjectType::Library,
+        name => project.name == name,
+    }
+}
+
+fn type_name(project_type: &ProjectType) -> &'static str {
+    match project_type {
+        ProjectType::Service => "service",
+        ProjectType::Worker => "worker",
+        ProjectType::Library => "library",
+    }
+}
+
+/// Built-in and configured import rules for a single import.
+fn check_import(
+    import: &ProjectImport,
+    from: &ProjectEntry,
+    to: &ProjectEntry,
+    config: &ArchitectureConfig,
+) -> Vec<(String, RuleLevel, String)> {
+    let mut violations = vec![];
+    let crosses = from.name != to.name;
+
+    if crosses && from.r#type != ProjectType::Library && to.r#type == ProjectType::Service {
+        let non_sdk: Vec<&str> = import
+            .names
+            .iter()
+            .filter(|name| !name.contains("SdkClient"))
+            .map(String::as_str)
+            .collect();
+        let message = if import.is_relative {
+            Some(format!(
+                "`{}` reaches into `{}` through a relative import; use its SDK client instead",
+                from.name, to.name
+            ))
+        } else if import.names.is_empty() || !non_sdk.is_empty() {
+            Some(format!(
+                "`{}` imports {} from `{}`; other services may only be used through their SDK client",
+                from.name,
+                if non_sdk.is_empty() {
+                    "everything".to_string()
+                } else {
+                    non_sdk
+                        .iter()
+                        .map(|name| format!("`{}`", name))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                },
+                import.specifier
+            ))
+        } else {
+            None
+        };
+        if let Some(message) = message {
+            violations.push((
+                SERVICE_SDK_ONLY.to_string(),
+                config.level(SERVICE_SDK_ONLY),
+                message,
+            ));
+        }
+    }
+
+    if from.r#type == ProjectType::Worker && has_segment(&import.target_path, "api/controllers") {
+        violations.push((
+            WORKER_NO_CONTROLLERS.to_string(),
+            config.level(WORKER_NO_CONTROLLERS),
+            format!(
+                "worker `{}` imports controller code from `{}`",
+                from.name, import.specifier
+            ),
+        ));
+    }
+
+    if from.r#type == ProjectType::Library && to.r#type != ProjectType::Library {
+        violations.push((
+            LIBRARY_NO_SERVICES.to_string(),
+            config.level(LIBRARY_NO_SERVICES),
+            format!(
+                "library `{}` depends on {} `{}`",
+                from.name,
+                type_name(&to.r#type),
+                to.name
+            ),
+        ));
+    }
+
+    if crosses && has_segment(&import.target_path, "persistence") {
+        violations.push((
+            NO_DEEP_PERSISTENCE.to_string(),
+            config.level(NO_DEEP_PERSISTENCE),
+            format!(
+                "`{}` imports `{}`, which is inside the persistence layer of `{}`",
+                from.name, import.specifier, to.name
+            ),
+        ));
+    }
+
+    if crosses {
+        for rule in &config.forbid {
+            if matches_selector(&rule.from, from)
+                && matches_selector(&rule.to, to)
+                && rule
+                    .path
+                    .as_ref()
+                    .is_none_or(|path| has_segment(&import.target_path, path))
+            {
+                violations.push((
+                    rule.name.clone(),
+                    rule.level.unwrap_or(RuleLevel::Error),
+                    rule.message.clone().unwrap_or_else(|| {
+                        format!(
+                            "`{}` may not import `{}` from `{}`",
+                            from.name, import.specifier, to.name
+                        )
+                    }),
+                ));
+            }
+        }
+    }
+
+    violations
+}
+
+/// Strongly connected components with more than one project, found with
+/// Tarjan's algorithm.
+fn project_cycles(graph: &BTreeMap<&str, BTreeSet<&str>>) -> Vec<Vec<String>> {
+    struct Tarjan<'g, 'a> {
+        graph: &'g BTreeMap<&'a str, BTreeSet<&'a str>>,
+        index: HashMap<&'a str, usize>,
+        low: HashMap<&'a str, usize>,
+        stack: Vec<&'a str>,
+        on_stack: BTreeSet<&'a str>,
+        components: Vec<Vec<String>>,
+    }
+
+    impl<'a> Tarjan<'_, 'a> {
+        fn visit(&mut self, node: &'a str) {
+            let index = self.index.len();
+            self.index.insert(node, index);
+            self.low.insert(node, index);
+            self.stack.push(node);
+            self.on_stack.insert(node);
+
+            for &next in self.graph.get(node).into_iter().flatten() {
+                if !self.index.contains_key(next) {
+                    self.visit(next);
+                    let low = self.low[node].min(self.low[next]);
+                    self.low.insert(node, low);
+                } else if self.on_stack.contains(next) {
+                    let low = self.low[node].min(self.index[next]);
+                    self.low.insert(node, low);
+                }
+            }
+
+            if self.low[node] == self.index[node] {
+                let mut component = vec![];
+                while let Some(member) = self.stack.pop() {
+                    self.on_stack.remove(member);
+                    component.push(member.to_string());
+                    if member == node {
+                        break;
+                    }
+                }
+                if component.len() > 1 {
+                    component.sort();
+                    self.components.push(component);
+                }
+            }
+        }
+    }
+
+    let mut tarjan = Tarjan {
+        graph,
+        index: HashMap::new(),
+        low: HashMap::new(),
+        stack: vec![],
+        on_stack: BTreeSet::new(),
+        components: vec![],
+    };
+    for &node in graph.keys() {
+        if !tarjan.index.contains_key(node) {
+            tarjan.visit(node);
+        }
+    }
+    tarjan.components.sort();
+    tarjan.components
+}
+
+/// Shortest path from `start` back to itself inside a component.
+fn cycle_path<'a>(
+    graph: &BTreeMap<&'a str, BTreeSet<&'a str>>,
+    component: &[String],
+    start: &'a str,
+) -> Vec<&'a str> {
+    let mut previous: HashMap<&str, &str> = HashMap::new();
+    let mut queue = VecDeque::from([start]);
+    while let Some(node) = queue.pop_front() {
+        for &next in graph.get(node).into_iter().flatten() {
+            if next == start {
+                let mut path = vec![start, node];
+                let mut current = node;
+                while let Some(&before) = previous.get(current) {
+                    path.push(before);
+                    current = before;
+                }
+                // `path` runs backwards from the closing edge
+                path.reverse();
+                return path;
+            }
+            if component.iter().any(|member| member == next) && !previous.contains_key(next) {
+                previous.insert(next, node);
+                queue.push_back(next);
+            }
+        }
+    }
+    vec![start]
+}
+
+/// Applies the architecture rules to the application's imports.
+pub(crate) fn check_architecture(
+    imports: &[ProjectImport],
+    projects: &[ProjectEntry],
+    config: &ArchitectureConfig,
+) -> Vec<Diagnostic> {
+    let projects: HashMap<&str, &ProjectEntry> = projects
+        .iter()
+        .map(|project| (project.name.as_str(), project))
+        .collect();
+
+    let mut diagnostics = vec![];
+    let mut graph: BTreeMap<&str, BTreeSet<&str>> = BTreeMap::new();
+    let mut first_edge: HashMap<(&str, &str), &ProjectImport> = HashMap::new();
+
+    for import in imports {
+        let (Some(from), Some(to)) = (
+            projects.get(import.from_project.as_str()),
+            projects.get(import.to_project.as_str()),
+        ) else {
+            continue;
+        };
+        for (rule, level, message) in check_import(import, from, to, config) {
+            if level != RuleLevel::Off {
+                diagnostics.push(Diagnostic {
+                    rule,
+                    level,
+                    file: import.file.clone(),
+                    line: import.line,
+                    message,
+                });
+            }
+        }
+        if from.name != to.name {
+            graph
+                .entry(&import.from_project)
+                .or_default()
+                .insert(&import.to_project);
+            first_edge
+                .entry((&import.from_project, &import.to_project))
+                .or_insert(import);
+        }
+    }
+
+    let cycle_level = config.level(NO_CYCLES);
+    if cycle_level != RuleLevel::Off {
+        for component in project_cycles(&graph) {
+            let start = graph
+                .keys()
+                .find(|node| **node == component[0])
+                .copied()
+                .unwrap();
+            let path = cycle_path(&graph, &component, start);
+            let edges: Vec<&ProjectImport> = path
+                .windows(2)
+                .filter_map(|pair| first_edge.get(&(pair[0], pair[1])).copied())
+                .collect();
+            let Some(first) = edges.first() else {
+                continue;
+            };
+            diagnostics.push(Diagnostic {
+                rule: NO_CYCLES.to_string(),
+                level: cycle_level,
+                file: first.file.clone(),
+                line: first.line,
+                message: format!(
+                    "dependency cycle {} ({})",
+                    path.join(" -> "),
+                    edges
+                        .iter()
+                        .map(|edge| format!(
+                            "{} imports {} at {}:{}",
+                            edge.from_project, edge.to_project, edge.file, edge.line
+                        ))
+                        .collect::<Vec<_>>()
+                        .join("; ")
+                ),
+            });
+        }
+    }
+
+    diagnostics.sort_by(|a, b| (&a.file, a.line, &a.rule).cmp(&(&b.file, b.line, &b.rule)));
+    diagnostics
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::{create_dir_all, write};
+
+    use tempfile::tempdir;
+
+    use super::*;
+
+    fn project(name: &str, r#type: ProjectType) -> ProjectEntry {
+        ProjectEntry {
+            r#type,
+            name: name.to_string(),
+            description: String::new(),
+            variant: None,
+            resources: None,
+            routers: None,
+            metadata: None,
+        }
+    }
+
+    fn source(root: &Path, path: &str, content: &str) {
+        let path = root.join(path);
+        create_dir_all(path.parent().unwrap()).unwrap();
+        write(path, content).unwrap();
+    }
+
+    #[test]
+    fn test_architecture_rules() {
+        let dir = tempdir().unwrap();
+        let root = dir.path();
+        let modules = root.join("src/modules");
+        source(
+            &modules,
+            "iam/api/controllers/user.controller.ts",
+            "export const getUser = 1;\n",
+        );
+        source(
+            &modules,
+            "iam/persistence/entities/user.entity.ts",
+            "export class User {}\n",
+        );
+        source(
+            &modules,
+            "billing/services/plan.service.ts",
+            "import { IamSdkClient } from '@app/iam';\n\
+             import { UserRecord } from '@app/iam';\n\
+             import { User } from '../../iam/persistence/entities/user.entity';\n",
+        );
+        source(
+            &modules,
+            "iam/services/user.service.ts",
+            "import type { BillingSdkClient } from '@app/billing';\n",
+        );
+        source(
+            &modules,
+            "mailer/jobs/send.ts",
+            "\n\nimport { getUser } from '@app/iam/api/controllers/user.controller';\n",
+        );
+        source(&modules, "core/index.ts", "export * from '@app/billing';\n");
+
+        let projects = vec![
+            project("iam", ProjectType::Service),
+            project("billing", ProjectType::Service),
+            project("mailer", ProjectType::Worker),
+            project("core", ProjectType::Library),
+        ];
+        let imports = scan_project_imports(root, &modules, "app", &projects, &[]).unwrap();
+        let config = ArchitectureConfig {
+            rules: BTreeMap::from([(NO_CYCLES.to_string(), RuleLevel::Warn)]),
+            ..Default::default()
+        };
+        config.validate().unwrap();
+
+        let diagnostics = check_architecture(&imports, &projects, &config);
+        let found: Vec<(&str, &str, usize, RuleLevel)> = diagnostics
+            .iter()
+            .map(|d| (d.rule.as_str(), d.file.as_str(), d.line, d.level))
+            .collect();
+
+        let billing = "src/modules/billing/services/plan.service.ts";
+        assert!(found.contains(&(SERVICE_SDK_ONLY, billing, 2, RuleLevel::Error)));
+        assert!(found.contains(&(SERVICE_SDK_ONLY, billing, 3, RuleLevel::Error)));
+        assert!(found.contains(&(NO_DEEP_PERSISTENCE, billing, 3, RuleLevel::Error)));
+        assert!(!found.contains(&(SERVICE_SDK_ONLY, billing, 1, RuleLevel::Error)));
+        assert!(found.contains(&(
+            WORKER_NO_CONTROLLERS,
+            "src/modules/mailer/jobs/send.ts",
+            3,
+            RuleLevel::Error
+        )));
+        assert!(found.contains(&(
+            LIBRARY_NO_SERVICES,
+            "src/modules/core/index.ts",
+            1,
+            RuleLevel::Error
+        )));
+        assert!(found.contains(&(NO_CYCLES, billing, 1, RuleLevel::Warn)));
+        let cycle = diagnostics.iter().find(|d| d.rule == NO_CYCLES).unwrap();
+        assert!(
+            cycle
+                .message
+                .starts_with("dependency cycle billing -> iam -> billing")
+        );
+
+        let unknown = ArchitectureConfig {
+            rules: BTreeMap::from([("no-cycle".to_string(), RuleLevel::Off)]),
+            ..Default::default()
+        };
+        assert!(unknown.validate().is_err());
+    }
+
+    #[test]
+    fn test_forbidden_imports() {
+        let import = ProjectImport {
+            file: "src/modules/billing/api/x.ts".to_string(),
+            line: 4,
+            specifier: "@app/iam/domain/schemas".to_string(),
+            names: vec!["UserSchema".to_string()],
+            from_project: "billing".to_string(),
+            to_project: "iam".to_string(),
+            target_path: "domain/schemas".to_string(),
+            is_relative: false,
+        };
+        let projects = vec![
+            project("iam", ProjectType::Service),
+            project("billing", ProjectType::Service),
+        ];
+        let config = ArchitectureConfig {
+            rules: BTreeMap::from([(SERVICE_SDK_ONLY.to_string(), RuleLevel::Off)]),
+            forbid: vec![ForbiddenImport {
+                name: "no-shared-schemas".to_string(),
+                from: "service".to_string(),
+                to: "iam".to_string(),
+                path: Some("domain".to_string()),
+                message: None,
+                level: Some(RuleLevel::Warn),
+            }],
+            ignore: vec![],
+        };
+
+        let diagnostics = check_architecture(&[import], &projects, &config);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].rule, "no-shared-schemas");
+        assert_eq!(diagnostics[0].level, RuleLevel::Warn);
+    }
+}
@@ -1,3 +1,4 @@
+pub(crate) mod analysis;
 pub(crate) mod deletions;
 pub(crate) mod infrastructure;
 pub(crate) mod injections;
@@ -0,0 +1,564 @@
+//! Single-pass analysis of a modules directory.
+//!
+//! Every infrastructure visitor (env vars, runtime dependencies, integrations,
+//! worker config and SDK imports) runs against one parse of each file, files
+//! are parsed in parallel, and results can be kept in `.forklaunch/cache` keyed
+//! by content hash so unchanged files are not parsed again on the next run.
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs,
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicUsize, Ordering},
+    thread,
+};
+
+use anyhow::{Context, Result};
+use oxc_allocator::Allocator;
+use oxc_parser::{Parser, ParserReturn};
+use oxc_span::SourceType;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use walkdir::WalkDir;
+
+use crate::core::{
+    ast::infrastructure::{
+        env::{EnvVarUsage, env_vars_in_program},
+        integrations::{Integration, integrations_in_program, resolve_database_integrations},
+        runtime_deps::{RuntimeDependency, runtime_deps_in_program},
+        service_dependencies::sdk_services_in_program,
+        worker_config::{WorkerConfig, worker_config_in_program},
+    },
+    rendered_template::RenderedTemplatesCache,
+};
+
+/// Bump when a visitor changes what it extracts, so stale cache entries are dropped.
+const ANALYSIS_VERSION: u32 = 1;
+const CACHE_FILE: &str = "ast-analysis.json";
+const REGISTRATIONS_FILE: &str = "registrations.ts";
+
+/// Which files of each project are analyzed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum AnalysisScope {
+    /// Only `<project>/registrations.ts`.
+    Registrations,
+    /// Every `.ts`/`.tsx` source outside `node_modules` and tests.
+    AllSources,
+}
+
+/// Everything the visitors extract from one file.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(crate) struct SourceAnalysis {
+    pub(crate) env_vars: Vec<EnvVarUsage>,
+    pub(crate) runtime_deps: Vec<RuntimeDependency>,
+    pub(crate) integrations: Vec<Integration>,
+    pub(crate) worker_config: Option<WorkerConfig>,
+    pub(crate) sdk_services: Vec<String>,
+}
+
+/// Parses `source` once and runs every visitor over the program.
+pub(crate) fn analyze_source(source: &str) -> SourceAnalysis {
+    let allocator = Allocator::default();
+    let ParserReturn {
+        program, errors, ..
+    } = Parser::new(
+        &allocator,
+        source,
+        SourceType::default().with_typescript(true),
+    )
+    .parse();
+
+    if !errors.is_empty() {
+        log::debug!("TypeScript parse errors during analysis: {:?}", errors);
+    }
+
+    SourceAnalysis {
+        env_vars: env_vars_in_program(&program),
+        runtime_deps: runtime_deps_in_program(&program),
+        integrations: integrations_in_program(&program),
+        // A partially parsed worker config is worse than none.
+        worker_config: if errors.is_empty() {
+            worker_config_in_program(&program)
+        } else {
+            None
+        },
+        sdk_services: sdk_services_in_program(&program),
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct AnalysisCache {
+    version: String,
+    files: BTreeMap<String, CachedAnalysis>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CachedAnalysis {
+    hash: String,
+    analysis: SourceAnalysis,
+}
+
+fn cache_version() -> String {
+    format!("{}-{}", env!("CARGO_PKG_VERSION"), ANALYSIS_VERSION)
+}
+
+fn content_hash(content: &str) -> String {
+    format!("{:x}", Sha256::digest(content.as_bytes()))
+}
+
+/// The application root is the nearest ancestor holding `.forklaunch/manifest.toml`.
+fn find_app_root(modules_path: &Path) -> Option<PathBuf> {
+    modules_path
+        .ancestors()
+        .find(|ancestor| ancestor.join(".forklaunch").join("manifest.toml").is_file())
+        .map(Path::to_path_buf)
+}
+
+fn cache_dir(app_root: &Path) -> PathBuf {
+    app_root.join(".forklaunch").join("cache")
+}
+
+fn cache_key(app_root: &Path, path: &Path) -> String {
+    path.strip_prefix(app_root)
+        .unwrap_or(path)
+        .to_string_lossy()
+        .replace('\\', "/")
+}
+
+impl AnalysisCache {
+    fn load(app_root: &Path) -> Self {
+        let path = cache_dir(app_root).join(CACHE_FILE);
+        let cache = fs::read_to_string(&path)
+            .ok()
+            .and_then(|content| serde_json::from_str::<AnalysisCache>(&content).ok());
+
+        match cache {
+            Some(cache) if cache.version == cache_version() => cache,
+            _ => AnalysisCache {
+                version: cache_version(),
+                files: BTreeMap::new(),
+            },
+        }
+    }
+
+    fn save(&self, app_root: &Path) -> Result<()> {
+        let dir = cache_dir(app_root);
+        fs::create_dir_all(&dir).with_context(|| format!("Failed to create {}", dir.display()))?;
+
+        let gitignore = dir.join(".gitignore");
+        if !gitignore.exists() {
+            fs::write(&gitignore, "*\n")?;
+        }
+
+        let path = dir.join(CACHE_FILE);
+        fs::write(&path, serde_json::to_string(self)?)
+            .with_context(|| format!("Failed to write {}", path.display()))
+    }
+}
+
+/// Analysis results for every project in a modules directory, keyed by project
+/// name and then by file path relative to the project.
+pub(crate) struct ModulesAnalysis {
+    modules_path: PathBuf,
+    projects: BTreeMap<String, BTreeMap<PathBuf, SourceAnalysis>>,
+}
+
+impl ModulesAnalysis {
+    /// Analyzes every file in scope without touching the on-disk cache.
+    pub(crate) fn analyze(
+        modules_path: &Path,
+        rendered_templates_cache: &RenderedTemplatesCache,
+        scope: AnalysisScope,
+    ) -> Result<Self> {
+        Self::run(modules_path, rendered_templates_cache, scope, None)
+    }
+
+    /// Like [`ModulesAnalysis::analyze`], but reuses results from
+    /// `.forklaunch/cache` for files whose content has not changed and writes
+    /// the refreshed cache back.
+    pub(crate) fn analyze_cached(
+        modules_path: &Path,
+        rendered_templates_cache: &RenderedTemplatesCache,
+        scope: AnalysisScope,
+    ) -> Result<Self> {
+        let app_root = find_app_root(modules_path);
+        Self::run(
+            modules_path,
+            rendered_templates_cache,
+            scope,
+            app_root.as_deref(),
+        )
+    }
+
+    fn run(
+        modules_path: &Path,
+        rendered_templates_cache: &RenderedTemplatesCache,
+        scope: AnalysisScope,
+        app_root: Option<&Path>,
+    ) -> Result<Self> {
+        let mut cache = app_root.map(AnalysisCache::load);
+
+        let mut projects: BTreeMap<String, BTreeMap<PathBuf, SourceAnalysis>> = BTreeMap::new();
+        let mut pending = Vec::new();
+
+        for (project, relative, path) in discover_sources(modules_path, scope)? {
+            let Some(template) = rendered_templates_cache.get(&path)? else {
+                continue;
+            };
+            let hash = content_hash(&template.content);
+
+            let cached = cache.as_ref().zip(app_root).and_then(|(cache, app_root)| {
+                cache
+                    .files
+                    .get(&cache_key(app_root, &path))
+                    .filter(|entry| entry.hash == hash)
+            });
+
+            match cached {
+                Some(entry) => {
+                    projects
+                        .entry(project)
+                        .or_default()
+                        .insert(relative, entry.analysis.clone());
+                }
+                None => pending.push((project, relative, path, hash, template.content)),
+            }
+        }
+
+        let sources: Vec<&str> = pending
+            .iter()
+            .map(|(_, _, _, _, content)| content.as_str())
+            .collect();
+        let analyses = analyze_sources(&sources);
+
+        log::debug!(
+            "Analyzed {} file(s) under {}, reused {} from cache",
+            analyses.len(),
+            modules_path.display(),
+            projects.values().map(BTreeMap::len).sum::<usize>()
+        );
+
+        for ((project, relative, path, hash, _), analysis) in pending.into_iter().zip(analyses) {
+            if let (Some(cache), Some(app_root)) = (cache.as_mut(), app_root) {
+                cache.files.insert(
+                    cache_key(app_root, &path),
+                    CachedAnalysis {
+                        hash,
+                        analysis: analysis.clone(),
+                    },
+                );
+            }
+            projects
+                .entry(project)
+                .or_default()
+                .insert(relative, analysis);
+        }
+
+        if let (Some(mut cache), Some(app_root)) = (cache, app_root) {
+            cache.files.retain(|key, _| app_root.join(key).is_file());
+            if let Err(error) = cache.save(app_root) {
+                log::debug!("Failed to save analysis cache: {:#}", error);
+            }
+        }
+
+        Ok(Self {
+            modules_path: modules_path.to_path_buf(),
+            projects,
+        })
+    }
+
+    fn registrations(&self) -> impl Iterator<Item = (&String, &SourceAnalysis)> {
+        self.projects.iter().filter_map(|(project, files)| {
+            files
+                .get(Path::new(REGISTRATIONS_FILE))
+                .map(|analysis| (project, analysis))
+        })
+    }
+
+    /// Env vars read in each project's `registrations.ts`, including projects that read none.
+    pub(crate) fn env_vars(&self) -> HashMap<String, Vec<EnvVarUsage>> {
+        self.registrations()
+            .map(|(project, analysis)| (project.clone(), analysis.env_vars.clone()))
+            .collect()
+    }
+
+    /// Runtime dependencies registered by each project.
+    pub(crate) fn runtime_deps(&self) -> HashMap<String, Vec<RuntimeDependency>> {
+        self.registrations()
+            .filter(|(_, analysis)| !analysis.runtime_deps.is_empty())
+            .map(|(project, analysis)| (project.clone(), analysis.runtime_deps.clone()))
+            .collect()
+    }
+
+    /// Integrations registered by each project, with MikroORM resolved to its database.
+    pub(crate) fn integrations(&self) -> HashMap<String, Vec<Integration>> {
+        self.registrations()
+            .filter(|(_, analysis)| !analysis.integrations.is_empty())
+            .map(|(project, analysis)| {
+                let mut integrations = analysis.integrations.clone();
+                resolve_database_integrations(&self.modules_path.join(project), &mut integrations);
+                (project.clone(), integrations)
+            })
+            .collect()
+    }
+
+    /// Worker configuration for each project that declares one.
+    pub(crate) fn worker_configs(&self) -> HashMap<String, WorkerConfig> {
+        self.registrations()
+            .filter_map(|(project, analysis)| {
+                analysis
+                    .worker_config
+                    .clone()
+                    .map(|config| (project.clone(), config))
+            })
+            .collect()
+    }
+
+    /// Other services each project imports an SDK client from, as
+    /// `(service_name, dependency_type)` pairs sorted by name.
+    pub(crate) fn service_dependencies(&self) -> HashMap<String, Vec<(String, String)>> {
+        let mut all_deps = HashMap::new();
+
+        for (project, files) in &self.projects {
+            let mut deps: Vec<(String, String)> = files
+                .values()
+                .flat_map(|analysis| &analysis.sdk_services)
+                // Don't add self-reference
+                .filter(|service| *service != project)
+                .map(|service| (service.clone(), "network".to_string()))
+                .collect();
+
+            if deps.is_empty() {
+                continue;
+            }
+            deps.sort_by(|a, b| a.0.cmp(&b.0));
+            deps.dedup_by(|a, b| a.0 == b.0);
+            all_deps.insert(project.clone(), deps);
+        }
+
+        all_deps
+    }
+}
+
+/// Lists `(project, path relative to project, absolute path)` for every file in scope.
+fn discover_sources(
+    modules_path: &Path,
+    scope: AnalysisScope,
+) -> Result<Vec<(String, PathBuf, PathBuf)>> {
+    let mut sources = Vec::new();
+
+    if !modules_path.exists() {
+        return Ok(sources);
+    }
+
+    match scope {
+        AnalysisScope::Registrations => {
+            for entry in fs::read_dir(modules_path)? {
+                let path = entry?.path();
+                let registrations_path = path.join(REGISTRATIONS_FILE);
+                if path.is_dir() && registrations_path.exists() {
+                    let project = path
+                        .file_name()
+                        .and_then(|n| n.to_str())
+                        .unwrap_or("unknown")
+                        .to_string();
+                    sources.push((
+                        project,
+                        PathBuf::from(REGISTRATIONS_FILE),
+                        registrations_path,
+                    ));
+                }
+            }
+        }
+        AnalysisScope::AllSources => {
+            let walker = WalkDir::new(modules_path)
+                .min_depth(2)
+                .into_iter()
+                .filter_entry(|entry| entry.file_name() != "node_modules");
+
+            for entry in walker.filter_map(|e| e.ok()) {
+                let path = entry.path();
+                let file_name = entry.file_name().to_string_lossy();
+                if !entry.file_type().is_file()
+                    || !path
+                        .extension()
+                        .is_some_and(|ext| ext == "ts" || ext == "tsx")
+                    || file_name.contains(".test.")
+                    || file_name.contains(".spec.")
+                {
+                    continue;
+                }
+
+                let Ok(relative) = path.strip_prefix(modules_path) else {
+                    continue;
+                };
+                let mut components = relative.components();
+                let Some(project) = components.next() else {
+                    continue;
+                };
+                sources.push((
+                    project.as_os_str().to_string_lossy().to_string(),
+                    components.as_path().to_path_buf(),
+                    path.to_path_buf(),
+                ));
+            }
+        }
+    }
+
+    Ok(sources)
+}
+
+/// Analyzes sources on up to `available_parallelism` threads, preserving order.
+fn analyze_sources(sources: &[&str]) -> Vec<SourceAnalysis> {
+    let workers = thread::available_parallelism()
+        .map_or(1, |n| n.get())
+        .min(sources.len());
+    if workers <= 1 {
+        return sources
+            .iter()
+            .map(|source| analyze_source(source))
+            .collect();
+    }
+
+    let next = AtomicUsize::new(0);
+    let mut results = vec![SourceAnalysis::default(); sources.len()];
+
+    thread::scope(|scope| {
+        let handles: Vec<_> = (0..workers)
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut done = Vec::new();
+                    loop {
+                        let index = next.fetch_add(1, Ordering::Relaxed);
+                        let Some(source) = sources.get(index) else {
+                            break;
+                        };
+                        done.push((index, analyze_source(source)));
+                    }
+                    done
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            let done = handle
+                .join()
+                .unwrap_or_else(|panic| std::panic::resume_unwind(panic));
+            for (index, analysis) in done {
+                results[index] = analysis;
+            }
+        }
+    });
+
+    results
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REGISTRATIONS: &str = r#"
+import { BillingSdkClient } from '@app/billing';
+import { MikroORM } from '@mikro-orm/core';
+
+const port = Number(getEnvVar('PORT'));
+const redisUrl = getEnvVar('REDIS_URL') ?? 'redis://localhost:6379';
+"#;
+
+    fn write_app(root: &Path) -> PathBuf {
+        fs::create_dir_all(root.join(".forklaunch")).unwrap();
+        fs::write(root.join(".forklaunch").join("manifest.toml"), "").unwrap();
+
+        let modules = root.join("src").join("modules");
+        fs::create_dir_all(modules.join("orders").join("api")).unwrap();
+        fs::write(
+            modules.join("orders").join(REGISTRATIONS_FILE),
+            REGISTRATIONS,
+        )
+        .unwrap();
+        fs::write(
+            modules.join("orders").join("api").join("client.ts"),
+            "import { IamSdkClient } from '@app/iam';\n",
+        )
+        .unwrap();
+        fs::write(
+            modules.join("orders").join("api").join("client.test.ts"),
+            "import { PaymentsSdkClient } from '@app/payments';\n",
+        )
+        .unwrap();
+        modules
+    }
+
+    #[test]
+    fn test_runs_every_visitor_on_one_parse() {
+        let analysis = analyze_source(REGISTRATIONS);
+
+        let names: Vec<_> = analysis
+            .env_vars
+            .iter()
+            .map(|v| v.var_name.as_str())
+            .collect();
+        assert_eq!(names, vec!["PORT", "REDIS_URL"]);
+        assert_eq!(analysis.sdk_services, vec!["billing".to_string()]);
+    }
+
+    #[test]
+    fn test_scopes_and_aggregates_projects() {
+        let temp = tempfile::tempdir().unwrap();
+        let modules = write_app(temp.path());
+        let cache = RenderedTemplatesCache::new();
+
+        let registrations =
+            ModulesAnalysis::analyze(&modules, &cache, AnalysisScope::Registrations).unwrap();
+        assert_eq!(registrations.env_vars()["orders"].len(), 2);
+        assert_eq!(
+            registrations.service_dependencies()["orders"],
+            vec![("billing".to_string(), "network".to_string())]
+        );
+
+        let all = ModulesAnalysis::analyze(&modules, &cache, AnalysisScope::AllSources).unwrap();
+        let services: Vec<_> = all.service_dependencies()["orders"]
+            .iter()
+            .map(|(name, _)| name.clone())
+            .collect();
+        assert_eq!(services, vec!["billing".to_string(), "iam".to_string()]);
+    }
+
+    #[test]
+    fn test_cache_reuses_unchanged_files() {
+        let temp = tempfile::tempdir().unwrap();
+        let modules = write_app(temp.path());
+        let cache = RenderedTemplatesCache::new();
+
+        ModulesAnalysis::analyze_cached(&modules, &cache, AnalysisScope::Registrations).unwrap();
+        let cache_path = cache_dir(temp.path()).join(CACHE_FILE);
+        assert!(cache_path.exists());
+        assert!(cache_dir(temp.path()).join(".gitignore").exists());
+
+        // Tamper with the cached entry: it is served as long as the hash matches.
+        let mut stored: AnalysisCache =
+            serde_json::from_str(&fs::read_to_string(&cache_path).unwrap()).unwrap();
+        let entry = stored
+            .files
+            .get_mut("src/modules/orders/registrations.ts")
+            .unwrap();
+        entry.analysis.env_vars.truncate(1);
+        stored.save(temp.path()).unwrap();
+
+        let reused =
+            ModulesAnalysis::analyze_cached(&modules, &cache, AnalysisScope::Registrations)
+                .unwrap();
+        assert_eq!(reused.env_vars()["orders"].len(), 1);
+
+        // Changing the file invalidates its entry.
+        fs::write(
+            modules.join("orders").join(REGISTRATIONS_FILE),
+            format!("{}const key = getEnvVar('API_KEY');\n", REGISTRATIONS),
+        )
+        .unwrap();
+        let refreshed =
+            ModulesAnalysis::analyze_cached(&modules, &cache, AnalysisScope::Registrations)
+                .unwrap();
+        assert_eq!(refreshed.env_vars()["orders"].len(), 3);
+    }
+}
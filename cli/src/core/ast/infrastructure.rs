@@ -1,5 +1,6 @@
 pub(crate) mod database;
 pub(crate) mod env;
+pub(crate) mod express_routes;
 pub(crate) mod integrations;
 pub(crate) mod kafka;
 pub(crate) mod redis;
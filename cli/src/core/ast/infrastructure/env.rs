@@ -1,37 +1,99 @@
-use std::{collections::HashSet, fs, path::Path};
+use std::{
+    collections::{HashMap, HashSet},
+    fs,
+    path::Path,
+};
 
 use anyhow::Result;
 use oxc_allocator::Allocator;
-use oxc_ast::ast::{CallExpression, Expression};
+use oxc_ast::ast::{
+    BinaryExpression, CallExpression, Expression, LogicalExpression, LogicalOperator, Program,
+};
 use oxc_ast_visit::Visit;
 use oxc_parser::{Parser, ParserReturn};
 use oxc_span::SourceType;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use walkdir::WalkDir;
 
-use crate::core::rendered_template::RenderedTemplatesCache;
+use crate::core::{
+    ast::analysis::{AnalysisScope, ModulesAnalysis},
+    env_schema::EnvValueType,
+    rendered_template::RenderedTemplatesCache,
+};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EnvVarUsage {
     pub var_name: String,
     #[allow(dead_code)]
     pub line: usize,
     #[allow(dead_code)]
     pub column: usize,
+    /// Type implied by the call site, e.g. `Number(getEnvVar('PORT'))`.
+    pub value_type: Option<EnvValueType>,
+    /// Set for `getEnvVar('X') ?? fallback`; such variables may be left unset.
+    pub optional: bool,
+    /// The fallback when it is a literal.
+    pub default_value: Option<String>,
 }
 
 pub struct EnvVarVisitor {
     pub env_vars: Vec<EnvVarUsage>,
+    value_types: HashMap<u32, EnvValueType>,
+    fallbacks: HashMap<u32, Option<String>>,
 }
 
 impl EnvVarVisitor {
     pub fn new() -> Self {
         Self {
             env_vars: Vec::new(),
+            value_types: HashMap::new(),
+            fallbacks: HashMap::new(),
         }
     }
 }
 
+fn is_get_env_var_call(call: &CallExpression) -> bool {
+    matches!(&call.callee, Expression::Identifier(ident) if ident.name == "getEnvVar")
+}
+
+/// Span start of a `getEnvVar(...)` call, looking through parentheses and casts.
+fn get_env_var_call_start(expr: &Expression) -> Option<u32> {
+    match expr.get_inner_expression() {
+        Expression::CallExpression(call) if is_get_env_var_call(call) => Some(call.span.start),
+        _ => None,
+    }
+}
+
+fn literal_text(expr: &Expression) -> Option<String> {
+    match expr.get_inner_expression() {
+        Expression::StringLiteral(lit) => Some(lit.value.to_string()),
+        Expression::NumericLiteral(lit) => Some(lit.value.to_string()),
+        Expression::BooleanLiteral(lit) => Some(lit.value.to_string()),
+        _ => None,
+    }
+}
+
 impl<'a> Visit<'a> for EnvVarVisitor {
     fn visit_call_expression(&mut self, call: &CallExpression<'a>) {
+        if let Expression::Identifier(ident) = &call.callee
+            && matches!(ident.name.as_str(), "Number" | "parseInt" | "parseFloat")
+            && let Some(start) = call
+                .arguments
+                .first()
+                .and_then(|arg| arg.as_expression())
+                .and_then(get_env_var_call_start)
+        {
+            self.value_types.insert(start, EnvValueType::Number);
+        }
+
+        if let Expression::StaticMemberExpression(member) = &call.callee
+            && member.property.name == "split"
+            && let Some(start) = get_env_var_call_start(&member.object)
+        {
+            self.value_types.insert(start, EnvValueType::List);
+        }
+
         if let Expression::Identifier(ident) = &call.callee {
             if ident.name == "getEnvVar" {
                 if let Some(arg) = call.arguments.first() {
@@ -39,11 +101,15 @@ impl<'a> Visit<'a> for EnvVarVisitor {
                         let var_name = str_lit.value.to_string();
 
                         let line = str_lit.span.start as usize;
+                        let fallback = self.fallbacks.get(&call.span.start);
 
                         self.env_vars.push(EnvVarUsage {
                             var_name,
                             line,
                             column: 0,
+                            value_type: self.value_types.get(&call.span.start).copied(),
+                            optional: fallback.is_some(),
+                            default_value: fallback.cloned().flatten(),
                         });
                     }
                 }
@@ -52,15 +118,31 @@ impl<'a> Visit<'a> for EnvVarVisitor {
 
         oxc_ast_visit::walk::walk_call_expression(self, call);
     }
-}
 
-pub fn extract_env_vars_from_file(
-    file_path: &Path,
-    rendered_templates_cache: &RenderedTemplatesCache,
-) -> Result<Vec<EnvVarUsage>> {
-    let source_code = rendered_templates_cache.get(file_path)?.unwrap().content;
+    fn visit_logical_expression(&mut self, expr: &LogicalExpression<'a>) {
+        if matches!(expr.operator, LogicalOperator::Coalesce | LogicalOperator::Or)
+            && let Some(start) = get_env_var_call_start(&expr.left)
+        {
+            self.fallbacks.insert(start, literal_text(&expr.right));
+        }
+
+        oxc_ast_visit::walk::walk_logical_expression(self, expr);
+    }
 
-    extract_env_vars_from_source(&source_code)
+    fn visit_binary_expression(&mut self, expr: &BinaryExpression<'a>) {
+        if expr.operator.is_equality() {
+            let pairs = [(&expr.left, &expr.right), (&expr.right, &expr.left)];
+            for (call, other) in pairs {
+                if let Some(start) = get_env_var_call_start(call)
+                    && matches!(literal_text(other).as_deref(), Some("true" | "false"))
+                {
+                    self.value_types.insert(start, EnvValueType::Boolean);
+                }
+            }
+        }
+
+        oxc_ast_visit::walk::walk_binary_expression(self, expr);
+    }
 }
 
 pub fn extract_env_vars_from_source(source_code: &str) -> Result<Vec<EnvVarUsage>> {
@@ -79,63 +161,71 @@ pub fn extract_env_vars_from_source(source_code: &str) -> Result<Vec<EnvVarUsage
         log::debug!("TypeScript parse errors during env scan: {:?}", errors);
     }
 
-    let mut visitor = EnvVarVisitor::new();
-    visitor.visit_program(&program);
+    Ok(env_vars_in_program(&program))
+}
 
-    Ok(visitor.env_vars)
+pub(crate) fn env_vars_in_program(program: &Program) -> Vec<EnvVarUsage> {
+    let mut visitor = EnvVarVisitor::new();
+    visitor.visit_program(program);
+    visitor.env_vars
 }
 
 pub fn find_all_env_vars(
     modules_path: &Path,
     rendered_templates_cache: &RenderedTemplatesCache,
-) -> Result<std::collections::HashMap<String, Vec<EnvVarUsage>>> {
-    let mut all_env_vars = std::collections::HashMap::new();
-
-    let registrations_files = find_registrations_files(modules_path)?;
-
-    for file_path in registrations_files {
-        let project_name = get_project_name_from_path(&file_path)?;
-        let env_vars = extract_env_vars_from_file(&file_path, rendered_templates_cache)?;
-        all_env_vars.insert(project_name, env_vars);
-    }
-
-    Ok(all_env_vars)
+) -> Result<HashMap<String, Vec<EnvVarUsage>>> {
+    Ok(ModulesAnalysis::analyze(
+        modules_path,
+        rendered_templates_cache,
+        AnalysisScope::Registrations,
+    )?
+    .env_vars())
 }
 
-fn find_registrations_files(modules_path: &Path) -> Result<Vec<std::path::PathBuf>> {
-    let mut registrations_files = Vec::new();
+const SKIPPED_SOURCE_DIRS: &[&str] = &["node_modules", "dist", "build", "coverage", ".git"];
 
-    if !modules_path.exists() {
-        return Ok(registrations_files);
-    }
+/// Collects every variable a project's sources read, through `getEnvVar` in
+/// any file (not just `registrations.ts`) or directly from `process.env`.
+pub fn find_env_var_references(project_path: &Path) -> Result<HashSet<String>> {
+    let mut references = HashSet::new();
+    let process_env = Regex::new(r#"process\.env(?:\.([A-Za-z_][A-Za-z0-9_]*)|\[\s*['"]([A-Za-z_][A-Za-z0-9_]*)['"]\s*\])"#)?;
+
+    let walker = WalkDir::new(project_path).into_iter().filter_entry(|entry| {
+        entry.depth() == 0
+            || !entry
+                .file_name()
+                .to_str()
+                .is_some_and(|name| SKIPPED_SOURCE_DIRS.contains(&name))
+    });
 
-    for entry in fs::read_dir(modules_path)? {
-        let entry = entry?;
+    for entry in walker.filter_map(|entry| entry.ok()) {
         let path = entry.path();
+        let is_source = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .is_some_and(|ext| matches!(ext, "ts" | "mts" | "cts" | "js" | "mjs" | "cjs"));
+        if !is_source || !path.is_file() {
+            continue;
+        }
 
-        if path.is_dir() {
-            let registrations_path = path.join("registrations.ts");
-            if registrations_path.exists() {
-                registrations_files.push(registrations_path);
+        let Ok(source_code) = fs::read_to_string(path) else {
+            continue;
+        };
+        if source_code.contains("getEnvVar") {
+            references.extend(
+                extract_env_vars_from_source(&source_code)?
+                    .into_iter()
+                    .map(|usage| usage.var_name),
+            );
+        }
+        for captures in process_env.captures_iter(&source_code) {
+            if let Some(name) = captures.get(1).or_else(|| captures.get(2)) {
+                references.insert(name.as_str().to_string());
             }
         }
     }
 
-    Ok(registrations_files)
-}
-
-fn get_project_name_from_path(file_path: &Path) -> Result<String> {
-    let parent = file_path
-        .parent()
-        .ok_or_else(|| anyhow::anyhow!("Invalid file path"))?;
-
-    let project_name = parent
-        .file_name()
-        .ok_or_else(|| anyhow::anyhow!("Could not extract project name"))?
-        .to_string_lossy()
-        .to_string();
-
-    Ok(project_name)
+    Ok(references)
 }
 
 #[allow(dead_code)]
@@ -180,6 +270,10 @@ mod tests {
         let var_names: HashSet<_> = env_vars.iter().map(|v| &v.var_name).collect();
         assert!(var_names.contains(&"HOST".to_string()));
         assert!(var_names.contains(&"PORT".to_string()));
+
+        let port = env_vars.iter().find(|v| v.var_name == "PORT").unwrap();
+        assert_eq!(port.value_type, Some(EnvValueType::Number));
+        assert!(!port.optional);
     }
 
     #[test]
@@ -205,5 +299,19 @@ mod tests {
         let var_names: HashSet<_> = env_vars.iter().map(|v| &v.var_name).collect();
         assert!(var_names.contains(&"VERSION".to_string()));
         assert!(var_names.contains(&"CORS_ORIGINS".to_string()));
+
+        let version = env_vars.iter().find(|v| v.var_name == "VERSION").unwrap();
+        assert!(version.optional);
+        assert_eq!(version.default_value.as_deref(), Some("v1"));
+        let cors = env_vars.iter().find(|v| v.var_name == "CORS_ORIGINS").unwrap();
+        assert_eq!(cors.value_type, Some(EnvValueType::List));
+    }
+
+    #[test]
+    fn test_extract_env_vars_boolean_comparison() {
+        let source = "const enabled = getEnvVar('FEATURE_ENABLED') === 'true';";
+
+        let env_vars = extract_env_vars_from_source(source).unwrap();
+        assert_eq!(env_vars[0].value_type, Some(EnvValueType::Boolean));
     }
 }
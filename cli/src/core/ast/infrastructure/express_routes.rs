@@ -0,0 +1,517 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::Result;
+use oxc_allocator::Allocator;
+use oxc_ast::ast::{
+    Argument, BindingPatternKind, CallExpression, Expression, ImportDeclaration,
+    ImportDeclarationSpecifier, VariableDeclarator,
+};
+use oxc_ast_visit::Visit;
+use oxc_parser::{Parser, ParserReturn};
+use oxc_span::SourceType;
+use walkdir::WalkDir;
+
+const HTTP_METHODS: &[&str] = &[
+    "get", "post", "put", "patch", "delete", "options", "head", "all",
+];
+
+const SKIPPED_SOURCE_DIRS: &[&str] = &["node_modules", "dist", "build", "coverage", ".git"];
+
+const MAX_MOUNT_DEPTH: usize = 8;
+
+const SOURCE_EXTENSIONS: &[&str] = &["ts", "mts", "cts", "js", "mjs", "cjs"];
+
+/// A route registered directly on an Express or Hyper-Express app or router.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ExpressRoute {
+    pub method: String,
+    pub path: String,
+    /// The app or router the route is registered on.
+    pub receiver: String,
+}
+
+/// `receiver.use('/prefix', target)` where `target` is an identifier.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExpressMount {
+    pub receiver: String,
+    pub prefix: String,
+    pub target: String,
+}
+
+/// Routes, mounts and module imports found in one source file.
+#[derive(Debug, Default)]
+pub struct ExpressFileAnalysis {
+    pub routes: Vec<ExpressRoute>,
+    pub mounts: Vec<ExpressMount>,
+    /// Local binding name to module specifier, from `import` and `require`.
+    pub imports: BTreeMap<String, String>,
+    /// Every module specifier imported or required by the file.
+    pub modules: BTreeSet<String>,
+    /// Whether the file calls `.listen(...)`.
+    pub listens: bool,
+}
+
+fn static_string(expr: &Expression) -> Option<String> {
+    match expr.get_inner_expression() {
+        Expression::StringLiteral(lit) => Some(lit.value.to_string()),
+        Expression::TemplateLiteral(template) if template.expressions.is_empty() => template
+            .quasis
+            .first()
+            .and_then(|quasi| quasi.value.cooked.as_ref())
+            .map(|cooked| cooked.to_string()),
+        _ => None,
+    }
+}
+
+fn first_string_argument(call: &CallExpression) -> Option<String> {
+    call.arguments
+        .first()
+        .and_then(Argument::as_expression)
+        .and_then(static_string)
+}
+
+fn receiver_name(expr: &Expression) -> String {
+    match expr.get_inner_expression() {
+        Expression::Identifier(ident) => ident.name.to_string(),
+        Expression::StaticMemberExpression(member) => {
+            format!("{}.{}", receiver_name(&member.object), member.property.name)
+        }
+        Expression::ThisExpression(_) => "this".to_string(),
+        _ => "<expression>".to_string(),
+    }
+}
+
+fn require_specifier(expr: &Expression) -> Option<String> {
+    match expr.get_inner_expression() {
+        Expression::CallExpression(call) if matches!(&call.callee, Expression::Identifier(ident) if ident.name == "require") => {
+            first_string_argument(call)
+        }
+        _ => None,
+    }
+}
+
+impl<'a> Visit<'a> for ExpressFileAnalysis {
+    fn visit_import_declaration(&mut self, decl: &ImportDeclaration<'a>) {
+        let source = decl.source.value.to_string();
+        if let Some(specifiers) = &decl.specifiers {
+            for specifier in specifiers {
+                let local = match specifier {
+                    ImportDeclarationSpecifier::ImportSpecifier(spec) => &spec.local.name,
+                    ImportDeclarationSpecifier::ImportDefaultSpecifier(spec) => &spec.local.name,
+                    ImportDeclarationSpecifier::ImportNamespaceSpecifier(spec) => &spec.local.name,
+                };
+                self.imports.insert(local.to_string(), source.clone());
+            }
+        }
+        self.modules.insert(source);
+
+        oxc_ast_visit::walk::walk_import_declaration(self, decl);
+    }
+
+    fn visit_variable_declarator(&mut self, declarator: &VariableDeclarator<'a>) {
+        if let BindingPatternKind::BindingIdentifier(ident) = &declarator.id.kind
+            && let Some(source) = declarator.init.as_ref().and_then(require_specifier)
+        {
+            self.imports.insert(ident.name.to_string(), source);
+        }
+
+        oxc_ast_visit::walk::walk_variable_declarator(self, declarator);
+    }
+
+    fn visit_call_expression(&mut self, call: &CallExpression<'a>) {
+        if let Expression::Identifier(ident) = &call.callee
+            && ident.name == "require"
+            && let Some(source) = first_string_argument(call)
+        {
+            self.modules.insert(source);
+        }
+
+        if let Expression::StaticMemberExpression(member) = &call.callee {
+            let method = member.property.name.as_str();
+
+            if HTTP_METHODS.contains(&method) && !call.arguments.is_empty() {
+                // `app.get('/path', handler)` or `router.route('/path').get(handler)`
+                let route = match member.object.get_inner_expression() {
+                    Expression::CallExpression(inner) if matches!(&inner.callee, Expression::StaticMemberExpression(m) if m.property.name == "route") =>
+                    {
+                        let Expression::StaticMemberExpression(route_member) = &inner.callee else {
+                            unreachable!()
+                        };
+                        first_string_argument(inner)
+                            .map(|path| (path, receiver_name(&route_member.object)))
+                    }
+                    object => first_string_argument(call)
+                        .filter(|path| {
+                            call.arguments.len() >= 2 && (path.starts_with('/') || path == "*")
+                        })
+                        .map(|path| (path, receiver_name(object))),
+                };
+                if let Some((path, receiver)) = route {
+                    self.routes.push(ExpressRoute {
+                        method: method.to_uppercase(),
+                        path,
+                        receiver,
+                    });
+                }
+            }
+
+            if method == "use"
+                && let Some(prefix) = first_string_argument(call)
+            {
+                for argument in call.arguments.iter().skip(1) {
+                    if let Some(Expression::Identifier(target)) =
+                        argument.as_expression().map(|e| e.get_inner_expression())
+                    {
+                        self.mounts.push(ExpressMount {
+                            receiver: receiver_name(&member.object),
+                            prefix: prefix.clone(),
+                            target: target.name.to_string(),
+                        });
+                    }
+                }
+            }
+
+            if method == "listen" {
+                self.listens = true;
+            }
+        }
+
+        oxc_ast_visit::walk::walk_call_expression(self, call);
+    }
+}
+
+pub fn analyze_express_source(source_code: &str) -> ExpressFileAnalysis {
+    let allocator = Allocator::default();
+
+    let ParserReturn {
+        program, errors, ..
+    } = Parser::new(
+        &allocator,
+        source_code,
+        SourceType::default().with_typescript(true),
+    )
+    .parse();
+
+    if !errors.is_empty() {
+        log::debug!("TypeScript parse errors during route scan: {:?}", errors);
+    }
+
+    let mut analysis = ExpressFileAnalysis::default();
+    analysis.visit_program(&program);
+    analysis
+}
+
+/// A route with the mount prefixes of the files that mount it applied.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ResolvedRoute {
+    pub method: String,
+    pub path: String,
+    /// Source file relative to the project root.
+    pub file: PathBuf,
+}
+
+/// Whole-project result of [`analyze_express_project`].
+#[derive(Debug, Default)]
+pub struct ExpressProjectAnalysis {
+    pub routes: Vec<ResolvedRoute>,
+    /// Every module specifier the project imports.
+    pub modules: BTreeSet<String>,
+    /// Files calling `.listen(...)`, relative to the project root.
+    pub entrypoints: Vec<PathBuf>,
+    /// `.use(...)` targets that could not be traced to a project file.
+    pub unresolved_mounts: Vec<String>,
+}
+
+fn resolve_relative_module(
+    from_file: &Path,
+    specifier: &str,
+    files: &BTreeSet<PathBuf>,
+) -> Option<PathBuf> {
+    if !specifier.starts_with('.') {
+        return None;
+    }
+    let base = from_file.parent()?.join(specifier);
+    let base = normalize(&base);
+    let without_js = base.with_extension("");
+
+    let mut candidates = vec![base.clone()];
+    for stem in [&base, &without_js] {
+        for extension in SOURCE_EXTENSIONS {
+            candidates.push(stem.with_extension(extension));
+            candidates.push(stem.join(format!("index.{}", extension)));
+        }
+    }
+    candidates
+        .into_iter()
+        .find(|candidate| files.contains(candidate))
+}
+
+fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            std::path::Component::ParentDir => {
+                normalized.pop();
+            }
+            std::path::Component::CurDir => {}
+            other => normalized.push(other),
+        }
+    }
+    normalized
+}
+
+fn join_route_path(prefix: &str, path: &str) -> String {
+    let joined = format!(
+        "{}/{}",
+        prefix.trim_end_matches('/'),
+        path.trim_start_matches('/')
+    );
+    let trimmed = joined.trim_end_matches('/');
+    if trimmed.is_empty() {
+        "/".to_string()
+    } else {
+        trimmed.to_string()
+    }
+}
+
+/// Follows nested routers: a router mounted under `/api` that itself mounts
+/// `/users` puts the inner file's routes under `/api/users`.
+fn collect_mount_prefixes(
+    file: &Path,
+    suffix: &str,
+    mounted_by: &BTreeMap<PathBuf, Vec<(PathBuf, String)>>,
+    depth: usize,
+    prefixes: &mut BTreeSet<String>,
+) {
+    match mounted_by.get(file) {
+        Some(parents) if depth < MAX_MOUNT_DEPTH => {
+            for (parent, prefix) in parents {
+                let joined = join_route_path(prefix, suffix);
+                collect_mount_prefixes(parent, &joined, mounted_by, depth + 1, prefixes);
+            }
+        }
+        _ if depth > 0 => {
+            prefixes.insert(suffix.to_string());
+        }
+        _ => {}
+    }
+}
+
+/// Scans every source file of a plain Express or Hyper-Express project for
+/// routes and follows `app.use('/prefix', router)` through relative imports
+/// so that routes declared in router modules get their full path.
+pub fn analyze_express_project(project_path: &Path) -> Result<ExpressProjectAnalysis> {
+    let walker = WalkDir::new(project_path)
+        .into_iter()
+        .filter_entry(|entry| {
+            entry.depth() == 0
+                || !entry
+                    .file_name()
+                    .to_str()
+                    .is_some_and(|name| SKIPPED_SOURCE_DIRS.contains(&name))
+        });
+
+    let mut analyses: BTreeMap<PathBuf, ExpressFileAnalysis> = BTreeMap::new();
+    for entry in walker.filter_map(|entry| entry.ok()) {
+        let path = entry.path();
+        let is_source = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .is_some_and(|ext| SOURCE_EXTENSIONS.contains(&ext))
+            && !path.to_string_lossy().ends_with(".d.ts");
+        if !is_source || !path.is_file() {
+            continue;
+        }
+        let Ok(source_code) = fs::read_to_string(path) else {
+            continue;
+        };
+        let relative = path
+            .strip_prefix(project_path)
+            .unwrap_or(path)
+            .to_path_buf();
+        analyses.insert(relative, analyze_express_source(&source_code));
+    }
+
+    let files: BTreeSet<PathBuf> = analyses.keys().cloned().collect();
+
+    // For every router file, the files that mount it and under which prefix.
+    let mut mounted_by: BTreeMap<PathBuf, Vec<(PathBuf, String)>> = BTreeMap::new();
+    let mut unresolved_mounts = vec![];
+    for (file, analysis) in &analyses {
+        for mount in &analysis.mounts {
+            let target_file = analysis
+                .imports
+                .get(&mount.target)
+                .and_then(|specifier| resolve_relative_module(file, specifier, &files));
+            match target_file {
+                Some(target_file) => mounted_by
+                    .entry(target_file)
+                    .or_default()
+                    .push((file.clone(), mount.prefix.clone())),
+                // Routers declared and mounted in the same file are handled
+                // per route below.
+                None if analysis.routes.iter().any(|r| r.receiver == mount.target) => {}
+                None => unresolved_mounts.push(format!(
+                    "{}.use('{}', {}) in {}",
+                    mount.receiver,
+                    mount.prefix,
+                    mount.target,
+                    file.display()
+                )),
+            }
+        }
+    }
+
+    let mut analysis = ExpressProjectAnalysis {
+        unresolved_mounts,
+        ..Default::default()
+    };
+    for (file, file_analysis) in &analyses {
+        analysis
+            .modules
+            .extend(file_analysis.modules.iter().cloned());
+        if file_analysis.listens {
+            analysis.entrypoints.push(file.clone());
+        }
+
+        let mut file_prefixes = BTreeSet::new();
+        collect_mount_prefixes(file, "", &mounted_by, 0, &mut file_prefixes);
+        for route in &file_analysis.routes {
+            // Routes registered on a router mounted in the same file pick
+            // up that mount's prefix.
+            let local_prefixes: Vec<&String> = file_analysis
+                .mounts
+                .iter()
+                .filter(|mount| mount.target == route.receiver)
+                .map(|mount| &mount.prefix)
+                .collect();
+
+            let mut paths = BTreeSet::new();
+            if file_prefixes.is_empty() && local_prefixes.is_empty() {
+                paths.insert(join_route_path("", &route.path));
+            }
+            for prefix in &file_prefixes {
+                paths.insert(join_route_path(prefix, &route.path));
+            }
+            for prefix in local_prefixes {
+                paths.insert(join_route_path(prefix, &route.path));
+            }
+            for path in paths {
+                analysis.routes.push(ResolvedRoute {
+                    method: route.method.clone(),
+                    path,
+                    file: file.clone(),
+                });
+            }
+        }
+    }
+    analysis.routes.sort();
+    analysis.routes.dedup();
+
+    Ok(analysis)
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+
+    #[test]
+    fn test_analyze_express_source() {
+        let analysis = analyze_express_source(
+            r#"
+            import express from 'express';
+            import usersRouter from './routes/users';
+            const orders = require('./routes/orders');
+
+            const app = express();
+            app.get('/health', (req, res) => res.send('ok'));
+            app.route(`/items`).post(createItem);
+            app.use(express.json());
+            app.use('/users', authenticate, usersRouter);
+            app.listen(3000);
+            "#,
+        );
+
+        assert_eq!(
+            analysis.routes,
+            vec![
+                ExpressRoute {
+                    method: "GET".to_string(),
+                    path: "/health".to_string(),
+                    receiver: "app".to_string(),
+                },
+                ExpressRoute {
+                    method: "POST".to_string(),
+                    path: "/items".to_string(),
+                    receiver: "app".to_string(),
+                },
+            ]
+        );
+        assert_eq!(
+            analysis
+                .mounts
+                .iter()
+                .map(|m| m.target.as_str())
+                .collect::<Vec<_>>(),
+            vec!["authenticate", "usersRouter"]
+        );
+        assert_eq!(
+            analysis.imports.get("orders"),
+            Some(&"./routes/orders".to_string())
+        );
+        assert!(analysis.modules.contains("express"));
+        assert!(analysis.listens);
+    }
+
+    #[test]
+    fn test_analyze_express_project_resolves_mount_prefixes() {
+        let temp_dir = TempDir::new().unwrap();
+        let project = temp_dir.path();
+        fs::create_dir_all(project.join("src/routes")).unwrap();
+        fs::create_dir_all(project.join("node_modules/express")).unwrap();
+        fs::write(
+            project.join("src/server.ts"),
+            "import express from 'express';\nimport api from './routes';\nconst app = express();\napp.use('/api', api);\napp.listen(3000);\n",
+        )
+        .unwrap();
+        fs::write(
+            project.join("src/routes/index.ts"),
+            "import { Router } from 'express';\nimport users from './users.js';\nconst router = Router();\nrouter.use('/users', users);\nexport default router;\n",
+        )
+        .unwrap();
+        fs::write(
+            project.join("src/routes/users.ts"),
+            "import { Router } from 'express';\nimport { PrismaClient } from '@prisma/client';\nconst router = Router();\nrouter.get('/', list);\nrouter.get('/:id', get);\nexport default router;\n",
+        )
+        .unwrap();
+        fs::write(
+            project.join("node_modules/express/index.js"),
+            "app.get('/ignored', x);",
+        )
+        .unwrap();
+
+        let analysis = analyze_express_project(project).unwrap();
+
+        let routes: Vec<(String, String)> = analysis
+            .routes
+            .iter()
+            .map(|r| (r.method.clone(), r.path.clone()))
+            .collect();
+        assert_eq!(
+            routes,
+            vec![
+                ("GET".to_string(), "/api/users".to_string()),
+                ("GET".to_string(), "/api/users/:id".to_string()),
+            ]
+        );
+        assert_eq!(analysis.entrypoints, vec![PathBuf::from("src/server.ts")]);
+        assert!(analysis.modules.contains("@prisma/client"));
+        assert!(analysis.unresolved_mounts.is_empty());
+    }
+}
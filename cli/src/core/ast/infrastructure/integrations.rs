@@ -1,19 +1,13 @@
-use std::{collections::HashMap, fs, path::Path};
+use std::{collections::HashMap, path::Path};
 
-use anyhow::Result;
-use oxc_allocator::Allocator;
-use oxc_ast::ast::{ObjectExpression, ObjectProperty, PropertyKey, VariableDeclaration};
+use oxc_ast::ast::{ObjectExpression, ObjectProperty, Program, PropertyKey, VariableDeclaration};
 use oxc_ast_visit::Visit;
-use oxc_parser::{Parser, ParserReturn};
-use oxc_span::SourceType;
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
-use crate::core::{
-    rendered_template::RenderedTemplatesCache,
-    sync::detection::detect_database_from_mikro_orm_config,
-};
+use crate::core::sync::detection::detect_database_from_mikro_orm_config;
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Integration {
     pub id: String,
     pub integration_type: String,
@@ -201,107 +195,52 @@ impl<'a> Visit<'a> for IntegrationsVisitor {
     }
 }
 
-pub fn extract_integrations_from_file(
-    file_path: &Path,
-    rendered_templates_cache: &RenderedTemplatesCache,
-) -> Result<Vec<Integration>> {
-    let source_code = rendered_templates_cache.get(file_path)?.unwrap().content;
-
-    extract_integrations_from_source(&source_code)
-}
-
-pub fn extract_integrations_from_source(source_code: &str) -> Result<Vec<Integration>> {
-    let allocator = Allocator::default();
-
-    let ParserReturn {
-        program, errors, ..
-    } = Parser::new(
-        &allocator,
-        source_code,
-        SourceType::default().with_typescript(true),
-    )
-    .parse();
-
-    if !errors.is_empty() {
-        log::debug!(
-            "TypeScript parse errors during integrations scan: {:?}",
-            errors
-        );
-    }
-
+pub(crate) fn integrations_in_program(program: &Program) -> Vec<Integration> {
     let mut visitor = IntegrationsVisitor::new();
-    visitor.visit_program(&program);
-
-    Ok(visitor.integrations)
+    visitor.visit_program(program);
+    visitor.integrations
 }
 
-pub fn find_all_integrations(
-    modules_path: &Path,
-    rendered_templates_cache: &RenderedTemplatesCache,
-) -> Result<HashMap<String, Vec<Integration>>> {
-    let mut all_integrations = HashMap::new();
-
-    if !modules_path.exists() {
-        return Ok(all_integrations);
-    }
-
-    for entry in fs::read_dir(modules_path)? {
-        let entry = entry?;
-        let path = entry.path();
-
-        if path.is_dir() {
-            let registrations_path = path.join("registrations.ts");
-            if registrations_path.exists() {
-                let project_name = path
-                    .file_name()
-                    .and_then(|n| n.to_str())
-                    .unwrap_or("unknown")
-                    .to_string();
-
-                let mut integrations =
-                    extract_integrations_from_file(&registrations_path, rendered_templates_cache)?;
-
-                // For MikroORM integrations, replace the ID with the database type
-                for integration in &mut integrations {
-                    if integration.id.to_ascii_lowercase().contains("mikro")
-                        && integration.id.to_ascii_lowercase().contains("orm")
-                    {
-                        // Extract database type from mikro-orm.config.ts
-                        if let Ok(Some(database)) = detect_database_from_mikro_orm_config(&path) {
-                            // Convert database enum to display name and technology
-                            let (db_name, db_technology) = match database {
-                                crate::constants::Database::PostgreSQL => {
-                                    ("PostgreSQL", "postgresql")
-                                }
-                                crate::constants::Database::MySQL => ("MySQL", "mysql"),
-                                crate::constants::Database::MariaDB => ("MariaDB", "mariadb"),
-                                crate::constants::Database::MsSQL => ("MsSQL", "mssql"),
-                                crate::constants::Database::MongoDB => ("MongoDB", "mongodb"),
-                                crate::constants::Database::SQLite => ("SQLite", "sqlite"),
-                                crate::constants::Database::BetterSQLite => {
-                                    ("Better SQLite", "better-sqlite")
-                                }
-                                crate::constants::Database::LibSQL => ("LibSQL", "libsql"),
-                            };
-                            integration.id = db_name.to_string();
-                            integration.technology = Some(db_technology.to_string());
-                        }
-                    }
-                }
-
-                if !integrations.is_empty() {
-                    all_integrations.insert(project_name, integrations);
-                }
+/// Replaces a MikroORM integration's id with the database configured in the
+/// project's `mikro-orm.config.ts`.
+pub(crate) fn resolve_database_integrations(
+    project_path: &Path,
+    integrations: &mut [Integration],
+) {
+    for integration in integrations {
+        if integration.id.to_ascii_lowercase().contains("mikro")
+            && integration.id.to_ascii_lowercase().contains("orm")
+        {
+            // Extract database type from mikro-orm.config.ts
+            if let Ok(Some(database)) = detect_database_from_mikro_orm_config(project_path) {
+                // Convert database enum to display name and technology
+                let (db_name, db_technology) = match database {
+                    crate::constants::Database::PostgreSQL => ("PostgreSQL", "postgresql"),
+                    crate::constants::Database::MySQL => ("MySQL", "mysql"),
+                    crate::constants::Database::MariaDB => ("MariaDB", "mariadb"),
+                    crate::constants::Database::MsSQL => ("MsSQL", "mssql"),
+                    crate::constants::Database::MongoDB => ("MongoDB", "mongodb"),
+                    crate::constants::Database::SQLite => ("SQLite", "sqlite"),
+                    crate::constants::Database::BetterSQLite => ("Better SQLite", "better-sqlite"),
+                    crate::constants::Database::LibSQL => ("LibSQL", "libsql"),
+                };
+                integration.id = db_name.to_string();
+                integration.technology = Some(db_technology.to_string());
             }
         }
     }
-
-    Ok(all_integrations)
 }
 
 #[cfg(test)]
 mod tests {
+    use anyhow::Result;
+
     use super::*;
+    use crate::core::ast::analysis::analyze_source;
+
+    fn extract_integrations_from_source(source: &str) -> Result<Vec<Integration>> {
+        Ok(analyze_source(source).integrations)
+    }
 
     #[test]
     fn test_extract_integrations() {
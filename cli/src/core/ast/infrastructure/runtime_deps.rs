@@ -1,15 +1,9 @@
-use std::{collections::HashSet, fs, path::Path};
+use std::collections::HashSet;
 
-use anyhow::Result;
-use oxc_allocator::Allocator;
-use oxc_ast::ast::{ObjectProperty, PropertyKey, VariableDeclaration};
+use oxc_ast::ast::{ObjectProperty, Program, PropertyKey, VariableDeclaration};
 use oxc_ast_visit::Visit;
-use oxc_parser::{Parser, ParserReturn};
-use oxc_span::SourceType;
 use serde::{Deserialize, Serialize};
 
-use crate::core::rendered_template::RenderedTemplatesCache;
-
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum ResourceType {
@@ -32,7 +26,7 @@ impl ResourceType {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct RuntimeDependency {
     pub name: String,
     pub resource_type: ResourceType,
@@ -119,87 +113,10 @@ impl<'a> Visit<'a> for RuntimeDepsVisitor {
     }
 }
 
-pub fn extract_runtime_deps_from_file(
-    file_path: &Path,
-    rendered_templates_cache: &RenderedTemplatesCache,
-) -> Result<Vec<RuntimeDependency>> {
-    let source_code = rendered_templates_cache.get(file_path)?.unwrap().content;
-
-    extract_runtime_deps_from_source(&source_code)
-}
-
-pub fn extract_runtime_deps_from_source(source_code: &str) -> Result<Vec<RuntimeDependency>> {
-    let allocator = Allocator::default();
-
-    let ParserReturn {
-        program, errors, ..
-    } = Parser::new(
-        &allocator,
-        source_code,
-        SourceType::default().with_typescript(true),
-    )
-    .parse();
-
-    if !errors.is_empty() {
-        log::debug!(
-            "TypeScript parse errors during runtime deps scan: {:?}",
-            errors
-        );
-    }
-
+pub(crate) fn runtime_deps_in_program(program: &Program) -> Vec<RuntimeDependency> {
     let mut visitor = RuntimeDepsVisitor::new();
-    visitor.visit_program(&program);
-
-    Ok(visitor.dependencies)
-}
-
-pub fn find_all_runtime_deps(
-    modules_path: &Path,
-    rendered_templates_cache: &RenderedTemplatesCache,
-) -> Result<std::collections::HashMap<String, Vec<RuntimeDependency>>> {
-    let mut all_deps = std::collections::HashMap::new();
-
-    if !modules_path.exists() {
-        return Ok(all_deps);
-    }
-
-    for entry in fs::read_dir(modules_path)? {
-        let entry = entry?;
-        let path = entry.path();
-
-        if path.is_dir() {
-            let registrations_path = path.join("registrations.ts");
-            if registrations_path.exists() {
-                let project_name = path
-                    .file_name()
-                    .and_then(|n| n.to_str())
-                    .unwrap_or("unknown")
-                    .to_string();
-
-                let deps =
-                    extract_runtime_deps_from_file(&registrations_path, rendered_templates_cache)?;
-
-                // For MikroORM, extract database type from mikro-orm.config.ts
-                // This ensures we have the correct database type information
-                if deps.iter().any(|d| d.name == "MikroORM") {
-                    if let Ok(Some(_database)) =
-                        crate::core::sync::detection::detect_database_from_mikro_orm_config(&path)
-                    {
-                        // Database type extracted successfully - MikroORM dependency is valid
-                        // The database type is stored in the project's resources, not in runtime deps
-                        // So we just verify it exists and keep the MikroORM dependency
-                    }
-                    // If database type can't be extracted, we still keep MikroORM as a database dependency
-                }
-
-                if !deps.is_empty() {
-                    all_deps.insert(project_name, deps);
-                }
-            }
-        }
-    }
-
-    Ok(all_deps)
+    visitor.visit_program(program);
+    visitor.dependencies
 }
 
 pub fn get_unique_resource_types(deps: &[RuntimeDependency]) -> Vec<String> {
@@ -211,7 +128,14 @@ pub fn get_unique_resource_types(deps: &[RuntimeDependency]) -> Vec<String> {
 
 #[cfg(test)]
 mod tests {
+    use anyhow::Result;
+
     use super::*;
+    use crate::core::ast::analysis::analyze_source;
+
+    fn extract_runtime_deps_from_source(source: &str) -> Result<Vec<RuntimeDependency>> {
+        Ok(analyze_source(source).runtime_deps)
+    }
 
     #[test]
     fn test_extract_runtime_deps() {
@@ -1,35 +1,26 @@
 use std::{collections::HashMap, path::Path};
 
 use anyhow::Result;
-use oxc_allocator::Allocator;
-use oxc_ast::ast::ImportDeclaration;
+use oxc_ast::ast::{ImportDeclaration, Program};
 use oxc_ast_visit::Visit;
-use oxc_parser::{Parser, ParserReturn};
-use oxc_span::SourceType;
-use walkdir::WalkDir;
 
-use crate::core::rendered_template::RenderedTemplatesCache;
+use crate::core::{
+    ast::analysis::{AnalysisScope, ModulesAnalysis},
+    rendered_template::RenderedTemplatesCache,
+};
 
 /// Detects SDK client imports from other services in the mesh.
 /// e.g., `import { BillingSdkClient } from "@myapp/billing"` would indicate
 /// this service depends on the `billing` service.
-
-#[derive(Debug, Clone)]
-struct ServiceDependency {
-    /// The name of the service being imported from (e.g., "billing")
-    service_name: String,
-    /// The type of dependency (e.g., "network" for SDK client imports)
-    dependency_type: String,
-}
-
 struct SdkImportVisitor {
-    pub dependencies: Vec<ServiceDependency>,
+    /// Names of the services imported from (e.g., "billing")
+    pub services: Vec<String>,
 }
 
 impl SdkImportVisitor {
     fn new() -> Self {
         Self {
-            dependencies: Vec::new(),
+            services: Vec::new(),
         }
     }
 
@@ -70,10 +61,7 @@ impl<'a> Visit<'a> for SdkImportVisitor {
                     // Check for SdkClient pattern (case insensitive for the suffix)
                     if imported_name.ends_with("SdkClient") {
                         if let Some(service_name) = self.extract_service_from_import(source) {
-                            self.dependencies.push(ServiceDependency {
-                                service_name,
-                                dependency_type: "network".to_string(),
-                            });
+                            self.services.push(service_name);
                         }
                     }
                 }
@@ -82,36 +70,11 @@ impl<'a> Visit<'a> for SdkImportVisitor {
     }
 }
 
-/// Extract SDK client dependencies from a single file
-fn extract_sdk_dependencies_from_file(
-    file_path: &Path,
-    rendered_templates_cache: &RenderedTemplatesCache,
-) -> Result<Vec<ServiceDependency>> {
-    let source_code = rendered_templates_cache.get(file_path)?.unwrap().content;
-    extract_sdk_dependencies_from_source(&source_code)
-}
-
-/// Extract SDK client dependencies from source code
-fn extract_sdk_dependencies_from_source(source_code: &str) -> Result<Vec<ServiceDependency>> {
-    let allocator = Allocator::default();
-
-    let ParserReturn {
-        program, errors, ..
-    } = Parser::new(
-        &allocator,
-        source_code,
-        SourceType::default().with_typescript(true),
-    )
-    .parse();
-
-    if !errors.is_empty() {
-        log::debug!("TypeScript parse errors during SDK deps scan: {:?}", errors);
-    }
-
+/// Services whose SDK clients a program imports.
+pub(crate) fn sdk_services_in_program(program: &Program) -> Vec<String> {
     let mut visitor = SdkImportVisitor::new();
-    visitor.visit_program(&program);
-
-    Ok(visitor.dependencies)
+    visitor.visit_program(program);
+    visitor.services
 }
 
 /// Find all SDK client dependencies for all services/workers in the modules path.
@@ -120,63 +83,22 @@ pub fn find_all_service_dependencies(
     modules_path: &Path,
     rendered_templates_cache: &RenderedTemplatesCache,
 ) -> Result<HashMap<String, Vec<(String, String)>>> {
-    let mut all_deps: HashMap<String, Vec<(String, String)>> = HashMap::new();
-
-    if !modules_path.exists() {
-        return Ok(all_deps);
-    }
-
-    for entry in WalkDir::new(modules_path)
-        .into_iter()
-        .filter_map(|e| e.ok())
-        .filter(|e| {
-            e.path()
-                .extension()
-                .map_or(false, |ext| ext == "ts" || ext == "tsx")
-                && !e.path().to_string_lossy().contains("node_modules")
-                && !e.path().to_string_lossy().contains(".test.")
-                && !e.path().to_string_lossy().contains(".spec.")
-        })
-    {
-        let file_path = entry.path();
-
-        // Determine which service this file belongs to
-        let relative = file_path.strip_prefix(modules_path).ok();
-        let service_name = relative.and_then(|p| {
-            p.components()
-                .next()
-                .and_then(|c| c.as_os_str().to_str().map(|s| s.to_string()))
-        });
-
-        if let Some(service) = service_name {
-            if let Ok(deps) =
-                extract_sdk_dependencies_from_file(file_path, rendered_templates_cache)
-            {
-                for dep in deps {
-                    // Don't add self-reference
-                    if dep.service_name != service {
-                        all_deps
-                            .entry(service.clone())
-                            .or_insert_with(Vec::new)
-                            .push((dep.service_name, dep.dependency_type));
-                    }
-                }
-            }
-        }
-    }
-
-    // Deduplicate dependencies for each service (by service name only)
-    for deps in all_deps.values_mut() {
-        deps.sort_by(|a, b| a.0.cmp(&b.0));
-        deps.dedup_by(|a, b| a.0 == b.0);
-    }
-
-    Ok(all_deps)
+    Ok(ModulesAnalysis::analyze(
+        modules_path,
+        rendered_templates_cache,
+        AnalysisScope::AllSources,
+    )?
+    .service_dependencies())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::ast::analysis::analyze_source;
+
+    fn extract_sdk_dependencies_from_source(source: &str) -> Result<Vec<String>> {
+        Ok(analyze_source(source).sdk_services)
+    }
 
     #[test]
     fn test_extract_sdk_dependencies() {
@@ -190,8 +112,8 @@ mod tests {
         let deps = extract_sdk_dependencies_from_source(source).unwrap();
 
         assert_eq!(deps.len(), 2);
-        assert!(deps.iter().any(|d| d.service_name == "billing" && d.dependency_type == "network"));
-        assert!(deps.iter().any(|d| d.service_name == "iam" && d.dependency_type == "network"));
+        assert!(deps.contains(&"billing".to_string()));
+        assert!(deps.contains(&"iam".to_string()));
     }
 
     #[test]
@@ -1,18 +1,11 @@
-use std::{collections::HashMap, fs, path::Path};
-
-use anyhow::Result;
-use oxc_allocator::Allocator;
 use oxc_ast::ast::{
-    ArrowFunctionExpression, Expression, ObjectExpression, ObjectProperty, PropertyKey,
+    ArrowFunctionExpression, Expression, ObjectExpression, ObjectProperty, Program, PropertyKey,
     VariableDeclaration,
 };
 use oxc_ast_visit::Visit;
-use oxc_parser::{Parser, ParserReturn};
-use oxc_span::SourceType;
-
-use crate::core::rendered_template::RenderedTemplatesCache;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct WorkerConfig {
     pub concurrency: Option<i32>,
     pub timeout: Option<i32>,
@@ -235,67 +228,22 @@ impl<'a> Visit<'a> for WorkerConfigVisitor {
     }
 }
 
-pub fn extract_worker_config_from_source(source: &str) -> Result<Option<WorkerConfig>> {
-    let allocator = Allocator::default();
-    let source_type = SourceType::from_path("registrations.ts").unwrap_or_default();
-    let ParserReturn { program, errors, .. } =
-        Parser::new(&allocator, source, source_type).parse();
-
-    if !errors.is_empty() {
-        return Ok(None);
-    }
-
+pub(crate) fn worker_config_in_program(program: &Program) -> Option<WorkerConfig> {
     let mut visitor = WorkerConfigVisitor::new();
-    visitor.visit_program(&program);
-
-    Ok(visitor.config)
-}
-
-pub fn find_all_worker_configs(
-    modules_path: &Path,
-    rendered_templates_cache: &RenderedTemplatesCache,
-) -> Result<HashMap<String, WorkerConfig>> {
-    let mut configs = HashMap::new();
-
-    if !modules_path.exists() {
-        return Ok(configs);
-    }
-
-    for entry in fs::read_dir(modules_path)? {
-        let entry = entry?;
-        let path = entry.path();
-
-        if path.is_dir() {
-            let registrations_path = path.join("registrations.ts");
-            if registrations_path.exists() {
-                let project_name = path
-                    .file_name()
-                    .and_then(|n| n.to_str())
-                    .unwrap_or("unknown")
-                    .to_string();
-
-                // Try to get from cache first, then fall back to reading file
-                let content = if let Ok(Some(cached)) =
-                    rendered_templates_cache.get(&registrations_path)
-                {
-                    cached.content
-                } else {
-                    fs::read_to_string(&registrations_path)?
-                };
-
-                if let Some(config) = extract_worker_config_from_source(&content)? {
-                    configs.insert(project_name, config);
-                }
-            }
-        }
-    }
-
-    Ok(configs)
+    visitor.visit_program(program);
+    visitor.config
 }
 
 #[cfg(test)]
 mod tests {
+    use anyhow::Result;
+
     use super::*;
+    use crate::core::ast::analysis::analyze_source;
+
+    fn extract_worker_config_from_source(source: &str) -> Result<Option<WorkerConfig>> {
+        Ok(analyze_source(source).worker_config)
+    }
 
     #[test]
     fn test_extract_worker_config_from_factory() {
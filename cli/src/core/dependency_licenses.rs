@@ -0,0 +1,728 @@
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet},
+    fs::{read_dir, read_to_string},
+    path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::architecture::RuleLevel;
+
+const LICENSE_FILE_PREFIXES: [&str; 3] = ["LICENSE", "LICENCE", "COPYING"];
+
+/// Dependency license policy, declared as `[licenses]` in the manifest.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub(crate) struct LicensePolicy {
+    /// SPDX ids that may be used; empty allows anything that is not denied.
+    /// A trailing `*` matches a prefix, e.g. `BSD-*`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(crate) allow: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(crate) deny: Vec<String>,
+    /// Package names exempt from every check
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(crate) exceptions: Vec<String>,
+    /// Level for copyleft licenses that conflict with the application's
+    /// license (default: error)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) copyleft: Option<RuleLevel>,
+    /// Level for packages without a recognizable license (default: warn)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) unknown: Option<RuleLevel>,
+}
+
+/// A package installed in `node_modules` that a workspace project depends
+/// on, directly or transitively.
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct InstalledPackage {
+    pub(crate) name: String,
+    pub(crate) version: String,
+    pub(crate) license: Option<String>,
+    /// Repository or homepage URL
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) source: Option<String>,
+    pub(crate) projects: BTreeSet<String>,
+    /// Only reachable through devDependencies
+    pub(crate) dev_only: bool,
+    #[serde(skip)]
+    pub(crate) dir: PathBuf,
+}
+
+/// Installed dependencies of the workspace projects.
+#[derive(Debug, Default)]
+pub(crate) struct DependencyInventory {
+    pub(crate) packages: Vec<InstalledPackage>,
+    /// Direct dependencies that are not installed, as `project: package`
+    pub(crate) missing: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct LicenseFinding {
+    pub(crate) rule: &'static str,
+    pub(crate) level: RuleLevel,
+    pub(crate) package: String,
+    pub(crate) version: String,
+    pub(crate) license: Option<String>,
+    pub(crate) projects: Vec<String>,
+    pub(crate) message: String,
+}
+
+/// License declared in a package.json: `"license": "MIT"`,
+/// `"license": { "type": "MIT" }` or the legacy `"licenses"` array.
+pub(crate) fn package_license(package_json: &Value) -> Option<String> {
+    let license_name = |value: &Value| {
+        value
+            .as_str()
+            .or_else(|| value.get("type").and_then(Value::as_str))
+            .map(str::to_string)
+    };
+    if let Some(license) = package_json.get("license").and_then(license_name) {
+        return Some(license);
+    }
+    let licenses = package_json
+        .get("licenses")?
+        .as_array()?
+        .iter()
+        .filter_map(license_name)
+        .collect::<Vec<_>>();
+    match licenses.len() {
+        0 => None,
+        1 => Some(licenses[0].clone()),
+        _ => Some(format!("({})", licenses.join(" OR "))),
+    }
+}
+
+fn read_package_json(path: &Path) -> Option<Value> {
+    serde_json::from_str(&read_to_string(path).ok()?).ok()
+}
+
+/// Node module resolution: the first `node_modules/<package>` from `dir` up
+/// to `app_root`, as a canonical path so pnpm symlinks resolve into the
+/// store where the package's own dependencies live.
+fn resolve_package(dir: &Path, app_root: &Path, package: &str) -> Option<PathBuf> {
+    let mut dir = dir.to_path_buf();
+    loop {
+        let candidate = dir.join("node_modules").join(package);
+        if candidate.join("package.json").is_file() {
+            return Some(candidate.canonicalize().unwrap_or(candidate));
+        }
+        if dir == app_root || !dir.starts_with(app_root) {
+            return None;
+        }
+        dir = dir.parent()?.to_path_buf();
+    }
+}
+
+fn is_local_specifier(specifier: &str) -> bool {
+    ["workspace:", "link:", "file:", "portal:"]
+        .iter()
+        .any(|protocol| specifier.starts_with(protocol))
+}
+
+fn dependency_names<'a>(package_json: &'a Value, sections: &[&str]) -> Vec<&'a str> {
+    sections
+        .iter()
+        .filter_map(|section| package_json.get(section).and_then(Value::as_object))
+        .flatten()
+        .filter(|(_, specifier)| !is_local_specifier(specifier.as_str().unwrap_or_default()))
+        .map(|(name, _)| name.as_str())
+        .collect()
+}
+
+fn package_source(package_json: &Value) -> Option<String> {
+    let repository = package_json.get("repository");
+    repository
+        .and_then(Value::as_str)
+        .or_else(|| {
+            repository
+                .and_then(|r| r.get("url"))
+                .and_then(Value::as_str)
+        })
+        .or_else(|| package_json.get("homepage").and_then(Value::as_str))
+        .map(|url| url.trim_start_matches("git+").to_string())
+}
+
+/// Walk the installed dependency tree of each project. Workspace packages
+/// are skipped; their own dependencies are collected with their project.
+pub(crate) fn collect_installed_packages(
+    app_root: &Path,
+    modules_path: &str,
+    projects: &[String],
+    include_dev: bool,
+) -> DependencyInventory {
+    let app_root = app_root
+        .canonicalize()
+        .unwrap_or_else(|_| app_root.to_path_buf());
+    let mut by_dir: HashMap<PathBuf, InstalledPackage> = HashMap::new();
+    let mut missing = Vec::new();
+
+    for project in projects {
+        let project_dir = app_root.join(modules_path).join(project);
+        let Some(package_json) = read_package_json(&project_dir.join("package.json")) else {
+            continue;
+        };
+
+        let mut roots: Vec<(&str, bool)> =
+            dependency_names(&package_json, &["dependencies", "optionalDependencies"])
+                .into_iter()
+                .map(|name| (name, false))
+                .collect();
+        if include_dev {
+            roots.extend(
+                dependency_names(&package_json, &["devDependencies"])
+                    .into_iter()
+                    .map(|name| (name, true)),
+            );
+        }
+
+        // Production dependencies are walked first, so anything they reach
+        // is never marked dev-only
+        let mut visited: HashSet<PathBuf> = HashSet::new();
+        for (root, dev) in roots {
+            let Some(root_dir) = resolve_package(&project_dir, &app_root, root) else {
+                if package_json
+                    .get("optionalDependencies")
+                    .and_then(|optional| optional.get(root))
+                    .is_none()
+                {
+                    missing.push(format!("{}: {}", project, root));
+                }
+                continue;
+            };
+
+            let mut stack = vec![root_dir];
+            while let Some(dir) = stack.pop() {
+                if !visited.insert(dir.clone()) {
+                    continue;
+                }
+                let Some(manifest) = read_package_json(&dir.join("package.json")) else {
+                    continue;
+                };
+
+                let package = by_dir
+                    .entry(dir.clone())
+                    .or_insert_with(|| InstalledPackage {
+                        name: manifest
+                            .get("name")
+                            .and_then(Value::as_str)
+                            .unwrap_or(root)
+                            .to_string(),
+                        version: manifest
+                            .get("version")
+                            .and_then(Value::as_str)
+                            .unwrap_or("0.0.0")
+                            .to_string(),
+                        license: package_license(&manifest),
+                        source: package_source(&manifest),
+                        projects: BTreeSet::new(),
+                        dev_only: true,
+                        dir: dir.clone(),
+                    });
+                package.projects.insert(project.clone());
+                package.dev_only &= dev;
+
+                for dependency in
+                    dependency_names(&manifest, &["dependencies", "optionalDependencies"])
+                {
+                    if let Some(dependency_dir) = resolve_package(&dir, &app_root, dependency) {
+                        stack.push(dependency_dir);
+                    }
+                }
+            }
+        }
+    }
+
+    // pnpm installs a copy per peer dependency set; report each version once
+    let mut packages: BTreeMap<(String, String), InstalledPackage> = BTreeMap::new();
+    for package in by_dir.into_values() {
+        match packages.get_mut(&(package.name.clone(), package.version.clone())) {
+            Some(existing) => {
+                existing.projects.extend(package.projects);
+                existing.dev_only &= package.dev_only;
+            }
+            None => {
+                packages.insert((package.name.clone(), package.version.clone()), package);
+            }
+        }
+    }
+
+    DependencyInventory {
+        packages: packages.into_values().collect(),
+        missing,
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum LicenseExpression {
+    Id(String),
+    And(Vec<LicenseExpression>),
+    Or(Vec<LicenseExpression>),
+}
+
+impl LicenseExpression {
+    /// Parses an SPDX expression; `WITH` exceptions are dropped.
+    fn parse(expression: &str) -> Option<Self> {
+        let spaced = expression.replace('(', " ( ").replace(')', " ) ");
+        let tokens: Vec<&str> = spaced.split_whitespace().collect();
+        let mut position = 0;
+        let parsed = Self::parse_or(&tokens, &mut position)?;
+        (position == tokens.len()).then_some(parsed)
+    }
+
+    fn parse_or(tokens: &[&str], position: &mut usize) -> Option<Self> {
+        let mut terms = vec![Self::parse_and(tokens, position)?];
+        while tokens
+            .get(*position)
+            .is_some_and(|t| t.eq_ignore_ascii_case("OR"))
+        {
+            *position += 1;
+            terms.push(Self::parse_and(tokens, position)?);
+        }
+        Some(if terms.len() == 1 {
+            terms.remove(0)
+        } else {
+            Self::Or(terms)
+        })
+    }
+
+    fn parse_and(tokens: &[&str], position: &mut usize) -> Option<Self> {
+        let mut factors = vec![Self::parse_factor(tokens, position)?];
+        while tokens
+            .get(*position)
+            .is_some_and(|t| t.eq_ignore_ascii_case("AND"))
+        {
+            *position += 1;
+            factors.push(Self::parse_factor(tokens, position)?);
+        }
+        Some(if factors.len() == 1 {
+            factors.remove(0)
+        } else {
+            Self::And(factors)
+        })
+    }
+
+    fn parse_factor(tokens: &[&str], position: &mut usize) -> Option<Self> {
+        let token = *tokens.get(*position)?;
+        *position += 1;
+        if token == "(" {
+            let inner = Self::parse_or(tokens, position)?;
+            if tokens.get(*position) != Some(&")") {
+                return None;
+            }
+            *position += 1;
+            return Some(inner);
+        }
+        if token == ")" || ["AND", "OR", "WITH"].contains(&token.to_uppercase().as_str()) {
+            return None;
+        }
+        if tokens
+            .get(*position)
+            .is_some_and(|t| t.eq_ignore_ascii_case("WITH"))
+        {
+            tokens.get(*position + 1)?;
+            *position += 2;
+        }
+        Some(Self::Id(token.to_string()))
+    }
+
+    /// Whether the licensee can satisfy `accept`: any alternative of an
+    /// `OR`, every part of an `AND`.
+    fn satisfies(&self, accept: &dyn Fn(&str) -> bool) -> bool {
+        match self {
+            Self::Id(id) => accept(id),
+            Self::And(parts) => parts.iter().all(|part| part.satisfies(accept)),
+            Self::Or(alternatives) => alternatives.iter().any(|alt| alt.satisfies(accept)),
+        }
+    }
+
+    fn ids(&self) -> Vec<&str> {
+        match self {
+            Self::Id(id) => vec![id.as_str()],
+            Self::And(parts) | Self::Or(parts) => parts.iter().flat_map(Self::ids).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Copyleft {
+    None,
+    /// File or library level (LGPL, MPL, EPL, ...)
+    Weak,
+    /// Whole program (GPL)
+    Strong,
+    /// Whole program, including use over a network (AGPL, SSPL)
+    Network,
+}
+
+fn copyleft(id: &str) -> Copyleft {
+    let id = id.to_uppercase();
+    if id.starts_with("AGPL") || id.starts_with("SSPL") {
+        Copyleft::Network
+    } else if id.starts_with("GPL") {
+        Copyleft::Strong
+    } else if ["LGPL", "MPL", "EPL", "CDDL", "CPL", "EUPL", "OSL"]
+        .iter()
+        .any(|prefix| id.starts_with(prefix))
+    {
+        Copyleft::Weak
+    } else {
+        Copyleft::None
+    }
+}
+
+/// GPL-1.0/2.0 without `-or-later` cannot be relicensed under GPL-3.0.
+fn is_gpl_v3_compatible(id: &str) -> bool {
+    let id = id.to_uppercase();
+    !(id.starts_with("GPL-1.0") || id.starts_with("GPL-2.0"))
+        || id.ends_with('+')
+        || id.ends_with("-OR-LATER")
+}
+
+/// Whether a dependency under `id` can be shipped inside an application
+/// licensed under `app_license` (a `License` choice id, or `none` for
+/// proprietary code).
+fn is_compatible(id: &str, app_license: &str) -> bool {
+    let app = app_license.to_uppercase();
+    match copyleft(id) {
+        Copyleft::None | Copyleft::Weak => true,
+        Copyleft::Strong => {
+            (app.starts_with("GPL-3") || app.starts_with("AGPL-3")) && is_gpl_v3_compatible(id)
+        }
+        Copyleft::Network => app.starts_with("AGPL-3") && id.to_uppercase().starts_with("AGPL"),
+    }
+}
+
+fn matches_pattern(pattern: &str, id: &str) -> bool {
+    match pattern.strip_suffix('*') {
+        Some(prefix) => id.to_uppercase().starts_with(&prefix.to_uppercase()),
+        None => pattern.eq_ignore_ascii_case(id),
+    }
+}
+
+/// Check installed packages against the policy and the application's own
+/// license.
+pub(crate) fn check_licenses(
+    packages: &[InstalledPackage],
+    policy: &LicensePolicy,
+    app_license: &str,
+) -> Vec<LicenseFinding> {
+    let unknown_level = policy.unknown.unwrap_or(RuleLevel::Warn);
+    let copyleft_level = policy.copyleft.unwrap_or(RuleLevel::Error);
+    let denied = |id: &str| {
+        policy
+            .deny
+            .iter()
+            .any(|pattern| matches_pattern(pattern, id))
+    };
+    let allowed = |id: &str| {
+        !denied(id)
+            && (policy.allow.is_empty()
+                || policy
+                    .allow
+                    .iter()
+                    .any(|pattern| matches_pattern(pattern, id)))
+    };
+
+    let mut findings = Vec::new();
+    for package in packages {
+        if policy.exceptions.contains(&package.name) {
+            continue;
+        }
+        let mut finding = |rule, level, message: String| {
+            if level != RuleLevel::Off {
+                findings.push(LicenseFinding {
+                    rule,
+                    level,
+                    package: package.name.clone(),
+                    version: package.version.clone(),
+                    license: package.license.clone(),
+                    projects: package.projects.iter().cloned().collect(),
+                    message,
+                });
+            }
+        };
+
+        let expression = package
+            .license
+            .as_deref()
+            .filter(|license| {
+                !license.eq_ignore_ascii_case("UNLICENSED")
+                    && !license.to_uppercase().starts_with("SEE LICENSE")
+            })
+            .and_then(LicenseExpression::parse);
+        let Some(expression) = expression else {
+            finding(
+                "unknown",
+                unknown_level,
+                match &package.license {
+                    Some(license) => format!("License '{}' is not an SPDX expression", license),
+                    None => "No license declared".to_string(),
+                },
+            );
+            continue;
+        };
+        let license = package.license.as_deref().unwrap_or_default();
+
+        if !expression.satisfies(&allowed) {
+            let denied_ids: Vec<&str> = expression
+                .ids()
+                .into_iter()
+                .filter(|id| denied(id))
+                .collect();
+            if denied_ids.is_empty() {
+                finding(
+                    "not-allowed",
+                    RuleLevel::Error,
+                    format!("{} is not in the allowed licenses", license),
+                );
+            } else {
+                finding(
+                    "denied",
+                    RuleLevel::Error,
+                    format!("{} is denied by the license policy", denied_ids.join(", ")),
+                );
+            }
+        }
+
+        // Dev-only packages are not distributed with the application
+        if !package.dev_only && !expression.satisfies(&|id| is_compatible(id, app_license)) {
+            finding(
+                "copyleft",
+                copyleft_level,
+                if app_license == "none" {
+                    format!("{} is copyleft and the application is proprietary", license)
+                } else {
+                    format!(
+                        "{} is copyleft and conflicts with the application's {} license",
+                        license, app_license
+                    )
+                },
+            );
+        }
+    }
+    findings
+}
+
+/// License text shipped with a package (LICENSE, LICENCE.md, COPYING, ...).
+pub(crate) fn license_text(package_dir: &Path) -> Option<String> {
+    let mut files: Vec<PathBuf> = read_dir(package_dir)
+        .ok()?
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.is_file()
+                && path.file_name().is_some_and(|name| {
+                    let name = name.to_string_lossy().to_uppercase();
+                    LICENSE_FILE_PREFIXES
+                        .iter()
+                        .any(|prefix| name.starts_with(prefix))
+                })
+        })
+        .collect();
+    files.sort();
+    read_to_string(files.first()?).ok()
+}
+
+/// Third-party notice file for the packages distributed with the
+/// application, with each package's license text when it ships one.
+pub(crate) fn render_notice(app_name: &str, packages: &[InstalledPackage]) -> String {
+    let separator = "-".repeat(80);
+    let mut notice = format!(
+        "THIRD-PARTY SOFTWARE NOTICES\n\n{} includes the following third-party packages.\n",
+        app_name
+    );
+    for package in packages.iter().filter(|package| !package.dev_only) {
+        notice.push_str(&format!(
+            "\n{}\n\n{}@{}\nLicense: {}\n",
+            separator,
+            package.name,
+            package.version,
+            package.license.as_deref().unwrap_or("UNKNOWN")
+        ));
+        if let Some(source) = &package.source {
+            notice.push_str(&format!("Source: {}\n", source));
+        }
+        if let Some(text) = license_text(&package.dir) {
+            notice.push('\n');
+            notice.push_str(text.trim_end());
+            notice.push('\n');
+        }
+    }
+    notice
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::{create_dir_all, write};
+
+    use serde_json::json;
+    use tempfile::tempdir;
+
+    use super::*;
+
+    #[test]
+    fn test_package_license() {
+        assert_eq!(
+            package_license(&json!({ "license": "MIT" })).as_deref(),
+            Some("MIT")
+        );
+        assert_eq!(
+            package_license(&json!({ "license": { "type": "ISC" } })).as_deref(),
+            Some("ISC")
+        );
+        assert_eq!(
+            package_license(&json!({ "licenses": [{ "type": "MIT" }, { "type": "Apache-2.0" }] }))
+                .as_deref(),
+            Some("(MIT OR Apache-2.0)")
+        );
+        assert_eq!(package_license(&json!({})), None);
+    }
+
+    #[test]
+    fn test_license_expression() {
+        assert_eq!(
+            LicenseExpression::parse("(MIT OR GPL-3.0) AND BSD-3-Clause"),
+            Some(LicenseExpression::And(vec![
+                LicenseExpression::Or(vec![
+                    LicenseExpression::Id("MIT".to_string()),
+                    LicenseExpression::Id("GPL-3.0".to_string()),
+                ]),
+                LicenseExpression::Id("BSD-3-Clause".to_string()),
+            ]))
+        );
+        assert_eq!(
+            LicenseExpression::parse("GPL-2.0-or-later WITH Classpath-exception-2.0"),
+            Some(LicenseExpression::Id("GPL-2.0-or-later".to_string()))
+        );
+        assert_eq!(LicenseExpression::parse("Apache 2.0"), None);
+        assert_eq!(LicenseExpression::parse("(MIT"), None);
+    }
+
+    fn package(name: &str, license: Option<&str>, dev_only: bool) -> InstalledPackage {
+        InstalledPackage {
+            name: name.to_string(),
+            version: "1.0.0".to_string(),
+            license: license.map(str::to_string),
+            source: None,
+            projects: BTreeSet::from(["billing".to_string()]),
+            dev_only,
+            dir: PathBuf::new(),
+        }
+    }
+
+    #[test]
+    fn test_check_licenses() {
+        let packages = vec![
+            package("express", Some("MIT"), false),
+            package("gpl-lib", Some("GPL-3.0"), false),
+            package("dual", Some("(MIT OR GPL-3.0)"), false),
+            package("gpl-dev-tool", Some("GPL-3.0"), true),
+            package("agpl-lib", Some("AGPL-3.0-only"), false),
+            package("mystery", None, false),
+            package("mpl-lib", Some("MPL-2.0"), false),
+            package("denied", Some("WTFPL"), false),
+        ];
+        let policy = LicensePolicy {
+            deny: vec!["WTFPL".to_string()],
+            exceptions: vec!["mpl-lib".to_string()],
+            ..Default::default()
+        };
+
+        let rules = |findings: Vec<LicenseFinding>| {
+            findings
+                .into_iter()
+                .map(|finding| format!("{}:{}", finding.package, finding.rule))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            rules(check_licenses(&packages, &policy, "MIT")),
+            [
+                "gpl-lib:copyleft",
+                "agpl-lib:copyleft",
+                "mystery:unknown",
+                "denied:denied"
+            ]
+        );
+        assert_eq!(
+            rules(check_licenses(&packages, &policy, "GPL-3.0")),
+            ["agpl-lib:copyleft", "mystery:unknown", "denied:denied"]
+        );
+
+        let allow_list = LicensePolicy {
+            allow: vec!["MIT".to_string(), "BSD-*".to_string()],
+            ..Default::default()
+        };
+        let findings = check_licenses(
+            &[
+                package("bsd", Some("BSD-3-Clause"), false),
+                package("isc", Some("ISC"), false),
+            ],
+            &allow_list,
+            "MIT",
+        );
+        assert_eq!(rules(findings), ["isc:not-allowed"]);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_collect_installed_packages_follows_pnpm_store() {
+        let dir = tempdir().unwrap();
+        let root = dir.path();
+        let write_json = |path: PathBuf, value: Value| {
+            create_dir_all(path.parent().unwrap()).unwrap();
+            write(path, value.to_string()).unwrap();
+        };
+
+        write_json(
+            root.join("src/modules/billing/package.json"),
+            json!({
+                "dependencies": { "express": "^4.0.0", "@app/core": "workspace:*" },
+                "devDependencies": { "typescript": "^5.0.0" }
+            }),
+        );
+        let store = root.join("node_modules/.pnpm/express@4.21.2/node_modules");
+        write_json(
+            store.join("express/package.json"),
+            json!({ "name": "express", "version": "4.21.2", "license": "MIT",
+                    "dependencies": { "qs": "^6.0.0" } }),
+        );
+        write(store.join("express/LICENSE"), "MIT License text").unwrap();
+        write_json(
+            store.join("qs/package.json"),
+            json!({ "name": "qs", "version": "6.13.0", "license": "BSD-3-Clause" }),
+        );
+        write_json(
+            root.join("node_modules/typescript/package.json"),
+            json!({ "name": "typescript", "version": "5.6.3", "license": "Apache-2.0" }),
+        );
+        create_dir_all(root.join("src/modules/billing/node_modules")).unwrap();
+        std::os::unix::fs::symlink(
+            store.join("express"),
+            root.join("src/modules/billing/node_modules/express"),
+        )
+        .unwrap();
+
+        let inventory =
+            collect_installed_packages(root, "src/modules", &["billing".to_string()], true);
+        let summary: Vec<_> = inventory
+            .packages
+            .iter()
+            .map(|p| format!("{}@{} {:?} {}", p.name, p.version, p.license, p.dev_only))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                "express@4.21.2 Some(\"MIT\") false",
+                "qs@6.13.0 Some(\"BSD-3-Clause\") false",
+                "typescript@5.6.3 Some(\"Apache-2.0\") true",
+            ]
+        );
+        assert!(inventory.missing.is_empty());
+
+        let notice = render_notice("my-app", &inventory.packages);
+        assert!(notice.contains("express@4.21.2\nLicense: MIT\n\nMIT License text\n"));
+        assert!(!notice.contains("typescript"));
+    }
+}
@@ -9,6 +9,8 @@ use anyhow::{Context, Result};
 use regex::Regex;
 use serde::{Deserialize, Serialize};
 
+use super::secrets::{is_encrypted_env_file, load_encrypted_env_vars};
+
 #[derive(Debug, Serialize)]
 pub(crate) struct Env {
     #[serde(rename = "DB_NAME", skip_serializing_if = "Option::is_none")]
@@ -227,10 +229,20 @@ pub(crate) fn load_env_file(path: &Path) -> Result<HashMap<String, String>> {
         return Ok(HashMap::new());
     }
 
+    if is_encrypted_env_file(path) {
+        return load_encrypted_env_vars(path);
+    }
+
     let content = fs::read_to_string(path)
         .with_context(|| format!("Failed to read env file: {}", path.display()))?;
 
-    let mut variables = HashMap::new();
+    Ok(parse_env_entries(&content).into_iter().collect())
+}
+
+/// Parses `KEY=VALUE` lines in file order, skipping blanks and `#` comments
+/// and stripping one level of matching quotes from values.
+pub(crate) fn parse_env_entries(content: &str) -> Vec<(String, String)> {
+    let mut entries = Vec::new();
 
     for line in content.lines() {
         let line = line.trim();
@@ -243,19 +255,20 @@ pub(crate) fn load_env_file(path: &Path) -> Result<HashMap<String, String>> {
             let key = key.trim().to_string();
             let value = value.trim().to_string();
 
-            let value = if (value.starts_with('"') && value.ends_with('"'))
-                || (value.starts_with('\'') && value.ends_with('\''))
+            let value = if value.len() >= 2
+                && ((value.starts_with('"') && value.ends_with('"'))
+                    || (value.starts_with('\'') && value.ends_with('\'')))
             {
                 value[1..value.len() - 1].to_string()
             } else {
                 value
             };
 
-            variables.insert(key, value);
+            entries.push((key, value));
         }
     }
 
-    Ok(variables)
+    entries
 }
 
 pub(crate) fn find_env_files(project_path: &Path) -> Result<Vec<PathBuf>> {
@@ -389,6 +402,35 @@ pub(crate) fn add_env_vars_to_file(file_path: &Path, vars: &HashMap<String, Stri
     Ok(())
 }
 
+/// Gives `KEY=` entries with an empty value the value from `vars`. Entries
+/// that already hold a value are never overwritten.
+pub(crate) fn fill_blank_env_vars(file_path: &Path, vars: &HashMap<String, String>) -> Result<()> {
+    let content = fs::read_to_string(file_path)
+        .with_context(|| format!("Failed to read env file: {}", file_path.display()))?;
+
+    let mut filled = String::with_capacity(content.len());
+    for line in content.split_inclusive('\n') {
+        let (body, newline) = match line.strip_suffix('\n') {
+            Some(body) => (body, "\n"),
+            None => (line, ""),
+        };
+        match body.split_once('=') {
+            Some((key, value))
+                if matches!(value.trim(), "" | "\"\"" | "''")
+                    && vars.contains_key(key.trim()) =>
+            {
+                filled.push_str(&format!("{}={}{}", key.trim(), vars[key.trim()], newline));
+            }
+            _ => filled.push_str(line),
+        }
+    }
+
+    fs::write(file_path, filled)
+        .with_context(|| format!("Failed to write to env file: {}", file_path.display()))?;
+
+    Ok(())
+}
+
 pub(crate) fn find_workspace_root(start_path: &Path) -> Result<PathBuf> {
     let mut current_path = start_path.canonicalize()?;
 
@@ -492,6 +534,24 @@ pub(crate) fn get_cascading_env_paths(
     Ok(env_paths)
 }
 
+/// Resolves the value each variable takes for a project by applying the
+/// cascade from the workspace root down, later files overriding earlier ones.
+/// Returns the value together with the file that supplied it.
+pub(crate) fn resolve_env_values(
+    project_path: &Path,
+    workspace_root: &Path,
+) -> Result<HashMap<String, (String, PathBuf)>> {
+    let mut resolved = HashMap::new();
+
+    for env_path in get_cascading_env_paths(project_path, workspace_root)? {
+        for (key, value) in load_env_file(&env_path)? {
+            resolved.insert(key, (value, env_path.clone()));
+        }
+    }
+
+    Ok(resolved)
+}
+
 #[cfg(test)]
 mod tests {
     use tempfile::TempDir;
@@ -516,6 +576,26 @@ mod tests {
         assert_eq!(vars.len(), 3);
     }
 
+    #[test]
+    fn test_fill_blank_env_vars() {
+        let temp_dir = TempDir::new().unwrap();
+        let env_path = temp_dir.path().join(".env.local");
+
+        fs::write(&env_path, "# Secrets\nHMAC_SECRET_KEY=\nSET_KEY=keep\nQUOTED=\"\"\nOTHER=").unwrap();
+
+        let vars = HashMap::from([
+            ("HMAC_SECRET_KEY".to_string(), "generated".to_string()),
+            ("SET_KEY".to_string(), "replaced".to_string()),
+            ("QUOTED".to_string(), "filled".to_string()),
+        ]);
+        fill_blank_env_vars(&env_path, &vars).unwrap();
+
+        assert_eq!(
+            fs::read_to_string(&env_path).unwrap(),
+            "# Secrets\nHMAC_SECRET_KEY=generated\nSET_KEY=keep\nQUOTED=filled\nOTHER="
+        );
+    }
+
     #[test]
     fn test_add_env_vars_to_file() {
         let temp_dir = TempDir::new().unwrap();
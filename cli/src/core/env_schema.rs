@@ -0,0 +1,376 @@
+use std::fmt;
+
+use base64::{
+    Engine,
+    engine::general_purpose::{STANDARD, URL_SAFE},
+};
+use regex::Regex;
+use reqwest::Url;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) enum EnvValueType {
+    String,
+    Number,
+    Port,
+    Boolean,
+    Url,
+    Host,
+    List,
+    Secret,
+    Hex,
+    Pem,
+}
+
+impl fmt::Display for EnvValueType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            EnvValueType::String => "string",
+            EnvValueType::Number => "number",
+            EnvValueType::Port => "port",
+            EnvValueType::Boolean => "boolean",
+            EnvValueType::Url => "url",
+            EnvValueType::Host => "host",
+            EnvValueType::List => "list",
+            EnvValueType::Secret => "secret",
+            EnvValueType::Hex => "hex",
+            EnvValueType::Pem => "pem",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Manifest override for one variable, declared as `[env_schema.<NAME>]`.
+/// Every field is optional and replaces the inferred value when present.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub(crate) struct EnvVarSchemaOverride {
+    #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
+    pub(crate) value_type: Option<EnvValueType>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) required: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) min_length: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) max_length: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) min: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) max: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) pattern: Option<String>,
+    #[serde(rename = "enum", default, skip_serializing_if = "Option::is_none")]
+    pub(crate) allowed: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) schemes: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) allow_unused: Option<bool>,
+    /// Limits the override to these projects; applies everywhere when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) projects: Option<Vec<String>>,
+}
+
+impl EnvVarSchemaOverride {
+    pub(crate) fn applies_to(&self, project_name: &str) -> bool {
+        self.projects
+            .as_ref()
+            .is_none_or(|projects| projects.iter().any(|p| p == project_name))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct EnvVarSchema {
+    pub(crate) name: String,
+    pub(crate) category: String,
+    #[serde(rename = "type")]
+    pub(crate) value_type: EnvValueType,
+    pub(crate) required: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) default: Option<String>,
+    /// Characters, or decoded bytes for `secret` values.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) min_length: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) max_length: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) min: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) max: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) pattern: Option<String>,
+    #[serde(rename = "enum", skip_serializing_if = "Option::is_none")]
+    pub(crate) allowed: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) schemes: Option<Vec<String>>,
+    pub(crate) allow_unused: bool,
+}
+
+impl EnvVarSchema {
+    pub(crate) fn new(name: &str, category: &str, value_type: EnvValueType) -> Self {
+        Self {
+            name: name.to_string(),
+            category: category.to_string(),
+            value_type,
+            required: true,
+            default: None,
+            min_length: None,
+            max_length: None,
+            min: None,
+            max: None,
+            pattern: None,
+            allowed: None,
+            schemes: None,
+            allow_unused: false,
+        }
+    }
+
+    pub(crate) fn apply_override(&mut self, schema_override: &EnvVarSchemaOverride) {
+        if let Some(value_type) = schema_override.value_type {
+            self.value_type = value_type;
+        }
+        if let Some(required) = schema_override.required {
+            self.required = required;
+        }
+        if let Some(allow_unused) = schema_override.allow_unused {
+            self.allow_unused = allow_unused;
+        }
+        if schema_override.min_length.is_some() {
+            self.min_length = schema_override.min_length;
+        }
+        if schema_override.max_length.is_some() {
+            self.max_length = schema_override.max_length;
+        }
+        if schema_override.min.is_some() {
+            self.min = schema_override.min;
+        }
+        if schema_override.max.is_some() {
+            self.max = schema_override.max;
+        }
+        if schema_override.pattern.is_some() {
+            self.pattern = schema_override.pattern.clone();
+        }
+        if schema_override.allowed.is_some() {
+            self.allowed = schema_override.allowed.clone();
+        }
+        if schema_override.schemes.is_some() {
+            self.schemes = schema_override.schemes.clone();
+        }
+    }
+
+    pub(crate) fn is_sensitive(&self) -> bool {
+        matches!(self.value_type, EnvValueType::Secret | EnvValueType::Pem)
+    }
+
+    /// Returns every type, format and constraint violation for `value`.
+    pub(crate) fn check(&self, value: &str) -> Vec<String> {
+        let mut violations = Vec::new();
+
+        if value.is_empty() {
+            if self.required {
+                violations.push("is empty".to_string());
+            }
+            return violations;
+        }
+
+        match self.value_type {
+            EnvValueType::String => {}
+            EnvValueType::Number => match value.parse::<f64>() {
+                Ok(number) => {
+                    if let Some(min) = self.min
+                        && number < min
+                    {
+                        violations.push(format!("must be at least {}", min));
+                    }
+                    if let Some(max) = self.max
+                        && number > max
+                    {
+                        violations.push(format!("must be at most {}", max));
+                    }
+                }
+                Err(_) => violations.push("expected a number".to_string()),
+            },
+            EnvValueType::Port => {
+                if !value.parse::<u16>().is_ok_and(|port| port > 0) {
+                    violations.push("expected a port between 1 and 65535".to_string());
+                }
+            }
+            EnvValueType::Boolean => {
+                if !["true", "false", "1", "0"].contains(&value.to_ascii_lowercase().as_str()) {
+                    violations.push("expected true or false".to_string());
+                }
+            }
+            EnvValueType::Url => match Url::parse(value) {
+                Ok(url) => {
+                    if let Some(schemes) = &self.schemes
+                        && !schemes.iter().any(|scheme| scheme == url.scheme())
+                    {
+                        violations.push(format!(
+                            "uses scheme '{}', expected {}",
+                            url.scheme(),
+                            schemes.join(" or ")
+                        ));
+                    }
+                }
+                Err(err) => violations.push(format!("expected a URL ({})", err)),
+            },
+            EnvValueType::Host => {
+                if value.contains("://")
+                    || value.contains('/')
+                    || value.contains(char::is_whitespace)
+                {
+                    violations.push("expected a host name without scheme or path".to_string());
+                }
+            }
+            EnvValueType::List => {
+                if value.split(',').any(|item| item.trim().is_empty()) {
+                    violations.push("contains an empty list item".to_string());
+                }
+            }
+            EnvValueType::Secret => {
+                if let Some(min_length) = self.min_length {
+                    let length = secret_length(value);
+                    if length < min_length {
+                        violations.push(format!(
+                            "is {} bytes, at least {} required",
+                            length, min_length
+                        ));
+                    }
+                }
+            }
+            EnvValueType::Hex => {
+                if !value.len().is_multiple_of(2) || !value.chars().all(|c| c.is_ascii_hexdigit()) {
+                    violations.push("expected an even-length hex string".to_string());
+                }
+            }
+            EnvValueType::Pem => {
+                if !value.contains("-----BEGIN ") || !value.contains("-----END ") {
+                    violations.push("expected a PEM-encoded key".to_string());
+                }
+            }
+        }
+
+        if self.value_type != EnvValueType::Secret
+            && let Some(min_length) = self.min_length
+            && value.chars().count() < min_length
+        {
+            violations.push(format!("must be at least {} characters", min_length));
+        }
+        if let Some(max_length) = self.max_length
+            && value.chars().count() > max_length
+        {
+            violations.push(format!("must be at most {} characters", max_length));
+        }
+        if let Some(pattern) = &self.pattern {
+            match Regex::new(&format!("^(?:{})$", pattern)) {
+                Ok(regex) if !regex.is_match(value) => {
+                    violations.push(format!("does not match pattern {}", pattern))
+                }
+                Ok(_) => {}
+                Err(_) => violations.push(format!("has an invalid schema pattern {}", pattern)),
+            }
+        }
+        if let Some(allowed) = &self.allowed
+            && !allowed.iter().any(|candidate| candidate == value)
+        {
+            violations.push(format!("must be one of {}", allowed.join(", ")));
+        }
+
+        violations
+    }
+}
+
+/// Keeps only a recognizable prefix of a sensitive value.
+pub(crate) fn mask_env_value(value: &str) -> String {
+    let length = value.chars().count();
+    if length < 12 {
+        return "*".repeat(8);
+    }
+    let prefix: String = value.chars().take(4).collect();
+    format!("{}**** ({} chars)", prefix, length)
+}
+
+/// Key material is usually base64; measure decoded bytes when it decodes
+/// and raw bytes otherwise.
+fn secret_length(value: &str) -> usize {
+    STANDARD
+        .decode(value)
+        .or_else(|_| URL_SAFE.decode(value))
+        .map(|bytes| bytes.len())
+        .unwrap_or(value.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_types() {
+        let port = EnvVarSchema::new("PORT", "Application", EnvValueType::Port);
+        assert!(port.check("8000").is_empty());
+        assert_eq!(
+            port.check("http"),
+            vec!["expected a port between 1 and 65535"]
+        );
+        assert_eq!(port.check(""), vec!["is empty"]);
+
+        let mut url = EnvVarSchema::new("REDIS_URL", "Cache (Redis)", EnvValueType::Url);
+        url.schemes = Some(vec!["redis".to_string(), "rediss".to_string()]);
+        assert!(url.check("redis://localhost:6379").is_empty());
+        assert_eq!(url.check("http://localhost").len(), 1);
+        assert_eq!(url.check("localhost:6379").len(), 1);
+
+        let mut secret =
+            EnvVarSchema::new("HMAC_SECRET_KEY", "Authentication", EnvValueType::Secret);
+        secret.min_length = Some(32);
+        assert!(secret.check(&STANDARD.encode([7u8; 32])).is_empty());
+        assert_eq!(
+            secret.check("short"),
+            vec!["is 5 bytes, at least 32 required"]
+        );
+    }
+
+    #[test]
+    fn test_mask_env_value() {
+        assert_eq!(mask_env_value("short"), "********");
+        assert_eq!(
+            mask_env_value("sk_test_0123456789abcdef"),
+            "sk_t**** (24 chars)"
+        );
+    }
+
+    #[test]
+    fn test_override_relaxes_and_constrains() {
+        let mut schema = EnvVarSchema::new("OTEL_LEVEL", "Observability", EnvValueType::String);
+        schema.apply_override(&EnvVarSchemaOverride {
+            required: Some(false),
+            allowed: Some(vec!["info".to_string(), "debug".to_string()]),
+            ..Default::default()
+        });
+        assert!(schema.check("").is_empty());
+        assert!(schema.check("debug").is_empty());
+        assert_eq!(schema.check("loud"), vec!["must be one of info, debug"]);
+
+        let scoped = EnvVarSchemaOverride {
+            projects: Some(vec!["billing".to_string()]),
+            ..Default::default()
+        };
+        assert!(scoped.applies_to("billing"));
+        assert!(!scoped.applies_to("iam"));
+    }
+
+    #[test]
+    fn test_override_from_manifest_toml() {
+        let overrides: std::collections::BTreeMap<String, EnvVarSchemaOverride> = toml::from_str(
+            "[WORKER_CONCURRENCY]\ntype = \"number\"\nmin = 1\n\n[LEGACY_FLAG]\nallow_unused = true\n",
+        )
+        .unwrap();
+
+        let mut schema =
+            EnvVarSchema::new("WORKER_CONCURRENCY", "Application", EnvValueType::String);
+        schema.apply_override(&overrides["WORKER_CONCURRENCY"]);
+        assert_eq!(schema.value_type, EnvValueType::Number);
+        assert_eq!(schema.check("0"), vec!["must be at least 1"]);
+        assert_eq!(overrides["LEGACY_FLAG"].allow_unused, Some(true));
+    }
+}
@@ -33,6 +33,25 @@ impl EnvironmentVariableScope {
     }
 }
 
+/// Takes each variable's value from the first project using it that defines it.
+pub(crate) fn resolve_scope_values(
+    vars: &[&ScopedEnvVar],
+    values_by_project: &HashMap<&str, HashMap<String, String>>,
+) -> HashMap<String, String> {
+    vars.iter()
+        .filter_map(|var| {
+            var.used_by
+                .iter()
+                .find_map(|project_name| {
+                    values_by_project
+                        .get(project_name.as_str())
+                        .and_then(|values| values.get(&var.name))
+                })
+                .map(|value| (var.name.clone(), value.clone()))
+        })
+        .collect()
+}
+
 /// Determine the scope for each environment variable based on usage patterns
 pub(crate) fn determine_env_var_scopes(
     project_env_vars: &HashMap<String, Vec<EnvVarUsage>>,
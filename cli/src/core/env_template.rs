@@ -9,7 +9,7 @@ use anyhow::{Context, Result};
 use termcolor::{Color, ColorSpec, StandardStream, WriteColor};
 
 use crate::core::{
-    ast::infrastructure::env::find_all_env_vars,
+    ast::analysis::{AnalysisScope, ModulesAnalysis},
     env::{add_env_vars_to_file, is_env_var_defined},
     manifest::{ProjectType, application::ApplicationManifestData},
     rendered_template::{RenderedTemplate, RenderedTemplatesCache},
@@ -17,7 +17,7 @@ use crate::core::{
 };
 
 /// Categorize an env var by its prefix into a human-readable category.
-fn categorize_env_var(var_name: &str) -> &'static str {
+pub(crate) fn categorize_env_var(var_name: &str) -> &'static str {
     if var_name.starts_with("DB_") {
         "Database"
     } else if var_name.starts_with("REDIS_") {
@@ -125,7 +125,12 @@ pub fn generate_env_templates(
     rendered_templates_cache: &mut RenderedTemplatesCache,
     stdout: &mut StandardStream,
 ) -> Result<()> {
-    let project_env_vars = find_all_env_vars(modules_path, rendered_templates_cache)?;
+    let project_env_vars = ModulesAnalysis::analyze_cached(
+        modules_path,
+        rendered_templates_cache,
+        AnalysisScope::Registrations,
+    )?
+    .env_vars();
 
     if project_env_vars.is_empty() {
         return Ok(());
@@ -215,7 +220,12 @@ pub fn sync_env_local_files(
     stdout: &mut StandardStream,
 ) -> Result<()> {
     let rendered_templates_cache = RenderedTemplatesCache::new();
-    let project_env_vars = find_all_env_vars(modules_path, &rendered_templates_cache)?;
+    let project_env_vars = ModulesAnalysis::analyze_cached(
+        modules_path,
+        &rendered_templates_cache,
+        AnalysisScope::Registrations,
+    )?
+    .env_vars();
 
     if project_env_vars.is_empty() {
         return Ok(());
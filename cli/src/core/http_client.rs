@@ -210,6 +210,31 @@ pub fn put_with_auth(auth_mode: &AuthMode, url: &str, body: Value) -> Result<Res
     }
 }
 
+/// GET a long-lived streaming response (server-sent events or NDJSON) with auth mode dispatch.
+///
+/// Unlike the other helpers this uses a caller-supplied timeout instead of the client default,
+/// and does not retry on 401/403 — callers are expected to fall back to regular polling.
+pub fn get_stream_with_auth(
+    auth_mode: &AuthMode,
+    url: &str,
+    timeout: std::time::Duration,
+) -> Result<Response> {
+    let authorization = match auth_mode {
+        AuthMode::Jwt => format!("Bearer {}", get_token()?),
+        AuthMode::Hmac { secret_key } => {
+            generate_hmac_auth_header(secret_key, Method::GET.as_str(), &extract_url_path(url)?, None)?
+        }
+    };
+
+    let client = Client::builder().timeout(timeout).build()?;
+    Ok(client
+        .get(url)
+        .header("Authorization", authorization)
+        .header("Accept", "text/event-stream, application/x-ndjson")
+        .header("Cache-Control", "no-cache")
+        .send()?)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
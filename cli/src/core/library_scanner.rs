@@ -8,6 +8,8 @@ use anyhow::{Context, Result};
 use regex::Regex;
 use serde::{Deserialize, Serialize};
 
+use crate::core::lockfile::Lockfile;
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct LibraryDefinition {
     pub name: String,
@@ -36,6 +38,11 @@ pub fn scan_project_libraries(package_json_path: &Path) -> Result<Vec<LibraryDef
     ))?;
 
     let json: serde_json::Value = serde_json::from_str(&content)?;
+    let package_dir = package_json_path.parent().unwrap_or(Path::new("."));
+    let package_dir = package_dir
+        .canonicalize()
+        .unwrap_or_else(|_| package_dir.to_path_buf());
+    let lockfile = Lockfile::find(&package_dir, Path::new("/"));
 
     let mut libraries = Vec::new();
 
@@ -47,7 +54,13 @@ pub fn scan_project_libraries(package_json_path: &Path) -> Result<Vec<LibraryDef
             if name.starts_with("@forklaunch") {
                 libraries.push(LibraryDefinition {
                     name: name.clone(),
-                    version: version.as_str().unwrap_or("unknown").to_string(),
+                    // Prefer the exact version the package manager resolved
+                    version: lockfile
+                        .as_ref()
+                        .and_then(|lockfile| lockfile.version(&package_dir, name))
+                        .or(version.as_str())
+                        .unwrap_or("unknown")
+                        .to_string(),
                 });
             }
         }
@@ -74,8 +87,7 @@ pub struct ImportScanner {
     #[allow(dead_code)]
     app_root: PathBuf,                                             // upper bound for lockfile search
     package_json_cache: HashMap<PathBuf, HashMap<String, String>>, // path → deps (specifiers)
-    lockfile_importers: HashMap<String, HashMap<String, String>>,  // relative_path → { pkg → exact_version }
-    lockfile_root: Option<PathBuf>,                                // directory containing pnpm-lock.yaml
+    lockfile: Option<Lockfile>,                                    // exact versions from the package manager
     visited: HashSet<PathBuf>,
     result_cache: HashMap<PathBuf, CodeNode>,
     scope_prefix: String,        // e.g., "@forklaunch-platform/"
@@ -105,8 +117,8 @@ impl ImportScanner {
 
         let canonical_app_root = app_root.canonicalize().unwrap_or_else(|_| app_root.to_path_buf());
 
-        // Find and parse pnpm-lock.yaml walking up from modules_root, bounded by app_root
-        let (lockfile_importers, lockfile_root) = Self::load_lockfile(
+        // Find and parse the lockfile walking up from modules_root, bounded by app_root
+        let lockfile = Lockfile::find(
             &modules_root.canonicalize().unwrap_or_else(|_| modules_root.to_path_buf()),
             &canonical_app_root,
         );
@@ -115,8 +127,7 @@ impl ImportScanner {
             modules_root: modules_root.canonicalize().unwrap_or_else(|_| modules_root.to_path_buf()),
             app_root: canonical_app_root,
             package_json_cache,
-            lockfile_importers,
-            lockfile_root,
+            lockfile,
             visited: HashSet::new(),
             result_cache: HashMap::new(),
             scope_prefix: if app_name.is_empty() {
@@ -129,7 +140,7 @@ impl ImportScanner {
     }
 
     /// Normalize "@scope/name/subpath" to "@scope/name" for package.json lookup
-    fn normalize_scoped_package(import_path: &str) -> String {
+    pub fn normalize_scoped_package(import_path: &str) -> String {
         if import_path.starts_with('@') {
             let parts: Vec<&str> = import_path.split('/').collect();
             if parts.len() >= 2 {
@@ -157,90 +168,6 @@ impl ImportScanner {
         Ok(deps)
     }
 
-    /// Walk up from `start_dir` to find pnpm-lock.yaml, stopping at `stop_dir` (inclusive).
-    fn load_lockfile(start_dir: &Path, stop_dir: &Path) -> (HashMap<String, HashMap<String, String>>, Option<PathBuf>) {
-        let mut dir = start_dir.to_path_buf();
-        loop {
-            let candidate = dir.join("pnpm-lock.yaml");
-            if candidate.exists() {
-                if let Ok(importers) = Self::parse_lockfile(&candidate) {
-                    return (importers, Some(dir));
-                }
-            }
-            // Stop at app_root — don't walk above it
-            if dir == stop_dir || !dir.starts_with(stop_dir) {
-                break;
-            }
-            match dir.parent() {
-                Some(parent) if parent != dir => dir = parent.to_path_buf(),
-                _ => break,
-            }
-        }
-        (HashMap::new(), None)
-    }
-
-    /// Parse pnpm-lock.yaml and extract importer → { pkg → exact_version } map.
-    fn parse_lockfile(lockfile_path: &Path) -> Result<HashMap<String, HashMap<String, String>>> {
-        let content = fs::read_to_string(lockfile_path)?;
-        let yaml: serde_yml::Value = serde_yml::from_str(&content)
-            .map_err(|e| anyhow::anyhow!("Failed to parse lockfile: {}", e))?;
-
-        let mut result = HashMap::new();
-
-        let importers = yaml.get("importers")
-            .and_then(|v| v.as_mapping());
-        let importers = match importers {
-            Some(m) => m,
-            None => return Ok(result),
-        };
-
-        for (importer_key, importer_val) in importers {
-            let importer_path = match importer_key.as_str() {
-                Some(s) => s.to_string(),
-                None => continue,
-            };
-
-            let mut deps_map = HashMap::new();
-
-            // Process both dependencies and devDependencies
-            for section in &["dependencies", "devDependencies"] {
-                if let Some(deps) = importer_val.get(*section).and_then(|v| v.as_mapping()) {
-                    for (name_val, info_val) in deps {
-                        let name = match name_val.as_str() {
-                            Some(s) => s,
-                            None => continue,
-                        };
-                        let version = info_val.get("version")
-                            .and_then(|v| v.as_str())
-                            .unwrap_or("");
-
-                        // Skip workspace links (link:../foo)
-                        if version.starts_with("link:") {
-                            continue;
-                        }
-
-                        // Strip peer dep suffixes: "1.2.3(react@19.0.0)" → "1.2.3"
-                        let exact = if let Some(idx) = version.find('(') {
-                            &version[..idx]
-                        } else {
-                            version
-                        };
-
-                        if !exact.is_empty() {
-                            deps_map.insert(name.to_string(), exact.to_string());
-                        }
-                    }
-                }
-            }
-
-            if !deps_map.is_empty() {
-                result.insert(importer_path, deps_map);
-            }
-        }
-
-        Ok(result)
-    }
-
     pub fn scan(&mut self, file_path: &Path) -> Result<CodeNode> {
         // Canonicalize to ensure cache/visited checks work regardless of ../. in paths
         let file_path = file_path.canonicalize().unwrap_or_else(|_| file_path.to_path_buf());
@@ -448,36 +375,13 @@ impl ImportScanner {
 
     /// Look up a package version: lockfile (exact) → nearest package.json (specifier) → any cached.
     fn lookup_version(&mut self, pkg_name: &str, file_dir: &Path) -> Option<String> {
-        // 1. Try pnpm-lock.yaml for exact resolved version
-        if let Some(ref lockfile_root) = self.lockfile_root.clone() {
-            // Find the importer whose path best matches file_dir relative to lockfile root
-            let canonical_dir = file_dir.canonicalize().unwrap_or_else(|_| file_dir.to_path_buf());
-            let canonical_root = lockfile_root.canonicalize().unwrap_or_else(|_| lockfile_root.clone());
-            if let Ok(rel) = canonical_dir.strip_prefix(&canonical_root) {
-                let rel_str = rel.to_string_lossy().to_string();
-                // Walk up from file_dir's relative path to find the matching importer
-                let mut search = rel_str.as_str();
-                loop {
-                    if let Some(deps) = self.lockfile_importers.get(search) {
-                        if let Some(version) = deps.get(pkg_name) {
-                            return Some(version.clone());
-                        }
-                    }
-                    // Walk up one directory
-                    match search.rfind('/') {
-                        Some(idx) => search = &search[..idx],
-                        None => {
-                            // Try root importer "."
-                            if let Some(deps) = self.lockfile_importers.get(".") {
-                                if let Some(version) = deps.get(pkg_name) {
-                                    return Some(version.clone());
-                                }
-                            }
-                            break;
-                        }
-                    }
-                }
-            }
+        // 1. Try the lockfile for exact resolved version
+        if let Some(version) = self
+            .lockfile
+            .as_ref()
+            .and_then(|lockfile| lockfile.version(file_dir, pkg_name))
+        {
+            return Some(version.to_string());
         }
 
         // 2. Try nearest package.json (specifier/range)
@@ -505,38 +409,44 @@ impl ImportScanner {
     }
 
     fn resolve_local_import(&self, param_dir: &Path, import_path: &str) -> Option<PathBuf> {
-        let base = param_dir.join(import_path);
+        resolve_local_import(param_dir, import_path)
+    }
+}
 
-        // Try exact match
-        if base.exists() && base.is_file() {
-            return base.canonicalize().ok();
-        }
+/// Resolve a relative import the way TypeScript does: the exact file,
+/// then `.ts`, `.tsx` and `index.ts`.
+pub fn resolve_local_import(param_dir: &Path, import_path: &str) -> Option<PathBuf> {
+    let base = param_dir.join(import_path);
 
-        // Try appending .ts (not replacing extension, since filenames like "plan.controller"
-        // have dots that aren't file extensions)
-        let mut ts_name = base.as_os_str().to_os_string();
-        ts_name.push(".ts");
-        let ts = PathBuf::from(ts_name);
-        if ts.exists() {
-            return ts.canonicalize().ok();
-        }
+    // Try exact match
+    if base.exists() && base.is_file() {
+        return base.canonicalize().ok();
+    }
 
-        // Try appending .tsx
-        let mut tsx_name = base.as_os_str().to_os_string();
-        tsx_name.push(".tsx");
-        let tsx = PathBuf::from(tsx_name);
-        if tsx.exists() {
-            return tsx.canonicalize().ok();
-        }
+    // Try appending .ts (not replacing extension, since filenames like "plan.controller"
+    // have dots that aren't file extensions)
+    let mut ts_name = base.as_os_str().to_os_string();
+    ts_name.push(".ts");
+    let ts = PathBuf::from(ts_name);
+    if ts.exists() {
+        return ts.canonicalize().ok();
+    }
 
-        // Try /index.ts
-        let index = base.join("index.ts");
-        if index.exists() {
-            return index.canonicalize().ok();
-        }
+    // Try appending .tsx
+    let mut tsx_name = base.as_os_str().to_os_string();
+    tsx_name.push(".tsx");
+    let tsx = PathBuf::from(tsx_name);
+    if tsx.exists() {
+        return tsx.canonicalize().ok();
+    }
 
-        None
+    // Try /index.ts
+    let index = base.join("index.ts");
+    if index.exists() {
+        return index.canonicalize().ok();
     }
+
+    None
 }
 
 /// Parsed route extracted from a .routes.ts file
@@ -0,0 +1,528 @@
+use std::{
+    collections::HashMap,
+    fs::read_to_string,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result, bail};
+use serde_json::Value;
+use serde_yml::Value as YamlValue;
+
+/// Lockfiles in the order they are looked for in each directory.
+const LOCKFILE_NAMES: [(&str, LockfileKind); 4] = [
+    ("pnpm-lock.yaml", LockfileKind::Pnpm),
+    ("bun.lock", LockfileKind::Bun),
+    ("package-lock.json", LockfileKind::Npm),
+    ("yarn.lock", LockfileKind::Yarn),
+];
+
+const DEPENDENCY_SECTIONS: [&str; 3] = ["dependencies", "devDependencies", "optionalDependencies"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum LockfileKind {
+    Pnpm,
+    Bun,
+    Npm,
+    Yarn,
+}
+
+/// Exact dependency versions resolved by the package manager, per workspace.
+#[derive(Debug, Clone)]
+pub(crate) struct Lockfile {
+    #[allow(dead_code)]
+    pub(crate) kind: LockfileKind,
+    /// Directory containing the lockfile
+    pub(crate) root: PathBuf,
+    /// Workspace path relative to `root` (`.` for the root) → { package → exact version }
+    importers: HashMap<String, HashMap<String, String>>,
+}
+
+impl Lockfile {
+    /// Walk up from `start_dir` to the first directory with a supported
+    /// lockfile, stopping at `stop_dir` (inclusive). Lockfiles that fail to
+    /// parse are skipped.
+    pub(crate) fn find(start_dir: &Path, stop_dir: &Path) -> Option<Self> {
+        let mut dir = start_dir.to_path_buf();
+        loop {
+            for (name, _) in LOCKFILE_NAMES {
+                let candidate = dir.join(name);
+                if candidate.exists() {
+                    match Self::parse(&candidate) {
+                        Ok(lockfile) => return Some(lockfile),
+                        Err(error) => log::debug!("{:#}", error),
+                    }
+                }
+            }
+            if dir == stop_dir || !dir.starts_with(stop_dir) {
+                break;
+            }
+            match dir.parent() {
+                Some(parent) if parent != dir => dir = parent.to_path_buf(),
+                _ => break,
+            }
+        }
+        None
+    }
+
+    pub(crate) fn parse(path: &Path) -> Result<Self> {
+        let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+        let kind = LOCKFILE_NAMES
+            .iter()
+            .find(|(name, _)| *name == file_name)
+            .map(|(_, kind)| *kind)
+            .with_context(|| format!("Unsupported lockfile: {}", path.display()))?;
+        let content = read_to_string(path)
+            .with_context(|| format!("Failed to read lockfile: {}", path.display()))?;
+
+        let importers = match kind {
+            LockfileKind::Pnpm => parse_pnpm(&content),
+            LockfileKind::Bun => parse_bun(&content),
+            LockfileKind::Npm => parse_npm(&content),
+            LockfileKind::Yarn => parse_yarn(&content),
+        }
+        .with_context(|| format!("Failed to parse lockfile: {}", path.display()))?;
+
+        let root = path.parent().unwrap_or(Path::new("."));
+        Ok(Self {
+            kind,
+            root: root.canonicalize().unwrap_or_else(|_| root.to_path_buf()),
+            importers,
+        })
+    }
+
+    /// Exact version of `package` for the workspace containing `dir`,
+    /// falling back to parent workspaces and then the root.
+    pub(crate) fn version(&self, dir: &Path, package: &str) -> Option<&str> {
+        let dir = dir.canonicalize().unwrap_or_else(|_| dir.to_path_buf());
+        let relative = dir.strip_prefix(&self.root).ok()?;
+        let mut search = relative
+            .components()
+            .map(|component| component.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+        loop {
+            let importer = if search.is_empty() { "." } else { &search };
+            if let Some(version) = self
+                .importers
+                .get(importer)
+                .and_then(|deps| deps.get(package))
+            {
+                return Some(version);
+            }
+            match search.rfind('/') {
+                Some(index) => search.truncate(index),
+                None if !search.is_empty() => search.clear(),
+                None => return None,
+            }
+        }
+    }
+}
+
+/// Versions that point at the workspace rather than the registry.
+fn is_local_version(version: &str) -> bool {
+    ["link:", "workspace:", "file:", "portal:"]
+        .iter()
+        .any(|protocol| version.starts_with(protocol))
+}
+
+fn importer_key(path: &str) -> String {
+    let path = path.trim_start_matches("./").trim_end_matches('/');
+    if path.is_empty() {
+        ".".to_string()
+    } else {
+        path.to_string()
+    }
+}
+
+/// `importers` of pnpm-lock.yaml.
+fn parse_pnpm(content: &str) -> Result<HashMap<String, HashMap<String, String>>> {
+    let yaml: YamlValue = serde_yml::from_str(content)?;
+    let mut result = HashMap::new();
+
+    let Some(importers) = yaml.get("importers").and_then(|v| v.as_mapping()) else {
+        return Ok(result);
+    };
+
+    for (importer_key_value, importer) in importers {
+        let Some(importer_path) = importer_key_value.as_str() else {
+            continue;
+        };
+
+        let mut deps = HashMap::new();
+        for section in DEPENDENCY_SECTIONS {
+            let Some(section) = importer.get(section).and_then(|v| v.as_mapping()) else {
+                continue;
+            };
+            for (name, info) in section {
+                let (Some(name), Some(version)) =
+                    (name.as_str(), info.get("version").and_then(|v| v.as_str()))
+                else {
+                    continue;
+                };
+                if is_local_version(version) {
+                    continue;
+                }
+                // Strip peer dep suffixes: "1.2.3(react@19.0.0)" → "1.2.3"
+                let exact = version.split('(').next().unwrap_or(version);
+                if !exact.is_empty() {
+                    deps.insert(name.to_string(), exact.to_string());
+                }
+            }
+        }
+
+        if !deps.is_empty() {
+            result.insert(importer_key(importer_path), deps);
+        }
+    }
+
+    Ok(result)
+}
+
+/// bun.lock is JSON with trailing commas.
+fn strip_trailing_commas(content: &str) -> String {
+    let mut result = String::with_capacity(content.len());
+    let mut in_string = false;
+    let mut escaped = false;
+    // A comma is only written once the next token shows it is not trailing
+    let mut pending_comma = false;
+
+    for c in content.chars() {
+        if in_string {
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == '"' {
+                in_string = false;
+            }
+        } else if c == ',' {
+            pending_comma = true;
+            continue;
+        } else if c == '}' || c == ']' {
+            pending_comma = false;
+        } else if !c.is_whitespace() {
+            if pending_comma {
+                result.push(',');
+                pending_comma = false;
+            }
+            in_string = c == '"';
+        }
+        result.push(c);
+    }
+    result
+}
+
+/// `name@version` → (`name`, `version`), handling scoped names.
+fn split_descriptor(descriptor: &str) -> Option<(&str, &str)> {
+    let index = descriptor.get(1..)?.find('@')? + 1;
+    Some((&descriptor[..index], &descriptor[index + 1..]))
+}
+
+/// `workspaces` and `packages` of the text bun.lock. Packages are keyed by
+/// name, or `<parent>/<name>` where a workspace or package needs a different
+/// version than the hoisted one.
+fn parse_bun(content: &str) -> Result<HashMap<String, HashMap<String, String>>> {
+    let json: Value = serde_json::from_str(&strip_trailing_commas(content))?;
+    let mut result = HashMap::new();
+
+    let (Some(workspaces), Some(packages)) = (
+        json.get("workspaces").and_then(Value::as_object),
+        json.get("packages").and_then(Value::as_object),
+    ) else {
+        return Ok(result);
+    };
+
+    let resolved = |key: &str| -> Option<String> {
+        let descriptor = packages.get(key)?.get(0)?.as_str()?;
+        let (_, version) = split_descriptor(descriptor)?;
+        (!is_local_version(version)).then(|| version.to_string())
+    };
+
+    for (workspace_path, workspace) in workspaces {
+        let workspace_name = workspace.get("name").and_then(Value::as_str);
+        let mut deps = HashMap::new();
+        for section in DEPENDENCY_SECTIONS {
+            let Some(section) = workspace.get(section).and_then(Value::as_object) else {
+                continue;
+            };
+            for name in section.keys() {
+                let version = workspace_name
+                    .and_then(|workspace_name| resolved(&format!("{}/{}", workspace_name, name)))
+                    .or_else(|| resolved(name));
+                if let Some(version) = version {
+                    deps.insert(name.clone(), version);
+                }
+            }
+        }
+        if !deps.is_empty() {
+            result.insert(importer_key(workspace_path), deps);
+        }
+    }
+
+    Ok(result)
+}
+
+/// `packages` of package-lock.json v2 and v3. Workspaces are the keys
+/// outside `node_modules`; a dependency resolves to the workspace's own
+/// `node_modules` first, then the hoisted one.
+fn parse_npm(content: &str) -> Result<HashMap<String, HashMap<String, String>>> {
+    let json: Value = serde_json::from_str(content)?;
+    let mut result = HashMap::new();
+
+    let Some(packages) = json.get("packages").and_then(Value::as_object) else {
+        bail!("package-lock.json v1 is not supported; run npm install with npm 7 or later");
+    };
+
+    let resolved = |key: &str| -> Option<String> {
+        let package = packages.get(key)?;
+        if package.get("link").and_then(Value::as_bool) == Some(true) {
+            return None;
+        }
+        package
+            .get("version")
+            .and_then(Value::as_str)
+            .map(str::to_string)
+    };
+
+    for (workspace_path, workspace) in packages {
+        if workspace_path.contains("node_modules/") || workspace_path.starts_with("node_modules") {
+            continue;
+        }
+        let prefix = if workspace_path.is_empty() {
+            String::new()
+        } else {
+            format!("{}/", workspace_path)
+        };
+
+        let mut deps = HashMap::new();
+        for section in DEPENDENCY_SECTIONS {
+            let Some(section) = workspace.get(section).and_then(Value::as_object) else {
+                continue;
+            };
+            for name in section.keys() {
+                let version = resolved(&format!("{}node_modules/{}", prefix, name))
+                    .or_else(|| resolved(&format!("node_modules/{}", name)));
+                if let Some(version) = version {
+                    deps.insert(name.clone(), version);
+                }
+            }
+        }
+        if !deps.is_empty() {
+            result.insert(importer_key(workspace_path), deps);
+        }
+    }
+
+    Ok(result)
+}
+
+/// yarn.lock from Yarn 2 and later (berry). Each entry is keyed by the
+/// descriptors it satisfies, e.g. `"foo@npm:^1.0.0, foo@npm:^1.1.0"`, and
+/// workspaces are the entries resolved with the `workspace:` protocol.
+fn parse_yarn(content: &str) -> Result<HashMap<String, HashMap<String, String>>> {
+    let yaml: YamlValue = serde_yml::from_str(content)
+        .context("yarn.lock v1 is not supported; only Yarn 2 and later lockfiles can be read")?;
+    let Some(entries) = yaml.as_mapping() else {
+        bail!("yarn.lock v1 is not supported; only Yarn 2 and later lockfiles can be read");
+    };
+    if yaml.get("__metadata").is_none() {
+        bail!("yarn.lock v1 is not supported; only Yarn 2 and later lockfiles can be read");
+    }
+
+    let mut versions: HashMap<&str, &str> = HashMap::new();
+    let mut workspaces = vec![];
+    for (key, entry) in entries {
+        let Some(key) = key.as_str() else {
+            continue;
+        };
+        if key == "__metadata" {
+            continue;
+        }
+        let resolution = entry
+            .get("resolution")
+            .and_then(|v| v.as_str())
+            .unwrap_or("");
+        if let Some((_, location)) = resolution.split_once("@workspace:") {
+            workspaces.push((location, entry));
+            continue;
+        }
+        if let Some(version) = entry.get("version").and_then(|v| v.as_str()) {
+            for descriptor in key.split(", ") {
+                versions.insert(descriptor.trim(), version);
+            }
+        }
+    }
+
+    let mut result = HashMap::new();
+    for (location, workspace) in workspaces {
+        let mut deps = HashMap::new();
+        for section in DEPENDENCY_SECTIONS {
+            let Some(section) = workspace.get(section).and_then(|v| v.as_mapping()) else {
+                continue;
+            };
+            for (name, range) in section {
+                let (Some(name), Some(range)) = (name.as_str(), range.as_str()) else {
+                    continue;
+                };
+                // Ranges without a protocol are stored with `npm:`
+                let version = versions
+                    .get(format!("{}@{}", name, range).as_str())
+                    .or_else(|| versions.get(format!("{}@npm:{}", name, range).as_str()));
+                if let Some(version) = version {
+                    deps.insert(name.to_string(), version.to_string());
+                }
+            }
+        }
+        if !deps.is_empty() {
+            result.insert(importer_key(location), deps);
+        }
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::{create_dir_all, write};
+
+    use tempfile::tempdir;
+
+    use super::*;
+
+    fn lockfile(name: &str, content: &str) -> (tempfile::TempDir, Lockfile) {
+        let dir = tempdir().unwrap();
+        create_dir_all(dir.path().join("src/modules/iam/api")).unwrap();
+        write(dir.path().join(name), content).unwrap();
+        let lockfile = Lockfile::find(&dir.path().join("src/modules/iam"), dir.path()).unwrap();
+        (dir, lockfile)
+    }
+
+    #[test]
+    fn test_pnpm_lockfile() {
+        let (dir, lockfile) = lockfile(
+            "pnpm-lock.yaml",
+            "lockfileVersion: '9.0'\nimporters:\n  .:\n    devDependencies:\n      typescript:\n        specifier: ^5.0.0\n        version: 5.6.3\n  src/modules/iam:\n    dependencies:\n      express:\n        specifier: ^4.0.0\n        version: 4.21.2(supports-color@5.5.0)\n      '@app/core':\n        specifier: workspace:*\n        version: link:../core\n",
+        );
+        let iam = dir.path().join("src/modules/iam/api");
+
+        assert_eq!(lockfile.kind, LockfileKind::Pnpm);
+        assert_eq!(lockfile.version(&iam, "express"), Some("4.21.2"));
+        assert_eq!(lockfile.version(&iam, "typescript"), Some("5.6.3"));
+        assert_eq!(lockfile.version(&iam, "@app/core"), None);
+    }
+
+    #[test]
+    fn test_bun_lockfile() {
+        let (dir, lockfile) = lockfile(
+            "bun.lock",
+            r#"{
+  "lockfileVersion": 1,
+  "workspaces": {
+    "": {
+      "name": "app",
+      "devDependencies": { "typescript": "^5.0.0", },
+    },
+    "src/modules/iam": {
+      "name": "@app/iam",
+      "dependencies": { "@app/core": "workspace:*", "zod": "^3.0.0", "express": "^4.0.0", },
+    },
+  },
+  "packages": {
+    "@app/core": ["@app/core@workspace:src/modules/core"],
+    "express": ["express@5.0.1", "", {}, "sha512-a,b"],
+    "typescript": ["typescript@5.6.3", "", {}, "sha512-c"],
+    "zod": ["zod@3.24.1", "", {}, "sha512-d"],
+    "@app/iam/express": ["express@4.21.2", "", {}, "sha512-e"],
+  }
+}
+"#,
+        );
+        let iam = dir.path().join("src/modules/iam");
+
+        assert_eq!(lockfile.kind, LockfileKind::Bun);
+        assert_eq!(lockfile.version(&iam, "express"), Some("4.21.2"));
+        assert_eq!(lockfile.version(&iam, "zod"), Some("3.24.1"));
+        assert_eq!(lockfile.version(&iam, "typescript"), Some("5.6.3"));
+        assert_eq!(lockfile.version(&iam, "@app/core"), None);
+    }
+
+    #[test]
+    fn test_npm_lockfile() {
+        let (dir, lockfile) = lockfile(
+            "package-lock.json",
+            r#"{
+  "name": "app",
+  "lockfileVersion": 3,
+  "packages": {
+    "": { "name": "app", "workspaces": ["src/modules/*"], "devDependencies": { "typescript": "^5.0.0" } },
+    "src/modules/iam": { "name": "@app/iam", "dependencies": { "express": "^4.0.0", "@app/core": "*" } },
+    "node_modules/@app/core": { "resolved": "src/modules/core", "link": true },
+    "node_modules/express": { "version": "5.0.1" },
+    "node_modules/typescript": { "version": "5.6.3", "dev": true },
+    "src/modules/iam/node_modules/express": { "version": "4.21.2" }
+  }
+}"#,
+        );
+        let iam = dir.path().join("src/modules/iam");
+
+        assert_eq!(lockfile.kind, LockfileKind::Npm);
+        assert_eq!(lockfile.version(&iam, "express"), Some("4.21.2"));
+        assert_eq!(lockfile.version(&iam, "typescript"), Some("5.6.3"));
+        assert_eq!(lockfile.version(&iam, "@app/core"), None);
+    }
+
+    #[test]
+    fn test_yarn_berry_lockfile() {
+        let (dir, lockfile) = lockfile(
+            "yarn.lock",
+            r#"# This file is generated by running "yarn install" inside your project.
+
+__metadata:
+  version: 8
+  cacheKey: 10c0
+
+"@app/iam@workspace:src/modules/iam":
+  version: 0.0.0-use.local
+  resolution: "@app/iam@workspace:src/modules/iam"
+  dependencies:
+    "@app/core": "workspace:*"
+    express: "npm:^4.0.0"
+  languageName: unknown
+  linkType: soft
+
+"app@workspace:.":
+  version: 0.0.0-use.local
+  resolution: "app@workspace:."
+  dependencies:
+    typescript: "npm:^5.0.0"
+  languageName: unknown
+  linkType: soft
+
+"express@npm:^4.0.0, express@npm:^4.18.0":
+  version: 4.21.2
+  resolution: "express@npm:4.21.2"
+  languageName: node
+  linkType: hard
+
+"typescript@npm:^5.0.0":
+  version: 5.6.3
+  resolution: "typescript@npm:5.6.3"
+  languageName: node
+  linkType: hard
+"#,
+        );
+        let iam = dir.path().join("src/modules/iam");
+
+        assert_eq!(lockfile.kind, LockfileKind::Yarn);
+        assert_eq!(lockfile.version(&iam, "express"), Some("4.21.2"));
+        assert_eq!(lockfile.version(&iam, "typescript"), Some("5.6.3"));
+        assert_eq!(lockfile.version(&iam, "@app/core"), None);
+
+        let dir = tempdir().unwrap();
+        write(
+            dir.path().join("yarn.lock"),
+            "# yarn lockfile v1\n\nexpress@^4.0.0:\n  version \"4.21.2\"\n",
+        )
+        .unwrap();
+        assert!(Lockfile::parse(&dir.path().join("yarn.lock")).is_err());
+    }
+}
@@ -93,7 +93,7 @@ pub(crate) enum ProjectType {
 
 impl Content for ProjectType {}
 
-#[derive(Debug, Serialize, Deserialize, Content, Clone)]
+#[derive(Debug, Default, Serialize, Deserialize, Content, Clone)]
 pub(crate) struct ResourceInventory {
     pub(crate) database: Option<String>,
     pub(crate) cache: Option<String>,
@@ -172,6 +172,17 @@ macro_rules! internal_config_struct {
             $vis release_git_commit: Option<String>,
             #[serde(skip_serializing_if = "Option::is_none")]
             $vis release_git_branch: Option<String>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            $vis secrets_recipients: Option<Vec<String>>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            #[ramhorns(skip)]
+            $vis env_schema: Option<std::collections::BTreeMap<String, crate::core::env_schema::EnvVarSchemaOverride>>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            #[ramhorns(skip)]
+            $vis architecture: Option<crate::core::architecture::ArchitectureConfig>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            #[ramhorns(skip)]
+            $vis licenses: Option<crate::core::dependency_licenses::LicensePolicy>,
         }
     };
 }
@@ -276,6 +287,10 @@ macro_rules! config_struct {
                         release_version: shadow.release_version.clone(),
                         release_git_commit: shadow.release_git_commit.clone(),
                         release_git_branch: shadow.release_git_branch.clone(),
+                        secrets_recipients: shadow.secrets_recipients.clone(),
+                        env_schema: shadow.env_schema.clone(),
+                        architecture: shadow.architecture.clone(),
+                        licenses: shadow.licenses.clone(),
 
                         is_eslint: shadow.linter == "eslint",
                         is_biome: shadow.formatter == "biome",
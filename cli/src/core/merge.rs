@@ -0,0 +1,444 @@
+use std::{
+    fs::{create_dir_all, read_to_string, write},
+    path::{Component, Path, PathBuf},
+};
+
+use anyhow::{Context, Result};
+
+/// Directory under `.forklaunch/` holding the last generated version of
+/// every file the CLI writes.
+pub(crate) const GENERATED_BASE_DIR: &str = "generated";
+
+const CONFLICT_OURS_MARKER: &str = "<<<<<<< your changes";
+const CONFLICT_BASE_MARKER: &str = "||||||| last generated";
+const CONFLICT_SEPARATOR: &str = "=======";
+const CONFLICT_THEIRS_MARKER: &str = ">>>>>>> regenerated";
+
+/// Above this many cells the line diff gives up on finding common lines
+/// inside the differing region and treats it as one change.
+const MAX_DIFF_CELLS: usize = 4_000_000;
+
+/// A differing region: `base[base_start..base_end]` was replaced by
+/// `side[side_start..side_end]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Hunk {
+    base_start: usize,
+    base_end: usize,
+    side_start: usize,
+    side_end: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct MergeResult {
+    pub(crate) content: String,
+    /// 1-based line numbers of each conflict's opening marker.
+    pub(crate) conflicts: Vec<usize>,
+}
+
+impl MergeResult {
+    pub(crate) fn has_conflicts(&self) -> bool {
+        !self.conflicts.is_empty()
+    }
+}
+
+fn split_lines(content: &str) -> Vec<&str> {
+    content.split_inclusive('\n').collect()
+}
+
+fn diff_lines(base: &[&str], side: &[&str]) -> Vec<Hunk> {
+    let prefix = base
+        .iter()
+        .zip(side.iter())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let suffix = base[prefix..]
+        .iter()
+        .rev()
+        .zip(side[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    let base_mid = &base[prefix..base.len() - suffix];
+    let side_mid = &side[prefix..side.len() - suffix];
+    if base_mid.is_empty() && side_mid.is_empty() {
+        return vec![];
+    }
+
+    let whole = Hunk {
+        base_start: prefix,
+        base_end: prefix + base_mid.len(),
+        side_start: prefix,
+        side_end: prefix + side_mid.len(),
+    };
+    if base_mid.is_empty()
+        || side_mid.is_empty()
+        || (base_mid.len() + 1) * (side_mid.len() + 1) > MAX_DIFF_CELLS
+    {
+        return vec![whole];
+    }
+
+    // Longest common subsequence table over the differing middle, filled
+    // from the end so matches can be walked front to back.
+    let width = side_mid.len() + 1;
+    let mut lcs = vec![0u32; (base_mid.len() + 1) * width];
+    for i in (0..base_mid.len()).rev() {
+        for j in (0..side_mid.len()).rev() {
+            lcs[i * width + j] = if base_mid[i] == side_mid[j] {
+                lcs[(i + 1) * width + j + 1] + 1
+            } else {
+                lcs[(i + 1) * width + j].max(lcs[i * width + j + 1])
+            };
+        }
+    }
+
+    let mut hunks = vec![];
+    let (mut i, mut j) = (0, 0);
+    let (mut hunk_i, mut hunk_j) = (0, 0);
+    while i < base_mid.len() && j < side_mid.len() {
+        if base_mid[i] == side_mid[j] {
+            if hunk_i < i || hunk_j < j {
+                hunks.push(Hunk {
+                    base_start: prefix + hunk_i,
+                    base_end: prefix + i,
+                    side_start: prefix + hunk_j,
+                    side_end: prefix + j,
+                });
+            }
+            i += 1;
+            j += 1;
+            hunk_i = i;
+            hunk_j = j;
+        } else if lcs[(i + 1) * width + j] >= lcs[i * width + j + 1] {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    if hunk_i < base_mid.len() || hunk_j < side_mid.len() {
+        hunks.push(Hunk {
+            base_start: prefix + hunk_i,
+            base_end: prefix + base_mid.len(),
+            side_start: prefix + hunk_j,
+            side_end: prefix + side_mid.len(),
+        });
+    }
+
+    hunks
+}
+
+/// Rebuilds `base[start..end]` with the side's hunks in that range applied.
+fn apply_hunks<'a>(
+    base: &[&'a str],
+    side: &[&'a str],
+    hunks: &[Hunk],
+    start: usize,
+    end: usize,
+) -> Vec<&'a str> {
+    let mut lines = vec![];
+    let mut position = start;
+    for hunk in hunks {
+        lines.extend_from_slice(&base[position..hunk.base_start]);
+        lines.extend_from_slice(&side[hunk.side_start..hunk.side_end]);
+        position = hunk.base_end;
+    }
+    lines.extend_from_slice(&base[position..end]);
+    lines
+}
+
+/// When one side is the other plus lines that were not in the base, the
+/// larger side already contains both sets of changes.
+fn resolve_extension<'a>(
+    base: &[&str],
+    ours: &[&'a str],
+    theirs: &[&'a str],
+) -> Option<Vec<&'a str>> {
+    let prefix = ours
+        .iter()
+        .zip(theirs.iter())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let suffix = ours[prefix..]
+        .iter()
+        .rev()
+        .zip(theirs[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let ours_rest = &ours[prefix..ours.len() - suffix];
+    let theirs_rest = &theirs[prefix..theirs.len() - suffix];
+
+    let only_new_lines = |lines: &[&str]| lines.iter().all(|line| !base.contains(line));
+    if ours_rest.is_empty() && only_new_lines(theirs_rest) {
+        Some(theirs.to_vec())
+    } else if theirs_rest.is_empty() && only_new_lines(ours_rest) {
+        Some(ours.to_vec())
+    } else {
+        None
+    }
+}
+
+fn push_marker(content: &mut String, marker: &str) {
+    if !content.is_empty() && !content.ends_with('\n') {
+        content.push('\n');
+    }
+    content.push_str(marker);
+    content.push('\n');
+}
+
+/// Three-way merges the user's copy of a file (`ours`) with freshly
+/// generated output (`theirs`), using the previously generated version as
+/// the common ancestor. Clashing hunks are written with diff3-style
+/// conflict markers.
+pub(crate) fn merge_three_way(base: &str, ours: &str, theirs: &str) -> MergeResult {
+    if ours == base || ours == theirs {
+        return MergeResult {
+            content: theirs.to_string(),
+            conflicts: vec![],
+        };
+    }
+    if theirs == base {
+        return MergeResult {
+            content: ours.to_string(),
+            conflicts: vec![],
+        };
+    }
+
+    let base_lines = split_lines(base);
+    let ours_lines = split_lines(ours);
+    let theirs_lines = split_lines(theirs);
+    let ours_hunks = diff_lines(&base_lines, &ours_lines);
+    let theirs_hunks = diff_lines(&base_lines, &theirs_lines);
+
+    let mut content = String::new();
+    let mut conflicts = vec![];
+    let mut position = 0;
+    let (mut next_ours, mut next_theirs) = (0, 0);
+
+    while next_ours < ours_hunks.len() || next_theirs < theirs_hunks.len() {
+        // Start a group at the earliest remaining hunk and absorb every hunk
+        // from either side that overlaps or touches it.
+        let group_start = match (ours_hunks.get(next_ours), theirs_hunks.get(next_theirs)) {
+            (Some(o), Some(t)) => o.base_start.min(t.base_start),
+            (Some(o), None) => o.base_start,
+            (None, Some(t)) => t.base_start,
+            (None, None) => unreachable!(),
+        };
+        let mut group_end = group_start;
+        let (ours_from, theirs_from) = (next_ours, next_theirs);
+        loop {
+            if let Some(hunk) = ours_hunks.get(next_ours)
+                && hunk.base_start <= group_end
+            {
+                group_end = group_end.max(hunk.base_end);
+                next_ours += 1;
+                continue;
+            }
+            if let Some(hunk) = theirs_hunks.get(next_theirs)
+                && hunk.base_start <= group_end
+            {
+                group_end = group_end.max(hunk.base_end);
+                next_theirs += 1;
+                continue;
+            }
+            break;
+        }
+
+        content.extend(base_lines[position..group_start].iter().copied());
+        position = group_end;
+
+        let base_region = &base_lines[group_start..group_end];
+        let ours_region = apply_hunks(
+            &base_lines,
+            &ours_lines,
+            &ours_hunks[ours_from..next_ours],
+            group_start,
+            group_end,
+        );
+        let theirs_region = apply_hunks(
+            &base_lines,
+            &theirs_lines,
+            &theirs_hunks[theirs_from..next_theirs],
+            group_start,
+            group_end,
+        );
+
+        let resolved = if ours_from == next_ours || ours_region == theirs_region {
+            Some(theirs_region.clone())
+        } else if theirs_from == next_theirs {
+            Some(ours_region.clone())
+        } else {
+            resolve_extension(base_region, &ours_region, &theirs_region)
+        };
+
+        match resolved {
+            Some(lines) => content.extend(lines),
+            None => {
+                if !content.is_empty() && !content.ends_with('\n') {
+                    content.push('\n');
+                }
+                conflicts.push(content.lines().count() + 1);
+                push_marker(&mut content, CONFLICT_OURS_MARKER);
+                content.extend(ours_region);
+                push_marker(&mut content, CONFLICT_BASE_MARKER);
+                content.extend(base_region.iter().copied());
+                push_marker(&mut content, CONFLICT_SEPARATOR);
+                content.extend(theirs_region);
+                push_marker(&mut content, CONFLICT_THEIRS_MARKER);
+            }
+        }
+    }
+
+    content.extend(base_lines[position..].iter().copied());
+
+    MergeResult { content, conflicts }
+}
+
+/// The last generated version of files written under an application,
+/// stored in `.forklaunch/generated/<relative path>`.
+#[derive(Debug)]
+pub(crate) struct GeneratedBaseStore {
+    app_root: PathBuf,
+}
+
+impl GeneratedBaseStore {
+    /// Finds the application that owns `path` by looking for the closest
+    /// ancestor containing a `.forklaunch` directory.
+    pub(crate) fn for_path(path: &Path) -> Option<Self> {
+        let absolute = if path.is_absolute() {
+            path.to_path_buf()
+        } else {
+            std::env::current_dir().ok()?.join(path)
+        };
+        absolute
+            .ancestors()
+            .skip(1)
+            .find(|ancestor| ancestor.join(".forklaunch").is_dir())
+            .map(|app_root| Self {
+                app_root: app_root.to_path_buf(),
+            })
+    }
+
+    fn base_path(&self, path: &Path) -> Option<PathBuf> {
+        let absolute = if path.is_absolute() {
+            path.to_path_buf()
+        } else {
+            std::env::current_dir().ok()?.join(path)
+        };
+        let relative = absolute.strip_prefix(&self.app_root).ok()?;
+
+        let mut normalized = PathBuf::new();
+        for component in relative.components() {
+            match component {
+                Component::Normal(part) => normalized.push(part),
+                Component::CurDir => {}
+                _ => return None,
+            }
+        }
+        // Env files hold local secrets and must not be copied into the
+        // committed .forklaunch directory.
+        if normalized.starts_with(".forklaunch")
+            || normalized
+                .file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with(".env"))
+        {
+            return None;
+        }
+
+        Some(
+            self.app_root
+                .join(".forklaunch")
+                .join(GENERATED_BASE_DIR)
+                .join(normalized),
+        )
+    }
+
+    pub(crate) fn load(&self, path: &Path) -> Option<String> {
+        read_to_string(self.base_path(path)?).ok()
+    }
+
+    pub(crate) fn record(&self, path: &Path, content: &str) -> Result<()> {
+        let Some(base_path) = self.base_path(path) else {
+            return Ok(());
+        };
+        if let Some(parent) = base_path.parent() {
+            create_dir_all(parent)
+                .with_context(|| format!("Failed to create {}", parent.display()))?;
+        }
+        write(&base_path, content)
+            .with_context(|| format!("Failed to record generated {}", path.display()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+
+    #[test]
+    fn test_merge_three_way_keeps_both_sides() {
+        let base = "import a;\n\nconst deps = {\n  a,\n};\n\nexport default deps;\n";
+        let ours =
+            "import a;\n\n// custom wiring\nconst deps = {\n  a,\n};\n\nexport default deps;\n";
+        let theirs =
+            "import a;\nimport b;\n\nconst deps = {\n  a,\n  b,\n};\n\nexport default deps;\n";
+
+        let merged = merge_three_way(base, ours, theirs);
+
+        assert!(!merged.has_conflicts());
+        assert_eq!(
+            merged.content,
+            "import a;\nimport b;\n\n// custom wiring\nconst deps = {\n  a,\n  b,\n};\n\nexport default deps;\n"
+        );
+    }
+
+    #[test]
+    fn test_merge_three_way_conflicts_and_extensions() {
+        let base = "a\nport = 3000\nc\n";
+        let ours = "a\nport = 8080\nc\n";
+        let theirs = "a\nport = 4000\nc\n";
+
+        let merged = merge_three_way(base, ours, theirs);
+        assert_eq!(merged.conflicts, vec![2]);
+        assert_eq!(
+            merged.content,
+            "a\n<<<<<<< your changes\nport = 8080\n||||||| last generated\nport = 3000\n=======\nport = 4000\n>>>>>>> regenerated\nc\n"
+        );
+
+        // Output generated from the user's copy contains their edit plus an
+        // injected line right next to it.
+        let transformed = "a\nport = 8080\ninjected\nc\n";
+        let merged = merge_three_way(base, ours, transformed);
+        assert!(!merged.has_conflicts());
+        assert_eq!(merged.content, transformed);
+
+        // A line the user deleted is not silently restored.
+        let merged = merge_three_way("a\nb\nc\n", "a\nc\n", "a\nb\nd\nc\n");
+        assert!(merged.has_conflicts());
+    }
+
+    #[test]
+    fn test_generated_base_store() {
+        let temp_dir = TempDir::new().unwrap();
+        let app_root = temp_dir.path();
+        std::fs::create_dir_all(app_root.join(".forklaunch")).unwrap();
+        std::fs::create_dir_all(app_root.join("src/modules/billing")).unwrap();
+        let file = app_root.join("src/modules/billing/server.ts");
+
+        let store = GeneratedBaseStore::for_path(&file).unwrap();
+        assert_eq!(store.load(&file), None);
+        store.record(&file, "generated\n").unwrap();
+        assert_eq!(store.load(&file), Some("generated\n".to_string()));
+        assert!(
+            app_root
+                .join(".forklaunch/generated/src/modules/billing/server.ts")
+                .exists()
+        );
+
+        for untracked in [".forklaunch/manifest.toml", "src/modules/billing/.env.local"] {
+            let untracked = app_root.join(untracked);
+            store.record(&untracked, "SECRET=1\n").unwrap();
+            assert_eq!(store.load(&untracked), None);
+        }
+    }
+}
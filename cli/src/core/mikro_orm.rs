@@ -0,0 +1,442 @@
+use std::{
+    collections::{BTreeSet, HashMap, HashSet},
+    fs::read_to_string,
+    io::Write,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+    time::{Duration, Instant},
+};
+
+use anyhow::{Context, Result, bail};
+use serde_yml::from_str as yaml_from_str;
+use termcolor::{Color, ColorSpec, StandardStream, WriteColor};
+
+use crate::{
+    constants::{Database, ERROR_FAILED_TO_PARSE_DOCKER_COMPOSE, Runtime},
+    core::{
+        ast::infrastructure::service_dependencies::find_all_service_dependencies,
+        database::is_in_memory_database,
+        docker::DockerCompose,
+        manifest::{ProjectType, application::ApplicationManifestData},
+        rendered_template::RenderedTemplatesCache,
+    },
+};
+
+const MIKRO_ORM_CONFIG: &str = "mikro-orm.config.ts";
+
+/// A service or worker that owns a MikroORM configuration.
+#[derive(Debug, Clone)]
+pub(crate) struct DatabaseProject {
+    pub(crate) name: String,
+    pub(crate) path: PathBuf,
+    pub(crate) database: Option<Database>,
+}
+
+/// Collects the projects with a `mikro-orm.config.ts`, or just `project` when
+/// one is given.
+pub(crate) fn database_projects(
+    app_root_path: &Path,
+    manifest_data: &ApplicationManifestData,
+    project: Option<&str>,
+) -> Result<Vec<DatabaseProject>> {
+    let modules_path = app_root_path.join(&manifest_data.modules_path);
+
+    if let Some(project) = project
+        && !manifest_data.projects.iter().any(|p| p.name == project)
+    {
+        bail!("Project '{}' not found in manifest", project);
+    }
+
+    let projects: Vec<DatabaseProject> = manifest_data
+        .projects
+        .iter()
+        .filter(|p| matches!(p.r#type, ProjectType::Service | ProjectType::Worker))
+        .filter(|p| project.is_none_or(|name| p.name == name))
+        .map(|p| DatabaseProject {
+            name: p.name.clone(),
+            path: modules_path.join(&p.name),
+            database: p
+                .resources
+                .as_ref()
+                .and_then(|resources| resources.database.as_ref())
+                .and_then(|database| database.parse::<Database>().ok()),
+        })
+        .filter(|p| p.path.join(MIKRO_ORM_CONFIG).exists())
+        .collect();
+
+    if let Some(project) = project
+        && projects.is_empty()
+    {
+        bail!("Project '{}' has no {}", project, MIKRO_ORM_CONFIG);
+    }
+
+    Ok(projects)
+}
+
+/// Orders projects so that each one comes after the projects it imports an
+/// SDK client from. Projects caught in a cycle are appended in name order and
+/// also returned separately so callers can warn about them.
+pub(crate) fn order_by_dependencies(
+    projects: &[String],
+    dependencies: &HashMap<String, Vec<(String, String)>>,
+) -> (Vec<String>, Vec<String>) {
+    let selected: HashSet<&String> = projects.iter().collect();
+    let mut remaining: BTreeSet<&String> = projects.iter().collect();
+    let mut ordered = vec![];
+
+    loop {
+        let ready: Vec<&String> = remaining
+            .iter()
+            .filter(|project| {
+                dependencies.get(**project).is_none_or(|deps| {
+                    deps.iter().all(|(dep, _)| {
+                        !selected.contains(dep) || !remaining.contains(dep) || dep == **project
+                    })
+                })
+            })
+            .copied()
+            .collect();
+        if ready.is_empty() {
+            break;
+        }
+        for project in ready {
+            remaining.remove(project);
+            ordered.push(project.clone());
+        }
+    }
+
+    let cyclic: Vec<String> = remaining.into_iter().cloned().collect();
+    ordered.extend(cyclic.iter().cloned());
+    (ordered, cyclic)
+}
+
+/// Starts the docker-compose database services the projects use if they are
+/// not already running. In-memory databases need no service. When docker is
+/// unavailable a warning is printed and the databases are assumed to be
+/// reachable some other way.
+pub(crate) fn ensure_database_services(
+    app_root_path: &Path,
+    manifest_data: &ApplicationManifestData,
+    projects: &[DatabaseProject],
+    stdout: &mut StandardStream,
+) -> Result<()> {
+    let docker_compose_path = app_root_path.join(
+        manifest_data
+            .docker_compose_path
+            .clone()
+            .unwrap_or("docker-compose.yaml".to_string()),
+    );
+    if !docker_compose_path.exists() {
+        return Ok(());
+    }
+
+    let docker_compose: DockerCompose = yaml_from_str(
+        &read_to_string(&docker_compose_path)
+            .with_context(|| format!("Failed to read {}", docker_compose_path.display()))?,
+    )
+    .context(ERROR_FAILED_TO_PARSE_DOCKER_COMPOSE)?;
+
+    let needed: BTreeSet<String> = projects
+        .iter()
+        .filter_map(|project| project.database)
+        .filter(|database| !is_in_memory_database(database))
+        .map(|database| database.to_string())
+        .filter(|service| docker_compose.services.contains_key(service))
+        .collect();
+    if needed.is_empty() {
+        return Ok(());
+    }
+
+    let running = Command::new("docker")
+        .args(["compose", "-f"])
+        .arg(&docker_compose_path)
+        .args(["ps", "--services", "--status", "running"])
+        .current_dir(app_root_path)
+        .stderr(Stdio::null())
+        .output();
+    let running: HashSet<String> = match running {
+        Ok(output) if output.status.success() => String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .map(|line| line.trim().to_string())
+            .collect(),
+        _ => {
+            stdout.set_color(ColorSpec::new().set_fg(Some(Color::Yellow)))?;
+            writeln!(
+                stdout,
+                "[WARN] Could not query docker compose, assuming {} is reachable",
+                needed.iter().cloned().collect::<Vec<_>>().join(", ")
+            )?;
+            stdout.reset()?;
+            return Ok(());
+        }
+    };
+
+    let missing: Vec<String> = needed
+        .into_iter()
+        .filter(|service| !running.contains(service))
+        .collect();
+    if missing.is_empty() {
+        return Ok(());
+    }
+
+    stdout.set_color(ColorSpec::new().set_fg(Some(Color::Cyan)))?;
+    writeln!(
+        stdout,
+        "[INFO] Starting docker-compose services: {}",
+        missing.join(", ")
+    )?;
+    stdout.reset()?;
+
+    let status = Command::new("docker")
+        .args(["compose", "-f"])
+        .arg(&docker_compose_path)
+        .args(["up", "-d", "--wait"])
+        .args(&missing)
+        .current_dir(app_root_path)
+        .status()
+        .context("Failed to run docker compose")?;
+    if !status.success() {
+        bail!("Failed to start {}", missing.join(", "));
+    }
+
+    Ok(())
+}
+
+/// Builds the MikroORM CLI invocation for a project, run through the
+/// application's package manager the same way the generated `migrate:*` and
+/// `seed` scripts run it.
+pub(crate) fn mikro_orm_command(
+    runtime: &Runtime,
+    project_path: &Path,
+    args: &[String],
+) -> Command {
+    let mut command = match runtime {
+        Runtime::Node => {
+            let mut command = Command::new("pnpm");
+            command.arg("exec");
+            command
+        }
+        Runtime::Bun => {
+            let mut command = Command::new("bun");
+            command.arg("x");
+            command
+        }
+    };
+    command
+        .arg("mikro-orm")
+        .args(args)
+        .current_dir(project_path)
+        .env("NODE_OPTIONS", "--import=tsx");
+    if std::env::var_os("DOTENV_FILE_PATH").is_none() {
+        command.env("DOTENV_FILE_PATH", ".env.local");
+    }
+    command
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum ProjectRunStatus {
+    Succeeded(Option<String>),
+    Failed(String),
+    Skipped(String),
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct ProjectRunResult {
+    pub(crate) project: String,
+    pub(crate) status: ProjectRunStatus,
+    pub(crate) duration: Duration,
+}
+
+/// Runs `run` for each project in dependency order (reverse order when
+/// `reverse` is set, for rollbacks). A project is skipped when a project it
+/// must wait for has failed; the remaining projects still run.
+pub(crate) fn run_in_dependency_order(
+    app_root_path: &Path,
+    manifest_data: &ApplicationManifestData,
+    projects: &[DatabaseProject],
+    reverse: bool,
+    stdout: &mut StandardStream,
+    mut run: impl FnMut(&DatabaseProject, &mut StandardStream) -> Result<Option<String>>,
+) -> Result<Vec<ProjectRunResult>> {
+    let modules_path = app_root_path.join(&manifest_data.modules_path);
+    let dependencies =
+        find_all_service_dependencies(&modules_path, &RenderedTemplatesCache::new())?;
+
+    let names: Vec<String> = projects.iter().map(|p| p.name.clone()).collect();
+    let (mut order, cyclic) = order_by_dependencies(&names, &dependencies);
+    if !cyclic.is_empty() {
+        stdout.set_color(ColorSpec::new().set_fg(Some(Color::Yellow)))?;
+        writeln!(
+            stdout,
+            "[WARN] Circular dependencies between {}, running them in name order",
+            cyclic.join(", ")
+        )?;
+        stdout.reset()?;
+    }
+    if reverse {
+        order.reverse();
+    }
+
+    let depends_on = |project: &str, other: &str| {
+        dependencies
+            .get(project)
+            .is_some_and(|deps| deps.iter().any(|(dep, _)| dep == other))
+    };
+
+    let mut failed: Vec<String> = vec![];
+    let mut results = vec![];
+    for name in order {
+        let project = projects.iter().find(|p| p.name == name).unwrap();
+
+        let blocker = failed.iter().find(|other| {
+            if reverse {
+                depends_on(other, &name)
+            } else {
+                depends_on(&name, other)
+            }
+        });
+        if let Some(blocker) = blocker {
+            results.push(ProjectRunResult {
+                project: name,
+                status: ProjectRunStatus::Skipped(format!("{} failed", blocker)),
+                duration: Duration::ZERO,
+            });
+            continue;
+        }
+
+        writeln!(stdout)?;
+        stdout.set_color(ColorSpec::new().set_fg(Some(Color::Cyan)))?;
+        writeln!(stdout, "[INFO] {}", name)?;
+        stdout.reset()?;
+
+        let started = Instant::now();
+        let status = match run(project, stdout) {
+            Ok(detail) => ProjectRunStatus::Succeeded(detail),
+            Err(error) => {
+                failed.push(name.clone());
+                ProjectRunStatus::Failed(format!("{:#}", error))
+            }
+        };
+        results.push(ProjectRunResult {
+            project: name,
+            status,
+            duration: started.elapsed(),
+        });
+    }
+
+    Ok(results)
+}
+
+/// Prints one line per project and fails when any project failed.
+pub(crate) fn print_run_summary(
+    title: &str,
+    results: &[ProjectRunResult],
+    stdout: &mut StandardStream,
+) -> Result<()> {
+    let width = results.iter().map(|r| r.project.len()).max().unwrap_or(0);
+
+    writeln!(stdout)?;
+    writeln!(stdout, "{}:", title)?;
+    for result in results {
+        let (label, color, detail) = match &result.status {
+            ProjectRunStatus::Succeeded(detail) => (
+                "[OK]     ",
+                Color::Green,
+                match detail {
+                    Some(detail) => format!("{:.1}s, {}", result.duration.as_secs_f64(), detail),
+                    None => format!("{:.1}s", result.duration.as_secs_f64()),
+                },
+            ),
+            ProjectRunStatus::Failed(error) => ("[FAILED] ", Color::Red, error.clone()),
+            ProjectRunStatus::Skipped(reason) => {
+                ("[SKIPPED]", Color::Yellow, format!("skipped, {}", reason))
+            }
+        };
+        write!(stdout, "  ")?;
+        stdout.set_color(ColorSpec::new().set_fg(Some(color)))?;
+        write!(stdout, "{}", label)?;
+        stdout.reset()?;
+        writeln!(
+            stdout,
+            " {:<width$}  {}",
+            result.project,
+            detail,
+            width = width
+        )?;
+    }
+
+    let unsuccessful = results
+        .iter()
+        .filter(|r| !matches!(r.status, ProjectRunStatus::Succeeded(_)))
+        .count();
+    if unsuccessful > 0 {
+        bail!(
+            "{} of {} project(s) did not complete",
+            unsuccessful,
+            results.len()
+        );
+    }
+
+    Ok(())
+}
+
+/// Runs a prepared command with inherited output and turns a non-zero exit
+/// into an error.
+pub(crate) fn run_command(mut command: Command) -> Result<()> {
+    let program = command.get_program().to_string_lossy().to_string();
+    let status = command
+        .status()
+        .with_context(|| format!("Failed to run {}", program))?;
+    if !status.success() {
+        bail!("{} exited with {}", program, status);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn deps(entries: &[(&str, &[&str])]) -> HashMap<String, Vec<(String, String)>> {
+        entries
+            .iter()
+            .map(|(project, deps)| {
+                (
+                    project.to_string(),
+                    deps.iter()
+                        .map(|dep| (dep.to_string(), "network".to_string()))
+                        .collect(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_order_by_dependencies() {
+        let projects: Vec<String> = ["billing", "iam", "orders", "reports"]
+            .iter()
+            .map(|p| p.to_string())
+            .collect();
+        let dependencies = deps(&[
+            ("billing", &["iam"]),
+            ("orders", &["billing", "iam", "not-selected"]),
+            ("iam", &[]),
+        ]);
+
+        let (order, cyclic) = order_by_dependencies(&projects, &dependencies);
+
+        assert_eq!(order, vec!["iam", "reports", "billing", "orders"]);
+        assert!(cyclic.is_empty());
+    }
+
+    #[test]
+    fn test_order_by_dependencies_with_cycle() {
+        let projects: Vec<String> = ["a", "b", "c"].iter().map(|p| p.to_string()).collect();
+        let dependencies = deps(&[("a", &["b"]), ("b", &["a"])]);
+
+        let (order, cyclic) = order_by_dependencies(&projects, &dependencies);
+
+        assert_eq!(order, vec!["c", "a", "b"]);
+        assert_eq!(cyclic, vec!["a", "b"]);
+    }
+}
@@ -1,6 +1,14 @@
-use std::{fs::create_dir_all, path::Path, process::Command as ProcessCommand};
+use std::{
+    fs::{File, create_dir_all, read_to_string, remove_file},
+    path::{Path, PathBuf},
+    process::{Child, Command as ProcessCommand, Stdio},
+    sync::atomic::{AtomicBool, AtomicUsize, Ordering},
+    thread,
+    time::{Duration, Instant},
+};
 
 use anyhow::{Context, Result, bail};
+use clap::ArgMatches;
 
 use crate::{
     constants::Runtime,
@@ -78,6 +86,133 @@ fn generate_dummy_value(var_name: &str, var_type: &str, iam_port: Option<u16>) -
     }
 }
 
+pub(crate) const DEFAULT_EXPORT_TIMEOUT_SECS: u64 = 120;
+
+/// How [`export_all_services`] runs the per-service exports.
+#[derive(Debug, Clone)]
+pub(crate) struct ExportOptions {
+    /// Maximum number of services exported at the same time.
+    pub(crate) jobs: usize,
+    /// How long a single service may run before it is killed.
+    pub(crate) timeout: Duration,
+    /// Keep exporting the remaining services when one fails.
+    pub(crate) continue_on_error: bool,
+}
+
+impl Default for ExportOptions {
+    fn default() -> Self {
+        Self {
+            jobs: thread::available_parallelism().map_or(1, |n| n.get()),
+            timeout: Duration::from_secs(DEFAULT_EXPORT_TIMEOUT_SECS),
+            continue_on_error: false,
+        }
+    }
+}
+
+impl ExportOptions {
+    /// Reads the `export-jobs`, `export-timeout` and `continue-on-error` args.
+    pub(crate) fn from_matches(matches: &ArgMatches) -> Self {
+        let defaults = Self::default();
+        Self {
+            jobs: matches
+                .get_one::<u64>("export-jobs")
+                .map_or(defaults.jobs, |jobs| *jobs as usize),
+            timeout: matches
+                .get_one::<u64>("export-timeout")
+                .map_or(defaults.timeout, |secs| Duration::from_secs(*secs)),
+            continue_on_error: matches.get_flag("continue-on-error"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum ExportStatus {
+    Exported,
+    Failed(String),
+    TimedOut,
+    /// Not started because another service had already failed.
+    Skipped,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct ServiceExport {
+    pub(crate) name: String,
+    pub(crate) status: ExportStatus,
+    pub(crate) elapsed: Duration,
+    /// Captured stdout/stderr, kept only when the export did not succeed.
+    pub(crate) log: Option<PathBuf>,
+}
+
+impl ServiceExport {
+    pub(crate) fn describe(&self) -> String {
+        let status = match &self.status {
+            ExportStatus::Exported => format!("exported in {:.1}s", self.elapsed.as_secs_f64()),
+            ExportStatus::Failed(reason) => format!("failed: {}", reason),
+            ExportStatus::TimedOut => {
+                format!("timed out after {:.0}s", self.elapsed.as_secs_f64())
+            }
+            ExportStatus::Skipped => "skipped".to_string(),
+        };
+        match &self.log {
+            Some(log) => format!("{} (log: {})", status, log.display()),
+            None => status,
+        }
+    }
+}
+
+/// Per-service outcome of [`export_all_services`], in manifest order.
+#[derive(Debug)]
+pub(crate) struct ExportReport {
+    pub(crate) services: Vec<ServiceExport>,
+}
+
+impl ExportReport {
+    pub(crate) fn exported(&self) -> Vec<&str> {
+        self.services
+            .iter()
+            .filter(|service| service.status == ExportStatus::Exported)
+            .map(|service| service.name.as_str())
+            .collect()
+    }
+
+    pub(crate) fn failures(&self) -> Vec<&ServiceExport> {
+        self.services
+            .iter()
+            .filter(|service| {
+                matches!(
+                    service.status,
+                    ExportStatus::Failed(_) | ExportStatus::TimedOut
+                )
+            })
+            .collect()
+    }
+}
+
+/// Last lines of a captured log, for inline error messages.
+fn log_tail(log: &Path, lines: usize) -> String {
+    let content = read_to_string(log).unwrap_or_default();
+    let all: Vec<&str> = content.lines().collect();
+    all[all.len().saturating_sub(lines)..].join("\n")
+}
+
+#[cfg(unix)]
+fn kill_process_tree(child: &mut Child) {
+    // The export runs in its own process group, so this also reaches the
+    // tsx/node processes started by the package manager.
+    let _ = ProcessCommand::new("kill")
+        .args(["-KILL", &format!("-{}", child.id())])
+        .status();
+    let _ = child.kill();
+}
+
+#[cfg(windows)]
+fn kill_process_tree(child: &mut Child) {
+    let _ = ProcessCommand::new("taskkill")
+        .args(["/T", "/F", "/PID", &child.id().to_string()])
+        .status();
+    let _ = child.kill();
+}
+
 pub(crate) fn export_service_openapi(
     service_path: &Path,
     service_name: &str,
@@ -85,7 +220,9 @@ pub(crate) fn export_service_openapi(
     runtime: &str,
     env_vars: &[EnvVarUsage],
     iam_port: Option<u16>,
-) -> Result<()> {
+    timeout: Duration,
+    log_file: &Path,
+) -> Result<ExportStatus> {
     if !service_path.join("package.json").exists() {
         bail!("No package.json found in {}", service_path.display());
     }
@@ -122,46 +259,71 @@ pub(crate) fn export_service_openapi(
         }
     };
 
+    let log = File::create(log_file)
+        .with_context(|| format!("Failed to create log file {}", log_file.display()))?;
+
     let mut cmd = ProcessCommand::new(package_manager);
     cmd.args(&args)
         .current_dir(service_path)
         .env("FORKLAUNCH_MODE", "openapi")
-        .env("FORKLAUNCH_OPENAPI_OUTPUT", output_file);
+        .env("FORKLAUNCH_OPENAPI_OUTPUT", output_file)
+        .stdin(Stdio::null())
+        .stdout(log.try_clone()?)
+        .stderr(log);
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+        cmd.process_group(0);
+    }
 
     for env_var in env_vars {
         let dummy_value = generate_dummy_value(&env_var.var_name, "string", iam_port);
         cmd.env(&env_var.var_name, &dummy_value);
     }
 
-    let output = cmd
-        .output()
+    let mut child = cmd
+        .spawn()
         .with_context(|| format!("Failed to run {} command", package_manager))?;
 
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        bail!("Service {} failed to export: {}", service_name, stderr);
+    let deadline = Instant::now() + timeout;
+    let status = loop {
+        if let Some(status) = child.try_wait()? {
+            break status;
+        }
+        if Instant::now() >= deadline {
+            kill_process_tree(&mut child);
+            let _ = child.wait();
+            return Ok(ExportStatus::TimedOut);
+        }
+        thread::sleep(Duration::from_millis(50));
+    };
+
+    if !status.success() {
+        return Ok(ExportStatus::Failed(format!(
+            "Service {} exited with {}",
+            service_name, status
+        )));
     }
 
     if !output_file.exists() {
-        bail!(
+        return Ok(ExportStatus::Failed(format!(
             "OpenAPI file not created at {:?} for service {}",
-            output_file,
-            service_name
-        );
+            output_file, service_name
+        )));
     }
 
-    Ok(())
+    Ok(ExportStatus::Exported)
 }
 
 pub(crate) fn export_all_services(
     app_root: &Path,
     manifest: &ApplicationManifestData,
     output_dir: &Path,
-) -> Result<Vec<String>> {
+    options: &ExportOptions,
+) -> Result<ExportReport> {
     use crate::core::rendered_template::RenderedTemplatesCache;
 
-    let mut exported_services = Vec::new();
-
     let runtime = manifest.runtime.as_str();
     let modules_path = app_root.join(&manifest.modules_path);
 
@@ -222,31 +384,133 @@ pub(crate) fn export_all_services(
             None
         });
 
-    for service in services {
-        let service_path = app_root.join(&manifest.modules_path).join(&service.name);
-        let service_output_dir = output_dir.join(&service.name);
+    let logs_dir = app_root.join(".forklaunch").join("logs");
+    create_dir_all(&logs_dir)
+        .with_context(|| format!("Failed to create directory: {:?}", logs_dir))?;
+
+    let export_service = |name: &str| -> Result<ServiceExport> {
+        let service_path = app_root.join(&manifest.modules_path).join(name);
+        let service_output_dir = output_dir.join(name);
 
         create_dir_all(&service_output_dir)
             .with_context(|| format!("Failed to create directory: {:?}", service_output_dir))?;
 
+        // A spec left over from an earlier run must not pass for a fresh export.
         let openapi_file = service_output_dir.join("openapi.json");
+        if openapi_file.exists() {
+            remove_file(&openapi_file)?;
+        }
 
         let service_env_vars = all_env_vars
-            .get(&service.name)
+            .get(name)
             .map(|v| v.as_slice())
             .unwrap_or(&[]);
 
-        export_service_openapi(
+        let log_file = logs_dir.join(format!("openapi-export-{}.log", name));
+        let started = Instant::now();
+        let status = export_service_openapi(
             &service_path,
-            &service.name,
+            name,
             &openapi_file,
             runtime,
             service_env_vars,
             iam_port,
-        )?;
+            options.timeout,
+            &log_file,
+        )
+        .unwrap_or_else(|error| ExportStatus::Failed(format!("{:#}", error)));
 
-        exported_services.push(service.name.clone());
+        let log = if status == ExportStatus::Exported {
+            let _ = remove_file(&log_file);
+            None
+        } else {
+            Some(log_file).filter(|log_file| log_file.exists())
+        };
+
+        Ok(ServiceExport {
+            name: name.to_string(),
+            status,
+            elapsed: started.elapsed(),
+            log,
+        })
+    };
+
+    let next = AtomicUsize::new(0);
+    let failed = AtomicBool::new(false);
+    let workers = options.jobs.clamp(1, services.len().max(1));
+
+    let mut results: Vec<Option<ServiceExport>> = vec![None; services.len()];
+    thread::scope(|scope| -> Result<()> {
+        let handles: Vec<_> = (0..workers)
+            .map(|_| {
+                scope.spawn(|| -> Result<Vec<(usize, ServiceExport)>> {
+                    let mut done = Vec::new();
+                    loop {
+                        if failed.load(Ordering::Relaxed) && !options.continue_on_error {
+                            break;
+                        }
+                        let index = next.fetch_add(1, Ordering::Relaxed);
+                        let Some(service) = services.get(index) else {
+                            break;
+                        };
+                        let export = export_service(&service.name)?;
+                        if export.status != ExportStatus::Exported {
+                            failed.store(true, Ordering::Relaxed);
+                        }
+                        done.push((index, export));
+                    }
+                    Ok(done)
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            let done = handle
+                .join()
+                .unwrap_or_else(|panic| std::panic::resume_unwind(panic))?;
+            for (index, export) in done {
+                results[index] = Some(export);
+            }
+        }
+        Ok(())
+    })?;
+
+    let report = ExportReport {
+        services: results
+            .into_iter()
+            .zip(&services)
+            .map(|(export, service)| {
+                export.unwrap_or_else(|| ServiceExport {
+                    name: service.name.clone(),
+                    status: ExportStatus::Skipped,
+                    elapsed: Duration::ZERO,
+                    log: None,
+                })
+            })
+            .collect(),
+    };
+
+    let failures = report.failures();
+    if !failures.is_empty() && !options.continue_on_error {
+        let details: Vec<String> = failures
+            .iter()
+            .map(|failure| {
+                let mut detail = format!("  {}: {}", failure.name, failure.describe());
+                if let Some(log) = &failure.log {
+                    let tail = log_tail(log, 20);
+                    if !tail.is_empty() {
+                        detail.push_str(&format!("\n{}", tail));
+                    }
+                }
+                detail
+            })
+            .collect();
+        bail!(
+            "OpenAPI export failed for {} service(s):\n{}\nRerun with --continue-on-error to export the rest.",
+            failures.len(),
+            details.join("\n")
+        );
     }
 
-    Ok(exported_services)
+    Ok(report)
 }
@@ -8,9 +8,12 @@ use std::{
 use anyhow::{Context, Result};
 use fs_extra::file::read_to_string;
 use include_dir::{Dir, include_dir};
-use termcolor::StandardStream;
+use termcolor::{Color, ColorSpec, StandardStream, WriteColor};
 
-use super::watermark::apply_watermark;
+use super::{
+    merge::{GeneratedBaseStore, MergeResult, merge_three_way},
+    watermark::apply_watermark,
+};
 use crate::constants::ERROR_FAILED_TO_CREATE_DIR;
 
 pub(crate) static TEMPLATES_DIR: Dir = include_dir!("src/templates");
@@ -30,11 +33,45 @@ pub(crate) fn create_forklaunch_dir(path_dir: &String, dryrun: bool) -> Result<(
     Ok(())
 }
 
+/// Merges freshly generated content with the copy on disk. If the user has
+/// edited the file since it was last generated, their edits are three-way
+/// merged into the new output instead of being overwritten.
+fn merge_with_user_edits(
+    path: &Path,
+    generated: String,
+    stdout: &mut StandardStream,
+) -> Result<(String, Vec<usize>)> {
+    let Some(store) = GeneratedBaseStore::for_path(path) else {
+        return Ok((generated, vec![]));
+    };
+
+    let merged = match (store.load(path), read_to_string(path).ok()) {
+        (Some(base), Some(current)) if current != base && current != generated => {
+            let merged = merge_three_way(&base, &current, &generated);
+            if !merged.has_conflicts() {
+                stdout.set_color(ColorSpec::new().set_fg(Some(Color::Cyan)))?;
+                writeln!(stdout, "[INFO] Merged your edits into {}", path.display())?;
+                stdout.reset()?;
+            }
+            merged
+        }
+        _ => MergeResult {
+            content: generated.clone(),
+            conflicts: vec![],
+        },
+    };
+
+    store.record(path, &generated)?;
+    Ok((merged.content, merged.conflicts))
+}
+
 pub(crate) fn write_rendered_templates(
     rendered_templates: &Vec<RenderedTemplate>,
     dryrun: bool,
     stdout: &mut StandardStream,
 ) -> Result<()> {
+    let mut conflicted_files = vec![];
+
     for rendered_template in rendered_templates {
         if !dryrun {
             create_dir_all(&rendered_template.path.parent().unwrap()).with_context(|| {
@@ -44,21 +81,49 @@ pub(crate) fn write_rendered_templates(
                 )
             })?;
 
-            write(
+            let (content, conflicts) = merge_with_user_edits(
                 &rendered_template.path,
                 apply_watermark(&rendered_template)?,
-            )
-            .with_context(|| match &rendered_template.context {
-                Some(context) => context.clone(),
-                None => format!(
-                    "Failed to write {}. Please check your target directory is writable",
-                    rendered_template.path.display()
-                ),
+                stdout,
+            )?;
+
+            write(&rendered_template.path, content).with_context(|| {
+                match &rendered_template.context {
+                    Some(context) => context.clone(),
+                    None => format!(
+                        "Failed to write {}. Please check your target directory is writable",
+                        rendered_template.path.display()
+                    ),
+                }
             })?;
+
+            if !conflicts.is_empty() {
+                conflicted_files.push((rendered_template.path.clone(), conflicts));
+            }
         } else {
             writeln!(stdout, "Would write {}", rendered_template.path.display())?;
         }
     }
+
+    if !conflicted_files.is_empty() {
+        conflicted_files.sort();
+        stdout.set_color(ColorSpec::new().set_fg(Some(Color::Yellow)))?;
+        writeln!(
+            stdout,
+            "[WARN] {} file(s) have edits that conflict with the regenerated output:",
+            conflicted_files.len()
+        )?;
+        stdout.reset()?;
+        for (path, conflicts) in &conflicted_files {
+            let lines: Vec<String> = conflicts.iter().map(|line| line.to_string()).collect();
+            writeln!(stdout, "  - {} (line {})", path.display(), lines.join(", "))?;
+        }
+        writeln!(
+            stdout,
+            "Resolve the <<<<<<< / >>>>>>> markers in these files, keeping your changes, the regenerated ones, or both"
+        )?;
+    }
+
     Ok(())
 }
 
@@ -105,3 +170,59 @@ impl RenderedTemplatesCache {
         self.internal_cache.drain()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use tempfile::TempDir;
+    use termcolor::ColorChoice;
+
+    use super::*;
+
+    #[test]
+    fn test_write_rendered_templates_merges_user_edits() {
+        let temp_dir = TempDir::new().unwrap();
+        let app_root = temp_dir.path();
+        fs::create_dir_all(app_root.join(".forklaunch")).unwrap();
+        let path = app_root.join("billing/config.yaml");
+        let mut stdout = StandardStream::stdout(ColorChoice::Never);
+        let render = |content: &str| {
+            vec![RenderedTemplate {
+                path: path.clone(),
+                content: content.to_string(),
+                context: None,
+            }]
+        };
+
+        write_rendered_templates(&render("name: billing\nport: 3000\n"), false, &mut stdout)
+            .unwrap();
+        fs::write(&path, "# tuned locally\nname: billing\nport: 3000\n").unwrap();
+
+        write_rendered_templates(
+            &render("name: billing\nport: 3000\nreplicas: 2\n"),
+            false,
+            &mut stdout,
+        )
+        .unwrap();
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            "# tuned locally\nname: billing\nport: 3000\nreplicas: 2\n"
+        );
+
+        fs::write(
+            &path,
+            "# tuned locally\nname: billing\nport: 8080\nreplicas: 2\n",
+        )
+        .unwrap();
+        write_rendered_templates(
+            &render("name: billing\nport: 4000\nreplicas: 2\n"),
+            false,
+            &mut stdout,
+        )
+        .unwrap();
+        let content = fs::read_to_string(&path).unwrap();
+        assert!(content.contains("<<<<<<< your changes\nport: 8080\n"));
+        assert!(content.contains("=======\nport: 4000\n>>>>>>> regenerated\n"));
+    }
+}
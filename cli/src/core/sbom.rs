@@ -0,0 +1,542 @@
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    fs::read_to_string,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Result, bail};
+use serde_json::{Value, json};
+
+use super::{
+    dependency_licenses::package_license,
+    lockfile::Lockfile,
+    manifest::{ProjectType, application::ApplicationManifestData},
+};
+
+/// Serialization formats supported by `forklaunch sbom`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SbomFormat {
+    /// CycloneDX 1.5 JSON
+    CycloneDxJson,
+    /// SPDX 2.3 JSON
+    SpdxJson,
+}
+
+impl SbomFormat {
+    pub(crate) const VARIANTS: [&'static str; 2] = ["cyclonedx-json", "spdx-json"];
+
+    pub(crate) fn parse(value: &str) -> Result<Self> {
+        match value {
+            "cyclonedx-json" => Ok(Self::CycloneDxJson),
+            "spdx-json" => Ok(Self::SpdxJson),
+            other => bail!("Unknown SBOM format '{}'", other),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ComponentKind {
+    /// Deployable workspace project (service or worker)
+    Application,
+    /// Workspace library
+    Library,
+    /// Third-party npm package
+    Package,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct SbomComponent {
+    pub(crate) bom_ref: String,
+    pub(crate) kind: ComponentKind,
+    pub(crate) name: String,
+    pub(crate) version: Option<String>,
+    pub(crate) purl: String,
+    pub(crate) license: Option<String>,
+    /// Only reachable through devDependencies
+    pub(crate) dev_only: bool,
+}
+
+/// Software bill of materials for an application or a single project.
+#[derive(Debug, Clone)]
+pub(crate) struct Sbom {
+    pub(crate) root: SbomComponent,
+    pub(crate) components: Vec<SbomComponent>,
+    /// bom-ref → bom-refs it depends on
+    pub(crate) dependencies: BTreeMap<String, BTreeSet<String>>,
+}
+
+/// A workspace project's package.json, as far as the SBOM is concerned.
+struct WorkspacePackage {
+    project: String,
+    kind: ComponentKind,
+    dir: PathBuf,
+    name: String,
+    version: Option<String>,
+    license: Option<String>,
+    /// package → (specifier, dev only)
+    dependencies: BTreeMap<String, (String, bool)>,
+}
+
+/// `pkg:npm/%40scope/name@1.0.0`, without the version when it is unknown.
+pub(crate) fn npm_purl(name: &str, version: Option<&str>) -> String {
+    let name = match name.strip_prefix('@') {
+        Some(scoped) => format!("%40{}", scoped),
+        None => name.to_string(),
+    };
+    match version {
+        Some(version) => format!("pkg:npm/{}@{}", name, version),
+        None => format!("pkg:npm/{}", name),
+    }
+}
+
+fn read_package_json(path: &Path) -> Option<Value> {
+    serde_json::from_str(&read_to_string(path).ok()?).ok()
+}
+
+/// package.json of an installed package, looking in `node_modules` from
+/// `dir` up to `app_root`.
+fn installed_package_json(dir: &Path, app_root: &Path, package: &str) -> Option<Value> {
+    let mut dir = dir.to_path_buf();
+    loop {
+        if let Some(package_json) =
+            read_package_json(&dir.join("node_modules").join(package).join("package.json"))
+        {
+            return Some(package_json);
+        }
+        if dir == app_root || !dir.starts_with(app_root) {
+            return None;
+        }
+        dir = dir.parent()?.to_path_buf();
+    }
+}
+
+fn load_workspace_package(
+    app_root: &Path,
+    modules_path: &str,
+    app_name: &str,
+    project: &str,
+    kind: ComponentKind,
+) -> Option<WorkspacePackage> {
+    let dir = app_root.join(modules_path).join(project);
+    let package_json = read_package_json(&dir.join("package.json"))?;
+
+    let mut dependencies = BTreeMap::new();
+    for (section, dev) in [("devDependencies", true), ("dependencies", false)] {
+        if let Some(section) = package_json.get(section).and_then(Value::as_object) {
+            for (name, specifier) in section {
+                dependencies.insert(
+                    name.clone(),
+                    (specifier.as_str().unwrap_or_default().to_string(), dev),
+                );
+            }
+        }
+    }
+
+    Some(WorkspacePackage {
+        project: project.to_string(),
+        kind,
+        name: package_json
+            .get("name")
+            .and_then(Value::as_str)
+            .map(str::to_string)
+            .unwrap_or_else(|| format!("@{}/{}", app_name, project)),
+        version: package_json
+            .get("version")
+            .and_then(Value::as_str)
+            .map(str::to_string),
+        license: package_license(&package_json),
+        dir,
+        dependencies,
+    })
+}
+
+/// Build the SBOM for the application, or for `project` and the workspace
+/// projects it depends on.
+///
+/// Package versions come from the lockfile, falling back to the version
+/// installed in `node_modules`. Licenses are read from the installed
+/// package.json when present.
+pub(crate) fn build_sbom(
+    app_root: &Path,
+    manifest: &ApplicationManifestData,
+    app_version: Option<&str>,
+    project: Option<&str>,
+) -> Result<Sbom> {
+    let app_root = app_root
+        .canonicalize()
+        .unwrap_or_else(|_| app_root.to_path_buf());
+    let lockfile = Lockfile::find(&app_root, &app_root);
+
+    let workspace: Vec<WorkspacePackage> = manifest
+        .projects
+        .iter()
+        .filter_map(|entry| {
+            let kind = match entry.r#type {
+                ProjectType::Library => ComponentKind::Library,
+                ProjectType::Service | ProjectType::Worker => ComponentKind::Application,
+            };
+            load_workspace_package(
+                &app_root,
+                &manifest.modules_path,
+                &manifest.app_name,
+                &entry.name,
+                kind,
+            )
+        })
+        .collect();
+    let by_package_name: HashMap<&str, &WorkspacePackage> = workspace
+        .iter()
+        .map(|package| (package.name.as_str(), package))
+        .collect();
+
+    let workspace_ref = |package: &WorkspacePackage| format!("project:{}", package.project);
+    let workspace_component = |package: &WorkspacePackage| SbomComponent {
+        bom_ref: workspace_ref(package),
+        kind: package.kind,
+        name: package.name.clone(),
+        version: package.version.clone(),
+        purl: npm_purl(&package.name, package.version.as_deref()),
+        license: package.license.clone(),
+        dev_only: false,
+    };
+
+    // Projects in scope and the root component everything hangs off
+    let (root, included): (SbomComponent, Vec<&WorkspacePackage>) = match project {
+        Some(project) => {
+            let Some(selected) = workspace.iter().find(|package| package.project == project) else {
+                bail!(
+                    "Project '{}' not found in manifest or has no package.json",
+                    project
+                );
+            };
+            let mut included = vec![selected];
+            let mut index = 0;
+            while index < included.len() {
+                for name in included[index].dependencies.keys() {
+                    if let Some(dependency) = by_package_name.get(name.as_str())
+                        && !included.iter().any(|p| p.project == dependency.project)
+                    {
+                        included.push(dependency);
+                    }
+                }
+                index += 1;
+            }
+            (workspace_component(selected), included)
+        }
+        None => (
+            SbomComponent {
+                bom_ref: format!("app:{}", manifest.app_name),
+                kind: ComponentKind::Application,
+                name: manifest.app_name.clone(),
+                version: app_version.map(str::to_string),
+                purl: format!(
+                    "pkg:generic/{}{}",
+                    manifest.app_name,
+                    app_version.map(|v| format!("@{}", v)).unwrap_or_default()
+                ),
+                license: Some(manifest.license.clone()).filter(|l| !l.is_empty()),
+                dev_only: false,
+            },
+            workspace.iter().collect(),
+        ),
+    };
+
+    let mut dependencies: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+    if project.is_none() {
+        // The application is made up of the projects in its peer topology;
+        // fall back to every project when the topology is empty
+        let mut members: BTreeSet<&str> = manifest
+            .project_peer_topology
+            .values()
+            .flatten()
+            .map(String::as_str)
+            .collect();
+        if members.is_empty() {
+            members = workspace.iter().map(|p| p.project.as_str()).collect();
+        }
+        dependencies.insert(
+            root.bom_ref.clone(),
+            included
+                .iter()
+                .filter(|package| members.contains(package.project.as_str()))
+                .map(|package| workspace_ref(package))
+                .collect(),
+        );
+    }
+
+    let mut components: Vec<SbomComponent> = included
+        .iter()
+        .filter(|package| project != Some(package.project.as_str()))
+        .map(|package| workspace_component(package))
+        .collect();
+    let mut packages: BTreeMap<String, SbomComponent> = BTreeMap::new();
+
+    for package in &included {
+        let mut depends_on = BTreeSet::new();
+        for (name, (specifier, dev)) in &package.dependencies {
+            if let Some(dependency) = by_package_name.get(name.as_str()) {
+                depends_on.insert(workspace_ref(dependency));
+                continue;
+            }
+
+            let installed = installed_package_json(&package.dir, &app_root, name);
+            let version = lockfile
+                .as_ref()
+                .and_then(|lockfile| lockfile.version(&package.dir, name))
+                .map(str::to_string)
+                .or_else(|| {
+                    installed
+                        .as_ref()
+                        .and_then(|json| json.get("version"))
+                        .and_then(Value::as_str)
+                        .map(str::to_string)
+                });
+            if version.is_none() {
+                log::debug!("No resolved version for {}@{}", name, specifier);
+            }
+
+            let purl = npm_purl(name, version.as_deref());
+            depends_on.insert(purl.clone());
+            packages
+                .entry(purl.clone())
+                .and_modify(|component| component.dev_only &= *dev)
+                .or_insert_with(|| SbomComponent {
+                    bom_ref: purl.clone(),
+                    kind: ComponentKind::Package,
+                    name: name.clone(),
+                    version,
+                    purl,
+                    license: installed.as_ref().and_then(package_license),
+                    dev_only: *dev,
+                });
+        }
+        dependencies.insert(workspace_ref(package), depends_on);
+    }
+
+    components.extend(packages.into_values());
+    Ok(Sbom {
+        root,
+        components,
+        dependencies,
+    })
+}
+
+impl Sbom {
+    pub(crate) fn render(&self, format: SbomFormat, timestamp: &str) -> Value {
+        match format {
+            SbomFormat::CycloneDxJson => self.to_cyclonedx(timestamp),
+            SbomFormat::SpdxJson => self.to_spdx(timestamp),
+        }
+    }
+
+    fn to_cyclonedx(&self, timestamp: &str) -> Value {
+        let component = |component: &SbomComponent| {
+            let mut value = json!({
+                "bom-ref": component.bom_ref,
+                "type": match component.kind {
+                    ComponentKind::Application => "application",
+                    ComponentKind::Library | ComponentKind::Package => "library",
+                },
+                "name": component.name,
+                "purl": component.purl,
+            });
+            if let Some(version) = &component.version {
+                value["version"] = json!(version);
+            }
+            if let Some(license) = &component.license {
+                value["licenses"] = json!([cyclonedx_license(license)]);
+            }
+            if component.dev_only {
+                value["scope"] = json!("excluded");
+            }
+            value
+        };
+
+        json!({
+            "bomFormat": "CycloneDX",
+            "specVersion": "1.5",
+            "serialNumber": format!("urn:uuid:{}", uuid::Uuid::new_v4()),
+            "version": 1,
+            "metadata": {
+                "timestamp": timestamp,
+                "tools": {
+                    "components": [{
+                        "type": "application",
+                        "name": "forklaunch",
+                        "version": env!("CARGO_PKG_VERSION"),
+                    }],
+                },
+                "component": component(&self.root),
+            },
+            "components": self.components.iter().map(component).collect::<Vec<_>>(),
+            "dependencies": self
+                .dependencies
+                .iter()
+                .map(|(bom_ref, depends_on)| json!({ "ref": bom_ref, "dependsOn": depends_on }))
+                .collect::<Vec<_>>(),
+        })
+    }
+
+    fn to_spdx(&self, timestamp: &str) -> Value {
+        let ids: HashMap<&str, String> = std::iter::once(&self.root)
+            .chain(&self.components)
+            .enumerate()
+            .map(|(index, component)| {
+                (
+                    component.bom_ref.as_str(),
+                    format!("SPDXRef-Package-{}", index),
+                )
+            })
+            .collect();
+        let dev_only: BTreeSet<&str> = self
+            .components
+            .iter()
+            .filter(|component| component.dev_only)
+            .map(|component| component.bom_ref.as_str())
+            .collect();
+
+        let package = |component: &SbomComponent| {
+            let license = component
+                .license
+                .clone()
+                .unwrap_or_else(|| "NOASSERTION".to_string());
+            json!({
+                "SPDXID": ids[component.bom_ref.as_str()],
+                "name": component.name,
+                "versionInfo": component.version.clone().unwrap_or_else(|| "NOASSERTION".to_string()),
+                "downloadLocation": "NOASSERTION",
+                "filesAnalyzed": false,
+                "licenseConcluded": "NOASSERTION",
+                "licenseDeclared": license,
+                "primaryPackagePurpose": match component.kind {
+                    ComponentKind::Application => "APPLICATION",
+                    ComponentKind::Library | ComponentKind::Package => "LIBRARY",
+                },
+                "externalRefs": [{
+                    "referenceCategory": "PACKAGE-MANAGER",
+                    "referenceType": "purl",
+                    "referenceLocator": component.purl,
+                }],
+            })
+        };
+
+        let mut relationships = vec![json!({
+            "spdxElementId": "SPDXRef-DOCUMENT",
+            "relationshipType": "DESCRIBES",
+            "relatedSpdxElement": ids[self.root.bom_ref.as_str()],
+        })];
+        for (bom_ref, depends_on) in &self.dependencies {
+            for dependency in depends_on {
+                // SPDX expresses dev dependencies from the dependency's side
+                relationships.push(if dev_only.contains(dependency.as_str()) {
+                    json!({
+                        "spdxElementId": ids[dependency.as_str()],
+                        "relationshipType": "DEV_DEPENDENCY_OF",
+                        "relatedSpdxElement": ids[bom_ref.as_str()],
+                    })
+                } else {
+                    json!({
+                        "spdxElementId": ids[bom_ref.as_str()],
+                        "relationshipType": "DEPENDS_ON",
+                        "relatedSpdxElement": ids[dependency.as_str()],
+                    })
+                });
+            }
+        }
+
+        json!({
+            "spdxVersion": "SPDX-2.3",
+            "dataLicense": "CC0-1.0",
+            "SPDXID": "SPDXRef-DOCUMENT",
+            "name": self.root.name,
+            "documentNamespace": format!(
+                "https://forklaunch.com/spdx/{}-{}",
+                self.root.name.trim_start_matches('@').replace('/', "-"),
+                uuid::Uuid::new_v4()
+            ),
+            "creationInfo": {
+                "created": timestamp,
+                "creators": [format!("Tool: forklaunch-{}", env!("CARGO_PKG_VERSION"))],
+            },
+            "packages": std::iter::once(&self.root)
+                .chain(&self.components)
+                .map(package)
+                .collect::<Vec<_>>(),
+            "relationships": relationships,
+        })
+    }
+}
+
+/// SPDX identifiers and expressions go in `license.id` / `expression`;
+/// anything else (e.g. `SEE LICENSE IN LICENSE.md`) is kept as a name.
+fn cyclonedx_license(license: &str) -> Value {
+    if license.contains(" OR ") || license.contains(" AND ") || license.contains(" WITH ") {
+        json!({ "expression": license })
+    } else if license
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '.' | '+'))
+    {
+        json!({ "license": { "id": license } })
+    } else {
+        json!({ "license": { "name": license } })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_npm_purl() {
+        assert_eq!(
+            npm_purl("@forklaunch/core", Some("0.15.2")),
+            "pkg:npm/%40forklaunch/core@0.15.2"
+        );
+        assert_eq!(npm_purl("express", None), "pkg:npm/express");
+    }
+
+    #[test]
+    fn test_render_formats() {
+        let component = |bom_ref: &str, kind, dev_only| SbomComponent {
+            bom_ref: bom_ref.to_string(),
+            kind,
+            name: bom_ref.to_string(),
+            version: Some("1.0.0".to_string()),
+            purl: npm_purl(bom_ref, Some("1.0.0")),
+            license: Some("MIT".to_string()),
+            dev_only,
+        };
+        let sbom = Sbom {
+            root: component("app", ComponentKind::Application, false),
+            components: vec![
+                component("express", ComponentKind::Package, false),
+                component("typescript", ComponentKind::Package, true),
+            ],
+            dependencies: BTreeMap::from([(
+                "app".to_string(),
+                BTreeSet::from(["express".to_string(), "typescript".to_string()]),
+            )]),
+        };
+
+        let cyclonedx = sbom.render(SbomFormat::CycloneDxJson, "2025-01-01T00:00:00Z");
+        assert_eq!(cyclonedx["metadata"]["component"]["type"], "application");
+        assert_eq!(cyclonedx["components"][1]["scope"], "excluded");
+        assert_eq!(
+            cyclonedx["components"][0]["licenses"][0]["license"]["id"],
+            "MIT"
+        );
+        assert_eq!(
+            cyclonedx["dependencies"][0]["dependsOn"],
+            json!(["express", "typescript"])
+        );
+
+        let spdx = sbom.render(SbomFormat::SpdxJson, "2025-01-01T00:00:00Z");
+        let relationships = spdx["relationships"].as_array().unwrap();
+        assert_eq!(relationships[0]["relationshipType"], "DESCRIBES");
+        assert_eq!(relationships[1]["relationshipType"], "DEPENDS_ON");
+        assert_eq!(relationships[2]["relationshipType"], "DEV_DEPENDENCY_OF");
+        assert_eq!(relationships[2]["spdxElementId"], "SPDXRef-Package-2");
+    }
+}
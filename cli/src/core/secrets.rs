@@ -0,0 +1,561 @@
+use std::{
+    collections::{BTreeSet, HashMap},
+    env::var,
+    fs,
+    io::{Read, Write},
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use age::{
+    DecryptError, Decryptor, Encryptor,
+    x25519::{Identity, Recipient},
+};
+use anyhow::{Context, Result, anyhow, bail};
+use base64::{Engine, engine::general_purpose::STANDARD};
+use chacha20poly1305::{
+    AeadCore, ChaCha20Poly1305, Key, KeyInit, Nonce,
+    aead::{Aead, OsRng, Payload},
+};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+use super::env::parse_env_entries;
+
+pub(crate) const ENCRYPTED_ENV_FILE_NAME: &str = ".env.enc";
+pub(crate) const AGE_KEY_ENV_VAR: &str = "FORKLAUNCH_AGE_KEY";
+pub(crate) const AGE_KEY_FILE_ENV_VAR: &str = "FORKLAUNCH_AGE_KEY_FILE";
+
+const HEADER_PREFIX: &str = "#forklaunch:";
+const VALUE_PREFIX: &str = "ENC[chacha20poly1305,";
+const VALUE_SUFFIX: &str = "]";
+const NONCE_LEN: usize = 12;
+
+type HmacSha256 = Hmac<Sha256>;
+
+pub(crate) fn is_encrypted_env_file(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| name == ENCRYPTED_ENV_FILE_NAME)
+}
+
+/// True for a value still in its `ENC[...]` form, i.e. one that could not be
+/// decrypted locally.
+pub(crate) fn is_sealed_value(value: &str) -> bool {
+    value.starts_with(VALUE_PREFIX)
+}
+
+/// Where `secrets keygen` writes the local identity, overridable with
+/// `FORKLAUNCH_AGE_KEY_FILE`.
+pub(crate) fn get_identity_path() -> Result<PathBuf> {
+    if let Ok(path) = var(AGE_KEY_FILE_ENV_VAR)
+        && !path.is_empty()
+    {
+        return Ok(PathBuf::from(path));
+    }
+    Ok(Path::new(&var("HOME")?)
+        .join(".forklaunch")
+        .join("age-key.txt"))
+}
+
+fn parse_identities(content: &str) -> Result<Vec<Identity>> {
+    content
+        .lines()
+        .map(str::trim)
+        .filter(|line| line.starts_with("AGE-SECRET-KEY-"))
+        .map(|line| {
+            Identity::from_str(line).map_err(|err| anyhow!("Invalid age identity: {}", err))
+        })
+        .collect()
+}
+
+/// Loads every age identity available to this machine: the
+/// `FORKLAUNCH_AGE_KEY` variable first, then the identity file.
+pub(crate) fn load_identities() -> Result<Vec<Identity>> {
+    let mut identities = Vec::new();
+
+    if let Ok(key) = var(AGE_KEY_ENV_VAR) {
+        identities.extend(parse_identities(&key)?);
+    }
+
+    let identity_path = match get_identity_path() {
+        Ok(path) => path,
+        Err(_) => return Ok(identities),
+    };
+    if identity_path.exists() {
+        let content = fs::read_to_string(&identity_path)
+            .with_context(|| format!("Failed to read {}", identity_path.display()))?;
+        identities.extend(
+            parse_identities(&content)
+                .with_context(|| format!("Failed to parse {}", identity_path.display()))?,
+        );
+    }
+
+    Ok(identities)
+}
+
+pub(crate) fn parse_recipients(recipients: &[String]) -> Result<Vec<Recipient>> {
+    recipients
+        .iter()
+        .map(|recipient| {
+            Recipient::from_str(recipient.trim())
+                .map_err(|err| anyhow!("Invalid age recipient '{}': {}", recipient, err))
+        })
+        .collect()
+}
+
+/// A `.env.enc` file as stored on disk. Keys stay readable; each value is
+/// sealed with a per-file data key that is itself age-encrypted to every
+/// recipient listed in the header.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct EncryptedEnvFile {
+    pub(crate) recipients: Vec<String>,
+    wrapped_key: String,
+    mac: String,
+    pub(crate) entries: Vec<(String, String)>,
+}
+
+/// Summary of how the recipients changed when a file was written.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub(crate) struct RekeyReport {
+    pub(crate) added: Vec<String>,
+    pub(crate) removed: Vec<String>,
+    pub(crate) rotated: bool,
+}
+
+impl RekeyReport {
+    pub(crate) fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && !self.rotated
+    }
+}
+
+impl EncryptedEnvFile {
+    pub(crate) fn parse(content: &str) -> Result<Self> {
+        let mut recipients = None;
+        let mut wrapped_key = None;
+        let mut mac = None;
+
+        for line in content.lines() {
+            let Some(header) = line.trim().strip_prefix(HEADER_PREFIX) else {
+                continue;
+            };
+            match header.split_once('=') {
+                Some(("recipients", value)) => {
+                    recipients = Some(
+                        value
+                            .split(',')
+                            .map(str::trim)
+                            .filter(|r| !r.is_empty())
+                            .map(String::from)
+                            .collect::<Vec<_>>(),
+                    )
+                }
+                Some(("data_key", value)) => wrapped_key = Some(value.trim().to_string()),
+                Some(("mac", value)) => mac = Some(value.trim().to_string()),
+                _ => {}
+            }
+        }
+
+        Ok(Self {
+            recipients: recipients.ok_or_else(|| anyhow!("Missing recipients header"))?,
+            wrapped_key: wrapped_key.ok_or_else(|| anyhow!("Missing data_key header"))?,
+            mac: mac.ok_or_else(|| anyhow!("Missing mac header"))?,
+            entries: parse_env_entries(content),
+        })
+    }
+
+    pub(crate) fn read(path: &Path) -> Result<Self> {
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        Self::parse(&content).with_context(|| {
+            format!(
+                "{} is not a valid forklaunch encrypted env file",
+                path.display()
+            )
+        })
+    }
+
+    pub(crate) fn render(&self) -> String {
+        let mut content =
+            String::from("# Values are encrypted; edit with `forklaunch secrets edit`.\n");
+        content.push_str(&format!(
+            "{}recipients={}\n",
+            HEADER_PREFIX,
+            self.recipients.join(",")
+        ));
+        content.push_str(&format!("{}data_key={}\n", HEADER_PREFIX, self.wrapped_key));
+        content.push_str(&format!("{}mac={}\n", HEADER_PREFIX, self.mac));
+        for (key, value) in &self.entries {
+            content.push_str(&format!("{}={}\n", key, value));
+        }
+        content
+    }
+
+    pub(crate) fn write(&self, path: &Path) -> Result<()> {
+        fs::write(path, self.render())
+            .with_context(|| format!("Failed to write {}", path.display()))
+    }
+
+    /// Recovers the data key, or `None` when none of `identities` is a
+    /// recipient of this file.
+    fn unwrap_data_key(&self, identities: &[Identity]) -> Result<Option<Key>> {
+        if identities.is_empty() {
+            return Ok(None);
+        }
+
+        let wrapped = STANDARD
+            .decode(&self.wrapped_key)
+            .context("data_key header is not valid base64")?;
+        let decryptor = Decryptor::new_buffered(&wrapped[..])
+            .map_err(|err| anyhow!("data_key header is not a valid age payload: {}", err))?;
+        let mut reader = match decryptor.decrypt(
+            identities
+                .iter()
+                .map(|identity| identity as &dyn age::Identity),
+        ) {
+            Ok(reader) => reader,
+            Err(DecryptError::NoMatchingKeys) => return Ok(None),
+            Err(err) => bail!("Failed to decrypt data key: {}", err),
+        };
+
+        let mut key = Vec::new();
+        reader
+            .read_to_end(&mut key)
+            .context("Failed to decrypt data key")?;
+        if key.len() != 32 {
+            bail!("Decrypted data key has an unexpected length");
+        }
+        Ok(Some(*Key::from_slice(&key)))
+    }
+
+    /// Decrypts every value. Returns `None` when no identity can open the
+    /// file, and an error when the file was tampered with.
+    pub(crate) fn decrypt(&self, identities: &[Identity]) -> Result<Option<Vec<(String, String)>>> {
+        let Some(key) = self.unwrap_data_key(identities)? else {
+            return Ok(None);
+        };
+        self.verify_mac(&key)?;
+
+        let cipher = ChaCha20Poly1305::new(&key);
+        self.entries
+            .iter()
+            .map(|(name, value)| Ok((name.clone(), decrypt_value(&cipher, name, value)?)))
+            .collect::<Result<Vec<_>>>()
+            .map(Some)
+    }
+
+    fn verify_mac(&self, key: &Key) -> Result<()> {
+        let expected = STANDARD
+            .decode(&self.mac)
+            .context("mac header is not valid base64")?;
+        entries_mac(key, &self.entries)
+            .verify_slice(&expected)
+            .map_err(|_| anyhow!("Encrypted env file failed its integrity check; entries were added, removed or modified outside `forklaunch secrets`"))
+    }
+}
+
+fn entries_mac(key: &Key, entries: &[(String, String)]) -> HmacSha256 {
+    let mut mac = <HmacSha256 as Mac>::new_from_slice(key).expect("HMAC accepts any key length");
+    for (name, value) in entries {
+        mac.update(name.as_bytes());
+        mac.update(b"=");
+        mac.update(value.as_bytes());
+        mac.update(b"\n");
+    }
+    mac
+}
+
+fn encrypt_value(cipher: &ChaCha20Poly1305, name: &str, plaintext: &str) -> Result<String> {
+    let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+    let ciphertext = cipher
+        .encrypt(
+            &nonce,
+            Payload {
+                msg: plaintext.as_bytes(),
+                aad: name.as_bytes(),
+            },
+        )
+        .map_err(|_| anyhow!("Failed to encrypt {}", name))?;
+
+    let mut sealed = nonce.to_vec();
+    sealed.extend(ciphertext);
+    Ok(format!(
+        "{}{}{}",
+        VALUE_PREFIX,
+        STANDARD.encode(sealed),
+        VALUE_SUFFIX
+    ))
+}
+
+fn decrypt_value(cipher: &ChaCha20Poly1305, name: &str, value: &str) -> Result<String> {
+    let encoded = value
+        .strip_prefix(VALUE_PREFIX)
+        .and_then(|rest| rest.strip_suffix(VALUE_SUFFIX))
+        .ok_or_else(|| anyhow!("Value of {} is not encrypted", name))?;
+    let sealed = STANDARD
+        .decode(encoded)
+        .with_context(|| format!("Value of {} is not valid base64", name))?;
+    if sealed.len() < NONCE_LEN {
+        bail!("Value of {} is truncated", name);
+    }
+    let (nonce, ciphertext) = sealed.split_at(NONCE_LEN);
+    let plaintext = cipher
+        .decrypt(
+            Nonce::from_slice(nonce),
+            Payload {
+                msg: ciphertext,
+                aad: name.as_bytes(),
+            },
+        )
+        .map_err(|_| anyhow!("Failed to decrypt {}", name))?;
+    String::from_utf8(plaintext).with_context(|| format!("Value of {} is not UTF-8", name))
+}
+
+fn wrap_data_key(key: &Key, recipients: &[String]) -> Result<String> {
+    let parsed = parse_recipients(recipients)?;
+    if parsed.is_empty() {
+        bail!("At least one secrets recipient is required");
+    }
+
+    let encryptor = Encryptor::with_recipients(
+        parsed
+            .iter()
+            .map(|recipient| recipient as &dyn age::Recipient),
+    )
+    .map_err(|err| anyhow!("Failed to encrypt data key: {}", err))?;
+    let mut wrapped = Vec::new();
+    let mut writer = encryptor.wrap_output(&mut wrapped)?;
+    writer.write_all(key)?;
+    writer.finish()?;
+
+    Ok(STANDARD.encode(wrapped))
+}
+
+/// Encrypts `vars` for `recipients`.
+///
+/// When `previous` is given its data key and ciphertexts are reused for
+/// unchanged values so the committed diff only shows what was edited. Adding
+/// recipients re-wraps the existing data key; removing one rotates the data
+/// key and re-encrypts every value, since the removed teammate may still hold
+/// the old one.
+pub(crate) fn encrypt_env(
+    vars: &[(String, String)],
+    recipients: &[String],
+    previous: Option<&EncryptedEnvFile>,
+    identities: &[Identity],
+) -> Result<(EncryptedEnvFile, RekeyReport)> {
+    let recipients: Vec<String> = recipients.iter().map(|r| r.trim().to_string()).collect();
+    let mut report = RekeyReport::default();
+
+    let reusable = match previous {
+        Some(previous) => {
+            let key = previous.unwrap_data_key(identities)?.ok_or_else(|| {
+                anyhow!("None of the local age identities is a recipient of the existing encrypted env file")
+            })?;
+            previous.verify_mac(&key)?;
+
+            let old: BTreeSet<&String> = previous.recipients.iter().collect();
+            let new: BTreeSet<&String> = recipients.iter().collect();
+            report.added = new.difference(&old).map(|r| r.to_string()).collect();
+            report.removed = old.difference(&new).map(|r| r.to_string()).collect();
+
+            if report.removed.is_empty() {
+                Some((key, previous))
+            } else {
+                report.rotated = true;
+                None
+            }
+        }
+        None => None,
+    };
+
+    let (key, wrapped_key, previous_values) = match reusable {
+        Some((key, previous)) => {
+            let wrapped_key = if report.added.is_empty() {
+                previous.wrapped_key.clone()
+            } else {
+                wrap_data_key(&key, &recipients)?
+            };
+            let cipher = ChaCha20Poly1305::new(&key);
+            let previous_values = previous
+                .entries
+                .iter()
+                .map(|(name, value)| {
+                    Ok((
+                        name.clone(),
+                        (decrypt_value(&cipher, name, value)?, value.clone()),
+                    ))
+                })
+                .collect::<Result<HashMap<_, _>>>()?;
+            (key, wrapped_key, previous_values)
+        }
+        None => {
+            let key = ChaCha20Poly1305::generate_key(&mut OsRng);
+            let wrapped_key = wrap_data_key(&key, &recipients)?;
+            (key, wrapped_key, HashMap::new())
+        }
+    };
+
+    let cipher = ChaCha20Poly1305::new(&key);
+    let entries = vars
+        .iter()
+        .map(|(name, value)| {
+            let sealed = match previous_values.get(name) {
+                Some((plaintext, sealed)) if plaintext == value => sealed.clone(),
+                _ => encrypt_value(&cipher, name, value)?,
+            };
+            Ok((name.clone(), sealed))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let mac = STANDARD.encode(entries_mac(&key, &entries).finalize().into_bytes());
+
+    Ok((
+        EncryptedEnvFile {
+            recipients,
+            wrapped_key,
+            mac,
+            entries,
+        },
+        report,
+    ))
+}
+
+/// Reads a `.env.enc` for env resolution. Values are decrypted when a local
+/// identity is a recipient; otherwise the keys are still returned (with their
+/// sealed values) so presence checks keep working for teammates without
+/// access.
+pub(crate) fn load_encrypted_env_vars(path: &Path) -> Result<HashMap<String, String>> {
+    let file = EncryptedEnvFile::read(path)?;
+    let identities = load_identities()?;
+
+    match file.decrypt(&identities)? {
+        Some(vars) => Ok(vars.into_iter().collect()),
+        None => Ok(file.entries.into_iter().collect()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use age::secrecy::ExposeSecret;
+
+    use super::*;
+
+    fn vars(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_round_trip_and_reuse_unchanged_values() {
+        let alice = Identity::generate();
+        let recipients = vec![alice.to_public().to_string()];
+        let identities = vec![alice];
+
+        let (file, _) = encrypt_env(
+            &vars(&[("DB_PASSWORD", "hunter2"), ("API_KEY", "abc")]),
+            &recipients,
+            None,
+            &identities,
+        )
+        .unwrap();
+        assert!(file.entries[0].1.starts_with(VALUE_PREFIX));
+
+        let parsed = EncryptedEnvFile::parse(&file.render()).unwrap();
+        assert_eq!(parsed, file);
+        assert_eq!(
+            parsed.decrypt(&identities).unwrap().unwrap(),
+            vars(&[("DB_PASSWORD", "hunter2"), ("API_KEY", "abc")])
+        );
+
+        let (edited, report) = encrypt_env(
+            &vars(&[("DB_PASSWORD", "hunter2"), ("API_KEY", "xyz")]),
+            &recipients,
+            Some(&parsed),
+            &identities,
+        )
+        .unwrap();
+        assert!(report.is_empty());
+        assert_eq!(edited.entries[0], parsed.entries[0]);
+        assert_ne!(edited.entries[1], parsed.entries[1]);
+        assert_eq!(edited.wrapped_key, parsed.wrapped_key);
+    }
+
+    #[test]
+    fn test_rekey_adds_and_removes_recipients() {
+        let alice = Identity::generate();
+        let bob = Identity::generate();
+        let alice_pub = alice.to_public().to_string();
+        let bob_pub = bob.to_public().to_string();
+        let secrets = vars(&[("TOKEN", "s3cret")]);
+
+        let (file, _) = encrypt_env(&secrets, std::slice::from_ref(&alice_pub), None, &[]).unwrap();
+        assert!(file.decrypt(std::slice::from_ref(&bob)).unwrap().is_none());
+
+        let (shared, report) = encrypt_env(
+            &secrets,
+            &[alice_pub.clone(), bob_pub.clone()],
+            Some(&file),
+            std::slice::from_ref(&alice),
+        )
+        .unwrap();
+        assert_eq!(report.added, vec![bob_pub.clone()]);
+        assert!(!report.rotated);
+        assert_eq!(shared.entries, file.entries);
+        assert_eq!(
+            shared.decrypt(std::slice::from_ref(&bob)).unwrap().unwrap(),
+            secrets
+        );
+
+        let (revoked, report) = encrypt_env(
+            &secrets,
+            &[alice_pub],
+            Some(&shared),
+            std::slice::from_ref(&bob),
+        )
+        .unwrap();
+        assert_eq!(report.removed, vec![bob_pub]);
+        assert!(report.rotated);
+        assert_ne!(revoked.entries, shared.entries);
+        assert!(revoked.decrypt(&[bob]).unwrap().is_none());
+        assert_eq!(revoked.decrypt(&[alice]).unwrap().unwrap(), secrets);
+    }
+
+    #[test]
+    fn test_tampering_is_detected() {
+        let alice = Identity::generate();
+        let (file, _) = encrypt_env(
+            &vars(&[("A", "1"), ("B", "2")]),
+            &[alice.to_public().to_string()],
+            None,
+            &[],
+        )
+        .unwrap();
+
+        let mut swapped = file.clone();
+        swapped.entries[0].1 = file.entries[1].1.clone();
+        assert!(swapped.decrypt(std::slice::from_ref(&alice)).is_err());
+
+        let mut dropped = file.clone();
+        dropped.entries.pop();
+        assert!(dropped.decrypt(&[alice]).is_err());
+    }
+
+    #[test]
+    fn test_parse_identities_ignores_comments() {
+        let identity = Identity::generate();
+        let content = format!(
+            "# created: today\n# public key: {}\n{}\n",
+            identity.to_public(),
+            identity.to_string().expose_secret()
+        );
+        let parsed = parse_identities(&content).unwrap();
+        assert_eq!(parsed.len(), 1);
+        assert_eq!(
+            parsed[0].to_public().to_string(),
+            identity.to_public().to_string()
+        );
+    }
+}
@@ -0,0 +1,767 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use chrono::{DateTime, SecondsFormat};
+use convert_case::{Case, Casing};
+use uuid::Builder;
+
+use crate::core::static_analysis::entity_analyzer::{
+    EntityDefinition, EntityProperty, RelationType,
+};
+
+const FIRST_NAMES: &[&str] = &[
+    "Ada",
+    "Grace",
+    "Alan",
+    "Linus",
+    "Margaret",
+    "Dennis",
+    "Barbara",
+    "Ken",
+    "Frances",
+    "Edsger",
+    "Radia",
+    "Tim",
+    "Hedy",
+    "John",
+    "Katherine",
+    "Guido",
+    "Anita",
+    "Bjarne",
+    "Sophie",
+    "James",
+];
+
+const LAST_NAMES: &[&str] = &[
+    "Lovelace",
+    "Hopper",
+    "Turing",
+    "Torvalds",
+    "Hamilton",
+    "Ritchie",
+    "Liskov",
+    "Thompson",
+    "Allen",
+    "Dijkstra",
+    "Perlman",
+    "Berners-Lee",
+    "Lamarr",
+    "McCarthy",
+    "Johnson",
+    "van Rossum",
+    "Borg",
+    "Stroustrup",
+    "Wilson",
+    "Gosling",
+];
+
+const COMPANY_WORDS: &[&str] = &[
+    "Acme",
+    "Globex",
+    "Initech",
+    "Umbrella",
+    "Stark",
+    "Wayne",
+    "Hooli",
+    "Vandelay",
+    "Soylent",
+    "Tyrell",
+    "Wonka",
+    "Cyberdyne",
+];
+
+const COMPANY_SUFFIXES: &[&str] = &["Inc", "LLC", "Labs", "Group", "Systems", "Partners"];
+
+const CITIES: &[&str] = &[
+    "Amsterdam",
+    "Austin",
+    "Berlin",
+    "Lisbon",
+    "London",
+    "Nairobi",
+    "Osaka",
+    "Paris",
+    "Seoul",
+    "Toronto",
+];
+
+const COUNTRIES: &[&str] = &["NL", "US", "DE", "PT", "GB", "KE", "JP", "FR", "KR", "CA"];
+
+const STREETS: &[&str] = &[
+    "Main St",
+    "Oak Ave",
+    "Maple Rd",
+    "Cedar Ln",
+    "Park Blvd",
+    "Elm St",
+    "Lake Dr",
+    "Hill Rd",
+];
+
+const LOREM: &[&str] = &[
+    "lorem",
+    "ipsum",
+    "dolor",
+    "sit",
+    "amet",
+    "consectetur",
+    "adipiscing",
+    "elit",
+    "sed",
+    "do",
+    "eiusmod",
+    "tempor",
+    "incididunt",
+    "ut",
+    "labore",
+    "et",
+    "dolore",
+    "magna",
+    "aliqua",
+];
+
+const STATUSES: &[&str] = &["active", "inactive", "pending"];
+
+const CURRENCIES: &[&str] = &["USD", "EUR", "GBP"];
+
+/// 2024-01-01T00:00:00Z, so generated dates do not depend on when the
+/// command runs.
+const BASE_TIMESTAMP: i64 = 1_704_067_200;
+
+const SECONDS_PER_YEAR: u64 = 365 * 24 * 60 * 60;
+
+/// Deterministic SplitMix64 generator. Implemented here rather than taken
+/// from `rand` so the same seed produces the same data across crate
+/// upgrades.
+pub(crate) struct SeedRng(u64);
+
+impl SeedRng {
+    pub(crate) fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    fn below(&mut self, bound: u64) -> u64 {
+        if bound == 0 {
+            0
+        } else {
+            self.next_u64() % bound
+        }
+    }
+
+    fn between(&mut self, min: i64, max: i64) -> i64 {
+        min + self.below((max - min + 1) as u64) as i64
+    }
+
+    fn chance(&mut self, numerator: u64, denominator: u64) -> bool {
+        self.below(denominator) < numerator
+    }
+
+    fn pick<'a>(&mut self, items: &[&'a str]) -> &'a str {
+        items[self.below(items.len() as u64) as usize]
+    }
+
+    fn hex(&mut self, len: usize) -> String {
+        (0..len)
+            .map(|_| char::from_digit(self.below(16) as u32, 16).unwrap())
+            .collect()
+    }
+
+    fn uuid(&mut self) -> String {
+        let mut bytes = [0u8; 16];
+        bytes[..8].copy_from_slice(&self.next_u64().to_le_bytes());
+        bytes[8..].copy_from_slice(&self.next_u64().to_le_bytes());
+        Builder::from_random_bytes(bytes).into_uuid().to_string()
+    }
+}
+
+/// How primary keys of the base entity are written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum IdStyle {
+    /// `SqlBaseEntity`: a `uuid` string in `id`.
+    Uuid,
+    /// `NoSqlBaseEntity`: an `ObjectId` in `_id`.
+    ObjectId,
+}
+
+impl IdStyle {
+    fn field(&self) -> &'static str {
+        match self {
+            IdStyle::Uuid => "id",
+            IdStyle::ObjectId => "_id",
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct GeneratedSeedData {
+    pub(crate) data_ts: String,
+    pub(crate) seeder_ts: String,
+    pub(crate) seeder_class: String,
+    /// Entity names in insertion order.
+    pub(crate) entity_order: Vec<String>,
+    pub(crate) warnings: Vec<String>,
+}
+
+fn relation_target(property: &EntityProperty) -> String {
+    property
+        .type_name
+        .strip_prefix("Collection<")
+        .and_then(|inner| inner.strip_suffix('>'))
+        .unwrap_or(&property.type_name)
+        .to_string()
+}
+
+/// Relations whose value is written on this side: many-to-one, owning
+/// one-to-one and owning many-to-many.
+fn owned_relation(property: &EntityProperty) -> Option<(&RelationType, String)> {
+    let relation_type = property.relation_type.as_ref()?;
+    (!property.is_inverse_side).then(|| (relation_type, relation_target(property)))
+}
+
+/// The named entities plus every entity they reference, so that each
+/// generated relation points at a generated record.
+pub(crate) fn select_entities<'a>(
+    entities: &'a [EntityDefinition],
+    roots: &[String],
+) -> Vec<&'a EntityDefinition> {
+    let by_name: BTreeMap<&str, &EntityDefinition> =
+        entities.iter().map(|e| (e.name.as_str(), e)).collect();
+
+    let mut selected: BTreeSet<&str> = BTreeSet::new();
+    let mut stack: Vec<&str> = roots
+        .iter()
+        .filter_map(|root| by_name.get(root.as_str()).map(|e| e.name.as_str()))
+        .collect();
+    while let Some(name) = stack.pop() {
+        if !selected.insert(name) {
+            continue;
+        }
+        for property in &by_name[name].properties {
+            if let Some((_, target)) = owned_relation(property)
+                && let Some(entity) = by_name.get(target.as_str())
+            {
+                stack.push(entity.name.as_str());
+            }
+        }
+    }
+
+    entities
+        .iter()
+        .filter(|e| selected.contains(e.name.as_str()))
+        .collect()
+}
+
+/// Orders entities so referenced entities come first. Entities caught in a
+/// cycle keep their relative order at the end.
+fn order_entities<'a>(entities: &[&'a EntityDefinition]) -> Vec<&'a EntityDefinition> {
+    let names: BTreeSet<&str> = entities.iter().map(|e| e.name.as_str()).collect();
+    let mut remaining: Vec<&EntityDefinition> = entities.to_vec();
+    let mut ordered: Vec<&EntityDefinition> = vec![];
+
+    loop {
+        let placed: BTreeSet<&str> = ordered.iter().map(|e| e.name.as_str()).collect();
+        let (ready, blocked): (Vec<_>, Vec<_>) = remaining.into_iter().partition(|entity| {
+            entity
+                .properties
+                .iter()
+                .all(|property| match owned_relation(property) {
+                    Some((_, target)) => {
+                        target == entity.name
+                            || !names.contains(target.as_str())
+                            || placed.contains(target.as_str())
+                    }
+                    None => true,
+                })
+        });
+        remaining = blocked;
+        if ready.is_empty() {
+            break;
+        }
+        ordered.extend(ready);
+    }
+
+    ordered.extend(remaining);
+    ordered
+}
+
+fn variable_name(entity: &str) -> String {
+    format!("{}SeedData", entity.to_case(Case::Camel))
+}
+
+fn quote(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+fn sentence(rng: &mut SeedRng, words: usize) -> String {
+    let text = (0..words)
+        .map(|_| rng.pick(LOREM))
+        .collect::<Vec<_>>()
+        .join(" ");
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(first) => format!("{}{}.", first.to_uppercase(), chars.as_str()),
+        None => text,
+    }
+}
+
+fn date(offset_seconds: i64) -> String {
+    let value = DateTime::from_timestamp(BASE_TIMESTAMP + offset_seconds, 0)
+        .unwrap_or_default()
+        .to_rfc3339_opts(SecondsFormat::Millis, true);
+    format!("new Date(\"{}\")", value)
+}
+
+/// Picks a realistic value from the property's name, falling back to its
+/// type. Returns `None` when the type has no generator.
+fn fake_value(
+    entity: &EntityDefinition,
+    property: &EntityProperty,
+    index: usize,
+    rng: &mut SeedRng,
+) -> Option<String> {
+    let name = property.name.to_lowercase();
+    let entity_name = entity.name.to_lowercase();
+    let n = index + 1;
+    let has = |parts: &[&str]| parts.iter().any(|part| name.contains(part));
+
+    let value = match property.type_name.as_str() {
+        "string" => {
+            let first = rng.pick(FIRST_NAMES);
+            let last = rng.pick(LAST_NAMES);
+            let text = if has(&["email"]) {
+                format!(
+                    "{}.{}{}@example.com",
+                    first.to_lowercase(),
+                    last.to_lowercase().replace([' ', '-'], ""),
+                    n
+                )
+            } else if has(&["firstname", "givenname"]) {
+                first.to_string()
+            } else if has(&["lastname", "surname", "familyname"]) {
+                last.to_string()
+            } else if has(&["username", "handle"]) {
+                format!("{}{}", first.to_lowercase(), n)
+            } else if has(&["phone", "mobile"]) {
+                format!("+1-555-{:04}", rng.below(10_000))
+            } else if has(&["avatar", "image", "photo", "logo"]) {
+                format!("https://example.com/images/{}.png", n)
+            } else if has(&["url", "website", "link", "domain"]) {
+                format!("https://example.com/{}/{}", entity_name, n)
+            } else if has(&["street", "address"]) {
+                format!("{} {}", rng.between(1, 999), rng.pick(STREETS))
+            } else if has(&["city"]) {
+                rng.pick(CITIES).to_string()
+            } else if has(&["country"]) {
+                rng.pick(COUNTRIES).to_string()
+            } else if has(&["zip", "postal"]) {
+                format!("{:05}", rng.below(100_000))
+            } else if has(&["currency"]) {
+                rng.pick(CURRENCIES).to_string()
+            } else if has(&["status", "state"]) {
+                rng.pick(STATUSES).to_string()
+            } else if has(&["locale", "language"]) {
+                "en-US".to_string()
+            } else if has(&["color", "colour"]) {
+                format!("#{}", rng.hex(6))
+            } else if has(&["slug"]) {
+                format!("{}-{}-{}", rng.pick(LOREM), rng.pick(LOREM), n)
+            } else if has(&["password", "hash", "secret", "token"]) {
+                format!(
+                    "seed-{}-{}",
+                    property.name.to_case(Case::Kebab),
+                    rng.hex(16)
+                )
+            } else if has(&["code", "sku"]) {
+                format!("{}-{:04}", rng.hex(3).to_uppercase(), n)
+            } else if has(&[
+                "description",
+                "message",
+                "content",
+                "body",
+                "bio",
+                "note",
+                "summary",
+                "comment",
+            ]) {
+                sentence(rng, 8)
+            } else if has(&["title", "subject", "headline"]) {
+                sentence(rng, 3).trim_end_matches('.').to_case(Case::Title)
+            } else if name == "name"
+                && ["organization", "company", "tenant", "team", "account"]
+                    .iter()
+                    .any(|part| entity_name.contains(part))
+            {
+                format!("{} {}", rng.pick(COMPANY_WORDS), rng.pick(COMPANY_SUFFIXES))
+            } else if has(&["name"]) {
+                format!("{} {}", first, last)
+            } else if name.ends_with("id") {
+                format!("{}_{}", property.name.trim_end_matches("Id"), rng.hex(14))
+            } else {
+                format!("{} {}", property.name.to_case(Case::Title), n)
+            };
+
+            // Keep unique columns unique even when a word list repeats
+            let unique_already = has(&[
+                "email", "username", "handle", "slug", "url", "website", "link", "password",
+                "hash", "secret", "token", "code", "sku",
+            ]) || name.ends_with("id")
+                || text.ends_with(&format!(" {}", n));
+            if property.is_unique && !unique_already {
+                quote(&format!("{} {}", text, n))
+            } else {
+                quote(&text)
+            }
+        }
+        "number" => {
+            if has(&["price", "amount", "cost", "total", "balance", "fee"]) {
+                format!("{}.{:02}", rng.between(1, 999), rng.below(100))
+            } else if name == "age" {
+                rng.between(18, 80).to_string()
+            } else if has(&["retry", "attempt"]) {
+                rng.between(0, 5).to_string()
+            } else if has(&["rating", "score", "stars"]) {
+                rng.between(1, 5).to_string()
+            } else if has(&["year"]) {
+                rng.between(2000, 2025).to_string()
+            } else if has(&["percent", "quantity", "count", "stock", "seats"]) {
+                rng.between(0, 100).to_string()
+            } else if has(&["lat"]) {
+                format!("{}.{:04}", rng.between(-89, 89), rng.below(10_000))
+            } else if has(&["lng", "lon"]) {
+                format!("{}.{:04}", rng.between(-179, 179), rng.below(10_000))
+            } else {
+                rng.between(1, 1000).to_string()
+            }
+        }
+        "boolean" => rng.chance(1, 2).to_string(),
+        "Date" => {
+            if has(&["birth", "dob"]) {
+                let offset =
+                    -(rng.below(55 * SECONDS_PER_YEAR) as i64) - 18 * SECONDS_PER_YEAR as i64;
+                date(offset)
+            } else if has(&["expire", "due", "end", "until"]) {
+                let offset = rng.below(SECONDS_PER_YEAR) as i64 + SECONDS_PER_YEAR as i64;
+                date(offset)
+            } else {
+                let offset = rng.below(SECONDS_PER_YEAR) as i64;
+                date(offset)
+            }
+        }
+        _ => return None,
+    };
+
+    Some(value)
+}
+
+/// Builds `<key>.seed.data.ts` and its seeder for `entities`, `count`
+/// records each. Output is fully determined by the entities, `count` and
+/// `seed`.
+pub(crate) fn generate_seed_data(
+    entities: &[&EntityDefinition],
+    key: &str,
+    count: usize,
+    seed: u64,
+    id_style: IdStyle,
+    command_line: &str,
+) -> GeneratedSeedData {
+    let ordered = order_entities(entities);
+    let generated: BTreeSet<&str> = ordered.iter().map(|e| e.name.as_str()).collect();
+    let mut rng = SeedRng::new(seed);
+    let mut warnings = vec![];
+    let id_field = id_style.field();
+
+    let mut sections = vec![];
+    let mut declared: BTreeSet<&str> = BTreeSet::new();
+    for entity in &ordered {
+        let mut records = vec![];
+        for index in 0..count {
+            let mut fields = vec![match id_style {
+                IdStyle::Uuid => format!("{}: {}", id_field, quote(&rng.uuid())),
+                IdStyle::ObjectId => format!("{}: new ObjectId(\"{}\")", id_field, rng.hex(24)),
+            }];
+
+            for property in &entity.properties {
+                if property.relation_type.is_some() {
+                    let Some((relation_type, target)) = owned_relation(property) else {
+                        continue;
+                    };
+                    // Forward references are only possible inside a cycle
+                    let available = generated.contains(target.as_str())
+                        && (declared.contains(target.as_str()) || target == entity.name);
+                    if !available {
+                        if !property.is_nullable
+                            && *relation_type != RelationType::ManyToMany
+                            && index == 0
+                        {
+                            warnings.push(format!(
+                                "{}.{} references {}, which is not generated; fill it in by hand",
+                                entity.name, property.name, target
+                            ));
+                        }
+                        continue;
+                    }
+                    if property.is_nullable && rng.chance(1, 5) {
+                        continue;
+                    }
+                    // A record may only point at itself-typed records created
+                    // before it
+                    let pool = if target == entity.name { index } else { count };
+                    if pool == 0 {
+                        continue;
+                    }
+                    let reference =
+                        |i: usize| format!("{}[{}].{}", variable_name(&target), i, id_field);
+                    let value = match relation_type {
+                        RelationType::OneToOne => {
+                            if target == entity.name {
+                                continue;
+                            }
+                            reference(index % count)
+                        }
+                        RelationType::ManyToOne => reference(rng.below(pool as u64) as usize),
+                        RelationType::ManyToMany => {
+                            let picks = rng.between(1, 3.min(pool as i64)) as usize;
+                            let chosen: BTreeSet<usize> = (0..picks)
+                                .map(|_| rng.below(pool as u64) as usize)
+                                .collect();
+                            format!(
+                                "[{}]",
+                                chosen
+                                    .into_iter()
+                                    .map(reference)
+                                    .collect::<Vec<_>>()
+                                    .join(", ")
+                            )
+                        }
+                        RelationType::OneToMany => continue,
+                    };
+                    fields.push(format!("{}: {}", property.name, value));
+                    continue;
+                }
+
+                if property.is_nullable && rng.chance(1, 5) {
+                    continue;
+                }
+                match fake_value(entity, property, index, &mut rng) {
+                    Some(value) => fields.push(format!("{}: {}", property.name, value)),
+                    None => {
+                        if !property.is_nullable && index == 0 {
+                            warnings.push(format!(
+                                "{}.{} has type {}, which has no generator; fill it in by hand",
+                                entity.name, property.name, property.type_name
+                            ));
+                        }
+                    }
+                }
+            }
+
+            // Base entities add timestamps that `RequiredEntityData` expects
+            if entity.extends.is_some() && !entity.properties.iter().any(|p| p.name == "createdAt")
+            {
+                let created = rng.below(SECONDS_PER_YEAR) as i64;
+                let updated = created + rng.below(30 * 24 * 60 * 60) as i64;
+                fields.push(format!("createdAt: {}", date(created)));
+                fields.push(format!("updatedAt: {}", date(updated)));
+            }
+
+            records.push(format!("  {{\n    {}\n  }}", fields.join(",\n    ")));
+        }
+
+        sections.push(format!(
+            "export const {} = [\n{}\n] satisfies RequiredEntityData<{}>[];",
+            variable_name(&entity.name),
+            records.join(",\n"),
+            entity.name
+        ));
+        declared.insert(entity.name.as_str());
+    }
+
+    let entity_names: Vec<String> = ordered.iter().map(|e| e.name.clone()).collect();
+    let mut entity_imports = entity_names.clone();
+    entity_imports.sort();
+
+    let mut data_ts = String::from("import { RequiredEntityData } from \"@mikro-orm/core\";\n");
+    if id_style == IdStyle::ObjectId {
+        data_ts.push_str("import { ObjectId } from \"@mikro-orm/mongodb\";\n");
+    }
+    data_ts.push_str(&format!(
+        "import {{ {} }} from \"./entities\";\n\n// Generated by `{}`\n\n{}\n",
+        entity_imports.join(", "),
+        command_line,
+        sections.join("\n\n")
+    ));
+
+    let seeder_class = format!("{}GeneratedSeeder", key.to_case(Case::Pascal));
+    let mut data_imports: Vec<String> = entity_names.iter().map(|e| variable_name(e)).collect();
+    data_imports.sort();
+    let seeder_ts = format!(
+        "import {{ EntityManager }} from \"@mikro-orm/core\";
+import {{ Seeder }} from \"@mikro-orm/seeder\";
+import {{ {entities} }} from \"../entities\";
+import {{ {data} }} from \"../{key}.seed.data\";
+
+// Generated by `{command_line}`
+export class {seeder_class} extends Seeder {{
+  async run(em: EntityManager): Promise<void> {{
+{creates}
+    return em.flush();
+  }}
+}}
+",
+        entities = entity_imports.join(", "),
+        data = data_imports.join(", "),
+        creates = entity_names
+            .iter()
+            .map(|entity| format!(
+                "    {}.forEach((data) => em.create({}, data));",
+                variable_name(entity),
+                entity
+            ))
+            .collect::<Vec<_>>()
+            .join("\n"),
+    );
+
+    GeneratedSeedData {
+        data_ts,
+        seeder_ts,
+        seeder_class,
+        entity_order: entity_names,
+        warnings,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn property(name: &str, type_name: &str) -> EntityProperty {
+        EntityProperty {
+            name: name.to_string(),
+            type_name: type_name.to_string(),
+            is_nullable: false,
+            is_collection: false,
+            relation_type: None,
+            is_unique: false,
+            is_inverse_side: false,
+        }
+    }
+
+    fn relation(name: &str, type_name: &str, relation_type: RelationType) -> EntityProperty {
+        EntityProperty {
+            is_collection: type_name.starts_with("Collection<"),
+            relation_type: Some(relation_type),
+            ..property(name, type_name)
+        }
+    }
+
+    fn entities() -> Vec<EntityDefinition> {
+        vec![
+            EntityDefinition {
+                name: "User".to_string(),
+                extends: Some("SqlBaseEntity".to_string()),
+                properties: vec![
+                    EntityProperty {
+                        is_unique: true,
+                        ..property("email", "string")
+                    },
+                    property("firstName", "string"),
+                    property("age", "number"),
+                    property("providerFields", "unknown"),
+                    relation("organization", "Organization", RelationType::ManyToOne),
+                    relation("roles", "Collection<Role>", RelationType::ManyToMany),
+                ],
+            },
+            EntityDefinition {
+                name: "Role".to_string(),
+                extends: Some("SqlBaseEntity".to_string()),
+                properties: vec![property("name", "string")],
+            },
+            EntityDefinition {
+                name: "Organization".to_string(),
+                extends: Some("SqlBaseEntity".to_string()),
+                properties: vec![
+                    property("name", "string"),
+                    EntityProperty {
+                        is_inverse_side: true,
+                        ..relation("users", "Collection<User>", RelationType::OneToMany)
+                    },
+                ],
+            },
+            EntityDefinition {
+                name: "AuditLog".to_string(),
+                extends: Some("SqlBaseEntity".to_string()),
+                properties: vec![property("message", "string")],
+            },
+        ]
+    }
+
+    #[test]
+    fn test_select_and_order_entities() {
+        let entities = entities();
+        let selected = select_entities(&entities, &["User".to_string()]);
+
+        let names: Vec<&str> = selected.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, vec!["User", "Role", "Organization"]);
+
+        let generated = generate_seed_data(&selected, "user", 3, 7, IdStyle::Uuid, "test");
+        assert_eq!(generated.entity_order, vec!["Role", "Organization", "User"]);
+        assert_eq!(
+            generated.warnings,
+            vec![
+                "User.providerFields has type unknown, which has no generator; fill it in by hand"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_generate_seed_data_is_deterministic() {
+        let entities = entities();
+        let selected = select_entities(&entities, &["User".to_string()]);
+
+        let first = generate_seed_data(&selected, "user", 5, 42, IdStyle::Uuid, "test");
+        let second = generate_seed_data(&selected, "user", 5, 42, IdStyle::Uuid, "test");
+        let other_seed = generate_seed_data(&selected, "user", 5, 43, IdStyle::Uuid, "test");
+
+        assert_eq!(first.data_ts, second.data_ts);
+        assert_ne!(first.data_ts, other_seed.data_ts);
+
+        assert!(
+            first
+                .data_ts
+                .contains("import { Organization, Role, User } from \"./entities\";")
+        );
+        assert!(
+            first
+                .data_ts
+                .contains("] satisfies RequiredEntityData<User>[];")
+        );
+        assert!(
+            first
+                .data_ts
+                .contains("organization: organizationSeedData[")
+        );
+        assert!(first.data_ts.contains("roles: [roleSeedData["));
+        assert!(first.data_ts.contains("@example.com\""));
+        assert_eq!(first.data_ts.matches("email: ").count(), 5);
+        assert!(!first.data_ts.contains("users:"));
+
+        let role_position = first.data_ts.find("export const roleSeedData").unwrap();
+        let user_position = first.data_ts.find("export const userSeedData").unwrap();
+        assert!(role_position < user_position);
+
+        assert_eq!(first.seeder_class, "UserGeneratedSeeder");
+        assert!(first.seeder_ts.contains("from \"../user.seed.data\";"));
+        assert!(
+            first
+                .seeder_ts
+                .contains("userSeedData.forEach((data) => em.create(User, data));")
+        );
+    }
+}
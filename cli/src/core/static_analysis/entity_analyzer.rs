@@ -18,23 +18,34 @@ pub enum RelationType {
 pub struct EntityProperty {
     pub name: String,
     pub type_name: String,
-    #[allow(dead_code)]
     pub is_nullable: bool,
     #[allow(dead_code)]
     pub is_collection: bool,
     pub relation_type: Option<RelationType>,
+    /// Declared with `@Unique()`.
+    pub is_unique: bool,
+    /// Relation declared with `mappedBy`, i.e. the side that does not own
+    /// the foreign key.
+    pub is_inverse_side: bool,
 }
 
 #[derive(Debug, Clone)]
 pub struct EntityDefinition {
     pub name: String,
-    #[allow(dead_code)]
     pub extends: Option<String>,
     pub properties: Vec<EntityProperty>,
 }
 
 pub struct EntityAnalyzer;
 
+#[derive(Debug, Default)]
+struct DecoratorInfo {
+    relation_type: Option<RelationType>,
+    is_nullable: bool,
+    is_unique: bool,
+    is_inverse_side: bool,
+}
+
 impl EntityAnalyzer {
     /// Parse a TypeScript entity file and extract entity definitions
     pub fn parse_entity_file(path: &Path) -> Result<Vec<EntityDefinition>> {
@@ -123,7 +134,12 @@ impl EntityAnalyzer {
         };
 
         // Analyze decorators to determine if this is a relation
-        let (relation_type, is_nullable) = Self::analyze_decorators(&prop_def.decorators);
+        let DecoratorInfo {
+            relation_type,
+            is_nullable,
+            is_unique,
+            is_inverse_side,
+        } = Self::analyze_decorators(&prop_def.decorators);
 
         // Extract type information from type annotation or initializer
         let (type_name, is_collection) = if let Some(type_annotation) = &prop_def.type_annotation {
@@ -135,55 +151,72 @@ impl EntityAnalyzer {
             return None;
         };
 
+        let is_inverse_side = is_inverse_side || relation_type == Some(RelationType::OneToMany);
+
         Some(EntityProperty {
             name,
             type_name,
-            is_nullable,
+            is_nullable: is_nullable || prop_def.optional,
             is_collection,
             relation_type,
+            is_unique,
+            is_inverse_side,
         })
     }
 
-    fn analyze_decorators(decorators: &[Decorator]) -> (Option<RelationType>, bool) {
-        let mut relation_type = None;
-        let mut is_nullable = false;
+    fn analyze_decorators(decorators: &[Decorator]) -> DecoratorInfo {
+        let mut info = DecoratorInfo::default();
 
         for decorator in decorators {
-            if let Expression::CallExpression(call) = &decorator.expression {
-                if let Expression::Identifier(id) = &call.callee {
-                    let decorator_name = id.name.as_str();
-
-                    // Detect relation decorators
-                    relation_type = match decorator_name {
-                        "ManyToOne" => Some(RelationType::ManyToOne),
-                        "OneToMany" => Some(RelationType::OneToMany),
-                        "ManyToMany" => Some(RelationType::ManyToMany),
-                        "OneToOne" => Some(RelationType::OneToOne),
-                        _ => relation_type,
-                    };
-
-                    // Check for nullable option in @Property decorator
-                    if decorator_name == "Property" {
-                        if let Some(Argument::ObjectExpression(obj)) = call.arguments.first() {
-                            for prop in &obj.properties {
-                                if let ObjectPropertyKind::ObjectProperty(obj_prop) = prop {
-                                    if let PropertyKey::StaticIdentifier(key) = &obj_prop.key {
-                                        if key.name.as_str() == "nullable" {
-                                            if let Expression::BooleanLiteral(lit) = &obj_prop.value
-                                            {
-                                                is_nullable = lit.value;
-                                            }
-                                        }
-                                    }
-                                }
-                            }
+            let Expression::CallExpression(call) = &decorator.expression else {
+                continue;
+            };
+            let Expression::Identifier(id) = &call.callee else {
+                continue;
+            };
+            let decorator_name = id.name.as_str();
+
+            // Detect relation decorators
+            info.relation_type = match decorator_name {
+                "ManyToOne" => Some(RelationType::ManyToOne),
+                "OneToMany" => Some(RelationType::OneToMany),
+                "ManyToMany" => Some(RelationType::ManyToMany),
+                "OneToOne" => Some(RelationType::OneToOne),
+                _ => info.relation_type,
+            };
+
+            if decorator_name == "Unique" {
+                info.is_unique = true;
+            }
+
+            // Options are the only argument of @Property and the second one
+            // of relation decorators (after the target)
+            let options = call.arguments.iter().find_map(|argument| match argument {
+                Argument::ObjectExpression(obj) => Some(obj),
+                _ => None,
+            });
+            let Some(options) = options else {
+                continue;
+            };
+            for prop in &options.properties {
+                if let ObjectPropertyKind::ObjectProperty(obj_prop) = prop
+                    && let PropertyKey::StaticIdentifier(key) = &obj_prop.key
+                {
+                    match (key.name.as_str(), &obj_prop.value) {
+                        ("nullable", Expression::BooleanLiteral(lit)) => {
+                            info.is_nullable = lit.value;
+                        }
+                        ("unique", Expression::BooleanLiteral(lit)) => {
+                            info.is_unique = lit.value;
                         }
+                        ("mappedBy", _) => info.is_inverse_side = true,
+                        _ => {}
                     }
                 }
             }
         }
 
-        (relation_type, is_nullable)
+        info
     }
 
     fn extract_type_info(type_ann: &TSType) -> (String, bool) {
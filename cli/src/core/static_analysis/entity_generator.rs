@@ -0,0 +1,344 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use super::{
+    schema_analyzer::{SchemaDefinition, SchemaProperty, SchemaType},
+    schema_generator::{BASE_ENTITY_PROPERTIES, plural},
+};
+
+/// An entity that generated relations may point at.
+#[derive(Debug, Clone)]
+pub struct KnownEntity {
+    pub class_name: String,
+    /// Import path relative to the entities directory, e.g.
+    /// `./organizationRecord.entity`
+    pub import_path: String,
+}
+
+/// TypeScript type for a schema type.
+fn ts_type(schema_type: &SchemaType) -> String {
+    match schema_type {
+        SchemaType::Primitive(name) => match name.as_str() {
+            "string" | "uuid" | "email" | "uri" => "string".to_string(),
+            "number" | "integer" => "number".to_string(),
+            "bigint" => "bigint".to_string(),
+            "boolean" => "boolean".to_string(),
+            "date" => "Date".to_string(),
+            "null" => "null".to_string(),
+            _ => "unknown".to_string(),
+        },
+        SchemaType::Literal(value) => value.clone(),
+        SchemaType::Enum(values) => values.join(" | "),
+        SchemaType::Array(inner) => match inner.as_ref() {
+            SchemaType::Union(_) | SchemaType::Enum(_) => format!("({})[]", ts_type(inner)),
+            _ => format!("{}[]", ts_type(inner)),
+        },
+        SchemaType::Record(_, value) => format!("Record<string, {}>", ts_type(value)),
+        SchemaType::Object(properties) => format!(
+            "{{ {} }}",
+            properties
+                .iter()
+                .map(|p| format!(
+                    "{}{}: {}",
+                    p.name,
+                    if p.is_optional { "?" } else { "" },
+                    ts_type(&p.schema_type)
+                ))
+                .collect::<Vec<_>>()
+                .join("; ")
+        ),
+        SchemaType::Union(members) => members.iter().map(ts_type).collect::<Vec<_>>().join(" | "),
+        SchemaType::Reference(_) | SchemaType::Unknown => "unknown".to_string(),
+    }
+}
+
+/// Allowed values when the schema type is a literal or a union of literals.
+fn enum_items(schema_type: &SchemaType) -> Option<Vec<String>> {
+    match schema_type {
+        SchemaType::Enum(values) => Some(values.clone()),
+        SchemaType::Literal(value) => Some(vec![value.clone()]),
+        SchemaType::Union(members) => members
+            .iter()
+            .map(|member| match member {
+                SchemaType::Literal(value) => Some(value.clone()),
+                _ => None,
+            })
+            .collect(),
+        _ => None,
+    }
+}
+
+/// Generates a MikroORM entity from an idiomatic validator schema.
+pub struct EntityGenerator {
+    schema: SchemaDefinition,
+    class_name: String,
+    app_name: String,
+    is_mongo: bool,
+    /// Entities in the same project, by camel case base name (`organization`)
+    known_entities: BTreeMap<String, KnownEntity>,
+}
+
+struct GeneratedProperty {
+    decorator: String,
+    declaration: String,
+}
+
+impl EntityGenerator {
+    pub fn new(
+        schema: SchemaDefinition,
+        class_name: String,
+        app_name: String,
+        is_mongo: bool,
+        known_entities: BTreeMap<String, KnownEntity>,
+    ) -> Self {
+        Self {
+            schema,
+            class_name,
+            app_name,
+            is_mongo,
+            known_entities,
+        }
+    }
+
+    /// `organizationId` -> the `organization` entity, `roleIds` -> `role`.
+    fn relation_target(&self, property: &SchemaProperty) -> Option<&KnownEntity> {
+        let base = if property.is_array {
+            property.name.strip_suffix("Ids")
+        } else {
+            property.name.strip_suffix("Id")
+        }?;
+        self.known_entities.get(base)
+    }
+
+    fn generate_property(
+        &self,
+        property: &SchemaProperty,
+        decorators: &mut BTreeSet<&'static str>,
+        relations: &mut BTreeSet<String>,
+    ) -> GeneratedProperty {
+        let optional = property.is_optional || property.is_nullable;
+        let nullable_option = if optional { ", { nullable: true }" } else { "" };
+        let marker = if optional { "?" } else { "!" };
+
+        if let Some(target) = self.relation_target(property) {
+            relations.insert(target.class_name.clone());
+            if property.is_array {
+                decorators.extend(["ManyToMany", "Collection"]);
+                return GeneratedProperty {
+                    decorator: format!("@ManyToMany('{}')", target.class_name),
+                    declaration: format!(
+                        "{} = new Collection<{}>(this);",
+                        plural(property.name.trim_end_matches("Ids")),
+                        target.class_name
+                    ),
+                };
+            }
+            decorators.insert("ManyToOne");
+            return GeneratedProperty {
+                decorator: format!("@ManyToOne('{}'{})", target.class_name, nullable_option),
+                declaration: format!(
+                    "{}{}: {};",
+                    property.name.trim_end_matches("Id"),
+                    marker,
+                    target.class_name
+                ),
+            };
+        }
+
+        let declaration = format!(
+            "{}{}: {};",
+            property.name,
+            marker,
+            ts_type(&property.schema_type)
+        );
+        let nullable = if optional { ", nullable: true" } else { "" };
+
+        if let Some(items) = enum_items(&property.schema_type) {
+            decorators.insert("Enum");
+            return GeneratedProperty {
+                decorator: format!("@Enum({{ items: [{}]{} }})", items.join(", "), nullable),
+                declaration,
+            };
+        }
+
+        decorators.insert("Property");
+        let decorator = match &property.schema_type {
+            SchemaType::Primitive(name) if name == "bigint" => {
+                format!("@Property({{ type: 'bigint'{} }})", nullable)
+            }
+            SchemaType::Primitive(name)
+                if matches!(
+                    name.as_str(),
+                    "string" | "uuid" | "email" | "uri" | "number" | "integer" | "boolean" | "date"
+                ) =>
+            {
+                format!("@Property({})", nullable_option.trim_start_matches(", "))
+            }
+            // Arrays, objects, records and anything unrecognized are stored
+            // as JSON
+            _ => format!("@Property({{ type: 'json'{} }})", nullable),
+        };
+        GeneratedProperty {
+            decorator,
+            declaration,
+        }
+    }
+
+    pub fn generate_entity_file(&self) -> String {
+        let base_entity = if self.is_mongo {
+            "NoSqlBaseEntity"
+        } else {
+            "SqlBaseEntity"
+        };
+        let mut decorators: BTreeSet<&'static str> = BTreeSet::from(["Entity"]);
+        let mut relations = BTreeSet::new();
+
+        let properties: Vec<GeneratedProperty> = self
+            .schema
+            .properties
+            .iter()
+            .filter(|property| !BASE_ENTITY_PROPERTIES.contains(&property.name.as_str()))
+            .map(|property| self.generate_property(property, &mut decorators, &mut relations))
+            .collect();
+
+        let mut imports = vec![
+            format!(
+                "import {{ {} }} from '@mikro-orm/core';",
+                decorators.into_iter().collect::<Vec<_>>().join(", ")
+            ),
+            format!(
+                "import {{ {} }} from '@{}/core';",
+                base_entity, self.app_name
+            ),
+        ];
+        for class_name in &relations {
+            if let Some(target) = self
+                .known_entities
+                .values()
+                .find(|known| &known.class_name == class_name)
+            {
+                imports.push(format!(
+                    "import type {{ {} }} from '{}';",
+                    class_name, target.import_path
+                ));
+            }
+        }
+
+        format!(
+            "{}\n\n// Entity class that defines the structure of the {} table\n@Entity()\nexport class {} extends {} {{\n{}\n}}\n",
+            imports.join("\n"),
+            self.class_name,
+            self.class_name,
+            base_entity,
+            properties
+                .iter()
+                .map(|p| format!("  {}\n  {}", p.decorator, p.declaration))
+                .collect::<Vec<_>>()
+                .join("\n\n")
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn property(name: &str, schema_type: SchemaType, is_optional: bool) -> SchemaProperty {
+        let (type_name, is_array) = match &schema_type {
+            SchemaType::Array(inner) => (inner.type_name(), true),
+            other => (other.type_name(), false),
+        };
+        SchemaProperty {
+            name: name.to_string(),
+            type_name,
+            is_optional,
+            is_nullable: false,
+            is_array,
+            schema_type,
+        }
+    }
+
+    fn primitive(name: &str) -> SchemaType {
+        SchemaType::Primitive(name.to_string())
+    }
+
+    #[test]
+    fn test_generate_entity_from_schema() {
+        let properties = vec![
+            property("id", primitive("uuid"), false),
+            property("email", primitive("email"), false),
+            property("age", primitive("number"), true),
+            property(
+                "tier",
+                SchemaType::Union(vec![
+                    SchemaType::Literal("'free'".to_string()),
+                    SchemaType::Literal("'pro'".to_string()),
+                ]),
+                false,
+            ),
+            property(
+                "address",
+                SchemaType::Object(vec![property("street", primitive("string"), false)]),
+                true,
+            ),
+            property("organizationId", primitive("uuid"), true),
+            property(
+                "roleIds",
+                SchemaType::Array(Box::new(primitive("uuid"))),
+                false,
+            ),
+        ];
+        let schema = SchemaDefinition {
+            name: "CustomerRequestSchema".to_string(),
+            schema_type: SchemaType::Object(properties.clone()),
+            properties,
+        };
+        let known_entities = BTreeMap::from([
+            (
+                "organization".to_string(),
+                KnownEntity {
+                    class_name: "OrganizationRecord".to_string(),
+                    import_path: "./organizationRecord.entity".to_string(),
+                },
+            ),
+            (
+                "role".to_string(),
+                KnownEntity {
+                    class_name: "RoleRecord".to_string(),
+                    import_path: "./roleRecord.entity".to_string(),
+                },
+            ),
+        ]);
+
+        let generator = EntityGenerator::new(
+            schema,
+            "CustomerRecord".to_string(),
+            "my-app".to_string(),
+            false,
+            known_entities,
+        );
+        let result = generator.generate_entity_file();
+
+        assert!(result.starts_with(
+            "import { Collection, Entity, Enum, ManyToMany, ManyToOne, Property } from '@mikro-orm/core';\n\
+             import { SqlBaseEntity } from '@my-app/core';\n\
+             import type { OrganizationRecord } from './organizationRecord.entity';\n\
+             import type { RoleRecord } from './roleRecord.entity';\n"
+        ));
+        assert!(result.contains("export class CustomerRecord extends SqlBaseEntity {"));
+        assert!(!result.contains("id!: string"));
+        assert!(result.contains("  @Property()\n  email!: string;"));
+        assert!(result.contains("  @Property({ nullable: true })\n  age?: number;"));
+        assert!(result.contains("  @Enum({ items: ['free', 'pro'] })\n  tier!: 'free' | 'pro';"));
+        assert!(result.contains(
+            "  @Property({ type: 'json', nullable: true })\n  address?: { street: string };"
+        ));
+        assert!(result.contains(
+            "  @ManyToOne('OrganizationRecord', { nullable: true })\n  organization?: OrganizationRecord;"
+        ));
+        assert!(
+            result.contains(
+                "  @ManyToMany('RoleRecord')\n  roles = new Collection<RoleRecord>(this);"
+            )
+        );
+    }
+}
@@ -1,6 +1,6 @@
 use super::{
     entity_analyzer::{EntityDefinition, EntityProperty, RelationType},
-    schema_analyzer::{SchemaDefinition, SchemaProperty},
+    schema_analyzer::{SchemaDefinition, SchemaProperty, SchemaType},
 };
 use convert_case::{Case, Casing};
 
@@ -175,18 +175,29 @@ export const {}ResponseMapper = responseMapper({{
                 // roles: await em.find(Role, { id: { $in: dto.roles } })
                 let relation_entity = self.extract_relation_entity(&entity_prop.type_name);
                 format!(
-                    "{}: await em.find({}, {{ id: {{ $in: dto.{} }} }}),",
-                    entity_prop.name, relation_entity, schema_prop.name
+                    "{}: await em.find({}, {{ id: {{ $in: {} }} }}),",
+                    entity_prop.name,
+                    relation_entity,
+                    self.id_reference(schema_prop)
                 )
             }
             Some(RelationType::ManyToOne) => {
                 // Single ID to entity reference
                 // organization: await em.findOne(Organization, { id: dto.organizationId })
                 let relation_entity = &entity_prop.type_name;
-                format!(
-                    "{}: await em.findOne({}, {{ id: dto.{} }}),",
-                    entity_prop.name, relation_entity, schema_prop.name
-                )
+                let lookup = format!(
+                    "await em.findOne({}, {{ id: {} }})",
+                    relation_entity,
+                    self.id_reference(schema_prop)
+                );
+                if schema_prop.is_optional || schema_prop.is_nullable {
+                    format!(
+                        "{}: dto.{} ? {} : undefined,",
+                        entity_prop.name, schema_prop.name, lookup
+                    )
+                } else {
+                    format!("{}: {},", entity_prop.name, lookup)
+                }
             }
             Some(RelationType::OneToMany) | Some(RelationType::OneToOne) => {
                 // Skip inverse side of relations in request mapping
@@ -239,6 +250,22 @@ export const {}ResponseMapper = responseMapper({{
         None
     }
 
+    /// How to read the related ID(s) from the DTO: the value itself, or the
+    /// `id` of nested objects such as `organization: { id: string }`.
+    fn id_reference(&self, schema_prop: &SchemaProperty) -> String {
+        let element = match &schema_prop.schema_type {
+            SchemaType::Array(inner) => inner.as_ref(),
+            other => other,
+        };
+        let has_nested_id = element.properties().iter().any(|p| p.name == "id");
+
+        match (has_nested_id, schema_prop.is_array) {
+            (false, _) => format!("dto.{}", schema_prop.name),
+            (true, false) => format!("dto.{}.id", schema_prop.name),
+            (true, true) => format!("dto.{}.map(({{ id }}) => id)", schema_prop.name),
+        }
+    }
+
     fn has_entity_property(&self, name: &str) -> bool {
         self.entity.properties.iter().any(|p| p.name == name)
     }
@@ -269,14 +296,19 @@ mod tests {
                     type_name: "string".to_string(),
                     is_optional: false,
                     is_array: false,
+                    is_nullable: false,
+                    schema_type: SchemaType::Primitive("string".to_string()),
                 },
                 SchemaProperty {
                     name: "email".to_string(),
                     type_name: "string".to_string(),
                     is_optional: false,
                     is_array: false,
+                    is_nullable: false,
+                    schema_type: SchemaType::Primitive("string".to_string()),
                 },
             ],
+            schema_type: SchemaType::Object(vec![]),
         };
 
         let entity = EntityDefinition {
@@ -289,6 +321,8 @@ mod tests {
                     is_nullable: false,
                     is_collection: false,
                     relation_type: None,
+                    is_unique: false,
+                    is_inverse_side: false,
                 },
                 EntityProperty {
                     name: "email".to_string(),
@@ -296,6 +330,8 @@ mod tests {
                     is_nullable: false,
                     is_collection: false,
                     relation_type: None,
+                    is_unique: false,
+                    is_inverse_side: false,
                 },
                 EntityProperty {
                     name: "createdAt".to_string(),
@@ -303,6 +339,8 @@ mod tests {
                     is_nullable: false,
                     is_collection: false,
                     relation_type: None,
+                    is_unique: false,
+                    is_inverse_side: false,
                 },
             ],
         };
@@ -328,20 +366,29 @@ mod tests {
                     type_name: "string".to_string(),
                     is_optional: false,
                     is_array: false,
+                    is_nullable: false,
+                    schema_type: SchemaType::Primitive("string".to_string()),
                 },
                 SchemaProperty {
                     name: "organizationId".to_string(),
                     type_name: "string".to_string(),
                     is_optional: false,
                     is_array: false,
+                    is_nullable: false,
+                    schema_type: SchemaType::Primitive("string".to_string()),
                 },
                 SchemaProperty {
                     name: "roleIds".to_string(),
                     type_name: "string".to_string(),
                     is_optional: false,
                     is_array: true,
+                    is_nullable: false,
+                    schema_type: SchemaType::Array(Box::new(SchemaType::Primitive(
+                        "string".to_string(),
+                    ))),
                 },
             ],
+            schema_type: SchemaType::Object(vec![]),
         };
 
         let entity = EntityDefinition {
@@ -354,6 +401,8 @@ mod tests {
                     is_nullable: false,
                     is_collection: false,
                     relation_type: None,
+                    is_unique: false,
+                    is_inverse_side: false,
                 },
                 EntityProperty {
                     name: "organization".to_string(),
@@ -361,6 +410,8 @@ mod tests {
                     is_nullable: false,
                     is_collection: false,
                     relation_type: Some(RelationType::ManyToOne),
+                    is_unique: false,
+                    is_inverse_side: false,
                 },
                 EntityProperty {
                     name: "roles".to_string(),
@@ -368,6 +419,8 @@ mod tests {
                     is_nullable: false,
                     is_collection: true,
                     relation_type: Some(RelationType::ManyToMany),
+                    is_unique: false,
+                    is_inverse_side: false,
                 },
             ],
         };
@@ -379,4 +432,66 @@ mod tests {
         assert!(result.contains("organization: await em.findOne(Organization"));
         assert!(result.contains("roles: await em.find(Role"));
     }
+
+    #[test]
+    fn test_generate_mapper_with_nested_relation_objects() {
+        let id_object = SchemaType::Object(vec![SchemaProperty {
+            name: "id".to_string(),
+            type_name: "string".to_string(),
+            is_optional: false,
+            is_array: false,
+            is_nullable: false,
+            schema_type: SchemaType::Primitive("string".to_string()),
+        }]);
+        let schema = SchemaDefinition {
+            name: "UserRequestSchema".to_string(),
+            properties: vec![
+                SchemaProperty {
+                    name: "organization".to_string(),
+                    type_name: "object".to_string(),
+                    is_optional: true,
+                    is_array: false,
+                    is_nullable: false,
+                    schema_type: id_object.clone(),
+                },
+                SchemaProperty {
+                    name: "roles".to_string(),
+                    type_name: "object".to_string(),
+                    is_optional: false,
+                    is_array: true,
+                    is_nullable: false,
+                    schema_type: SchemaType::Array(Box::new(id_object)),
+                },
+            ],
+            schema_type: SchemaType::Object(vec![]),
+        };
+
+        let relation = |name: &str, type_name: &str, relation_type| EntityProperty {
+            name: name.to_string(),
+            type_name: type_name.to_string(),
+            is_nullable: false,
+            is_collection: false,
+            relation_type: Some(relation_type),
+            is_unique: false,
+            is_inverse_side: false,
+        };
+        let entity = EntityDefinition {
+            name: "UserRecord".to_string(),
+            extends: Some("SqlBaseEntity".to_string()),
+            properties: vec![
+                relation("organization", "Organization", RelationType::ManyToOne),
+                relation("roles", "Collection<Role>", RelationType::ManyToMany),
+            ],
+        };
+
+        let generator = MapperGenerator::new(schema, entity, "my-app".to_string(), false);
+        let result = generator.generate_to_entity_body();
+
+        assert!(result.contains(
+            "organization: dto.organization ? await em.findOne(Organization, { id: dto.organization.id }) : undefined,"
+        ));
+        assert!(result.contains(
+            "roles: await em.find(Role, { id: { $in: dto.roles.map(({ id }) => id) } }),"
+        ));
+    }
 }
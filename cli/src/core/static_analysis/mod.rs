@@ -1,7 +1,11 @@
 pub mod schema_analyzer;
 pub mod entity_analyzer;
 pub mod mapper_generator;
+pub mod schema_generator;
+pub mod entity_generator;
 
 pub use schema_analyzer::SchemaAnalyzer;
 pub use entity_analyzer::EntityAnalyzer;
 pub use mapper_generator::MapperGenerator;
+pub use schema_generator::SchemaGenerator;
+pub use entity_generator::EntityGenerator;
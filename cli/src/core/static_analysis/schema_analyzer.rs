@@ -1,4 +1,9 @@
-use std::{fs::read_to_string, path::Path};
+use std::{
+    collections::{HashMap, HashSet},
+    fs::read_to_string,
+    path::{Path, PathBuf},
+    rc::Rc,
+};
 
 use anyhow::{Context, Result};
 use oxc_allocator::Allocator;
@@ -6,27 +11,179 @@ use oxc_ast::ast::*;
 use oxc_parser::{Parser, ParserReturn};
 use oxc_span::SourceType;
 
-#[derive(Debug, Clone)]
+/// How many `export ... from` hops to follow when resolving an imported
+/// schema.
+const MAX_RE_EXPORT_DEPTH: usize = 8;
+
+/// Structure of a schema value, as far as it can be read without running it.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SchemaType {
+    /// Validator primitive such as `string`, `number`, `uuid` or `date`
+    Primitive(String),
+    /// Single allowed value, as TypeScript source (`'admin'`, `3`, `true`)
+    Literal(String),
+    Array(Box<SchemaType>),
+    Record(Box<SchemaType>, Box<SchemaType>),
+    Object(Vec<SchemaProperty>),
+    Union(Vec<SchemaType>),
+    /// Allowed values, as TypeScript source
+    Enum(Vec<String>),
+    /// Schema imported from a file that could not be resolved, or a
+    /// reference back to a schema that is still being analyzed
+    Reference(String),
+    Unknown,
+}
+
+impl SchemaType {
+    /// TypeScript-like rendering of the type, e.g. `string[]` or
+    /// `'a' | 'b'`.
+    pub fn type_name(&self) -> String {
+        match self {
+            SchemaType::Primitive(name) | SchemaType::Literal(name) => name.clone(),
+            SchemaType::Array(inner) => match inner.as_ref() {
+                SchemaType::Union(_) | SchemaType::Enum(_) => format!("({})[]", inner.type_name()),
+                _ => format!("{}[]", inner.type_name()),
+            },
+            SchemaType::Record(_, _) => "Record".to_string(),
+            SchemaType::Object(_) => "object".to_string(),
+            SchemaType::Union(members) => members
+                .iter()
+                .map(SchemaType::type_name)
+                .collect::<Vec<_>>()
+                .join(" | "),
+            SchemaType::Enum(values) => values.join(" | "),
+            SchemaType::Reference(name) => name.clone(),
+            SchemaType::Unknown => "unknown".to_string(),
+        }
+    }
+
+    /// Properties of an object schema, or nothing for any other type.
+    pub fn properties(&self) -> &[SchemaProperty] {
+        match self {
+            SchemaType::Object(properties) => properties,
+            _ => &[],
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
 pub struct SchemaProperty {
     pub name: String,
+    /// Element type for arrays, `schema_type.type_name()` otherwise.
     #[allow(dead_code)]
     pub type_name: String,
     #[allow(dead_code)]
     pub is_optional: bool,
+    #[allow(dead_code)]
+    pub is_nullable: bool,
     pub is_array: bool,
+    pub schema_type: SchemaType,
+}
+
+impl SchemaProperty {
+    fn new(name: String, analyzed: AnalyzedType) -> Self {
+        let (type_name, is_array) = match &analyzed.schema_type {
+            SchemaType::Array(inner) => (inner.type_name(), true),
+            other => (other.type_name(), false),
+        };
+        Self {
+            name,
+            type_name,
+            is_optional: analyzed.is_optional,
+            is_nullable: analyzed.is_nullable,
+            is_array,
+            schema_type: analyzed.schema_type,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
 pub struct SchemaDefinition {
     pub name: String,
     pub properties: Vec<SchemaProperty>,
+    #[allow(dead_code)]
+    pub schema_type: SchemaType,
 }
 
 pub struct SchemaAnalyzer;
 
 impl SchemaAnalyzer {
-    /// Parse a TypeScript schema file and extract schema definitions
+    /// Parse a TypeScript schema file and extract schema definitions.
+    /// Schemas imported from relative paths are resolved through their
+    /// source files.
     pub fn parse_schema_file(path: &Path) -> Result<Vec<SchemaDefinition>> {
+        let mut resolver = SchemaResolver::default();
+        let file = resolver.analyze(path)?;
+
+        Ok(file
+            .exported
+            .iter()
+            .filter(|name| name.ends_with("Schema"))
+            .filter_map(|name| file.types.get(name).map(|analyzed| (name, analyzed)))
+            .map(|(name, analyzed)| SchemaDefinition {
+                name: name.clone(),
+                properties: analyzed.schema_type.properties().to_vec(),
+                schema_type: analyzed.schema_type.clone(),
+            })
+            .collect())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct AnalyzedType {
+    schema_type: SchemaType,
+    is_optional: bool,
+    is_nullable: bool,
+}
+
+impl AnalyzedType {
+    fn new(schema_type: SchemaType) -> Self {
+        Self {
+            schema_type,
+            is_optional: false,
+            is_nullable: false,
+        }
+    }
+}
+
+/// Everything another file may need from an analyzed file.
+#[derive(Debug, Default)]
+struct ResolvedFile {
+    types: HashMap<String, AnalyzedType>,
+    enums: HashMap<String, Vec<String>>,
+    /// Exported declarations, in source order
+    exported: Vec<String>,
+    /// `export { a as b } from './x'`: `b` -> (`a`, file)
+    re_exports: HashMap<String, (String, PathBuf)>,
+    /// `export * from './x'`
+    star_exports: Vec<PathBuf>,
+}
+
+/// Caches analyzed files so that schemas shared between files are only
+/// parsed once.
+#[derive(Default)]
+struct SchemaResolver {
+    files: HashMap<PathBuf, Rc<ResolvedFile>>,
+    visiting: HashSet<PathBuf>,
+}
+
+impl SchemaResolver {
+    fn analyze(&mut self, path: &Path) -> Result<Rc<ResolvedFile>> {
+        if let Some(file) = self.files.get(path) {
+            return Ok(file.clone());
+        }
+        if !self.visiting.insert(path.to_path_buf()) {
+            anyhow::bail!("Circular import through {}", path.display());
+        }
+
+        let result = Self::analyze_source(path, self);
+        self.visiting.remove(path);
+        let file = Rc::new(result?);
+        self.files.insert(path.to_path_buf(), file.clone());
+        Ok(file)
+    }
+
+    fn analyze_source(path: &Path, resolver: &mut SchemaResolver) -> Result<ResolvedFile> {
         let source = read_to_string(path)
             .with_context(|| format!("Failed to read schema file: {}", path.display()))?;
 
@@ -41,208 +198,517 @@ impl SchemaAnalyzer {
             anyhow::bail!("Failed to parse schema file: {:?}", errors);
         }
 
-        let mut schemas = Vec::new();
+        let directory = path.parent().unwrap_or(Path::new("."));
+        Ok(FileAnalyzer::new(directory, &program, resolver).finish())
+    }
 
-        // Visit all variable declarations looking for schema exports
-        for stmt in &program.body {
-            if let Statement::ExportNamedDeclaration(export_decl) = stmt {
-                if let Some(Declaration::VariableDeclaration(var_decl)) = &export_decl.declaration {
-                    for declarator in &var_decl.declarations {
-                        if let Some(schema) =
-                            Self::extract_schema_from_declarator(declarator, &source)
-                        {
-                            schemas.push(schema);
-                        }
-                    }
-                }
-            }
+    fn lookup(&mut self, path: &Path, name: &str, depth: usize) -> Option<AnalyzedType> {
+        if depth > MAX_RE_EXPORT_DEPTH {
+            return None;
         }
-
-        Ok(schemas)
+        let file = self.analyze(path).ok()?;
+        if let Some(analyzed) = file.types.get(name) {
+            return Some(analyzed.clone());
+        }
+        if let Some((imported, source)) = file.re_exports.get(name) {
+            return self.lookup(source, imported, depth + 1);
+        }
+        file.star_exports
+            .iter()
+            .find_map(|source| self.lookup(source, name, depth + 1))
     }
 
-    fn extract_schema_from_declarator(
-        declarator: &VariableDeclarator,
-        source: &str,
-    ) -> Option<SchemaDefinition> {
-        // Get the variable name
-        let name = match &declarator.id.kind {
-            BindingPatternKind::BindingIdentifier(id) => id.name.as_str().to_string(),
-            _ => return None,
-        };
-
-        // Only process if it looks like a schema (ends with "Schema")
-        if !name.ends_with("Schema") {
+    fn lookup_enum(&mut self, path: &Path, name: &str, depth: usize) -> Option<Vec<String>> {
+        if depth > MAX_RE_EXPORT_DEPTH {
             return None;
         }
+        let file = self.analyze(path).ok()?;
+        if let Some(values) = file.enums.get(name) {
+            return Some(values.clone());
+        }
+        if let Some((imported, source)) = file.re_exports.get(name) {
+            return self.lookup_enum(source, imported, depth + 1);
+        }
+        file.star_exports
+            .iter()
+            .find_map(|source| self.lookup_enum(source, name, depth + 1))
+    }
+}
 
-        // Extract properties from the initializer
-        let properties = if let Some(init) = &declarator.init {
-            Self::extract_properties_from_expression(init, source)
-        } else {
-            Vec::new()
-        };
+/// Resolves a relative import specifier to a TypeScript file.
+fn resolve_module(directory: &Path, specifier: &str) -> Option<PathBuf> {
+    if !specifier.starts_with('.') {
+        return None;
+    }
+    let base = directory.join(specifier);
+    let base_str = base.to_string_lossy();
+    let candidates = [
+        base.clone(),
+        PathBuf::from(format!("{}.ts", base_str.trim_end_matches(".js"))),
+        PathBuf::from(format!("{}.ts", base_str)),
+        base.join("index.ts"),
+    ];
+    candidates
+        .into_iter()
+        .find(|candidate| candidate.is_file())
+        .and_then(|candidate| candidate.canonicalize().ok())
+}
 
-        Some(SchemaDefinition { name, properties })
+fn literal_source(expr: &Expression) -> Option<String> {
+    match expr.get_inner_expression() {
+        Expression::StringLiteral(lit) => Some(format!("'{}'", lit.value)),
+        Expression::NumericLiteral(lit) => Some(if lit.value.fract() == 0.0 {
+            format!("{}", lit.value as i64)
+        } else {
+            lit.value.to_string()
+        }),
+        Expression::BooleanLiteral(lit) => Some(lit.value.to_string()),
+        Expression::NullLiteral(_) => Some("null".to_string()),
+        _ => None,
     }
+}
 
-    fn extract_properties_from_expression(expr: &Expression, source: &str) -> Vec<SchemaProperty> {
-        match expr {
-            Expression::ObjectExpression(obj_expr) => {
-                let mut properties = Vec::new();
-
-                for prop in &obj_expr.properties {
-                    if let ObjectPropertyKind::ObjectProperty(obj_prop) = prop {
-                        if let Some(schema_prop) =
-                            Self::extract_property_from_object_property(obj_prop, source)
-                        {
-                            properties.push(schema_prop);
-                        }
+/// Values of an `as const` object such as `{ Active: 'active' }`.
+fn object_literal_values(obj: &ObjectExpression) -> Option<Vec<String>> {
+    obj.properties
+        .iter()
+        .map(|prop| match prop {
+            ObjectPropertyKind::ObjectProperty(obj_prop) => literal_source(&obj_prop.value),
+            ObjectPropertyKind::SpreadProperty(_) => None,
+        })
+        .collect()
+}
+
+fn enum_values(declaration: &TSEnumDeclaration) -> Vec<String> {
+    let mut next_number = 0i64;
+    declaration
+        .body
+        .members
+        .iter()
+        .map(
+            |member| match member.initializer.as_ref().and_then(literal_source) {
+                Some(value) => {
+                    if let Ok(number) = value.parse::<i64>() {
+                        next_number = number + 1;
                     }
+                    value
                 }
+                None => {
+                    next_number += 1;
+                    (next_number - 1).to_string()
+                }
+            },
+        )
+        .collect()
+}
 
-                properties
+/// Flattens nested unions and turns `null`/`undefined` members into flags.
+fn normalize_union(members: Vec<AnalyzedType>) -> AnalyzedType {
+    let mut is_optional = false;
+    let mut is_nullable = false;
+    let mut types = vec![];
+    for member in members {
+        is_optional |= member.is_optional;
+        is_nullable |= member.is_nullable;
+        match member.schema_type {
+            SchemaType::Union(inner) => types.extend(inner),
+            SchemaType::Literal(value) | SchemaType::Primitive(value) if value == "null" => {
+                is_nullable = true
             }
-            Expression::CallExpression(call_expr) => {
-                // Handle cases like object({ ... }) or record(...)
-                if let Some(arg) = call_expr.arguments.first() {
-                    if let Argument::ObjectExpression(obj_expr) = arg {
-                        return Self::extract_properties_from_object_expression(obj_expr, source);
+            SchemaType::Primitive(value) if value == "undefined" => is_optional = true,
+            other => types.push(other),
+        }
+    }
+
+    let schema_type = match types.len() {
+        0 => SchemaType::Unknown,
+        1 => types.remove(0),
+        _ => SchemaType::Union(types),
+    };
+    AnalyzedType {
+        schema_type,
+        is_optional,
+        is_nullable,
+    }
+}
+
+struct FileAnalyzer<'a, 'r> {
+    declarations: HashMap<&'a str, &'a Expression<'a>>,
+    /// Local name -> (imported name, file) for relative imports
+    imports: HashMap<&'a str, (String, PathBuf)>,
+    resolved: ResolvedFile,
+    in_progress: HashSet<&'a str>,
+    resolver: &'r mut SchemaResolver,
+}
+
+impl<'a, 'r> FileAnalyzer<'a, 'r> {
+    fn new(directory: &Path, program: &'a Program<'a>, resolver: &'r mut SchemaResolver) -> Self {
+        let mut analyzer = Self {
+            declarations: HashMap::new(),
+            imports: HashMap::new(),
+            resolved: ResolvedFile::default(),
+            in_progress: HashSet::new(),
+            resolver,
+        };
+
+        for stmt in &program.body {
+            match stmt {
+                Statement::ImportDeclaration(import) => {
+                    let Some(path) = resolve_module(directory, &import.source.value) else {
+                        continue;
+                    };
+                    for specifier in import.specifiers.iter().flatten() {
+                        if let ImportDeclarationSpecifier::ImportSpecifier(spec) = specifier {
+                            analyzer.imports.insert(
+                                spec.local.name.as_str(),
+                                (spec.imported.name().to_string(), path.clone()),
+                            );
+                        }
+                    }
+                }
+                Statement::ExportNamedDeclaration(export_decl) => {
+                    if let Some(source) = &export_decl.source {
+                        let Some(path) = resolve_module(directory, &source.value) else {
+                            continue;
+                        };
+                        for specifier in &export_decl.specifiers {
+                            analyzer.resolved.re_exports.insert(
+                                specifier.exported.name().to_string(),
+                                (specifier.local.name().to_string(), path.clone()),
+                            );
+                        }
+                        continue;
+                    }
+                    match &export_decl.declaration {
+                        Some(Declaration::VariableDeclaration(var_decl)) => {
+                            for name in analyzer.add_variables(var_decl) {
+                                analyzer.resolved.exported.push(name.to_string());
+                            }
+                        }
+                        Some(Declaration::TSEnumDeclaration(enum_decl)) => {
+                            analyzer.add_enum(enum_decl);
+                            analyzer
+                                .resolved
+                                .exported
+                                .push(enum_decl.id.name.to_string());
+                        }
+                        _ => {}
+                    }
+                }
+                Statement::ExportAllDeclaration(export_all) if export_all.exported.is_none() => {
+                    if let Some(path) = resolve_module(directory, &export_all.source.value) {
+                        analyzer.resolved.star_exports.push(path);
                     }
                 }
-                Vec::new()
+                Statement::VariableDeclaration(var_decl) => {
+                    analyzer.add_variables(var_decl);
+                }
+                Statement::TSEnumDeclaration(enum_decl) => analyzer.add_enum(enum_decl),
+                _ => {}
             }
-            _ => Vec::new(),
         }
-    }
 
-    fn extract_properties_from_object_expression(
-        obj_expr: &ObjectExpression,
-        source: &str,
-    ) -> Vec<SchemaProperty> {
-        let mut properties = Vec::new();
+        analyzer
+    }
 
-        for prop in &obj_expr.properties {
-            if let ObjectPropertyKind::ObjectProperty(obj_prop) = prop {
-                if let Some(schema_prop) =
-                    Self::extract_property_from_object_property(obj_prop, source)
-                {
-                    properties.push(schema_prop);
-                }
+    fn add_variables(&mut self, var_decl: &'a VariableDeclaration<'a>) -> Vec<&'a str> {
+        let mut names = vec![];
+        for declarator in &var_decl.declarations {
+            let (BindingPatternKind::BindingIdentifier(id), Some(init)) =
+                (&declarator.id.kind, &declarator.init)
+            else {
+                continue;
+            };
+            let name = id.name.as_str();
+            if let Expression::ObjectExpression(obj) = init.get_inner_expression()
+                && let Some(values) = object_literal_values(obj)
+            {
+                self.resolved.enums.insert(name.to_string(), values);
             }
+            self.declarations.insert(name, init);
+            names.push(name);
         }
+        names
+    }
 
-        properties
+    fn add_enum(&mut self, enum_decl: &TSEnumDeclaration) {
+        self.resolved
+            .enums
+            .insert(enum_decl.id.name.to_string(), enum_values(enum_decl));
     }
 
-    fn extract_property_from_object_property(
-        obj_prop: &ObjectProperty,
-        source: &str,
-    ) -> Option<SchemaProperty> {
-        // Get property name
-        let name = match &obj_prop.key {
-            PropertyKey::StaticIdentifier(id) => id.name.as_str().to_string(),
-            PropertyKey::StringLiteral(lit) => lit.value.as_str().to_string(),
-            _ => return None,
-        };
+    fn finish(mut self) -> ResolvedFile {
+        let mut names: Vec<&'a str> = self.declarations.keys().copied().collect();
+        names.sort();
+        for name in names {
+            self.resolve_local(name);
+        }
+        self.resolved
+    }
 
-        // Analyze the property value to determine type
-        let (type_name, is_optional, is_array) =
-            Self::analyze_property_value(&obj_prop.value, source);
+    fn resolve_local(&mut self, name: &'a str) -> AnalyzedType {
+        if let Some(analyzed) = self.resolved.types.get(name) {
+            return analyzed.clone();
+        }
+        if !self.in_progress.insert(name) {
+            return AnalyzedType::new(SchemaType::Reference(name.to_string()));
+        }
+        let analyzed = self.analyze(self.declarations[name]);
+        self.in_progress.remove(name);
+        self.resolved
+            .types
+            .insert(name.to_string(), analyzed.clone());
+        analyzed
+    }
 
-        Some(SchemaProperty {
-            name,
-            type_name,
-            is_optional,
-            is_array,
-        })
+    /// Resolves an identifier to a schema declared in this file or imported
+    /// from a relative path.
+    fn resolve_identifier(&mut self, name: &'a str) -> Option<AnalyzedType> {
+        if self.declarations.contains_key(name) {
+            return Some(self.resolve_local(name));
+        }
+        let (imported, path) = self.imports.get(name)?.clone();
+        Some(
+            self.resolver
+                .lookup(&path, &imported, 0)
+                .unwrap_or_else(|| AnalyzedType::new(SchemaType::Reference(name.to_string()))),
+        )
     }
 
-    fn analyze_property_value(expr: &Expression, _source: &str) -> (String, bool, bool) {
-        match expr {
-            // Direct type references: string, number, date, boolean
+    fn is_schema_identifier(&self, name: &str) -> bool {
+        self.declarations.contains_key(name) || self.imports.contains_key(name)
+    }
+
+    fn analyze(&mut self, expr: &'a Expression<'a>) -> AnalyzedType {
+        match expr.get_inner_expression() {
+            // Direct type references: string, number, date, or another schema
             Expression::Identifier(id) => {
-                let type_name = id.name.as_str();
-                (type_name.to_string(), false, false)
+                let name = id.name.as_str();
+                self.resolve_identifier(name)
+                    .unwrap_or_else(|| AnalyzedType::new(SchemaType::Primitive(name.to_string())))
+            }
+            Expression::ObjectExpression(obj) => {
+                AnalyzedType::new(SchemaType::Object(self.analyze_object(obj)))
             }
+            Expression::CallExpression(call) => self.analyze_call(call),
+            other => match literal_source(other) {
+                Some(value) => AnalyzedType::new(SchemaType::Literal(value)),
+                None => AnalyzedType::new(SchemaType::Unknown),
+            },
+        }
+    }
 
+    fn analyze_object(&mut self, obj: &'a ObjectExpression<'a>) -> Vec<SchemaProperty> {
+        let mut properties: Vec<SchemaProperty> = vec![];
+        for prop in &obj.properties {
+            match prop {
+                ObjectPropertyKind::ObjectProperty(obj_prop) => {
+                    let name = match &obj_prop.key {
+                        PropertyKey::StaticIdentifier(id) => id.name.as_str().to_string(),
+                        PropertyKey::StringLiteral(lit) => lit.value.as_str().to_string(),
+                        _ => continue,
+                    };
+                    let property = SchemaProperty::new(name, self.analyze(&obj_prop.value));
+                    properties.retain(|existing| existing.name != property.name);
+                    properties.push(property);
+                }
+                // `...OtherSchema` copies the other schema's properties
+                ObjectPropertyKind::SpreadProperty(spread) => {
+                    let spread = self.analyze(&spread.argument);
+                    for property in spread.schema_type.properties() {
+                        properties.retain(|existing| existing.name != property.name);
+                        properties.push(property.clone());
+                    }
+                }
+            }
+        }
+        properties
+    }
+
+    fn argument(&mut self, call: &'a CallExpression<'a>, index: usize) -> AnalyzedType {
+        match call
+            .arguments
+            .get(index)
+            .and_then(|argument| argument.as_expression())
+        {
+            Some(expr) => self.analyze(expr),
+            None => AnalyzedType::new(SchemaType::Unknown),
+        }
+    }
+
+    fn analyze_call(&mut self, call: &'a CallExpression<'a>) -> AnalyzedType {
+        match &call.callee {
             // Function calls: optional(string), array(string), etc.
-            Expression::CallExpression(call_expr) => {
-                if let Expression::Identifier(callee) = &call_expr.callee {
-                    let function_name = callee.name.as_str();
-
-                    match function_name {
-                        "optional" => {
-                            // optional(string) -> (string, true, false)
-                            // optional(array(string)) -> (string, true, true)
-                            if let Some(arg) = call_expr.arguments.first() {
-                                let (inner_type, _, is_array) = match arg {
-                                    Argument::Identifier(id) => {
-                                        (id.name.as_str().to_string(), false, false)
-                                    }
-                                    Argument::CallExpression(call) => {
-                                        // optional(array(string))
-                                        if let Expression::Identifier(callee) = &call.callee {
-                                            if callee.name.as_str() == "array" {
-                                                if let Some(inner_arg) = call.arguments.first() {
-                                                    let inner_type = match inner_arg {
-                                                        Argument::Identifier(inner_id) => {
-                                                            inner_id.name.as_str().to_string()
-                                                        }
-                                                        _ => "unknown".to_string(),
-                                                    };
-                                                    return (inner_type, true, true);
-                                                }
-                                            }
-                                        }
-                                        ("unknown".to_string(), false, false)
-                                    }
-                                    _ => ("unknown".to_string(), false, false),
-                                };
-                                return (inner_type, true, is_array);
-                            }
-                            ("unknown".to_string(), true, false)
-                        }
-                        "array" => {
-                            // array(string) -> (string, false, true)
-                            if let Some(arg) = call_expr.arguments.first() {
-                                let inner_type = match arg {
-                                    Argument::Identifier(id) => id.name.as_str().to_string(),
-                                    Argument::CallExpression(call) => {
-                                        // array(optional(string))
-                                        if let Expression::Identifier(callee) = &call.callee {
-                                            if callee.name.as_str() == "optional" {
-                                                if let Some(Argument::Identifier(inner)) =
-                                                    call.arguments.first()
-                                                {
-                                                    return (
-                                                        inner.name.as_str().to_string(),
-                                                        true,
-                                                        true,
-                                                    );
-                                                }
-                                            }
-                                        }
-                                        "unknown".to_string()
-                                    }
-                                    _ => "unknown".to_string(),
-                                };
-                                return (inner_type, false, true);
-                            }
-                            ("unknown".to_string(), false, true)
-                        }
-                        "record" => {
-                            // record(string, unknown) -> Map type
-                            ("Record".to_string(), false, false)
-                        }
-                        _ => (function_name.to_string(), false, false),
+            Expression::Identifier(callee) => self.analyze_constructor(callee.name.as_str(), call),
+            Expression::StaticMemberExpression(member) => {
+                let method = member.property.name.as_str();
+                match member.object.get_inner_expression() {
+                    // Namespaced constructors: z.string(), Type.Object({ ... })
+                    Expression::Identifier(namespace)
+                        if !self.is_schema_identifier(namespace.name.as_str()) =>
+                    {
+                        let mut chars = method.chars();
+                        let name = match chars.next() {
+                            Some(first) => format!("{}{}", first.to_lowercase(), chars.as_str()),
+                            None => String::new(),
+                        };
+                        self.analyze_constructor(&name, call)
                     }
+                    // Chained methods: z.string().optional().nullable()
+                    _ => {
+                        let inner = self.analyze(&member.object);
+                        self.apply_method(inner, method, call)
+                    }
+                }
+            }
+            _ => AnalyzedType::new(SchemaType::Unknown),
+        }
+    }
+
+    /// Validator functions and the zod/typebox constructors, which share
+    /// names once lowercased.
+    fn analyze_constructor(&mut self, name: &str, call: &'a CallExpression<'a>) -> AnalyzedType {
+        match name {
+            "optional" => AnalyzedType {
+                is_optional: true,
+                ..self.argument(call, 0)
+            },
+            "nullable" => AnalyzedType {
+                is_nullable: true,
+                ..self.argument(call, 0)
+            },
+            "nullish" => AnalyzedType {
+                is_optional: true,
+                is_nullable: true,
+                ..self.argument(call, 0)
+            },
+            "array" => AnalyzedType::new(SchemaType::Array(Box::new(
+                self.argument(call, 0).schema_type,
+            ))),
+            "record" => {
+                let (key, value) = if call.arguments.len() >= 2 {
+                    (
+                        self.argument(call, 0).schema_type,
+                        self.argument(call, 1).schema_type,
+                    )
                 } else {
-                    ("unknown".to_string(), false, false)
+                    (
+                        SchemaType::Primitive("string".to_string()),
+                        self.argument(call, 0).schema_type,
+                    )
+                };
+                AnalyzedType::new(SchemaType::Record(Box::new(key), Box::new(value)))
+            }
+            "object" | "strictObject" | "looseObject" => match call.arguments.first() {
+                Some(_) => self.argument(call, 0),
+                None => AnalyzedType::new(SchemaType::Object(vec![])),
+            },
+            "union" => {
+                let members: Vec<&'a Expression<'a>> = match call
+                    .arguments
+                    .first()
+                    .and_then(|argument| argument.as_expression())
+                    .map(Expression::get_inner_expression)
+                {
+                    // union([a, b]) as in zod and typebox
+                    Some(Expression::ArrayExpression(array)) => array
+                        .elements
+                        .iter()
+                        .filter_map(|element| element.as_expression())
+                        .collect(),
+                    // union(a, b)
+                    _ => call
+                        .arguments
+                        .iter()
+                        .filter_map(|argument| argument.as_expression())
+                        .collect(),
+                };
+                let members = members.into_iter().map(|m| self.analyze(m)).collect();
+                normalize_union(members)
+            }
+            "literal" => AnalyzedType::new(
+                call.arguments
+                    .first()
+                    .and_then(|argument| argument.as_expression())
+                    .and_then(literal_source)
+                    .map_or(SchemaType::Unknown, SchemaType::Literal),
+            ),
+            "enum" | "enum_" | "nativeEnum" => {
+                let values = call
+                    .arguments
+                    .first()
+                    .and_then(|argument| argument.as_expression())
+                    .and_then(|expr| self.enum_values_of(expr));
+                AnalyzedType::new(values.map_or(SchemaType::Unknown, SchemaType::Enum))
+            }
+            "null" => AnalyzedType {
+                is_nullable: true,
+                ..AnalyzedType::new(SchemaType::Primitive("null".to_string()))
+            },
+            _ => AnalyzedType::new(SchemaType::Primitive(name.to_string())),
+        }
+    }
+
+    fn apply_method(
+        &mut self,
+        inner: AnalyzedType,
+        method: &str,
+        call: &'a CallExpression<'a>,
+    ) -> AnalyzedType {
+        match method {
+            "optional" | "default" | "catch" => AnalyzedType {
+                is_optional: true,
+                ..inner
+            },
+            "nullable" => AnalyzedType {
+                is_nullable: true,
+                ..inner
+            },
+            "nullish" => AnalyzedType {
+                is_optional: true,
+                is_nullable: true,
+                ..inner
+            },
+            "array" => AnalyzedType::new(SchemaType::Array(Box::new(inner.schema_type))),
+            "or" => {
+                let other = self.argument(call, 0);
+                normalize_union(vec![inner, other])
+            }
+            "extend" | "merge" => {
+                let other = self.argument(call, 0);
+                let mut properties = inner.schema_type.properties().to_vec();
+                for property in other.schema_type.properties() {
+                    properties.retain(|existing| existing.name != property.name);
+                    properties.push(property.clone());
+                }
+                AnalyzedType {
+                    schema_type: SchemaType::Object(properties),
+                    ..inner
                 }
             }
+            // Refinements such as .min(1), .email() or .describe() keep the
+            // type
+            _ => inner,
+        }
+    }
 
-            _ => ("unknown".to_string(), false, false),
+    fn enum_values_of(&mut self, expr: &'a Expression<'a>) -> Option<Vec<String>> {
+        match expr.get_inner_expression() {
+            Expression::ArrayExpression(array) => array
+                .elements
+                .iter()
+                .map(|element| element.as_expression().and_then(literal_source))
+                .collect(),
+            Expression::ObjectExpression(obj) => object_literal_values(obj),
+            Expression::Identifier(id) => {
+                let name = id.name.as_str();
+                if let Some(values) = self.resolved.enums.get(name) {
+                    return Some(values.clone());
+                }
+                let (imported, path) = self.imports.get(name)?.clone();
+                self.resolver.lookup_enum(&path, &imported, 0)
+            }
+            _ => None,
         }
     }
 }
@@ -362,4 +828,151 @@ export const TestSchema = {
         assert!(categories_prop.is_optional);
         assert!(categories_prop.is_array);
     }
+
+    #[test]
+    fn test_parse_nested_unions_enums_and_imported_schemas() {
+        let dir = tempdir().unwrap();
+        write(
+            dir.path().join("address.schema.ts"),
+            r#"
+import { string, optional } from '@forklaunch/validator';
+
+export enum Country {
+  Netherlands = 'NL',
+  Germany = 'DE'
+}
+
+export const AddressSchema = {
+  street: string,
+  zip: optional(string)
+};
+"#,
+        )
+        .unwrap();
+        write(
+            dir.path().join("index.ts"),
+            "export * from './address.schema';\n",
+        )
+        .unwrap();
+        let schema_path = dir.path().join("customer.schema.ts");
+        write(
+            &schema_path,
+            r#"
+import { string, number, union, literal, enum_, array, nullable } from '@forklaunch/validator';
+import { AddressSchema, Country } from '.';
+
+const AuditSchema = {
+  createdBy: string
+};
+
+export const CustomerSchema = {
+  ...AuditSchema,
+  name: string,
+  tier: union([literal('free'), literal('pro')]),
+  country: enum_(Country),
+  address: AddressSchema,
+  previousAddresses: array(AddressSchema),
+  contact: {
+    phone: nullable(string),
+    score: union(number, null)
+  }
+};
+"#,
+        )
+        .unwrap();
+
+        let schemas = SchemaAnalyzer::parse_schema_file(&schema_path).unwrap();
+        assert_eq!(schemas.len(), 1);
+        let properties = &schemas[0].properties;
+        let property = |name: &str| properties.iter().find(|p| p.name == name).unwrap();
+
+        assert_eq!(properties[0].name, "createdBy");
+        assert_eq!(property("tier").type_name, "'free' | 'pro'");
+        assert_eq!(
+            property("country").schema_type,
+            SchemaType::Enum(vec!["'NL'".to_string(), "'DE'".to_string()])
+        );
+
+        let address = property("address").schema_type.properties();
+        assert_eq!(address.len(), 2);
+        assert!(address[1].is_optional);
+
+        let previous = property("previousAddresses");
+        assert!(previous.is_array);
+        assert_eq!(previous.type_name, "object");
+
+        let contact = property("contact").schema_type.properties();
+        assert!(contact[0].is_nullable);
+        assert_eq!(contact[1].type_name, "number");
+        assert!(contact[1].is_nullable);
+    }
+
+    #[test]
+    fn test_parse_zod_and_typebox_schemas() {
+        let dir = tempdir().unwrap();
+        let schema_path = dir.path().join("order.schema.ts");
+        write(
+            &schema_path,
+            r#"
+import { z } from 'zod';
+import { Type } from '@sinclair/typebox';
+
+const Status = { Open: 'open', Closed: 'closed' } as const;
+
+export const ZodOrderSchema = z.object({
+  id: z.string().uuid(),
+  note: z.string().optional().nullable(),
+  status: z.nativeEnum(Status),
+  items: z.array(z.object({ sku: z.string(), quantity: z.number().int().min(1) }))
+});
+
+export const ZodOrderUpdateSchema = ZodOrderSchema.extend({
+  reason: z.string().nullish()
+});
+
+export const TypeboxOrderSchema = Type.Object({
+  id: Type.String(),
+  total: Type.Optional(Type.Number()),
+  kind: Type.Union([Type.Literal('retail'), Type.Literal('wholesale'), Type.Null()])
+});
+"#,
+        )
+        .unwrap();
+
+        let schemas = SchemaAnalyzer::parse_schema_file(&schema_path).unwrap();
+        let schema = |name: &str| schemas.iter().find(|s| s.name == name).unwrap();
+        let property = |schema_name: &str, name: &str| {
+            schema(schema_name)
+                .properties
+                .iter()
+                .find(|p| p.name == name)
+                .unwrap()
+                .clone()
+        };
+
+        assert_eq!(property("ZodOrderSchema", "id").type_name, "string");
+        let note = property("ZodOrderSchema", "note");
+        assert!(note.is_optional && note.is_nullable);
+        assert_eq!(
+            property("ZodOrderSchema", "status").type_name,
+            "'open' | 'closed'"
+        );
+        let items = property("ZodOrderSchema", "items");
+        assert!(items.is_array);
+        assert_eq!(
+            match items.schema_type {
+                SchemaType::Array(inner) => inner.properties().len(),
+                _ => 0,
+            },
+            2
+        );
+
+        assert_eq!(schema("ZodOrderUpdateSchema").properties.len(), 5);
+        assert!(property("ZodOrderUpdateSchema", "reason").is_optional);
+
+        assert!(property("TypeboxOrderSchema", "total").is_optional);
+        let kind = property("TypeboxOrderSchema", "kind");
+        assert_eq!(kind.type_name, "'retail' | 'wholesale'");
+        assert!(kind.is_nullable);
+    }
 }
@@ -0,0 +1,278 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use super::entity_analyzer::{EntityDefinition, EntityProperty, RelationType};
+
+/// Properties provided by `SqlBaseEntity`/`NoSqlBaseEntity`.
+pub const BASE_ENTITY_PROPERTIES: [&str; 4] = ["id", "_id", "createdAt", "updatedAt"];
+
+/// Worker bookkeeping that is set by the worker, not by the request.
+const WORKER_PROPERTIES: [&str; 2] = ["processed", "retryCount"];
+
+/// `roles` -> `role`, `categories` -> `category`.
+pub fn singular(name: &str) -> String {
+    if let Some(stem) = name.strip_suffix("ies") {
+        format!("{}y", stem)
+    } else if let Some(stem) = name.strip_suffix('s') {
+        stem.to_string()
+    } else {
+        name.to_string()
+    }
+}
+
+/// `role` -> `roles`, `category` -> `categories`.
+pub fn plural(name: &str) -> String {
+    if let Some(stem) = name.strip_suffix('y')
+        && !stem.ends_with(['a', 'e', 'i', 'o', 'u'])
+    {
+        format!("{}ies", stem)
+    } else if name.ends_with('s') {
+        format!("{}es", name)
+    } else {
+        format!("{}s", name)
+    }
+}
+
+/// `UserRecord` -> `User`, `PaymentEventRecord` -> `Payment`.
+pub fn entity_base_name(entity_name: &str) -> &str {
+    entity_name
+        .strip_suffix("EventRecord")
+        .or_else(|| entity_name.strip_suffix("Record"))
+        .unwrap_or(entity_name)
+}
+
+/// Generates idiomatic validator request and response schemas from an entity.
+/// The validator functions are imported from the application's core library,
+/// which re-exports them for the validator chosen in the manifest.
+pub struct SchemaGenerator {
+    entity: EntityDefinition,
+    app_name: String,
+    is_mongo: bool,
+    /// Imports for non-primitive property types (e.g. enums), by type name
+    type_imports: BTreeMap<String, String>,
+}
+
+impl SchemaGenerator {
+    pub fn new(
+        entity: EntityDefinition,
+        app_name: String,
+        is_mongo: bool,
+        type_imports: BTreeMap<String, String>,
+    ) -> Self {
+        Self {
+            entity,
+            app_name,
+            is_mongo,
+            type_imports,
+        }
+    }
+
+    fn id_validator(&self) -> &'static str {
+        if self.is_mongo { "string" } else { "uuid" }
+    }
+
+    fn is_worker(&self) -> bool {
+        self.entity.name.ends_with("EventRecord")
+    }
+
+    fn scalar_validator(&self, property: &EntityProperty) -> String {
+        match property.type_name.as_str() {
+            "string" => "string".to_string(),
+            "number" => "number".to_string(),
+            "boolean" => "boolean".to_string(),
+            "bigint" => "bigint".to_string(),
+            "Date" => "date".to_string(),
+            other if self.type_imports.contains_key(other) => format!("enum_({})", other),
+            _ => "unknown".to_string(),
+        }
+    }
+
+    fn wrap(validator: String, property: &EntityProperty) -> String {
+        if property.is_nullable {
+            format!("optional({})", validator)
+        } else {
+            validator
+        }
+    }
+
+    /// Request fields: writable columns, with relations as IDs
+    /// (`organizationId`, `roleIds`) as `MapperGenerator` expects them.
+    fn request_fields(&self) -> Vec<(String, String)> {
+        let mut fields = vec![];
+        for property in &self.entity.properties {
+            if BASE_ENTITY_PROPERTIES.contains(&property.name.as_str())
+                || (self.is_worker() && WORKER_PROPERTIES.contains(&property.name.as_str()))
+            {
+                continue;
+            }
+            let id = self.id_validator().to_string();
+            match (&property.relation_type, property.is_inverse_side) {
+                (None, _) => fields.push((
+                    property.name.clone(),
+                    Self::wrap(self.scalar_validator(property), property),
+                )),
+                (Some(RelationType::OneToMany), _) | (Some(_), true) => {}
+                (Some(RelationType::ManyToMany), false) => fields.push((
+                    format!("{}Ids", singular(&property.name)),
+                    Self::wrap(format!("array({})", id), property),
+                )),
+                (Some(_), false) => {
+                    fields.push((format!("{}Id", property.name), Self::wrap(id, property)))
+                }
+            }
+        }
+        fields
+    }
+
+    /// Response fields: what `entity.read()` serializes, with owned
+    /// relations as their primary keys.
+    fn response_fields(&self) -> Vec<(String, String)> {
+        let id = self.id_validator().to_string();
+        let mut fields = vec![("id".to_string(), id.clone())];
+        for property in &self.entity.properties {
+            if BASE_ENTITY_PROPERTIES.contains(&property.name.as_str()) {
+                continue;
+            }
+            match (&property.relation_type, property.is_inverse_side) {
+                (None, _) => fields.push((
+                    property.name.clone(),
+                    Self::wrap(self.scalar_validator(property), property),
+                )),
+                (Some(RelationType::OneToMany), _) | (Some(_), true) => {}
+                (Some(RelationType::ManyToMany), false) => {
+                    fields.push((property.name.clone(), format!("array({})", id)))
+                }
+                (Some(_), false) => {
+                    fields.push((property.name.clone(), Self::wrap(id.clone(), property)))
+                }
+            }
+        }
+        if self.entity.extends.is_some() {
+            fields.push(("createdAt".to_string(), "date".to_string()));
+            fields.push(("updatedAt".to_string(), "date".to_string()));
+        }
+        fields
+    }
+
+    fn render_schema(name: &str, fields: &[(String, String)]) -> String {
+        format!(
+            "export const {} = {{\n{}\n}};",
+            name,
+            fields
+                .iter()
+                .map(|(field, validator)| format!("  {}: {}", field, validator))
+                .collect::<Vec<_>>()
+                .join(",\n")
+        )
+    }
+
+    pub fn generate_schema_file(&self) -> String {
+        let pascal_case_name = entity_base_name(&self.entity.name);
+        let request_fields = self.request_fields();
+        let response_fields = self.response_fields();
+
+        // Validator functions are the leading identifiers of each expression
+        let mut validators = BTreeSet::new();
+        let mut used_types = BTreeSet::new();
+        for (_, validator) in request_fields.iter().chain(response_fields.iter()) {
+            for token in validator.split(|c: char| !c.is_alphanumeric() && c != '_') {
+                if self.type_imports.contains_key(token) {
+                    used_types.insert(token);
+                } else if !token.is_empty() {
+                    validators.insert(token);
+                }
+            }
+        }
+
+        let mut imports = vec![format!(
+            "import {{ {} }} from '@{}/core';",
+            validators.into_iter().collect::<Vec<_>>().join(", "),
+            self.app_name
+        )];
+        for type_name in used_types {
+            imports.push(format!(
+                "import {{ {} }} from '{}';",
+                type_name, self.type_imports[type_name]
+            ));
+        }
+
+        format!(
+            "{}\n\n// idiomatic validator schema defines the request schema. This should extend the request type\n{}\n\n// idiomatic validator schema defines the response schema. This should extend the response type\n{}\n",
+            imports.join("\n"),
+            Self::render_schema(
+                &format!("{}RequestSchema", pascal_case_name),
+                &request_fields
+            ),
+            Self::render_schema(
+                &format!("{}ResponseSchema", pascal_case_name),
+                &response_fields
+            )
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn property(name: &str, type_name: &str) -> EntityProperty {
+        EntityProperty {
+            name: name.to_string(),
+            type_name: type_name.to_string(),
+            is_nullable: false,
+            is_collection: false,
+            relation_type: None,
+            is_unique: false,
+            is_inverse_side: false,
+        }
+    }
+
+    #[test]
+    fn test_generate_schema_from_entity() {
+        let entity = EntityDefinition {
+            name: "UserRecord".to_string(),
+            extends: Some("SqlBaseEntity".to_string()),
+            properties: vec![
+                property("email", "string"),
+                EntityProperty {
+                    is_nullable: true,
+                    ..property("age", "number")
+                },
+                property("status", "UserStatus"),
+                EntityProperty {
+                    is_nullable: true,
+                    relation_type: Some(RelationType::ManyToOne),
+                    ..property("organization", "Organization")
+                },
+                EntityProperty {
+                    is_collection: true,
+                    relation_type: Some(RelationType::ManyToMany),
+                    ..property("roles", "Collection<Role>")
+                },
+                EntityProperty {
+                    is_collection: true,
+                    relation_type: Some(RelationType::OneToMany),
+                    is_inverse_side: true,
+                    ..property("sessions", "Collection<Session>")
+                },
+            ],
+        };
+        let type_imports = BTreeMap::from([(
+            "UserStatus".to_string(),
+            "../enum/userStatus.enum".to_string(),
+        )]);
+
+        let generator = SchemaGenerator::new(entity, "my-app".to_string(), false, type_imports);
+        let result = generator.generate_schema_file();
+
+        assert!(result.starts_with(
+            "import { array, date, enum_, number, optional, string, uuid } from '@my-app/core';\n\
+             import { UserStatus } from '../enum/userStatus.enum';\n"
+        ));
+        assert!(result.contains(
+            "export const UserRequestSchema = {\n  email: string,\n  age: optional(number),\n  status: enum_(UserStatus),\n  organizationId: optional(uuid),\n  roleIds: array(uuid)\n};"
+        ));
+        assert!(result.contains(
+            "export const UserResponseSchema = {\n  id: uuid,\n  email: string,\n  age: optional(number),\n  status: enum_(UserStatus),\n  organization: optional(uuid),\n  roles: array(uuid),\n  createdAt: date,\n  updatedAt: date\n};"
+        ));
+    }
+}
@@ -31,7 +31,9 @@ use crate::{
         },
         pnpm_workspace::PnpmWorkspace,
         rendered_template::{RenderedTemplate, RenderedTemplatesCache},
-        sync::detection::detect_routers_from_service,
+        sync::detection::{
+            detect_routers_from_service, detect_service_config, detect_worker_config,
+        },
         tsconfig::add_project_to_modules_tsconfig,
     },
 };
@@ -216,6 +218,109 @@ fn detect_routers_for_project(
     Ok(routers)
 }
 
+/// Re-detect routers, resources and worker type for a project that is
+/// already in the manifest and update its entry in place.
+///
+/// Values that can no longer be detected are kept as they are. Returns a
+/// description of each field that changed.
+pub fn refresh_project_in_manifest(
+    manifest_data: &mut ApplicationManifestData,
+    project_name: &str,
+    modules_path: &Path,
+) -> Result<Vec<String>> {
+    let project_path = modules_path.join(project_name);
+    let Some(project) = manifest_data
+        .projects
+        .iter_mut()
+        .find(|p| p.name == project_name)
+    else {
+        return Ok(vec![]);
+    };
+
+    let detected = match project.r#type {
+        ProjectType::Service => detect_service_config(&project_path)?,
+        ProjectType::Worker => detect_worker_config(&project_path)?,
+        ProjectType::Library => return Ok(vec![]),
+    };
+
+    let mut changes = vec![];
+
+    let routers = detect_routers_from_service(&project_path)?;
+    let previous_routers = project.routers.clone().unwrap_or_default();
+    for router in routers.iter().filter(|r| !previous_routers.contains(r)) {
+        changes.push(format!("router added: {}", router));
+    }
+    for router in previous_routers.iter().filter(|r| !routers.contains(r)) {
+        changes.push(format!("router removed: {}", router));
+    }
+    project.routers = if routers.is_empty() {
+        None
+    } else {
+        Some(routers)
+    };
+
+    let metadata = ProjectSyncMetadata {
+        project_type: project.r#type.clone(),
+        project_name: project_name.to_string(),
+        description: project.description.clone(),
+        database: detected.database,
+        infrastructure: detected.infrastructure,
+        worker_type: detected.worker_type,
+    };
+
+    let previous = project.resources.clone().unwrap_or_default();
+    let mut resources = metadata.to_resource_inventory().unwrap_or_default();
+    if resources.database.is_none() {
+        resources.database = previous.database.clone();
+    }
+    if resources.queue.is_none() && metadata.worker_type.is_none() {
+        resources.queue = previous.queue.clone();
+    }
+
+    for (label, before, after) in [
+        ("database", &previous.database, &resources.database),
+        ("cache", &previous.cache, &resources.cache),
+        ("queue", &previous.queue, &resources.queue),
+        ("object store", &previous.object_store, &resources.object_store),
+    ] {
+        if before != after {
+            changes.push(format!(
+                "{}: {} -> {}",
+                label,
+                before.as_deref().unwrap_or("none"),
+                after.as_deref().unwrap_or("none")
+            ));
+        }
+    }
+
+    project.resources = if resources.database.is_some()
+        || resources.cache.is_some()
+        || resources.queue.is_some()
+        || resources.object_store.is_some()
+    {
+        Some(resources)
+    } else {
+        None
+    };
+
+    if let Some(project_metadata) = metadata.to_project_metadata() {
+        let previous_type = project
+            .metadata
+            .as_ref()
+            .and_then(|m| m.r#type.clone());
+        if previous_type != project_metadata.r#type {
+            changes.push(format!(
+                "worker type: {} -> {}",
+                previous_type.as_deref().unwrap_or("none"),
+                project_metadata.r#type.as_deref().unwrap_or("none")
+            ));
+            project.metadata = Some(project_metadata);
+        }
+    }
+
+    Ok(changes)
+}
+
 /// Sync to docker-compose.yaml - delegated to existing functions
 ///
 /// Docker sync is handled separately because it requires complex ServiceManifestData/WorkerManifestData
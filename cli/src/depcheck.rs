@@ -12,12 +12,14 @@ use termcolor::{Color, ColorChoice, ColorSpec, StandardStream, WriteColor};
 
 use crate::{
     CliCommand,
-    core::command::command,
+    core::{command::command, lockfile::Lockfile},
 };
 
 struct ProjectDependencyVersion {
     project_name: String,
+    /// Resolved version from the lockfile, or the specifier without one
     version: String,
+    specifier: String,
 }
 
 pub(crate) struct DepcheckCommand;
@@ -49,6 +51,7 @@ impl CliCommand for DepcheckCommand {
 
         // Upfront validation
         let (app_root_path, manifest_data) = crate::core::validate::require_manifest(matches)?;
+        let lockfile = Lockfile::find(&app_root_path, &app_root_path);
 
         manifest_data.project_peer_topology.iter().try_for_each(
             |(group_name, group_projects)| -> Result<()> {
@@ -59,8 +62,9 @@ impl CliCommand for DepcheckCommand {
                 group_projects
                     .iter()
                     .try_for_each(|project| -> Result<()> {
+                        let project_path = Path::new(&app_root_path).join(manifest_data.modules_path.clone()).join(project);
                         if let Some(package_json_contents) = &read_to_string(
-                            Path::new(&app_root_path).join(manifest_data.modules_path.clone()).join(project).join("package.json"),
+                            project_path.join("package.json"),
                         )
                         .with_context(|| format!("Failed to read package.json for {}", project))
                         .ok()
@@ -82,11 +86,16 @@ impl CliCommand for DepcheckCommand {
                                     .unwrap()
                                     .iter()
                                     .chain(dev_dependencies.as_object().unwrap().iter())
-                                    .for_each(|(package_name, version)| {
+                                    .for_each(|(package_name, specifier)| {
+                                        let specifier = specifier.as_str().unwrap_or_default();
+                                        let version = lockfile
+                                            .as_ref()
+                                            .and_then(|lockfile| lockfile.version(&project_path, package_name))
+                                            .unwrap_or(specifier);
                                         if let Some(existing_version) =
                                             package_version_inventory.get(package_name)
                                         {
-                                            if existing_version[0].version != version.to_string() {
+                                            if existing_version[0].version != version {
                                                 conflicting_packages
                                                     .insert(package_name.to_string());
                                             }
@@ -97,6 +106,7 @@ impl CliCommand for DepcheckCommand {
                                             dependency_versions.push(ProjectDependencyVersion {
                                                 project_name: project.to_string(),
                                                 version: version.to_string(),
+                                                specifier: specifier.to_string(),
                                             });
                                         } else {
                                             package_version_inventory.insert(
@@ -104,6 +114,7 @@ impl CliCommand for DepcheckCommand {
                                                 vec![ProjectDependencyVersion {
                                                     project_name: project.to_string(),
                                                     version: version.to_string(),
+                                                    specifier: specifier.to_string(),
                                                 }],
                                             );
                                         }
@@ -139,11 +150,22 @@ impl CliCommand for DepcheckCommand {
 
                                 serialized_conflict.extend(project_dependency_versions.iter().map(
                                     |project_dependency_version| {
-                                        format!(
-                                            "  {}: {}",
-                                            project_dependency_version.project_name,
-                                            project_dependency_version.version
-                                        )
+                                        if project_dependency_version.version
+                                            == project_dependency_version.specifier
+                                        {
+                                            format!(
+                                                "  {}: {}",
+                                                project_dependency_version.project_name,
+                                                project_dependency_version.version
+                                            )
+                                        } else {
+                                            format!(
+                                                "  {}: {} ({})",
+                                                project_dependency_version.project_name,
+                                                project_dependency_version.version,
+                                                project_dependency_version.specifier
+                                            )
+                                        }
                                     },
                                 ));
 
@@ -1,4 +1,4 @@
-use std::io::Write;
+use std::{io::Write, time::Duration};
 
 use anyhow::{Context, Result, bail};
 use base64::{Engine as _, engine::general_purpose};
@@ -12,6 +12,7 @@ use crate::{
     CliCommand,
     constants::{ERROR_FAILED_TO_SEND_REQUEST, get_platform_management_api_url, get_platform_ui_url},
     core::command::command,
+    deploy::utils::DEFAULT_DEPLOYMENT_TIMEOUT_SECS,
 };
 
 #[derive(Debug, Serialize)]
@@ -187,6 +188,13 @@ impl CliCommand for CreateCommand {
                     .action(clap::ArgAction::SetTrue)
                     .help("Don't wait for deployment to complete"),
             )
+            .arg(
+                Arg::new("timeout")
+                    .long("timeout")
+                    .value_parser(clap::value_parser!(u64))
+                    .default_value(DEFAULT_DEPLOYMENT_TIMEOUT_SECS)
+                    .help("Maximum number of seconds to wait for completion (exit code 124 on timeout)"),
+            )
     }
 
     fn handler(&self, matches: &ArgMatches) -> Result<()> {
@@ -211,6 +219,7 @@ impl CliCommand for CreateCommand {
             .ok_or_else(|| anyhow::anyhow!("Region is required"))?;
 
         let wait = !matches.get_flag("no-wait");
+        let timeout = Duration::from_secs(*matches.get_one::<u64>("timeout").unwrap());
 
         stdout.set_color(ColorSpec::new().set_fg(Some(Color::Cyan)).set_bold(true))?;
         writeln!(
@@ -272,11 +281,20 @@ impl CliCommand for CreateCommand {
 
                 if wait {
                     writeln!(stdout)?;
-                    crate::deploy::utils::stream_deployment_status(
+                    let outcome = crate::deploy::utils::stream_deployment_status(
                         &auth_mode,
                         &deployment.id,
+                        timeout,
                         &mut stdout,
                     )?;
+                    if let Some(exit_code) = outcome.exit_code() {
+                        writeln!(
+                            stdout,
+                            "[INFO] Check status at: {}/apps/{}/deployments/{}",
+                            get_platform_ui_url(), application_id, deployment.id
+                        )?;
+                        std::process::exit(exit_code);
+                    }
                 } else {
                     writeln!(stdout)?;
                     stdout.set_color(ColorSpec::new().set_fg(Some(Color::Cyan)))?;
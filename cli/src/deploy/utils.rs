@@ -1,11 +1,31 @@
-use std::{io::Write, thread::sleep, time::Duration};
+use std::{
+    collections::HashMap,
+    io::{BufRead, BufReader, Write},
+    thread::sleep,
+    time::{Duration, Instant},
+};
 
 use anyhow::{Context, Result, bail};
+use rand::Rng;
 use serde::Deserialize;
 use termcolor::{Color, ColorSpec, StandardStream, WriteColor};
 
 use crate::core::hmac::AuthMode;
 
+/// Default upper bound (in seconds) on how long `deploy create`/`deploy destroy` wait for completion.
+pub(crate) const DEFAULT_DEPLOYMENT_TIMEOUT_SECS: &str = "3600";
+
+/// Exit code used when the platform reports the deployment as failed.
+pub(crate) const EXIT_CODE_DEPLOYMENT_FAILED: i32 = 1;
+
+/// Exit code used when the deployment did not finish within the configured timeout.
+/// Matches the convention of coreutils `timeout`.
+pub(crate) const EXIT_CODE_DEPLOYMENT_TIMED_OUT: i32 = 124;
+
+const POLL_INTERVAL: Duration = Duration::from_secs(3);
+const BACKOFF_BASE: Duration = Duration::from_secs(2);
+const BACKOFF_MAX: Duration = Duration::from_secs(60);
+
 #[derive(Debug, Deserialize)]
 pub(crate) struct DeploymentStatus {
     #[allow(dead_code)]
@@ -17,6 +37,8 @@ pub(crate) struct DeploymentStatus {
     pub(crate) completed_at: Option<String>,
     pub(crate) endpoints: Option<DeploymentEndpoints>,
     pub(crate) error: Option<String>,
+    #[serde(default)]
+    pub(crate) services: Vec<ServiceProgress>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -25,14 +47,78 @@ pub(crate) struct DeploymentEndpoints {
     pub(crate) docs: Option<String>,
 }
 
+/// Per-service (or per-worker) progress reported alongside the overall deployment status
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub(crate) struct ServiceProgress {
+    pub(crate) name: String,
+    pub(crate) status: String,
+    pub(crate) phase: Option<String>,
+    pub(crate) progress: Option<u8>,
+    pub(crate) error: Option<String>,
+}
+
+/// How a deployment status stream ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DeploymentOutcome {
+    Completed,
+    Failed,
+    TimedOut,
+}
+
+impl DeploymentOutcome {
+    /// Process exit code for non-successful outcomes
+    pub(crate) fn exit_code(&self) -> Option<i32> {
+        match self {
+            DeploymentOutcome::Completed => None,
+            DeploymentOutcome::Failed => Some(EXIT_CODE_DEPLOYMENT_FAILED),
+            DeploymentOutcome::TimedOut => Some(EXIT_CODE_DEPLOYMENT_TIMED_OUT),
+        }
+    }
+}
+
+/// Result of trying to follow the streaming endpoint
+enum StreamResult {
+    Finished(DeploymentOutcome),
+    Unsupported,
+    Interrupted(String),
+}
+
+/// Follow a deployment until it completes, fails or `timeout` elapses.
+///
+/// Prefers the streaming endpoint (server-sent events or NDJSON) and falls back to polling
+/// with exponential backoff and jitter when streaming is unavailable or the stream drops.
 pub(crate) fn stream_deployment_status(
     auth_mode: &AuthMode,
     deployment_id: &str,
+    timeout: Duration,
     stdout: &mut StandardStream,
-) -> Result<()> {
-    use crate::core::http_client;
+) -> Result<DeploymentOutcome> {
+    let status_url = deployment_status_url(auth_mode, deployment_id);
+    let deadline = Instant::now() + timeout;
+    let mut tracker = StatusTracker::new();
 
-    let url = if auth_mode.is_hmac() {
+    match follow_event_stream(auth_mode, &status_url, deadline, &mut tracker, stdout)? {
+        StreamResult::Finished(outcome) => return finish(outcome, timeout, stdout),
+        StreamResult::Unsupported => {
+            log::debug!("Deployment event stream not available, polling instead");
+        }
+        StreamResult::Interrupted(reason) => {
+            stdout.set_color(ColorSpec::new().set_fg(Some(Color::Yellow)))?;
+            writeln!(
+                stdout,
+                "[WARN] Status stream interrupted ({}), falling back to polling",
+                reason
+            )?;
+            stdout.reset()?;
+        }
+    }
+
+    let outcome = poll_status(auth_mode, &status_url, deadline, &mut tracker, stdout)?;
+    finish(outcome, timeout, stdout)
+}
+
+fn deployment_status_url(auth_mode: &AuthMode, deployment_id: &str) -> String {
+    if auth_mode.is_hmac() {
         format!(
             "{}/internal/deployments/{}",
             crate::constants::get_platform_management_api_url(),
@@ -44,89 +130,514 @@ pub(crate) fn stream_deployment_status(
             crate::constants::get_platform_management_api_url(),
             deployment_id
         )
+    }
+}
+
+fn follow_event_stream(
+    auth_mode: &AuthMode,
+    status_url: &str,
+    deadline: Instant,
+    tracker: &mut StatusTracker,
+    stdout: &mut StandardStream,
+) -> Result<StreamResult> {
+    use crate::core::http_client;
+
+    let remaining = deadline.saturating_duration_since(Instant::now());
+    if remaining.is_zero() {
+        return Ok(StreamResult::Finished(DeploymentOutcome::TimedOut));
+    }
+
+    let response = match http_client::get_stream_with_auth(
+        auth_mode,
+        &format!("{}/events", status_url),
+        remaining,
+    ) {
+        Ok(response) => response,
+        Err(error) => return Ok(StreamResult::Interrupted(error.to_string())),
+    };
+
+    if !response.status().is_success() {
+        return Ok(StreamResult::Unsupported);
+    }
+
+    let content_type = response
+        .headers()
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or_default()
+        .to_ascii_lowercase();
+
+    let format = if content_type.starts_with("text/event-stream") {
+        StreamFormat::ServerSentEvents
+    } else if content_type.starts_with("application/x-ndjson")
+        || content_type.starts_with("application/jsonl")
+    {
+        StreamFormat::NewlineDelimitedJson
+    } else {
+        return Ok(StreamResult::Unsupported);
     };
-    let mut last_phase: Option<String> = None;
+
+    let mut outcome = None;
+    let read_result = read_stream_events(BufReader::new(response), format, |payload| {
+        let status: DeploymentStatus = match serde_json::from_str(payload) {
+            Ok(status) => status,
+            Err(error) => {
+                log::debug!("Ignoring unparseable deployment event {}: {}", payload, error);
+                return Ok(true);
+            }
+        };
+        outcome = tracker.render(&status, stdout)?;
+        Ok(outcome.is_none())
+    });
+
+    if let Some(outcome) = outcome {
+        return Ok(StreamResult::Finished(outcome));
+    }
+
+    if Instant::now() >= deadline {
+        return Ok(StreamResult::Finished(DeploymentOutcome::TimedOut));
+    }
+
+    Ok(StreamResult::Interrupted(match read_result {
+        Ok(()) => "stream closed".to_string(),
+        Err(error) => error.to_string(),
+    }))
+}
+
+fn poll_status(
+    auth_mode: &AuthMode,
+    status_url: &str,
+    deadline: Instant,
+    tracker: &mut StatusTracker,
+    stdout: &mut StandardStream,
+) -> Result<DeploymentOutcome> {
+    use crate::core::http_client;
+
+    let mut backoff = Backoff::new(BACKOFF_BASE, BACKOFF_MAX);
 
     loop {
-        // Polling deployment status
-        let response = http_client::get_with_auth(auth_mode, &url)?;
-
-        if !response.status().is_success() {
-            let response_text = response
-                .text()
-                .with_context(|| "Failed to read status response")?;
-            bail!("Failed to get deployment status: {}", response_text);
+        if Instant::now() >= deadline {
+            return Ok(DeploymentOutcome::TimedOut);
         }
 
-        let response_text = response
-            .text()
-            .with_context(|| "Failed to read status response")?;
+        let delay = match http_client::get_with_auth(auth_mode, status_url) {
+            Ok(response) if response.status().is_success() => {
+                let response_text = response
+                    .text()
+                    .with_context(|| "Failed to read status response")?;
+
+                let status: DeploymentStatus = serde_json::from_str(&response_text)
+                    .with_context(|| {
+                        format!("Failed to parse deployment status: {}", response_text)
+                    })?;
 
-        let status: DeploymentStatus = serde_json::from_str(&response_text)
-            .with_context(|| format!("Failed to parse deployment status: {}", response_text))?;
+                if let Some(outcome) = tracker.render(&status, stdout)? {
+                    return Ok(outcome);
+                }
 
-        if let Some(phase) = &status.phase {
-            if last_phase.as_ref() != Some(phase) {
-                display_phase_update(phase, stdout)?;
-                last_phase = Some(phase.clone());
+                backoff.reset();
+                POLL_INTERVAL
+            }
+            Ok(response) if is_transient_status(response.status().as_u16()) => {
+                let delay = backoff.next_delay();
+                warn_retry(&response.status().to_string(), delay, stdout)?;
+                delay
+            }
+            Ok(response) => {
+                let status = response.status();
+                let response_text = response
+                    .text()
+                    .with_context(|| "Failed to read status response")?;
+                bail!(
+                    "Failed to get deployment status: {} (Status: {})",
+                    response_text,
+                    status
+                );
+            }
+            Err(error) if is_transient_error(&error) => {
+                let delay = backoff.next_delay();
+                warn_retry(&error.to_string(), delay, stdout)?;
+                delay
+            }
+            Err(error) => return Err(error),
+        };
+
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        sleep(delay.min(remaining));
+    }
+}
+
+fn warn_retry(reason: &str, delay: Duration, stdout: &mut StandardStream) -> Result<()> {
+    stdout.set_color(ColorSpec::new().set_fg(Some(Color::Yellow)))?;
+    writeln!(
+        stdout,
+        "[WARN] Status check failed ({}), retrying in {:.1}s",
+        reason,
+        delay.as_secs_f64()
+    )?;
+    stdout.reset()?;
+    Ok(())
+}
+
+fn finish(
+    outcome: DeploymentOutcome,
+    timeout: Duration,
+    stdout: &mut StandardStream,
+) -> Result<DeploymentOutcome> {
+    if outcome == DeploymentOutcome::TimedOut {
+        stdout.set_color(ColorSpec::new().set_fg(Some(Color::Red)).set_bold(true))?;
+        writeln!(
+            stdout,
+            "\n[ERROR] Timed out after {} waiting for the operation to finish",
+            format_elapsed(timeout)
+        )?;
+        stdout.reset()?;
+        writeln!(
+            stdout,
+            "[INFO] The operation may still be running on the platform"
+        )?;
+    }
+    Ok(outcome)
+}
+
+/// Keeps track of what has already been printed so only changes are shown
+struct StatusTracker {
+    started_at: Instant,
+    last_phase: Option<String>,
+    services: HashMap<String, ServiceProgress>,
+}
+
+impl StatusTracker {
+    fn new() -> Self {
+        Self {
+            started_at: Instant::now(),
+            last_phase: None,
+            services: HashMap::new(),
+        }
+    }
+
+    /// Print any phase or per-service changes. Returns the outcome once the status is terminal.
+    fn render(
+        &mut self,
+        status: &DeploymentStatus,
+        stdout: &mut StandardStream,
+    ) -> Result<Option<DeploymentOutcome>> {
+        let elapsed = format_elapsed(self.started_at.elapsed());
+
+        if let Some(phase) = &status.phase
+            && self.last_phase.as_ref() != Some(phase)
+        {
+            display_phase_update(phase, &elapsed, stdout)?;
+            self.last_phase = Some(phase.clone());
+        }
+
+        for service in &status.services {
+            if self.services.get(&service.name) != Some(service) {
+                display_service_update(service, &elapsed, stdout)?;
+                self.services.insert(service.name.clone(), service.clone());
             }
         }
 
         match status.status.as_str() {
             "completed" => {
                 stdout.set_color(ColorSpec::new().set_fg(Some(Color::Green)).set_bold(true))?;
-                writeln!(stdout, "\n[OK] Operation successful!")?;
+                writeln!(stdout, "\n[OK] Operation successful! ({})", elapsed)?;
                 stdout.reset()?;
 
-                if let Some(endpoints) = status.endpoints {
+                if let Some(endpoints) = &status.endpoints {
                     writeln!(stdout)?;
-                    if let Some(api) = endpoints.api {
+                    if let Some(api) = &endpoints.api {
                         writeln!(stdout, "[INFO] API: {}", api)?;
                     }
-                    if let Some(docs) = endpoints.docs {
+                    if let Some(docs) = &endpoints.docs {
                         writeln!(stdout, "[INFO] Docs: {}", docs)?;
                     }
                 }
-                break;
+                Ok(Some(DeploymentOutcome::Completed))
             }
             "failed" => {
                 stdout.set_color(ColorSpec::new().set_fg(Some(Color::Red)).set_bold(true))?;
-                writeln!(stdout, "\n[ERROR] Operation failed")?;
+                writeln!(stdout, "\n[ERROR] Operation failed ({})", elapsed)?;
                 stdout.reset()?;
 
-                if let Some(error) = status.error {
+                if let Some(error) = &status.error {
                     writeln!(stdout, "[ERROR] Error: {}", error)?;
                 }
-                bail!("Operation failed");
-            }
-            _ => {
-                sleep(Duration::from_secs(3));
+                for service in status.services.iter().filter(|s| s.error.is_some()) {
+                    writeln!(
+                        stdout,
+                        "[ERROR] {}: {}",
+                        service.name,
+                        service.error.as_deref().unwrap_or_default()
+                    )?;
+                }
+                Ok(Some(DeploymentOutcome::Failed))
             }
+            _ => Ok(None),
         }
     }
-
-    Ok(())
 }
 
-fn display_phase_update(phase: &str, stdout: &mut StandardStream) -> Result<()> {
+fn display_phase_update(phase: &str, elapsed: &str, stdout: &mut StandardStream) -> Result<()> {
     stdout.set_color(ColorSpec::new().set_fg(Some(Color::Cyan)))?;
     let message = match phase {
-        "validating" => "  Validating configuration...",
-        "provisioning_database" => "  Provisioning database (RDS PostgreSQL db.t3.micro)...",
-        "provisioning_cache" => "  Provisioning cache (ElastiCache Redis)...",
-        "creating_network" => "  Creating network infrastructure...",
-        "creating_load_balancer" => "  Creating load balancer...",
-        "deploying_services" => "  Deploying services (256m CPU, 512Mi RAM)...",
-        "configuring_autoscaling" => "  Configuring auto-scaling (1-2 replicas)...",
-        "configuring_monitoring" => "  Setting up monitoring (OTEL, Prometheus, Grafana)...",
-        "destroying_services" => "  Destroying services...",
-        "destroying_load_balancer" => "  Destroying load balancer...",
-        "destroying_network" => "  Destroying network infrastructure...",
-        "destroying_cache" => "  Destroying cache...",
-        "destroying_database" => "  Destroying database...",
+        "validating" => "Validating configuration...",
+        "provisioning_database" => "Provisioning database (RDS PostgreSQL db.t3.micro)...",
+        "provisioning_cache" => "Provisioning cache (ElastiCache Redis)...",
+        "creating_network" => "Creating network infrastructure...",
+        "creating_load_balancer" => "Creating load balancer...",
+        "deploying_services" => "Deploying services (256m CPU, 512Mi RAM)...",
+        "configuring_autoscaling" => "Configuring auto-scaling (1-2 replicas)...",
+        "configuring_monitoring" => "Setting up monitoring (OTEL, Prometheus, Grafana)...",
+        "destroying_services" => "Destroying services...",
+        "destroying_load_balancer" => "Destroying load balancer...",
+        "destroying_network" => "Destroying network infrastructure...",
+        "destroying_cache" => "Destroying cache...",
+        "destroying_database" => "Destroying database...",
         _ => phase,
     };
-    writeln!(stdout, "{}", message)?;
+    writeln!(stdout, "  [{}] {}", elapsed, message)?;
+    stdout.reset()?;
+    Ok(())
+}
+
+fn display_service_update(
+    service: &ServiceProgress,
+    elapsed: &str,
+    stdout: &mut StandardStream,
+) -> Result<()> {
+    let color = match service.status.as_str() {
+        "completed" | "healthy" | "running" => Color::Green,
+        "failed" | "unhealthy" => Color::Red,
+        _ => Color::White,
+    };
+
+    let mut line = format!("    [{}] {}: {}", elapsed, service.name, service.status);
+    if let Some(phase) = &service.phase {
+        line.push_str(&format!(" - {}", phase.replace('_', " ")));
+    }
+    if let Some(progress) = service.progress {
+        line.push_str(&format!(" ({}%)", progress.min(100)));
+    }
+
+    stdout.set_color(ColorSpec::new().set_fg(Some(color)))?;
+    writeln!(stdout, "{}", line)?;
     stdout.reset()?;
     Ok(())
 }
+
+/// Format a duration as `mm:ss`, or `h:mm:ss` once it exceeds an hour
+fn format_elapsed(duration: Duration) -> String {
+    let total = duration.as_secs();
+    let (hours, minutes, seconds) = (total / 3600, (total % 3600) / 60, total % 60);
+    if hours > 0 {
+        format!("{}:{:02}:{:02}", hours, minutes, seconds)
+    } else {
+        format!("{:02}:{:02}", minutes, seconds)
+    }
+}
+
+/// 408/425/429 and any 5xx are worth retrying; everything else is a hard failure
+fn is_transient_status(status: u16) -> bool {
+    matches!(status, 408 | 425 | 429) || (500..600).contains(&status)
+}
+
+fn is_transient_error(error: &anyhow::Error) -> bool {
+    error
+        .downcast_ref::<reqwest::Error>()
+        .map(|error| error.is_timeout() || error.is_connect() || error.is_request())
+        .unwrap_or(false)
+}
+
+/// Exponential backoff with equal jitter: each delay is drawn from `[cap / 2, cap]`
+/// where `cap = min(max, base * 2^attempt)`.
+struct Backoff {
+    base: Duration,
+    max: Duration,
+    attempt: u32,
+}
+
+impl Backoff {
+    fn new(base: Duration, max: Duration) -> Self {
+        Self {
+            base,
+            max,
+            attempt: 0,
+        }
+    }
+
+    fn next_delay(&mut self) -> Duration {
+        let delay = backoff_delay(
+            self.base,
+            self.max,
+            self.attempt,
+            rand::thread_rng().gen_range(0.0..=1.0),
+        );
+        self.attempt = self.attempt.saturating_add(1);
+        delay
+    }
+
+    fn reset(&mut self) {
+        self.attempt = 0;
+    }
+}
+
+fn backoff_delay(base: Duration, max: Duration, attempt: u32, jitter: f64) -> Duration {
+    let cap = base
+        .checked_mul(2u32.saturating_pow(attempt.min(16)))
+        .unwrap_or(max)
+        .min(max);
+    let half = cap / 2;
+    half + half.mul_f64(jitter.clamp(0.0, 1.0))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum StreamFormat {
+    ServerSentEvents,
+    NewlineDelimitedJson,
+}
+
+/// Read payloads from a streaming body, calling `on_event` for each one until it returns false.
+///
+/// For server-sent events the `data:` lines of each event are joined; comments, `ping`
+/// events and other fields are ignored. For NDJSON each non-empty line is one payload.
+fn read_stream_events<R: BufRead>(
+    reader: R,
+    format: StreamFormat,
+    mut on_event: impl FnMut(&str) -> Result<bool>,
+) -> Result<()> {
+    let mut data_lines: Vec<String> = Vec::new();
+    let mut event_name: Option<String> = None;
+
+    for line in reader.lines() {
+        let line = line.with_context(|| "Failed to read status stream")?;
+        let line = line.trim_end_matches('\r');
+
+        if format == StreamFormat::NewlineDelimitedJson {
+            if !line.trim().is_empty() && !on_event(line.trim())? {
+                return Ok(());
+            }
+            continue;
+        }
+
+        if line.is_empty() {
+            let is_ping = event_name.as_deref() == Some("ping");
+            if !data_lines.is_empty() && !is_ping && !on_event(&data_lines.join("\n"))? {
+                return Ok(());
+            }
+            data_lines.clear();
+            event_name = None;
+            continue;
+        }
+
+        if line.starts_with(':') {
+            continue;
+        }
+
+        let (field, value) = line.split_once(':').unwrap_or((line, ""));
+        let value = value.strip_prefix(' ').unwrap_or(value);
+        match field {
+            "data" => data_lines.push(value.to_string()),
+            "event" => event_name = Some(value.to_string()),
+            _ => {}
+        }
+    }
+
+    if !data_lines.is_empty() && event_name.as_deref() != Some("ping") {
+        on_event(&data_lines.join("\n"))?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+
+    fn collect(input: &str, format: StreamFormat) -> Vec<String> {
+        let mut events = Vec::new();
+        read_stream_events(Cursor::new(input), format, |payload| {
+            events.push(payload.to_string());
+            Ok(true)
+        })
+        .unwrap();
+        events
+    }
+
+    #[test]
+    fn test_read_stream_events_sse() {
+        let input = ": keep-alive\n\
+                     event: status\n\
+                     data: {\"status\":\"in_progress\"}\n\
+                     \n\
+                     event: ping\n\
+                     data: {}\n\
+                     \n\
+                     data: {\"status\":\n\
+                     data: \"completed\"}\n\
+                     \n";
+
+        let events = collect(input, StreamFormat::ServerSentEvents);
+        assert_eq!(
+            events,
+            vec![
+                "{\"status\":\"in_progress\"}".to_string(),
+                "{\"status\":\n\"completed\"}".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_read_stream_events_ndjson_stops_when_requested() {
+        let input = "{\"a\":1}\n\n{\"a\":2}\n{\"a\":3}\n";
+        let mut seen = Vec::new();
+        read_stream_events(Cursor::new(input), StreamFormat::NewlineDelimitedJson, |p| {
+            seen.push(p.to_string());
+            Ok(seen.len() < 2)
+        })
+        .unwrap();
+        assert_eq!(seen, vec!["{\"a\":1}", "{\"a\":2}"]);
+    }
+
+    #[test]
+    fn test_backoff_delay_is_bounded() {
+        let base = Duration::from_secs(2);
+        let max = Duration::from_secs(60);
+
+        assert_eq!(backoff_delay(base, max, 0, 0.0), Duration::from_secs(1));
+        assert_eq!(backoff_delay(base, max, 0, 1.0), Duration::from_secs(2));
+        assert_eq!(backoff_delay(base, max, 3, 1.0), Duration::from_secs(16));
+        assert_eq!(backoff_delay(base, max, 10, 1.0), max);
+        assert_eq!(backoff_delay(base, max, u32::MAX, 0.0), max / 2);
+    }
+
+    #[test]
+    fn test_is_transient_status() {
+        assert!(is_transient_status(503));
+        assert!(is_transient_status(500));
+        assert!(is_transient_status(429));
+        assert!(!is_transient_status(404));
+        assert!(!is_transient_status(401));
+    }
+
+    #[test]
+    fn test_format_elapsed() {
+        assert_eq!(format_elapsed(Duration::from_secs(75)), "01:15");
+        assert_eq!(format_elapsed(Duration::from_secs(3725)), "1:02:05");
+    }
+
+    #[test]
+    fn test_deployment_status_parses_service_progress() {
+        let status: DeploymentStatus = serde_json::from_str(
+            r#"{"id":"d1","status":"in_progress","phase":"deploying_services",
+                "services":[{"name":"billing","status":"deploying","progress":40}]}"#,
+        )
+        .unwrap();
+        assert_eq!(status.services.len(), 1);
+        assert_eq!(status.services[0].progress, Some(40));
+        assert_eq!(DeploymentOutcome::TimedOut.exit_code(), Some(124));
+        assert_eq!(DeploymentOutcome::Completed.exit_code(), None);
+    }
+}
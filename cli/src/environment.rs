@@ -1,10 +1,18 @@
 use anyhow::Result;
 use clap::{ArgMatches, Command};
+use diff::DiffCommand;
+use export::ExportCommand;
+use explain::ExplainCommand;
+use schema::SchemaCommand;
 use sync::SyncCommand;
 use validate::ValidateCommand;
 
 use crate::{CliCommand, core::command::command};
 
+pub(crate) mod diff;
+pub(crate) mod export;
+pub(crate) mod explain;
+pub(crate) mod schema;
 pub(crate) mod sync;
 pub(crate) mod validate;
 
@@ -12,6 +20,10 @@ pub(crate) mod validate;
 pub(crate) struct EnvironmentCommand {
     validate: ValidateCommand,
     sync: SyncCommand,
+    schema: SchemaCommand,
+    explain: ExplainCommand,
+    diff: DiffCommand,
+    export: ExportCommand,
 }
 
 impl EnvironmentCommand {
@@ -19,6 +31,10 @@ impl EnvironmentCommand {
         Self {
             validate: ValidateCommand::new(),
             sync: SyncCommand::new(),
+            schema: SchemaCommand::new(),
+            explain: ExplainCommand::new(),
+            diff: DiffCommand::new(),
+            export: ExportCommand::new(),
         }
     }
 }
@@ -33,12 +49,20 @@ impl CliCommand for EnvironmentCommand {
         .subcommand_required(true)
         .subcommand(self.validate.command())
         .subcommand(self.sync.command())
+        .subcommand(self.schema.command())
+        .subcommand(self.explain.command())
+        .subcommand(self.diff.command())
+        .subcommand(self.export.command())
     }
 
     fn handler(&self, matches: &ArgMatches) -> Result<()> {
         match matches.subcommand() {
             Some(("validate", sub_matches)) => self.validate.handler(sub_matches),
             Some(("sync", sub_matches)) => self.sync.handler(sub_matches),
+            Some(("schema", sub_matches)) => self.schema.handler(sub_matches),
+            Some(("explain", sub_matches)) => self.explain.handler(sub_matches),
+            Some(("diff", sub_matches)) => self.diff.handler(sub_matches),
+            Some(("export", sub_matches)) => self.export.handler(sub_matches),
             _ => unreachable!(),
         }
     }
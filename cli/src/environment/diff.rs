@@ -0,0 +1,377 @@
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet},
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Result, bail};
+use clap::{Arg, ArgAction, ArgMatches, Command};
+use termcolor::{Color, ColorChoice, ColorSpec, StandardStream, WriteColor};
+
+use super::{
+    explain::display_env_value,
+    schema::{build_project_schemas, find_project_schema},
+};
+use crate::{
+    CliCommand,
+    core::{
+        ast::infrastructure::env::find_all_env_vars,
+        command::command,
+        env::{find_workspace_root, get_cascading_env_paths, get_modules_path, load_env_file},
+        env_scope::{EnvironmentVariableScope, ScopedEnvVar, determine_env_var_scopes, resolve_scope_values},
+        rendered_template::RenderedTemplatesCache,
+    },
+};
+
+/// One side of a diff: either a standalone file such as a pulled platform
+/// config, or an env file name looked up in every directory of the cascade.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum EnvSource {
+    File(PathBuf),
+    Layer(String),
+}
+
+impl EnvSource {
+    /// A bare `.env*` name is always a layer; use `./.env.x` for the file.
+    pub(crate) fn parse(source: &str) -> Result<Self> {
+        if source.starts_with(".env") && !source.contains(['/', '\\']) {
+            return Ok(EnvSource::Layer(source.to_string()));
+        }
+        let path = Path::new(source);
+        if path.is_file() {
+            return Ok(EnvSource::File(path.to_path_buf()));
+        }
+        bail!(
+            "'{}' is neither an existing file nor an env file name like .env.production",
+            source
+        )
+    }
+
+    fn label(&self) -> String {
+        match self {
+            EnvSource::File(path) => path.display().to_string(),
+            EnvSource::Layer(name) => name.clone(),
+        }
+    }
+
+    /// Values this source provides to a project at `project_path`.
+    fn load(&self, project_path: &Path, workspace_root: &Path) -> Result<HashMap<String, String>> {
+        match self {
+            EnvSource::File(path) => load_env_file(path),
+            EnvSource::Layer(name) => {
+                let mut values = HashMap::new();
+                for env_path in get_cascading_env_paths(project_path, workspace_root)? {
+                    if env_path.file_name().and_then(|file| file.to_str()) == Some(name.as_str()) {
+                        values.extend(load_env_file(&env_path)?);
+                    }
+                }
+                Ok(values)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum EnvDiff {
+    /// Defined in `--from` only.
+    Missing { name: String, from: String },
+    /// Defined in `--to` only.
+    Extra { name: String, to: String },
+    Changed {
+        name: String,
+        from: String,
+        to: String,
+    },
+}
+
+pub(crate) fn diff_env_values<'a>(
+    names: impl IntoIterator<Item = &'a str>,
+    from: &HashMap<String, String>,
+    to: &HashMap<String, String>,
+) -> Vec<EnvDiff> {
+    let names: BTreeSet<&str> = names.into_iter().collect();
+
+    names
+        .into_iter()
+        .filter_map(|name| match (from.get(name), to.get(name)) {
+            (Some(from), None) => Some(EnvDiff::Missing {
+                name: name.to_string(),
+                from: from.clone(),
+            }),
+            (None, Some(to)) => Some(EnvDiff::Extra {
+                name: name.to_string(),
+                to: to.clone(),
+            }),
+            (Some(from), Some(to)) if from != to => Some(EnvDiff::Changed {
+                name: name.to_string(),
+                from: from.clone(),
+                to: to.clone(),
+            }),
+            _ => None,
+        })
+        .collect()
+}
+
+#[derive(Debug)]
+pub(crate) struct DiffCommand;
+
+impl DiffCommand {
+    pub(crate) fn new() -> Self {
+        Self
+    }
+}
+
+impl CliCommand for DiffCommand {
+    fn command(&self) -> Command {
+        command(
+            "diff",
+            "Compare environment variables between two env files or environments",
+        )
+        .long_about("Compares two environments per variable scope and reports keys missing from --to, extra keys only in --to, and differing values. Each side is either an env file name looked up across the .env cascade (e.g. .env.production) or a path to a single file such as a pulled platform config")
+        .arg(
+            Arg::new("from")
+                .long("from")
+                .required(true)
+                .help("Env file name or path to compare from"),
+        )
+        .arg(
+            Arg::new("to")
+                .long("to")
+                .required(true)
+                .help("Env file name or path to compare to"),
+        )
+        .arg(
+            Arg::new("project")
+                .long("project")
+                .help("Only compare the application scope and this project's scope"),
+        )
+        .arg(
+            Arg::new("base_path")
+                .short('p')
+                .long("path")
+                .help("The application path"),
+        )
+        .arg(
+            Arg::new("reveal")
+                .long("reveal")
+                .action(ArgAction::SetTrue)
+                .help("Print secret values instead of masking them"),
+        )
+    }
+
+    fn handler(&self, matches: &ArgMatches) -> Result<()> {
+        let (app_root, manifest) = crate::core::validate::require_manifest(matches)?;
+        let from = EnvSource::parse(matches.get_one::<String>("from").unwrap())?;
+        let to = EnvSource::parse(matches.get_one::<String>("to").unwrap())?;
+        let project_filter = matches.get_one::<String>("project");
+        let reveal = matches.get_flag("reveal");
+
+        let workspace_root = find_workspace_root(&app_root)?;
+        let modules_path = get_modules_path(&workspace_root)?;
+
+        let rendered_templates_cache = RenderedTemplatesCache::new();
+        let project_env_vars = find_all_env_vars(&modules_path, &rendered_templates_cache)?;
+        let schemas = build_project_schemas(&project_env_vars, &manifest);
+        let scoped_vars = determine_env_var_scopes(&project_env_vars, &manifest)?;
+
+        let mut scopes: BTreeMap<String, Vec<&ScopedEnvVar>> = BTreeMap::new();
+        for scoped_var in &scoped_vars {
+            let label = match (&scoped_var.scope, &scoped_var.scope_id) {
+                (EnvironmentVariableScope::Application, _) | (_, None) => "application".to_string(),
+                (scope, Some(scope_id)) => {
+                    if project_filter.is_some_and(|project_filter| project_filter != scope_id) {
+                        continue;
+                    }
+                    format!("{} {}", scope.as_str(), scope_id)
+                }
+            };
+            scopes.entry(label).or_default().push(scoped_var);
+        }
+
+        // Each project sees its own cascade, so resolve both sides per project.
+        let mut from_by_project: HashMap<&str, HashMap<String, String>> = HashMap::new();
+        let mut to_by_project: HashMap<&str, HashMap<String, String>> = HashMap::new();
+        for project_name in project_env_vars.keys() {
+            let project_path = modules_path.join(project_name);
+            from_by_project.insert(project_name, from.load(&project_path, &workspace_root)?);
+            to_by_project.insert(project_name, to.load(&project_path, &workspace_root)?);
+        }
+
+        let mut stdout = StandardStream::stdout(ColorChoice::Always);
+        writeln!(stdout, "Comparing {} -> {}", from.label(), to.label())?;
+
+        let mut totals = (0, 0, 0);
+
+        for (label, vars) in &scopes {
+            let from_values = resolve_scope_values(vars, &from_by_project);
+            let to_values = resolve_scope_values(vars, &to_by_project);
+
+            let diffs = diff_env_values(
+                vars.iter().map(|var| var.name.as_str()),
+                &from_values,
+                &to_values,
+            );
+            let display = |name: &str, value: &str| {
+                let project_name = vars
+                    .iter()
+                    .find(|var| var.name == name)
+                    .map(|var| var.used_by[0].as_str())
+                    .unwrap_or_default();
+                display_env_value(
+                    value,
+                    &find_project_schema(&schemas, project_name, name),
+                    reveal,
+                )
+            };
+            print_scope_diffs(label, &diffs, &display, &mut totals, &mut stdout)?;
+        }
+
+        // Keys defined at the application root that no project reads.
+        if project_filter.is_none() {
+            let referenced: HashSet<&str> =
+                scoped_vars.iter().map(|var| var.name.as_str()).collect();
+            let from_values = from.load(&workspace_root, &workspace_root)?;
+            let to_values = to.load(&workspace_root, &workspace_root)?;
+            let unreferenced = from_values
+                .keys()
+                .chain(to_values.keys())
+                .map(String::as_str)
+                .filter(|name| !referenced.contains(name));
+            let diffs = diff_env_values(unreferenced, &from_values, &to_values);
+            let display = |name: &str, value: &str| {
+                display_env_value(value, &find_project_schema(&schemas, "", name), reveal)
+            };
+            print_scope_diffs("unreferenced", &diffs, &display, &mut totals, &mut stdout)?;
+        }
+
+        let (missing, extra, changed) = totals;
+        if missing + extra + changed == 0 {
+            stdout.set_color(ColorSpec::new().set_fg(Some(Color::Green)))?;
+            writeln!(stdout, "\n[OK] No differences")?;
+            stdout.reset()?;
+        } else {
+            writeln!(
+                stdout,
+                "\n{} missing from {}, {} only in {}, {} differing",
+                missing,
+                to.label(),
+                extra,
+                to.label(),
+                changed
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+fn print_scope_diffs(
+    label: &str,
+    diffs: &[EnvDiff],
+    display: &dyn Fn(&str, &str) -> String,
+    totals: &mut (usize, usize, usize),
+    stdout: &mut StandardStream,
+) -> Result<()> {
+    if diffs.is_empty() {
+        return Ok(());
+    }
+
+    stdout.set_color(ColorSpec::new().set_fg(Some(Color::Cyan)).set_bold(true))?;
+    writeln!(stdout, "\n{}", label)?;
+    stdout.reset()?;
+
+    for diff in diffs {
+        match diff {
+            EnvDiff::Missing { name, from } => {
+                totals.0 += 1;
+                stdout.set_color(ColorSpec::new().set_fg(Some(Color::Red)))?;
+                writeln!(stdout, "  - {:<40} {}", name, display(name, from))?;
+            }
+            EnvDiff::Extra { name, to } => {
+                totals.1 += 1;
+                stdout.set_color(ColorSpec::new().set_fg(Some(Color::Green)))?;
+                writeln!(stdout, "  + {:<40} {}", name, display(name, to))?;
+            }
+            EnvDiff::Changed { name, from, to } => {
+                totals.2 += 1;
+                stdout.set_color(ColorSpec::new().set_fg(Some(Color::Yellow)))?;
+                writeln!(
+                    stdout,
+                    "  ~ {:<40} {} -> {}",
+                    name,
+                    display(name, from),
+                    display(name, to)
+                )?;
+            }
+        }
+        stdout.reset()?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use tempfile::TempDir;
+
+    use super::*;
+
+    #[test]
+    fn test_diff_env_values() {
+        let from = HashMap::from([
+            ("PORT".to_string(), "8000".to_string()),
+            ("REDIS_URL".to_string(), "redis://localhost".to_string()),
+            ("OTEL_LEVEL".to_string(), "debug".to_string()),
+        ]);
+        let to = HashMap::from([
+            ("PORT".to_string(), "8000".to_string()),
+            ("REDIS_URL".to_string(), "rediss://cache".to_string()),
+            ("SENTRY_DSN".to_string(), "https://sentry".to_string()),
+        ]);
+
+        let diffs = diff_env_values(
+            ["PORT", "REDIS_URL", "OTEL_LEVEL", "SENTRY_DSN", "UNSET"],
+            &from,
+            &to,
+        );
+
+        assert_eq!(
+            diffs,
+            vec![
+                EnvDiff::Missing {
+                    name: "OTEL_LEVEL".to_string(),
+                    from: "debug".to_string(),
+                },
+                EnvDiff::Changed {
+                    name: "REDIS_URL".to_string(),
+                    from: "redis://localhost".to_string(),
+                    to: "rediss://cache".to_string(),
+                },
+                EnvDiff::Extra {
+                    name: "SENTRY_DSN".to_string(),
+                    to: "https://sentry".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_layer_source_merges_cascade() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        let project = root.join("billing");
+        fs::create_dir_all(&project).unwrap();
+        fs::write(root.join(".env.production"), "PORT=80\nOTEL_LEVEL=info\n").unwrap();
+        fs::write(project.join(".env.production"), "PORT=8080\n").unwrap();
+        fs::write(project.join(".env.local"), "PORT=3000\n").unwrap();
+
+        let source = EnvSource::parse(".env.production").unwrap();
+        let values = source.load(&project, root).unwrap();
+
+        assert_eq!(values.get("PORT"), Some(&"8080".to_string()));
+        assert_eq!(values.get("OTEL_LEVEL"), Some(&"info".to_string()));
+        assert!(EnvSource::parse("missing/.env.production").is_err());
+    }
+}
@@ -0,0 +1,264 @@
+use std::{
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Result, bail};
+use clap::{Arg, ArgAction, ArgMatches, Command};
+use termcolor::{Color, ColorChoice, ColorSpec, StandardStream, WriteColor};
+
+use super::schema::{build_project_schemas, find_project_schema};
+use crate::{
+    CliCommand,
+    core::{
+        ast::infrastructure::env::find_all_env_vars,
+        command::command,
+        env::{find_workspace_root, get_cascading_env_paths, get_modules_path, load_env_file},
+        env_schema::{EnvVarSchema, mask_env_value},
+        rendered_template::RenderedTemplatesCache,
+        secrets::is_sealed_value,
+    },
+};
+
+/// Formats a value for display, masking sensitive values unless revealed.
+pub(crate) fn display_env_value(value: &str, schema: &EnvVarSchema, reveal: bool) -> String {
+    if value.is_empty() {
+        "(empty)".to_string()
+    } else if is_sealed_value(value) {
+        "(encrypted)".to_string()
+    } else if schema.is_sensitive() && !reveal {
+        mask_env_value(value)
+    } else {
+        value.to_string()
+    }
+}
+
+/// Every env file in the cascade that defines `var_name`, in override order;
+/// the last entry is the effective value.
+pub(crate) fn find_defining_layers(
+    var_name: &str,
+    env_paths: &[PathBuf],
+) -> Result<Vec<(PathBuf, String)>> {
+    let mut layers = Vec::new();
+    for env_path in env_paths {
+        if let Some(value) = load_env_file(env_path)?.remove(var_name) {
+            layers.push((env_path.clone(), value));
+        }
+    }
+    Ok(layers)
+}
+
+fn relative_to(path: &Path, root: &Path) -> String {
+    path.strip_prefix(root)
+        .unwrap_or(path)
+        .display()
+        .to_string()
+}
+
+#[derive(Debug)]
+pub(crate) struct ExplainCommand;
+
+impl ExplainCommand {
+    pub(crate) fn new() -> Self {
+        Self
+    }
+}
+
+impl CliCommand for ExplainCommand {
+    fn command(&self) -> Command {
+        command(
+            "explain",
+            "Show which env file layers define a variable and which one wins",
+        )
+        .arg(
+            Arg::new("var")
+                .required(true)
+                .help("The environment variable to explain"),
+        )
+        .arg(
+            Arg::new("project")
+                .long("project")
+                .help("Only explain the variable for this project"),
+        )
+        .arg(
+            Arg::new("base_path")
+                .short('p')
+                .long("path")
+                .help("The application path"),
+        )
+        .arg(
+            Arg::new("reveal")
+                .long("reveal")
+                .action(ArgAction::SetTrue)
+                .help("Print secret values instead of masking them"),
+        )
+    }
+
+    fn handler(&self, matches: &ArgMatches) -> Result<()> {
+        let (app_root, manifest) = crate::core::validate::require_manifest(matches)?;
+        let var_name = matches.get_one::<String>("var").unwrap();
+        let reveal = matches.get_flag("reveal");
+
+        let workspace_root = find_workspace_root(&app_root)?;
+        let modules_path = get_modules_path(&workspace_root)?;
+        let canonical_root = workspace_root.canonicalize()?;
+
+        let rendered_templates_cache = RenderedTemplatesCache::new();
+        let project_env_vars = find_all_env_vars(&modules_path, &rendered_templates_cache)?;
+        let schemas = build_project_schemas(&project_env_vars, &manifest);
+
+        let mut projects: Vec<String> = match matches.get_one::<String>("project") {
+            Some(project_name) => {
+                if !modules_path.join(project_name).is_dir() {
+                    bail!(
+                        "Project '{}' not found in {}",
+                        project_name,
+                        modules_path.display()
+                    );
+                }
+                vec![project_name.clone()]
+            }
+            None => project_env_vars
+                .iter()
+                .filter(|(_, usages)| usages.iter().any(|usage| &usage.var_name == var_name))
+                .map(|(project_name, _)| project_name.clone())
+                .collect(),
+        };
+        projects.sort();
+
+        let mut stdout = StandardStream::stdout(ColorChoice::Always);
+
+        if projects.is_empty() {
+            stdout.set_color(ColorSpec::new().set_fg(Some(Color::Yellow)))?;
+            writeln!(
+                stdout,
+                "[WARN] No project reads {}; showing the application root",
+                var_name
+            )?;
+            stdout.reset()?;
+        }
+
+        let locations: Vec<(String, PathBuf)> = if projects.is_empty() {
+            vec![("application".to_string(), workspace_root.clone())]
+        } else {
+            projects
+                .iter()
+                .map(|project_name| (project_name.clone(), modules_path.join(project_name)))
+                .collect()
+        };
+
+        for (location_name, location_path) in locations {
+            let schema = find_project_schema(&schemas, &location_name, var_name);
+            let env_paths = get_cascading_env_paths(&location_path, &workspace_root)?;
+            let layers = find_defining_layers(var_name, &env_paths)?;
+
+            stdout.set_color(ColorSpec::new().set_fg(Some(Color::Cyan)).set_bold(true))?;
+            write!(stdout, "\n{}", location_name)?;
+            stdout.reset()?;
+            writeln!(
+                stdout,
+                " ({}, {})",
+                schema.value_type,
+                if schema.required {
+                    "required"
+                } else {
+                    "optional"
+                }
+            )?;
+
+            for (index, (env_path, value)) in layers.iter().enumerate() {
+                let effective = index + 1 == layers.len();
+                if !effective {
+                    stdout.set_color(ColorSpec::new().set_dimmed(true))?;
+                }
+                write!(
+                    stdout,
+                    "  {:<40} {}",
+                    relative_to(env_path, &canonical_root),
+                    display_env_value(value, &schema, reveal)
+                )?;
+                stdout.reset()?;
+                if effective {
+                    stdout.set_color(ColorSpec::new().set_fg(Some(Color::Green)))?;
+                    write!(stdout, "  <- effective")?;
+                    stdout.reset()?;
+                }
+                writeln!(stdout)?;
+            }
+
+            match layers.last() {
+                None => {
+                    stdout.set_color(ColorSpec::new().set_fg(Some(Color::Yellow)))?;
+                    match &schema.default {
+                        Some(default) => writeln!(
+                            stdout,
+                            "  not defined in any env file; the code falls back to {}",
+                            default
+                        )?,
+                        None => writeln!(stdout, "  not defined in any env file")?,
+                    }
+                    stdout.reset()?;
+                }
+                Some((_, value)) if !is_sealed_value(value) => {
+                    for violation in schema.check(value) {
+                        stdout.set_color(ColorSpec::new().set_fg(Some(Color::Red)))?;
+                        writeln!(stdout, "  [ERROR] {} {}", var_name, violation)?;
+                        stdout.reset()?;
+                    }
+                }
+                Some(_) => {}
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use tempfile::TempDir;
+
+    use super::*;
+    use crate::core::env_schema::EnvValueType;
+
+    #[test]
+    fn test_find_defining_layers() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        let project = root.join("billing");
+        fs::create_dir_all(root.join(".forklaunch")).unwrap();
+        fs::write(root.join(".forklaunch/manifest.toml"), "").unwrap();
+        fs::create_dir_all(&project).unwrap();
+        fs::write(root.join(".env.local"), "REDIS_URL=redis://root\n").unwrap();
+        fs::write(project.join(".env"), "REDIS_URL=redis://project-default\n").unwrap();
+        fs::write(project.join(".env.local"), "REDIS_URL=redis://project\n").unwrap();
+
+        let env_paths = get_cascading_env_paths(&project, root).unwrap();
+        let layers = find_defining_layers("REDIS_URL", &env_paths).unwrap();
+
+        let values: Vec<&str> = layers.iter().map(|(_, value)| value.as_str()).collect();
+        assert_eq!(
+            values,
+            vec!["redis://root", "redis://project-default", "redis://project"]
+        );
+    }
+
+    #[test]
+    fn test_display_env_value() {
+        let secret = EnvVarSchema::new("HMAC_SECRET_KEY", "Authentication", EnvValueType::Secret);
+        let url = EnvVarSchema::new("REDIS_URL", "Cache (Redis)", EnvValueType::Url);
+
+        assert_eq!(
+            display_env_value("c2VjcmV0c2VjcmV0c2VjcmV0", &secret, false),
+            "c2Vj**** (24 chars)"
+        );
+        assert_eq!(
+            display_env_value("c2VjcmV0c2VjcmV0c2VjcmV0", &secret, true),
+            "c2VjcmV0c2VjcmV0c2VjcmV0"
+        );
+        assert_eq!(display_env_value("redis://x", &url, false), "redis://x");
+        assert_eq!(display_env_value("", &url, false), "(empty)");
+    }
+}
@@ -0,0 +1,600 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs,
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result, bail};
+use base64::{Engine, engine::general_purpose::STANDARD};
+use clap::{Arg, ArgMatches, Command};
+use serde::Serialize;
+use termcolor::{Color, ColorChoice, ColorSpec, StandardStream, WriteColor};
+
+use super::validate::is_env_template;
+use crate::{
+    CliCommand,
+    core::{
+        ast::infrastructure::env::find_all_env_vars,
+        command::command,
+        env::{find_workspace_root, get_cascading_env_paths, get_modules_path, load_env_file},
+        env_schema::{EnvValueType, EnvVarSchemaOverride},
+        env_scope::{
+            EnvironmentVariableScope, ScopedEnvVar, determine_env_var_scopes, resolve_scope_values,
+        },
+        rendered_template::RenderedTemplatesCache,
+        secrets::{ENCRYPTED_ENV_FILE_NAME, is_sealed_value},
+    },
+    release::create::is_secret_env_var,
+};
+
+/// Artifact layout for `environment export`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ExportFormat {
+    /// One `Secret` manifest per scope holding the secret keys
+    K8sSecret,
+    /// One `ConfigMap` manifest per scope holding the non-secret keys
+    K8sConfigMap,
+    /// Docker `--env-file` files, with secrets in a separate file
+    EnvFile,
+    /// One JSON document per scope with `config` and `secrets` objects
+    Json,
+}
+
+impl ExportFormat {
+    pub(crate) const VARIANTS: [&'static str; 4] =
+        ["k8s-secret", "k8s-configmap", "env-file", "json"];
+
+    pub(crate) fn parse(value: &str) -> Result<Self> {
+        match value {
+            "k8s-secret" => Ok(Self::K8sSecret),
+            "k8s-configmap" => Ok(Self::K8sConfigMap),
+            "env-file" => Ok(Self::EnvFile),
+            "json" => Ok(Self::Json),
+            other => bail!("Unknown export format '{}'", other),
+        }
+    }
+}
+
+/// The resolved variables of one scope, split into config and secrets.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ScopeExport {
+    pub(crate) name: String,
+    pub(crate) scope: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) scope_id: Option<String>,
+    pub(crate) environment: String,
+    pub(crate) config: BTreeMap<String, String>,
+    pub(crate) secrets: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct K8sResource<'a> {
+    api_version: &'static str,
+    kind: &'static str,
+    metadata: K8sMetadata<'a>,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    secret_type: Option<&'static str>,
+    data: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Serialize)]
+struct K8sMetadata<'a> {
+    name: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    namespace: Option<&'a str>,
+    labels: BTreeMap<&'static str, String>,
+}
+
+/// Lowercase alphanumerics and dashes, as Kubernetes object names require.
+fn to_resource_name(name: &str) -> String {
+    let mut resource_name = String::new();
+    for c in name.chars() {
+        if c.is_ascii_alphanumeric() {
+            resource_name.push(c.to_ascii_lowercase());
+        } else if !resource_name.ends_with('-') {
+            resource_name.push('-');
+        }
+    }
+    resource_name.trim_matches('-').to_string()
+}
+
+fn scope_stem(scoped_var: &ScopedEnvVar) -> String {
+    match (&scoped_var.scope, &scoped_var.scope_id) {
+        (EnvironmentVariableScope::Application, _) | (_, None) => "application".to_string(),
+        (_, Some(scope_id)) => scope_id.clone(),
+    }
+}
+
+/// Values a project sees in `env_name`: `.env`, then `.env.enc`, then
+/// `.env.<env_name>` in each directory from the root down. Without an
+/// environment, the full local cascade minus templates.
+pub(crate) fn load_target_env_values(
+    project_path: &Path,
+    workspace_root: &Path,
+    env_name: Option<&str>,
+) -> Result<HashMap<String, String>> {
+    let env_paths = get_cascading_env_paths(project_path, workspace_root)?;
+
+    let selected: Vec<PathBuf> = match env_name {
+        None => env_paths
+            .into_iter()
+            .filter(|env_path| !is_env_template(env_path))
+            .collect(),
+        Some(env_name) => {
+            let target_file = format!(".env.{}", env_name);
+            let rank = |env_path: &Path| match env_path.file_name().and_then(|name| name.to_str()) {
+                Some(".env") => Some(0),
+                Some(ENCRYPTED_ENV_FILE_NAME) => Some(1),
+                Some(name) if name == target_file => Some(2),
+                _ => None,
+            };
+
+            let mut directories: Vec<PathBuf> = Vec::new();
+            let mut ranked = Vec::new();
+            for env_path in env_paths {
+                let Some(rank) = rank(&env_path) else {
+                    continue;
+                };
+                let directory = env_path.parent().map(Path::to_path_buf).unwrap_or_default();
+                let directory_index = match directories.iter().position(|dir| dir == &directory) {
+                    Some(index) => index,
+                    None => {
+                        directories.push(directory);
+                        directories.len() - 1
+                    }
+                };
+                ranked.push((directory_index, rank, env_path));
+            }
+            ranked.sort();
+            ranked
+                .into_iter()
+                .map(|(_, _, env_path)| env_path)
+                .collect()
+        }
+    };
+
+    let mut values = HashMap::new();
+    for env_path in selected {
+        values.extend(load_env_file(&env_path)?);
+    }
+    Ok(values)
+}
+
+/// Groups resolved values by scope and splits secrets from config.
+pub(crate) fn build_scope_exports(
+    app_name: &str,
+    environment: &str,
+    scoped_vars: &[ScopedEnvVar],
+    values_by_project: &HashMap<&str, HashMap<String, String>>,
+    schema_overrides: Option<&BTreeMap<String, EnvVarSchemaOverride>>,
+) -> Vec<ScopeExport> {
+    let mut scopes: BTreeMap<String, Vec<&ScopedEnvVar>> = BTreeMap::new();
+    for scoped_var in scoped_vars {
+        scopes
+            .entry(scope_stem(scoped_var))
+            .or_default()
+            .push(scoped_var);
+    }
+
+    scopes
+        .into_iter()
+        .map(|(stem, vars)| {
+            let values = resolve_scope_values(&vars, values_by_project);
+            let mut export = ScopeExport {
+                name: to_resource_name(&format!("{}-{}", app_name, stem)),
+                scope: vars[0].scope.as_str().to_string(),
+                scope_id: vars[0].scope_id.clone(),
+                environment: environment.to_string(),
+                config: BTreeMap::new(),
+                secrets: BTreeMap::new(),
+            };
+
+            for (name, value) in values {
+                let declared_secret = schema_overrides
+                    .and_then(|overrides| overrides.get(&name))
+                    .and_then(|schema_override| schema_override.value_type)
+                    .is_some_and(|value_type| {
+                        matches!(value_type, EnvValueType::Secret | EnvValueType::Pem)
+                    });
+                if declared_secret || is_secret_env_var(&name, &value) {
+                    export.secrets.insert(name, value);
+                } else {
+                    export.config.insert(name, value);
+                }
+            }
+
+            export
+        })
+        .collect()
+}
+
+fn k8s_labels(app_name: &str, export: &ScopeExport) -> BTreeMap<&'static str, String> {
+    BTreeMap::from([
+        (
+            "app.kubernetes.io/component",
+            export
+                .scope_id
+                .clone()
+                .unwrap_or_else(|| export.scope.clone()),
+        ),
+        ("app.kubernetes.io/managed-by", "forklaunch".to_string()),
+        ("app.kubernetes.io/part-of", to_resource_name(app_name)),
+        ("forklaunch.dev/environment", export.environment.clone()),
+    ])
+}
+
+/// Renders the artifacts of one scope as `(file name, contents)` pairs.
+/// Halves with no keys produce no file.
+pub(crate) fn render_scope_export(
+    export: &ScopeExport,
+    format: ExportFormat,
+    app_name: &str,
+    namespace: Option<&str>,
+) -> Result<Vec<(String, String)>> {
+    let stem = export
+        .name
+        .strip_prefix(&format!("{}-", to_resource_name(app_name)))
+        .unwrap_or(&export.name);
+
+    let k8s_resource = |kind: &'static str, secret_type, data| K8sResource {
+        api_version: "v1",
+        kind,
+        metadata: K8sMetadata {
+            name: &export.name,
+            namespace,
+            labels: k8s_labels(app_name, export),
+        },
+        secret_type,
+        data,
+    };
+
+    let mut files = Vec::new();
+    match format {
+        ExportFormat::K8sSecret => {
+            if !export.secrets.is_empty() {
+                let data = export
+                    .secrets
+                    .iter()
+                    .map(|(name, value)| (name.clone(), STANDARD.encode(value)))
+                    .collect();
+                files.push((
+                    format!("{}.secret.yaml", stem),
+                    serde_yml::to_string(&k8s_resource("Secret", Some("Opaque"), data))?,
+                ));
+            }
+        }
+        ExportFormat::K8sConfigMap => {
+            if !export.config.is_empty() {
+                files.push((
+                    format!("{}.configmap.yaml", stem),
+                    serde_yml::to_string(&k8s_resource("ConfigMap", None, export.config.clone()))?,
+                ));
+            }
+        }
+        ExportFormat::EnvFile => {
+            // Docker env-files take values verbatim, so newlines stay escaped.
+            let render = |values: &BTreeMap<String, String>| {
+                values
+                    .iter()
+                    .map(|(name, value)| format!("{}={}\n", name, value.replace('\n', "\\n")))
+                    .collect::<String>()
+            };
+            if !export.config.is_empty() {
+                files.push((format!("{}.env", stem), render(&export.config)));
+            }
+            if !export.secrets.is_empty() {
+                files.push((format!("{}.secret.env", stem), render(&export.secrets)));
+            }
+        }
+        ExportFormat::Json => {
+            files.push((
+                format!("{}.json", stem),
+                format!("{}\n", serde_json::to_string_pretty(export)?),
+            ));
+        }
+    }
+
+    Ok(files)
+}
+
+#[derive(Debug)]
+pub(crate) struct ExportCommand;
+
+impl ExportCommand {
+    pub(crate) fn new() -> Self {
+        Self
+    }
+}
+
+impl CliCommand for ExportCommand {
+    fn command(&self) -> Command {
+        command(
+            "export",
+            "Export resolved environment variables per scope for deployment tooling",
+        )
+        .long_about("Resolves the .env cascade for an environment and writes one artifact per application, service and worker scope. Secret keys are split from plain config using the same inference as release manifests. Output is sorted and free of timestamps so it can be committed to GitOps repositories")
+        .arg(
+            Arg::new("format")
+                .short('f')
+                .long("format")
+                .required(true)
+                .value_parser(ExportFormat::VARIANTS)
+                .help("The artifact format"),
+        )
+        .arg(
+            Arg::new("env")
+                .short('e')
+                .long("env")
+                .help("Target environment; reads .env, .env.enc and .env.<env>. Defaults to the local cascade"),
+        )
+        .arg(
+            Arg::new("output")
+                .short('o')
+                .long("output")
+                .help("Output directory [default: env-export/<env>]"),
+        )
+        .arg(
+            Arg::new("namespace")
+                .long("namespace")
+                .help("Kubernetes namespace for k8s formats"),
+        )
+        .arg(
+            Arg::new("base_path")
+                .short('p')
+                .long("path")
+                .help("The application path"),
+        )
+    }
+
+    fn handler(&self, matches: &ArgMatches) -> Result<()> {
+        let (app_root, manifest) = crate::core::validate::require_manifest(matches)?;
+        let format = ExportFormat::parse(matches.get_one::<String>("format").unwrap())?;
+        let env_name = matches.get_one::<String>("env").map(String::as_str);
+        let namespace = matches.get_one::<String>("namespace").map(String::as_str);
+
+        if let Some(env_name) = env_name
+            && !env_name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        {
+            bail!("Invalid environment name '{}'", env_name);
+        }
+        let environment = env_name.unwrap_or("local");
+        let output_dir = matches
+            .get_one::<String>("output")
+            .map(PathBuf::from)
+            .unwrap_or_else(|| app_root.join("env-export").join(environment));
+
+        let workspace_root = find_workspace_root(&app_root)?;
+        let modules_path = get_modules_path(&workspace_root)?;
+
+        let rendered_templates_cache = RenderedTemplatesCache::new();
+        let project_env_vars = find_all_env_vars(&modules_path, &rendered_templates_cache)?;
+        let scoped_vars = determine_env_var_scopes(&project_env_vars, &manifest)?;
+
+        let mut values_by_project: HashMap<&str, HashMap<String, String>> = HashMap::new();
+        for project_name in project_env_vars.keys() {
+            values_by_project.insert(
+                project_name,
+                load_target_env_values(
+                    &modules_path.join(project_name),
+                    &workspace_root,
+                    env_name,
+                )?,
+            );
+        }
+
+        let exports = build_scope_exports(
+            &manifest.app_name,
+            environment,
+            &scoped_vars,
+            &values_by_project,
+            manifest.env_schema.as_ref(),
+        );
+
+        let sealed: Vec<&str> = exports
+            .iter()
+            .flat_map(|export| export.secrets.iter().chain(export.config.iter()))
+            .filter(|(_, value)| is_sealed_value(value))
+            .map(|(name, _)| name.as_str())
+            .collect();
+        if !sealed.is_empty() {
+            bail!(
+                "Cannot export encrypted values without an age identity: {}",
+                sealed.join(", ")
+            );
+        }
+
+        let mut stdout = StandardStream::stdout(ColorChoice::Always);
+
+        let mut unresolved: Vec<&str> = scoped_vars
+            .iter()
+            .filter(|var| {
+                !exports.iter().any(|export| {
+                    export.config.contains_key(&var.name) || export.secrets.contains_key(&var.name)
+                })
+            })
+            .map(|var| var.name.as_str())
+            .collect();
+        unresolved.sort();
+        if !unresolved.is_empty() {
+            stdout.set_color(ColorSpec::new().set_fg(Some(Color::Yellow)))?;
+            writeln!(
+                stdout,
+                "[WARN] {} variables have no value in {}: {}",
+                unresolved.len(),
+                environment,
+                unresolved.join(", ")
+            )?;
+            stdout.reset()?;
+        }
+
+        fs::create_dir_all(&output_dir)
+            .with_context(|| format!("Failed to create {}", output_dir.display()))?;
+
+        for export in &exports {
+            for (file_name, contents) in
+                render_scope_export(export, format, &manifest.app_name, namespace)?
+            {
+                let path = output_dir.join(&file_name);
+                fs::write(&path, contents)
+                    .with_context(|| format!("Failed to write {}", path.display()))?;
+                stdout.set_color(ColorSpec::new().set_fg(Some(Color::Green)))?;
+                write!(stdout, "[OK] ")?;
+                stdout.reset()?;
+                writeln!(
+                    stdout,
+                    "{} ({} config, {} secrets)",
+                    path.display(),
+                    export.config.len(),
+                    export.secrets.len()
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+
+    fn scoped(
+        name: &str,
+        scope: EnvironmentVariableScope,
+        scope_id: Option<&str>,
+        used_by: &[&str],
+    ) -> ScopedEnvVar {
+        ScopedEnvVar {
+            name: name.to_string(),
+            scope,
+            scope_id: scope_id.map(str::to_string),
+            used_by: used_by.iter().map(|project| project.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_load_target_env_values() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        let project = root.join("billing");
+        fs::create_dir_all(&project).unwrap();
+        fs::write(root.join(".env"), "PORT=8000\nOTEL_LEVEL=info\n").unwrap();
+        fs::write(root.join(".env.production"), "OTEL_LEVEL=warn\n").unwrap();
+        fs::write(root.join(".env.local"), "OTEL_LEVEL=debug\n").unwrap();
+        fs::write(project.join(".env"), "PORT=8001\n").unwrap();
+        fs::write(project.join(".env.production"), "PORT=80\n").unwrap();
+
+        let production = load_target_env_values(&project, root, Some("production")).unwrap();
+        assert_eq!(production.get("PORT"), Some(&"80".to_string()));
+        assert_eq!(production.get("OTEL_LEVEL"), Some(&"warn".to_string()));
+
+        let staging = load_target_env_values(&project, root, Some("staging")).unwrap();
+        assert_eq!(staging.get("PORT"), Some(&"8001".to_string()));
+        assert_eq!(staging.get("OTEL_LEVEL"), Some(&"info".to_string()));
+    }
+
+    #[test]
+    fn test_build_and_render_scope_exports() {
+        let scoped_vars = vec![
+            scoped(
+                "OTEL_LEVEL",
+                EnvironmentVariableScope::Application,
+                None,
+                &["billing", "iam"],
+            ),
+            scoped(
+                "HMAC_SECRET_KEY",
+                EnvironmentVariableScope::Application,
+                None,
+                &["billing", "iam"],
+            ),
+            scoped(
+                "STRIPE_API_KEY",
+                EnvironmentVariableScope::Service,
+                Some("billing"),
+                &["billing"],
+            ),
+            scoped(
+                "REDIS_URL",
+                EnvironmentVariableScope::Service,
+                Some("billing"),
+                &["billing"],
+            ),
+        ];
+        let billing = HashMap::from([
+            ("OTEL_LEVEL".to_string(), "info".to_string()),
+            ("HMAC_SECRET_KEY".to_string(), "c2VjcmV0".to_string()),
+            ("STRIPE_API_KEY".to_string(), "sk_live_1".to_string()),
+            ("REDIS_URL".to_string(), "redis://cache:6379".to_string()),
+        ]);
+        let values_by_project = HashMap::from([("billing", billing)]);
+        let exports = build_scope_exports(
+            "My App",
+            "production",
+            &scoped_vars,
+            &values_by_project,
+            None,
+        );
+
+        assert_eq!(exports.len(), 2);
+        assert_eq!(exports[0].name, "my-app-application");
+        assert_eq!(
+            exports[0].config.keys().collect::<Vec<_>>(),
+            vec!["OTEL_LEVEL"]
+        );
+        assert_eq!(
+            exports[0].secrets.keys().collect::<Vec<_>>(),
+            vec!["HMAC_SECRET_KEY"]
+        );
+        assert_eq!(exports[1].name, "my-app-billing");
+        assert_eq!(
+            exports[1].secrets.keys().collect::<Vec<_>>(),
+            vec!["STRIPE_API_KEY"]
+        );
+
+        let secret_files =
+            render_scope_export(&exports[1], ExportFormat::K8sSecret, "My App", Some("prod"))
+                .unwrap();
+        assert_eq!(secret_files.len(), 1);
+        assert_eq!(secret_files[0].0, "billing.secret.yaml");
+        assert_eq!(
+            secret_files[0].1,
+            "apiVersion: v1\n\
+             kind: Secret\n\
+             metadata:\n  \
+               name: my-app-billing\n  \
+               namespace: prod\n  \
+               labels:\n    \
+                 app.kubernetes.io/component: billing\n    \
+                 app.kubernetes.io/managed-by: forklaunch\n    \
+                 app.kubernetes.io/part-of: my-app\n    \
+                 forklaunch.dev/environment: production\n\
+             type: Opaque\n\
+             data:\n  \
+               STRIPE_API_KEY: c2tfbGl2ZV8x\n"
+        );
+
+        let env_files =
+            render_scope_export(&exports[0], ExportFormat::EnvFile, "My App", None).unwrap();
+        assert_eq!(
+            env_files,
+            vec![
+                (
+                    "application.env".to_string(),
+                    "OTEL_LEVEL=info\n".to_string()
+                ),
+                (
+                    "application.secret.env".to_string(),
+                    "HMAC_SECRET_KEY=c2VjcmV0\n".to_string()
+                ),
+            ]
+        );
+    }
+}
@@ -0,0 +1,277 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    io::Write,
+};
+
+use anyhow::Result;
+use clap::{Arg, ArgAction, ArgMatches, Command};
+use termcolor::{Color, ColorChoice, ColorSpec, StandardStream, WriteColor};
+
+use crate::{
+    CliCommand,
+    core::{
+        ast::infrastructure::env::{EnvVarUsage, find_all_env_vars},
+        command::command,
+        env::{find_workspace_root, get_modules_path},
+        env_schema::{EnvValueType, EnvVarSchema},
+        env_template::categorize_env_var,
+        manifest::application::ApplicationManifestData,
+        rendered_template::RenderedTemplatesCache,
+    },
+    release::{
+        create::{infer_component_property, is_cli_generated_key_var},
+        manifest_generator::EnvironmentVariableComponentProperty,
+    },
+};
+
+/// Secrets the CLI generates as 32 random bytes must stay at least that long.
+const GENERATED_SECRET_MIN_BYTES: usize = 32;
+
+fn is_secret_name(name: &str) -> bool {
+    if name.ends_with("_ID") || name.ends_with("_URL") || name.ends_with("_PATH") {
+        return false;
+    }
+    name.contains("SECRET")
+        || name.contains("TOKEN")
+        || name.contains("PASSWORD")
+        || name.ends_with("_KEY")
+}
+
+fn infer_value_type(name: &str, usage_hint: Option<EnvValueType>) -> EnvValueType {
+    let property = infer_component_property(name);
+
+    match usage_hint {
+        Some(EnvValueType::Number)
+            if property == Some(EnvironmentVariableComponentProperty::Port) =>
+        {
+            return EnvValueType::Port;
+        }
+        Some(hint) => return hint,
+        None => {}
+    }
+
+    if name.ends_with("_PATH") {
+        return EnvValueType::String;
+    }
+    if is_secret_name(name) {
+        return EnvValueType::Secret;
+    }
+    if name.ends_with("_ORIGINS") || name.ends_with("_BROKERS") {
+        return EnvValueType::List;
+    }
+
+    match property {
+        Some(EnvironmentVariableComponentProperty::Port) => EnvValueType::Port,
+        Some(
+            EnvironmentVariableComponentProperty::Url
+            | EnvironmentVariableComponentProperty::ConnectionString
+            | EnvironmentVariableComponentProperty::Endpoint,
+        ) => EnvValueType::Url,
+        Some(
+            EnvironmentVariableComponentProperty::Host
+            | EnvironmentVariableComponentProperty::Hostname
+            | EnvironmentVariableComponentProperty::Fqdn,
+        ) => EnvValueType::Host,
+        _ => EnvValueType::String,
+    }
+}
+
+/// Infers the schema of one variable from every place a project reads it.
+/// A variable is only optional when every read supplies a fallback.
+pub(crate) fn infer_env_var_schema(name: &str, usages: &[&EnvVarUsage]) -> EnvVarSchema {
+    let usage_hint = usages.iter().find_map(|usage| usage.value_type);
+    let value_type = infer_value_type(name, usage_hint);
+
+    let mut schema = EnvVarSchema::new(name, categorize_env_var(name), value_type);
+    schema.required = !usages.iter().all(|usage| usage.optional);
+    schema.default = usages.iter().find_map(|usage| usage.default_value.clone());
+
+    if value_type == EnvValueType::Secret
+        && (is_cli_generated_key_var(name) || name.contains("HMAC") || name.contains("JWT"))
+    {
+        schema.min_length = Some(GENERATED_SECRET_MIN_BYTES);
+    }
+
+    schema
+}
+
+/// Builds each project's env schema from its `getEnvVar` usages, then applies
+/// the `[env_schema.<NAME>]` overrides from the manifest.
+pub(crate) fn build_project_schemas(
+    project_env_vars: &HashMap<String, Vec<EnvVarUsage>>,
+    manifest: &ApplicationManifestData,
+) -> BTreeMap<String, Vec<EnvVarSchema>> {
+    let mut schemas = BTreeMap::new();
+
+    for (project_name, usages) in project_env_vars {
+        let mut by_name: BTreeMap<&str, Vec<&EnvVarUsage>> = BTreeMap::new();
+        for usage in usages {
+            by_name.entry(&usage.var_name).or_default().push(usage);
+        }
+
+        let project_schema = by_name
+            .into_iter()
+            .map(|(name, usages)| {
+                let mut schema = infer_env_var_schema(name, &usages);
+                if let Some(schema_override) = manifest
+                    .env_schema
+                    .as_ref()
+                    .and_then(|overrides| overrides.get(name))
+                    .filter(|schema_override| schema_override.applies_to(project_name))
+                {
+                    schema.apply_override(schema_override);
+                }
+                schema
+            })
+            .collect();
+
+        schemas.insert(project_name.clone(), project_schema);
+    }
+
+    schemas
+}
+
+/// The schema `project_name` uses for `name`, inferred from the name alone
+/// when the project doesn't read the variable.
+pub(crate) fn find_project_schema(
+    schemas: &BTreeMap<String, Vec<EnvVarSchema>>,
+    project_name: &str,
+    name: &str,
+) -> EnvVarSchema {
+    schemas
+        .get(project_name)
+        .and_then(|schema| schema.iter().find(|var| var.name == name))
+        .cloned()
+        .unwrap_or_else(|| infer_env_var_schema(name, &[]))
+}
+
+#[derive(Debug)]
+pub(crate) struct SchemaCommand;
+
+impl SchemaCommand {
+    pub(crate) fn new() -> Self {
+        Self
+    }
+}
+
+impl CliCommand for SchemaCommand {
+    fn command(&self) -> Command {
+        command(
+            "schema",
+            "Show the env schema inferred for each workspace project",
+        )
+        .long_about("Shows the type, requiredness and constraints inferred for every environment variable read through getEnvVar, after applying [env_schema.<NAME>] overrides from the manifest")
+        .arg(
+            Arg::new("base_path")
+                .short('p')
+                .long("path")
+                .help("The application path"),
+        )
+        .arg(
+            Arg::new("json")
+                .long("json")
+                .action(ArgAction::SetTrue)
+                .help("Print the schema as JSON"),
+        )
+    }
+
+    fn handler(&self, matches: &ArgMatches) -> Result<()> {
+        let (app_root, manifest) = crate::core::validate::require_manifest(matches)?;
+        let workspace_root = find_workspace_root(&app_root)?;
+        let modules_path = get_modules_path(&workspace_root)?;
+
+        let rendered_templates_cache = RenderedTemplatesCache::new();
+        let project_env_vars = find_all_env_vars(&modules_path, &rendered_templates_cache)?;
+        let schemas = build_project_schemas(&project_env_vars, &manifest);
+
+        if matches.get_flag("json") {
+            println!("{}", serde_json::to_string_pretty(&schemas)?);
+            return Ok(());
+        }
+
+        let mut stdout = StandardStream::stdout(ColorChoice::Always);
+        for (project_name, schema) in &schemas {
+            stdout.set_color(ColorSpec::new().set_fg(Some(Color::Cyan)).set_bold(true))?;
+            writeln!(stdout, "\n{} ({} variables)", project_name, schema.len())?;
+            stdout.reset()?;
+
+            for var in schema {
+                write!(
+                    stdout,
+                    "  {:<40} {:<8}",
+                    var.name,
+                    var.value_type.to_string()
+                )?;
+                if var.required {
+                    write!(stdout, " required")?;
+                } else {
+                    write!(stdout, " optional")?;
+                }
+                if let Some(default) = &var.default {
+                    write!(stdout, " default={}", default)?;
+                }
+                if let Some(min_length) = var.min_length {
+                    write!(stdout, " min_length={}", min_length)?;
+                }
+                if let Some(allowed) = &var.allowed {
+                    write!(stdout, " enum={}", allowed.join("|"))?;
+                }
+                writeln!(stdout)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn usage(name: &str, value_type: Option<EnvValueType>, optional: bool) -> EnvVarUsage {
+        EnvVarUsage {
+            var_name: name.to_string(),
+            line: 0,
+            column: 0,
+            value_type,
+            optional,
+            default_value: None,
+        }
+    }
+
+    #[test]
+    fn test_infer_env_var_schema() {
+        let port = usage("PORT", Some(EnvValueType::Number), false);
+        assert_eq!(
+            infer_env_var_schema("PORT", &[&port]).value_type,
+            EnvValueType::Port
+        );
+
+        let redis = usage("REDIS_URL", None, false);
+        assert_eq!(
+            infer_env_var_schema("REDIS_URL", &[&redis]).value_type,
+            EnvValueType::Url
+        );
+
+        let docs = usage("DOCS_PATH", None, true);
+        let docs_schema = infer_env_var_schema("DOCS_PATH", &[&docs]);
+        assert_eq!(docs_schema.value_type, EnvValueType::String);
+        assert!(!docs_schema.required);
+
+        let hmac = usage("HMAC_SECRET_KEY", None, false);
+        let hmac_schema = infer_env_var_schema("HMAC_SECRET_KEY", &[&hmac]);
+        assert_eq!(hmac_schema.value_type, EnvValueType::Secret);
+        assert_eq!(hmac_schema.min_length, Some(32));
+
+        let host = usage("DB_HOST", None, false);
+        assert_eq!(
+            infer_env_var_schema("DB_HOST", &[&host]).value_type,
+            EnvValueType::Host
+        );
+        let brokers = usage("KAFKA_BROKERS", None, false);
+        assert_eq!(
+            infer_env_var_schema("KAFKA_BROKERS", &[&brokers]).value_type,
+            EnvValueType::List
+        );
+    }
+}
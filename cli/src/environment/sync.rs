@@ -1,4 +1,8 @@
-use std::{collections::HashMap, io::Write, path::Path};
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    io::Write,
+    path::{Path, PathBuf},
+};
 
 use anyhow::{Context, Result};
 use clap::{ArgMatches, Command};
@@ -8,14 +12,22 @@ use crate::{
     CliCommand,
     constants::ERROR_FAILED_TO_PARSE_MANIFEST,
     core::{
-        ast::infrastructure::env::find_all_env_vars,
+        ast::{
+            analysis::{AnalysisScope, ModulesAnalysis},
+            infrastructure::env::EnvVarUsage,
+        },
         env::{
-            add_env_vars_to_file, find_workspace_root, get_modules_path, get_target_env_file,
-            is_env_var_defined,
+            add_env_vars_to_file, fill_blank_env_vars, find_workspace_root, get_modules_path,
+            get_target_env_file, is_env_var_defined, resolve_env_values,
+        },
+        env_generate::{
+            ComposeEndpoints, GeneratedEnvValue, classify_generated_var, generate_env_values,
         },
         manifest::application::ApplicationManifestData,
         rendered_template::{RenderedTemplatesCache, write_rendered_templates},
+        secrets::is_encrypted_env_file,
     },
+    environment::{schema::build_project_schemas, validate::is_env_template},
 };
 
 #[derive(Debug)]
@@ -31,7 +43,7 @@ impl CliCommand for SyncCommand {
     fn command(&self) -> Command {
         Command::new("sync")
             .about("Sync missing environment variables by adding them with blank values")
-            .long_about("Uses validate to find missing environment variables and adds them to appropriate .env files with blank values. Respects the .env hierarchy by placing common variables in root .env.local")
+            .long_about("Uses validate to find missing environment variables and adds them to appropriate .env files with blank values. Respects the .env hierarchy by placing common variables in root .env.local. With --generate, local secrets, keys and docker-compose connection URLs are filled in instead")
             .arg(
                 clap::Arg::new("dry-run")
                     .long("dry-run")
@@ -39,11 +51,19 @@ impl CliCommand for SyncCommand {
                     .help("Show what would be done without making changes")
                     .action(clap::ArgAction::SetTrue)
             )
+            .arg(
+                clap::Arg::new("generate")
+                    .long("generate")
+                    .short('g')
+                    .help("Generate local secrets, keypairs and docker-compose connection URLs for missing or blank variables")
+                    .action(clap::ArgAction::SetTrue)
+            )
     }
 
     fn handler(&self, matches: &ArgMatches) -> Result<()> {
         let mut stdout = StandardStream::stdout(ColorChoice::Always);
         let dry_run = matches.get_flag("dry-run");
+        let generate = matches.get_flag("generate");
 
         if dry_run {
             stdout.set_color(ColorSpec::new().set_fg(Some(Color::Yellow)))?;
@@ -62,8 +82,19 @@ impl CliCommand for SyncCommand {
         writeln!(stdout, "Workspace: {}", workspace_root.display())?;
         writeln!(stdout, "Modules path: {}", modules_path.display())?;
 
+        let manifest_path = workspace_root.join(".forklaunch").join("manifest.toml");
+        let manifest_content = std::fs::read_to_string(&manifest_path)
+            .with_context(|| format!("Failed to read manifest: {}", manifest_path.display()))?;
+        let manifest_data: ApplicationManifestData =
+            toml::from_str(&manifest_content).with_context(|| ERROR_FAILED_TO_PARSE_MANIFEST)?;
+
         let rendered_templates_cache = RenderedTemplatesCache::new();
-        let project_env_vars = find_all_env_vars(&modules_path, &rendered_templates_cache)?;
+        let project_env_vars = ModulesAnalysis::analyze_cached(
+            &modules_path,
+            &rendered_templates_cache,
+            AnalysisScope::Registrations,
+        )?
+        .env_vars();
 
         if project_env_vars.is_empty() {
             stdout.set_color(ColorSpec::new().set_fg(Some(Color::Yellow)))?;
@@ -94,14 +125,69 @@ impl CliCommand for SyncCommand {
             }
         }
 
-        if missing_vars_by_project.is_empty() {
+        let (blank_vars, existing_values) = if generate {
+            find_blank_env_vars(&project_env_vars, &workspace_root, &modules_path)?
+        } else {
+            (BTreeMap::new(), HashMap::new())
+        };
+
+        if missing_vars_by_project.is_empty() && blank_vars.is_empty() {
             stdout.set_color(ColorSpec::new().set_fg(Some(Color::Green)))?;
             writeln!(stdout, "No missing environment variables found!")?;
             stdout.reset()?;
             return Ok(());
         }
 
-        let sync_plan = create_sync_plan(&missing_vars_by_project, &workspace_root, &modules_path)?;
+        let mut sync_plan =
+            create_sync_plan(&missing_vars_by_project, &workspace_root, &modules_path)?;
+
+        if generate {
+            let mut var_projects: BTreeMap<String, Vec<String>> = BTreeMap::new();
+            for (project_name, vars) in &missing_vars_by_project {
+                for var_name in vars {
+                    var_projects
+                        .entry(var_name.clone())
+                        .or_default()
+                        .push(project_name.clone());
+                }
+            }
+            for (project_name, var_name) in blank_vars.values().flatten() {
+                var_projects
+                    .entry(var_name.clone())
+                    .or_default()
+                    .push(project_name.clone());
+            }
+
+            sync_plan.values = generate_sync_values(
+                &var_projects,
+                &project_env_vars,
+                &manifest_data,
+                &workspace_root,
+                &existing_values,
+                &mut stdout,
+            )?;
+            sync_plan.blank_vars = blank_vars
+                .into_iter()
+                .map(|(env_file, vars)| {
+                    let vars: Vec<String> = vars
+                        .into_iter()
+                        .map(|(_, var_name)| var_name)
+                        .filter(|var_name| sync_plan.values.contains_key(var_name))
+                        .collect::<BTreeSet<_>>()
+                        .into_iter()
+                        .collect();
+                    (env_file, vars)
+                })
+                .filter(|(_, vars)| !vars.is_empty())
+                .collect();
+
+            if missing_vars_by_project.is_empty() && sync_plan.blank_vars.is_empty() {
+                stdout.set_color(ColorSpec::new().set_fg(Some(Color::Green)))?;
+                writeln!(stdout, "No missing environment variables found!")?;
+                stdout.reset()?;
+                return Ok(());
+            }
+        }
 
         display_sync_plan(&sync_plan, &mut stdout)?;
 
@@ -109,12 +195,6 @@ impl CliCommand for SyncCommand {
             execute_sync_plan(&sync_plan, &mut stdout)?;
 
             // Generate .env.template files
-            let manifest_path = workspace_root.join(".forklaunch").join("manifest.toml");
-            let manifest_content = std::fs::read_to_string(&manifest_path)
-                .with_context(|| format!("Failed to read manifest: {}", manifest_path.display()))?;
-            let manifest_data: ApplicationManifestData =
-                toml::from_str(&manifest_content)
-                    .with_context(|| ERROR_FAILED_TO_PARSE_MANIFEST)?;
             let mut env_template_cache = RenderedTemplatesCache::new();
             crate::core::env_template::generate_env_templates(
                 &modules_path,
@@ -131,10 +211,13 @@ impl CliCommand for SyncCommand {
             stdout.set_color(ColorSpec::new().set_fg(Some(Color::Green)))?;
             writeln!(stdout, "\nEnvironment sync completed!")?;
             stdout.reset()?;
-            writeln!(
-                stdout,
-                "Remember to fill in the actual values for the added variables."
-            )?;
+            if sync_plan.blank_count() > 0 {
+                writeln!(
+                    stdout,
+                    "Remember to fill in the actual values for the {} variables left blank.",
+                    sync_plan.blank_count()
+                )?;
+            }
         } else {
             stdout.set_color(ColorSpec::new().set_fg(Some(Color::Cyan)))?;
             writeln!(
@@ -154,6 +237,128 @@ struct SyncPlan {
     project_vars: HashMap<String, Vec<String>>,
     root_env_file: std::path::PathBuf,
     project_env_files: HashMap<String, std::path::PathBuf>,
+    /// Values filled in by `--generate`; anything else is added blank.
+    values: HashMap<String, GeneratedEnvValue>,
+    /// Existing blank entries that `--generate` fills in place.
+    blank_vars: BTreeMap<PathBuf, Vec<String>>,
+}
+
+impl SyncPlan {
+    fn value_of(&self, var_name: &str) -> String {
+        self.values
+            .get(var_name)
+            .map(|generated| generated.value.clone())
+            .unwrap_or_default()
+    }
+
+    fn blank_count(&self) -> usize {
+        self.root_vars
+            .iter()
+            .chain(self.project_vars.values().flatten())
+            .filter(|var_name| !self.values.contains_key(*var_name))
+            .count()
+    }
+}
+
+/// Blank variable entries keyed by the env file that defines them, plus every
+/// non-empty value already configured.
+type BlankEnvVars = (
+    BTreeMap<PathBuf, Vec<(String, String)>>,
+    HashMap<String, String>,
+);
+
+fn find_blank_env_vars(
+    project_env_vars: &HashMap<String, Vec<EnvVarUsage>>,
+    workspace_root: &Path,
+    modules_path: &Path,
+) -> Result<BlankEnvVars> {
+    let mut blank_vars: BTreeMap<PathBuf, Vec<(String, String)>> = BTreeMap::new();
+    let mut existing_values = HashMap::new();
+
+    for (project_name, env_vars) in project_env_vars {
+        let resolved = resolve_env_values(&modules_path.join(project_name), workspace_root)?;
+
+        for env_var in env_vars {
+            if let Some((value, env_file)) = resolved.get(&env_var.var_name)
+                && value.is_empty()
+                && !is_env_template(env_file)
+                && !is_encrypted_env_file(env_file)
+            {
+                blank_vars
+                    .entry(env_file.clone())
+                    .or_default()
+                    .push((project_name.clone(), env_var.var_name.clone()));
+            }
+        }
+
+        for (var_name, (value, _)) in resolved {
+            if !value.is_empty() {
+                existing_values.insert(var_name, value);
+            }
+        }
+    }
+
+    Ok((blank_vars, existing_values))
+}
+
+/// Derives connection values from docker-compose and generates secrets and
+/// keys for everything else that can be created locally.
+fn generate_sync_values(
+    var_projects: &BTreeMap<String, Vec<String>>,
+    project_env_vars: &HashMap<String, Vec<EnvVarUsage>>,
+    manifest_data: &ApplicationManifestData,
+    workspace_root: &Path,
+    existing_values: &HashMap<String, String>,
+    stdout: &mut StandardStream,
+) -> Result<HashMap<String, GeneratedEnvValue>> {
+    let schemas = build_project_schemas(project_env_vars, manifest_data);
+    let compose = match ComposeEndpoints::load(workspace_root, manifest_data) {
+        Ok(compose) => compose,
+        Err(err) => {
+            stdout.set_color(ColorSpec::new().set_fg(Some(Color::Yellow)))?;
+            writeln!(
+                stdout,
+                "[WARN] Skipping docker-compose connection values: {:#}",
+                err
+            )?;
+            stdout.reset()?;
+            None
+        }
+    };
+
+    let mut values = HashMap::new();
+    let mut requests = BTreeMap::new();
+
+    for (var_name, projects) in var_projects {
+        let project_name = &projects[0];
+
+        if let Some(value) = compose
+            .as_ref()
+            .and_then(|compose| compose.local_connection_value(project_name, var_name))
+        {
+            values.insert(
+                var_name.clone(),
+                GeneratedEnvValue {
+                    value,
+                    description: "docker-compose connection".to_string(),
+                },
+            );
+            continue;
+        }
+
+        let schema = schemas
+            .get(project_name)
+            .and_then(|schema| schema.iter().find(|var| &var.name == var_name));
+        let sample = compose
+            .as_ref()
+            .and_then(|compose| compose.sample_value(project_name, var_name));
+        if let Some(kind) = classify_generated_var(var_name, schema, sample) {
+            requests.insert(var_name.clone(), kind);
+        }
+    }
+
+    values.extend(generate_env_values(&requests, existing_values)?);
+    Ok(values)
 }
 
 fn create_sync_plan(
@@ -201,6 +406,8 @@ fn create_sync_plan(
         project_vars: project_specific_vars,
         root_env_file,
         project_env_files,
+        values: HashMap::new(),
+        blank_vars: BTreeMap::new(),
     })
 }
 
@@ -218,9 +425,7 @@ fn display_sync_plan(plan: &SyncPlan, stdout: &mut StandardStream) -> Result<()>
         )?;
         writeln!(stdout, "   {}", plan.root_env_file.display())?;
         for var_name in &plan.root_vars {
-            stdout.set_color(ColorSpec::new().set_fg(Some(Color::Cyan)))?;
-            writeln!(stdout, "   - {}", var_name)?;
-            stdout.reset()?;
+            display_planned_var(plan, var_name, stdout)?;
         }
     }
 
@@ -231,24 +436,48 @@ fn display_sync_plan(plan: &SyncPlan, stdout: &mut StandardStream) -> Result<()>
                 writeln!(stdout, "\n   {} ({} variables):", project_name, vars.len())?;
                 writeln!(stdout, "   {}", env_file.display())?;
                 for var_name in vars {
-                    stdout.set_color(ColorSpec::new().set_fg(Some(Color::Cyan)))?;
-                    writeln!(stdout, "   - {}", var_name)?;
-                    stdout.reset()?;
+                    display_planned_var(plan, var_name, stdout)?;
                 }
             }
         }
     }
 
+    if !plan.blank_vars.is_empty() {
+        writeln!(stdout, "\nBlank variables to fill:")?;
+        for (env_file, vars) in &plan.blank_vars {
+            writeln!(stdout, "\n   {}", env_file.display())?;
+            for var_name in vars {
+                display_planned_var(plan, var_name, stdout)?;
+            }
+        }
+    }
+
     let total_vars =
         plan.root_vars.len() + plan.project_vars.values().map(|v| v.len()).sum::<usize>();
 
     stdout.set_color(ColorSpec::new().set_fg(Some(Color::Yellow)))?;
     writeln!(stdout, "\nTotal variables to add: {}", total_vars)?;
+    if !plan.values.is_empty() {
+        writeln!(stdout, "Generated values: {}", plan.values.len())?;
+    }
     stdout.reset()?;
 
     Ok(())
 }
 
+fn display_planned_var(plan: &SyncPlan, var_name: &str, stdout: &mut StandardStream) -> Result<()> {
+    stdout.set_color(ColorSpec::new().set_fg(Some(Color::Cyan)))?;
+    write!(stdout, "   - {}", var_name)?;
+    stdout.reset()?;
+    if let Some(generated) = plan.values.get(var_name) {
+        stdout.set_color(ColorSpec::new().set_fg(Some(Color::Green)))?;
+        write!(stdout, " (generated: {})", generated.description)?;
+        stdout.reset()?;
+    }
+    writeln!(stdout)?;
+    Ok(())
+}
+
 fn execute_sync_plan(plan: &SyncPlan, stdout: &mut StandardStream) -> Result<()> {
     stdout.set_color(ColorSpec::new().set_fg(Some(Color::Cyan)))?;
     writeln!(stdout, "\nExecuting sync plan...")?;
@@ -263,7 +492,7 @@ fn execute_sync_plan(plan: &SyncPlan, stdout: &mut StandardStream) -> Result<()>
 
         let mut root_vars_map = HashMap::new();
         for var_name in &plan.root_vars {
-            root_vars_map.insert(var_name.clone(), String::new());
+            root_vars_map.insert(var_name.clone(), plan.value_of(var_name));
         }
 
         add_env_vars_to_file(&plan.root_env_file, &root_vars_map)?;
@@ -287,7 +516,7 @@ fn execute_sync_plan(plan: &SyncPlan, stdout: &mut StandardStream) -> Result<()>
 
             let mut project_vars_map = HashMap::new();
             for var_name in vars {
-                project_vars_map.insert(var_name.clone(), String::new());
+                project_vars_map.insert(var_name.clone(), plan.value_of(var_name));
             }
 
             add_env_vars_to_file(env_file, &project_vars_map)?;
@@ -297,5 +526,22 @@ fn execute_sync_plan(plan: &SyncPlan, stdout: &mut StandardStream) -> Result<()>
         }
     }
 
+    for (env_file, vars) in &plan.blank_vars {
+        let filled_vars: HashMap<String, String> = vars
+            .iter()
+            .map(|var_name| (var_name.clone(), plan.value_of(var_name)))
+            .collect();
+
+        fill_blank_env_vars(env_file, &filled_vars)?;
+        stdout.set_color(ColorSpec::new().set_fg(Some(Color::Green)))?;
+        writeln!(
+            stdout,
+            "   Filled {} blank variables in {}",
+            vars.len(),
+            env_file.display()
+        )?;
+        stdout.reset()?;
+    }
+
     Ok(())
 }
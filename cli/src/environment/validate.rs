@@ -1,19 +1,33 @@
-use std::{collections::HashMap, io::Write, path::Path};
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    io::Write,
+    path::{Path, PathBuf},
+};
 
 use anyhow::Result;
-use clap::{ArgMatches, Command};
+use clap::{Arg, ArgMatches, Command};
 use termcolor::{Color, ColorChoice, ColorSpec, StandardStream, WriteColor};
 
 use crate::{
     CliCommand,
     core::{
-        ast::infrastructure::env::{EnvVarUsage, find_all_env_vars},
-        env::{find_workspace_root, get_modules_path, is_env_var_defined},
+        ast::infrastructure::env::{EnvVarUsage, find_all_env_vars, find_env_var_references},
+        env::{
+            find_env_files, find_workspace_root, get_modules_path, is_env_var_defined,
+            load_env_file, resolve_env_values,
+        },
+        env_schema::EnvVarSchema,
         env_scope::{EnvironmentVariableScope, ScopedEnvVar, determine_env_var_scopes},
         rendered_template::RenderedTemplatesCache,
+        manifest::application::ApplicationManifestData,
+        secrets::{ENCRYPTED_ENV_FILE_NAME, EncryptedEnvFile, is_sealed_value, load_identities},
     },
+    environment::schema::build_project_schemas,
 };
 
+/// Read by Node or tooling directly rather than through `getEnvVar`.
+const RUNTIME_ENV_VARS: &[&str] = &["NODE_ENV"];
+
 #[derive(Debug)]
 pub(crate) struct ValidateCommand;
 
@@ -27,7 +41,13 @@ impl CliCommand for ValidateCommand {
     fn command(&self) -> Command {
         Command::new("validate")
             .about("Check all workspace projects for missing environment variables")
-            .long_about("Validates that all environment variables referenced in registrations.ts files have corresponding entries in .env files")
+            .long_about("Validates that all environment variables referenced in registrations.ts files have corresponding entries in .env files, that their values match the inferred env schema, and reports variables defined in .env files that nothing reads")
+            .arg(
+                Arg::new("base_path")
+                    .short('p')
+                    .long("path")
+                    .help("The application path"),
+            )
     }
 
     fn handler(&self, matches: &ArgMatches) -> Result<()> {
@@ -61,18 +81,33 @@ impl CliCommand for ValidateCommand {
             writeln!(stdout, "  - {}", project_name)?;
         }
 
+        report_locked_secrets(
+            &workspace_root,
+            project_env_vars
+                .keys()
+                .map(|project_name| modules_path.join(project_name)),
+            &mut stdout,
+        )?;
+
         let scoped_env_vars = determine_env_var_scopes(&project_env_vars, &manifest)?;
 
+        let schemas = build_project_schemas(&project_env_vars, &manifest);
+
         let mut validation_results = ValidationResults::new();
 
-        for (project_name, env_vars) in &project_env_vars {
+        for (project_name, schema) in &schemas {
             let project_path = modules_path.join(project_name);
-            let project_result = validate_project(&project_path, env_vars)?;
+            let project_result =
+                validate_project(&project_path, &project_env_vars[project_name], schema)?;
             validation_results.add_project_result(project_name.clone(), project_result);
         }
 
         display_validation_results(&validation_results, &scoped_env_vars, &mut stdout)?;
 
+        let violations = check_schema_violations(&schemas, &modules_path, &workspace_root)?;
+        let unused = find_unused_env_vars(&schemas, &manifest, &modules_path, &workspace_root)?;
+        display_schema_results(&violations, &unused, &mut stdout)?;
+
         analyze_env_hierarchy(
             &project_env_vars,
             &modules_path,
@@ -80,7 +115,7 @@ impl CliCommand for ValidateCommand {
             &mut stdout,
         )?;
 
-        if validation_results.has_missing_vars() {
+        if validation_results.has_missing_vars() || !violations.is_empty() {
             std::process::exit(1);
         }
 
@@ -88,6 +123,194 @@ impl CliCommand for ValidateCommand {
     }
 }
 
+/// Values in a `.env.enc` that no local identity can open still count as
+/// defined, so say when validation only checked their presence.
+fn report_locked_secrets(
+    workspace_root: &Path,
+    project_paths: impl Iterator<Item = PathBuf>,
+    stdout: &mut StandardStream,
+) -> Result<()> {
+    let identities = load_identities()?;
+
+    for dir in std::iter::once(workspace_root.to_path_buf()).chain(project_paths) {
+        let path = dir.join(ENCRYPTED_ENV_FILE_NAME);
+        if !path.exists() {
+            continue;
+        }
+        if EncryptedEnvFile::read(&path)?.decrypt(&identities)?.is_none() {
+            stdout.set_color(ColorSpec::new().set_fg(Some(Color::Yellow)))?;
+            writeln!(
+                stdout,
+                "[WARN] Cannot decrypt {}; its keys are checked for presence only",
+                path.display()
+            )?;
+            stdout.reset()?;
+        }
+    }
+
+    Ok(())
+}
+
+#[derive(Debug)]
+struct SchemaViolation {
+    project: String,
+    var_name: String,
+    file: PathBuf,
+    message: String,
+}
+
+#[derive(Debug)]
+struct UnusedEnvVar {
+    var_name: String,
+    file: PathBuf,
+}
+
+/// Checks the value each variable resolves to for a project against its
+/// schema. Values still sealed in `.env.enc` cannot be checked.
+fn check_schema_violations(
+    schemas: &BTreeMap<String, Vec<EnvVarSchema>>,
+    modules_path: &Path,
+    workspace_root: &Path,
+) -> Result<Vec<SchemaViolation>> {
+    let mut violations = Vec::new();
+
+    for (project_name, schema) in schemas {
+        let values = resolve_env_values(&modules_path.join(project_name), workspace_root)?;
+
+        for var in schema {
+            let Some((value, file)) = values.get(&var.name) else {
+                continue;
+            };
+            if is_sealed_value(value) {
+                continue;
+            }
+            for message in var.check(value) {
+                violations.push(SchemaViolation {
+                    project: project_name.clone(),
+                    var_name: var.name.clone(),
+                    file: file.clone(),
+                    message,
+                });
+            }
+        }
+    }
+
+    Ok(violations)
+}
+
+pub(crate) fn is_env_template(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| name.ends_with(".template") || name.ends_with(".example"))
+}
+
+/// Finds variables defined in a project's own env files that none of the
+/// project's sources read, and variables in the workspace root env files that
+/// no project reads.
+fn find_unused_env_vars(
+    schemas: &BTreeMap<String, Vec<EnvVarSchema>>,
+    manifest: &ApplicationManifestData,
+    modules_path: &Path,
+    workspace_root: &Path,
+) -> Result<Vec<UnusedEnvVar>> {
+    let allowed_unused = |var_name: &str, project_name: Option<&str>| {
+        RUNTIME_ENV_VARS.contains(&var_name)
+            || manifest
+                .env_schema
+                .as_ref()
+                .and_then(|overrides| overrides.get(var_name))
+                .filter(|schema_override| {
+                    project_name.is_none_or(|project| schema_override.applies_to(project))
+                })
+                .and_then(|schema_override| schema_override.allow_unused)
+                .unwrap_or(false)
+    };
+
+    let mut unused = Vec::new();
+    let mut scan = |dir: &Path, used: &HashSet<&str>, project_name: Option<&str>| -> Result<()> {
+        for file in find_env_files(dir)? {
+            if is_env_template(&file) {
+                continue;
+            }
+            let mut names: Vec<String> = load_env_file(&file)?.into_keys().collect();
+            names.sort();
+            for var_name in names {
+                if !used.contains(var_name.as_str()) && !allowed_unused(&var_name, project_name) {
+                    unused.push(UnusedEnvVar {
+                        var_name,
+                        file: file.clone(),
+                    });
+                }
+            }
+        }
+        Ok(())
+    };
+
+    let mut used_anywhere = HashSet::new();
+    for (project_name, schema) in schemas {
+        let project_path = modules_path.join(project_name);
+        let mut used = find_env_var_references(&project_path)?;
+        used.extend(schema.iter().map(|var| var.name.clone()));
+
+        if project_path != workspace_root {
+            let used: HashSet<&str> = used.iter().map(String::as_str).collect();
+            scan(&project_path, &used, Some(project_name))?;
+        }
+        used_anywhere.extend(used);
+    }
+    let used_anywhere: HashSet<&str> = used_anywhere.iter().map(String::as_str).collect();
+    scan(workspace_root, &used_anywhere, None)?;
+
+    Ok(unused)
+}
+
+fn display_schema_results(
+    violations: &[SchemaViolation],
+    unused: &[UnusedEnvVar],
+    stdout: &mut StandardStream,
+) -> Result<()> {
+    stdout.set_color(ColorSpec::new().set_fg(Some(Color::Cyan)))?;
+    writeln!(stdout, "\nSchema Validation")?;
+    stdout.reset()?;
+    writeln!(stdout, "{}", "=".repeat(50))?;
+
+    if violations.is_empty() {
+        stdout.set_color(ColorSpec::new().set_fg(Some(Color::Green)))?;
+        writeln!(stdout, "All defined values match the env schema")?;
+        stdout.reset()?;
+    }
+    for violation in violations {
+        stdout.set_color(ColorSpec::new().set_fg(Some(Color::Red)))?;
+        write!(stdout, "  [INVALID] {}", violation.var_name)?;
+        stdout.reset()?;
+        writeln!(
+            stdout,
+            " {} ({}, from {})",
+            violation.message,
+            violation.project,
+            violation.file.display()
+        )?;
+    }
+
+    if !unused.is_empty() {
+        stdout.set_color(ColorSpec::new().set_fg(Some(Color::Yellow)))?;
+        writeln!(stdout, "\nUnused Variables ({}):", unused.len())?;
+        stdout.reset()?;
+        for var in unused {
+            stdout.set_color(ColorSpec::new().set_fg(Some(Color::Yellow)))?;
+            write!(stdout, "  [UNUSED] {}", var.var_name)?;
+            stdout.reset()?;
+            writeln!(stdout, " in {}", var.file.display())?;
+        }
+        writeln!(
+            stdout,
+            "Set allow_unused = true under [env_schema.<NAME>] in the manifest to silence a variable"
+        )?;
+    }
+
+    Ok(())
+}
+
 #[derive(Debug)]
 struct ProjectValidationResult {
     missing_vars: Vec<EnvVarUsage>,
@@ -127,14 +350,20 @@ impl ValidationResults {
 fn validate_project(
     project_path: &Path,
     env_vars: &[EnvVarUsage],
+    schema: &[EnvVarSchema],
 ) -> Result<ProjectValidationResult> {
     let mut missing_vars = Vec::new();
     let mut defined_vars = Vec::new();
 
     for env_var in env_vars {
+        let required = schema
+            .iter()
+            .find(|var| var.name == env_var.var_name)
+            .is_none_or(|var| var.required);
+
         if is_env_var_defined(project_path, &env_var.var_name)? {
             defined_vars.push(env_var.var_name.clone());
-        } else {
+        } else if required {
             missing_vars.push(env_var.clone());
         }
     }
@@ -0,0 +1,195 @@
+use std::{
+    fs::read_to_string,
+    path::{Component, Path, PathBuf},
+};
+
+use anyhow::{Context, Result, bail};
+use clap::{ArgMatches, Command};
+use entity::EntityCommand;
+use schema::SchemaCommand;
+
+use crate::{
+    CliCommand,
+    constants::Database,
+    core::{
+        command::command,
+        manifest::{ProjectEntry, application::ApplicationManifestData},
+        rendered_template::RenderedTemplate,
+    },
+};
+
+pub(crate) mod entity;
+pub(crate) mod schema;
+
+/// The project a source file belongs to, with its directory.
+pub(crate) fn project_for_file<'a>(
+    app_root_path: &Path,
+    manifest_data: &'a ApplicationManifestData,
+    file: &Path,
+) -> Result<(&'a ProjectEntry, PathBuf)> {
+    let file = file
+        .canonicalize()
+        .with_context(|| format!("File not found: {}", file.display()))?;
+    let modules_path = app_root_path.join(&manifest_data.modules_path);
+
+    manifest_data
+        .projects
+        .iter()
+        .filter_map(|project| {
+            let project_path = modules_path.join(&project.name).canonicalize().ok()?;
+            file.starts_with(&project_path)
+                .then_some((project, project_path))
+        })
+        .next()
+        .with_context(|| {
+            format!(
+                "{} is not inside a project of this application",
+                file.display()
+            )
+        })
+}
+
+pub(crate) fn is_mongo_project(project: &ProjectEntry) -> bool {
+    project
+        .resources
+        .as_ref()
+        .and_then(|resources| resources.database.as_ref())
+        .and_then(|database| database.parse::<Database>().ok())
+        .is_some_and(|database| database == Database::MongoDB)
+}
+
+/// Resolves `..` and `.` without touching the filesystem.
+fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            Component::CurDir => {}
+            other => normalized.push(other),
+        }
+    }
+    normalized
+}
+
+/// Rewrites a relative import written in `from_directory` so that it works
+/// from `to_directory`.
+pub(crate) fn relocate_import(
+    specifier: &str,
+    from_directory: &Path,
+    to_directory: &Path,
+) -> String {
+    if !specifier.starts_with('.') {
+        return specifier.to_string();
+    }
+    let target = normalize(&from_directory.join(specifier));
+    let to_directory = normalize(to_directory);
+
+    let common = target
+        .components()
+        .zip(to_directory.components())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let ups = to_directory.components().count() - common;
+    let rest: Vec<String> = target
+        .components()
+        .skip(common)
+        .map(|c| c.as_os_str().to_string_lossy().to_string())
+        .collect();
+
+    let prefix = if ups == 0 {
+        ".".to_string()
+    } else {
+        vec![".."; ups].join("/")
+    };
+    format!("{}/{}", prefix, rest.join("/"))
+}
+
+/// Refuses to replace an existing file unless `force` is set.
+pub(crate) fn check_output(output: &Path, force: bool) -> Result<()> {
+    if output.exists() && !force {
+        bail!(
+            "{} already exists. Pass --force to overwrite it or --output to write elsewhere",
+            output.display()
+        );
+    }
+    Ok(())
+}
+
+/// Adds `export * from './<module>';` to an `index.ts` barrel if the barrel
+/// exists and does not export the module yet.
+pub(crate) fn barrel_export(index_path: &Path, module: &str) -> Option<RenderedTemplate> {
+    let content = read_to_string(index_path).ok()?;
+    if content.contains(&format!("'./{}'", module))
+        || content.contains(&format!("\"./{}\"", module))
+    {
+        return None;
+    }
+    let mut content = content.trim_end().to_string();
+    if !content.is_empty() {
+        content.push('\n');
+    }
+    content.push_str(&format!("export * from './{}';\n", module));
+    Some(RenderedTemplate {
+        path: index_path.to_path_buf(),
+        content,
+        context: None,
+    })
+}
+
+#[derive(Debug)]
+pub(crate) struct GenerateCommand {
+    schema: SchemaCommand,
+    entity: EntityCommand,
+}
+
+impl GenerateCommand {
+    pub(crate) fn new() -> Self {
+        Self {
+            schema: SchemaCommand::new(),
+            entity: EntityCommand::new(),
+        }
+    }
+}
+
+impl CliCommand for GenerateCommand {
+    fn command(&self) -> Command {
+        command(
+            "generate",
+            "Generate validator schemas from entities, or entities from schemas",
+        )
+        .subcommand_required(true)
+        .subcommand(self.schema.command())
+        .subcommand(self.entity.command())
+    }
+
+    fn handler(&self, matches: &ArgMatches) -> Result<()> {
+        match matches.subcommand() {
+            Some(("schema", sub_matches)) => self.schema.handler(sub_matches),
+            Some(("entity", sub_matches)) => self.entity.handler(sub_matches),
+            _ => unreachable!(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_relocate_import() {
+        let entities = Path::new("/app/iam/persistence/entities");
+        let schemas = Path::new("/app/iam/domain/schemas");
+
+        assert_eq!(
+            relocate_import("../../domain/enum/status.enum", entities, schemas),
+            "../enum/status.enum"
+        );
+        assert_eq!(
+            relocate_import("./role.entity", entities, entities),
+            "./role.entity"
+        );
+        assert_eq!(relocate_import("@app/core", entities, schemas), "@app/core");
+    }
+}
@@ -0,0 +1,237 @@
+use std::{
+    collections::BTreeMap,
+    fs::read_dir,
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result, bail};
+use clap::{Arg, ArgAction, ArgMatches, Command};
+use convert_case::{Case, Casing};
+use termcolor::{Color, ColorChoice, ColorSpec, StandardStream, WriteColor};
+
+use super::{barrel_export, check_output, is_mongo_project, project_for_file};
+use crate::{
+    CliCommand,
+    core::{
+        command::command,
+        manifest::ProjectType,
+        rendered_template::{RenderedTemplate, write_rendered_templates},
+        static_analysis::{
+            EntityAnalyzer, EntityGenerator, SchemaAnalyzer, entity_generator::KnownEntity,
+            schema_analyzer::SchemaDefinition, schema_generator::entity_base_name,
+        },
+        validate::require_manifest,
+    },
+};
+
+const ENTITY_FILE_SUFFIX: &str = ".entity.ts";
+
+/// Picks the named schema, else the request schema, else the first one.
+fn select_schema(
+    mut schemas: Vec<SchemaDefinition>,
+    name: Option<&str>,
+    file: &Path,
+) -> Result<SchemaDefinition> {
+    let position = match name {
+        Some(name) => schemas
+            .iter()
+            .position(|schema| schema.name == name)
+            .with_context(|| format!("Schema '{}' not found in {}", name, file.display()))?,
+        None => {
+            if schemas.is_empty() {
+                bail!("No object schemas found in {}", file.display());
+            }
+            schemas
+                .iter()
+                .position(|schema| schema.name.ends_with("RequestSchema"))
+                .unwrap_or(0)
+        }
+    };
+    Ok(schemas.swap_remove(position))
+}
+
+/// `CustomerRequestSchema` -> `CustomerRecord`, or `CustomerEventRecord`
+/// for workers.
+fn entity_class_name(schema_name: &str, is_worker: bool) -> String {
+    let base = schema_name
+        .strip_suffix("RequestSchema")
+        .or_else(|| schema_name.strip_suffix("ResponseSchema"))
+        .or_else(|| schema_name.strip_suffix("Schema"))
+        .unwrap_or(schema_name)
+        .to_case(Case::Pascal);
+    format!(
+        "{}{}",
+        base,
+        if is_worker { "EventRecord" } else { "Record" }
+    )
+}
+
+/// Entities already declared in the project, which generated relations can
+/// point at.
+fn known_entities(entities_path: &Path) -> Result<BTreeMap<String, KnownEntity>> {
+    let mut known = BTreeMap::new();
+    let Ok(entries) = read_dir(entities_path) else {
+        return Ok(known);
+    };
+    for path in entries.flatten().map(|entry| entry.path()) {
+        let file_name = path.file_name().unwrap().to_string_lossy().to_string();
+        let Some(stem) = file_name.strip_suffix(".ts") else {
+            continue;
+        };
+        if !file_name.ends_with(ENTITY_FILE_SUFFIX) {
+            continue;
+        }
+        for entity in EntityAnalyzer::parse_entity_file(&path)? {
+            known.insert(
+                entity_base_name(&entity.name).to_case(Case::Camel),
+                KnownEntity {
+                    class_name: entity.name,
+                    import_path: format!("./{}", stem),
+                },
+            );
+        }
+    }
+    Ok(known)
+}
+
+#[derive(Debug)]
+pub(crate) struct EntityCommand;
+
+impl EntityCommand {
+    pub(crate) fn new() -> Self {
+        Self {}
+    }
+}
+
+impl CliCommand for EntityCommand {
+    fn command(&self) -> Command {
+        command(
+            "entity",
+            "Generate a MikroORM entity from an idiomatic validator schema",
+        )
+        .arg(
+            Arg::new("from_schema")
+                .long("from-schema")
+                .help("The schema file to read")
+                .required(true),
+        )
+        .arg(
+            Arg::new("schema")
+                .long("schema")
+                .help("Schema to use (defaults to the request schema)"),
+        )
+        .arg(
+            Arg::new("output")
+                .short('o')
+                .long("output")
+                .help("Entity file to write (defaults to persistence/entities/<name>.entity.ts)"),
+        )
+        .arg(
+            Arg::new("force")
+                .short('f')
+                .long("force")
+                .help("Overwrite the entity file if it exists")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("base_path")
+                .short('p')
+                .long("path")
+                .help("The application path"),
+        )
+        .arg(
+            Arg::new("dryrun")
+                .short('n')
+                .long("dryrun")
+                .help("Dry run the command")
+                .action(ArgAction::SetTrue),
+        )
+    }
+
+    fn handler(&self, matches: &ArgMatches) -> Result<()> {
+        let mut stdout = StandardStream::stdout(ColorChoice::Always);
+        let (app_root_path, manifest_data) = require_manifest(matches)?;
+
+        let schema_file = PathBuf::from(matches.get_one::<String>("from_schema").unwrap());
+        let dryrun = matches.get_flag("dryrun");
+
+        let (project, project_path) =
+            project_for_file(&app_root_path, &manifest_data, &schema_file)?;
+        let schema = select_schema(
+            SchemaAnalyzer::parse_schema_file(&schema_file)?,
+            matches.get_one::<String>("schema").map(String::as_str),
+            &schema_file,
+        )?;
+
+        let class_name = entity_class_name(&schema.name, project.r#type == ProjectType::Worker);
+        let entities_path = project_path.join("persistence").join("entities");
+        let module = format!("{}.entity", class_name.to_case(Case::Camel));
+        let output = matches
+            .get_one::<String>("output")
+            .map(PathBuf::from)
+            .unwrap_or_else(|| entities_path.join(format!("{}.ts", module)));
+        check_output(&output, matches.get_flag("force"))?;
+
+        let schema_name = schema.name.clone();
+        let content = EntityGenerator::new(
+            schema,
+            class_name.clone(),
+            manifest_data.app_name.clone(),
+            is_mongo_project(project),
+            known_entities(&entities_path)?,
+        )
+        .generate_entity_file();
+
+        let mut rendered_templates = vec![RenderedTemplate {
+            path: output.clone(),
+            content,
+            context: None,
+        }];
+        if output.parent() == Some(entities_path.as_path())
+            && let Some(index) = barrel_export(&entities_path.join("index.ts"), &module)
+        {
+            rendered_templates.push(index);
+        }
+
+        write_rendered_templates(&rendered_templates, dryrun, &mut stdout)?;
+
+        stdout.set_color(ColorSpec::new().set_fg(Some(Color::Green)))?;
+        writeln!(
+            stdout,
+            "[OK] {} {} from {} in {}",
+            if dryrun {
+                "Would generate"
+            } else {
+                "Generated"
+            },
+            class_name,
+            schema_name,
+            output.display()
+        )?;
+        stdout.reset()?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_entity_class_name() {
+        assert_eq!(
+            entity_class_name("CustomerRequestSchema", false),
+            "CustomerRecord"
+        );
+        assert_eq!(
+            entity_class_name("PaymentSchema", true),
+            "PaymentEventRecord"
+        );
+        assert_eq!(
+            entity_class_name("orderItemSchema", false),
+            "OrderItemRecord"
+        );
+    }
+}
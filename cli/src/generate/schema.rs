@@ -0,0 +1,227 @@
+use std::{
+    collections::BTreeMap,
+    fs::read_to_string,
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result, bail};
+use clap::{Arg, ArgAction, ArgMatches, Command};
+use convert_case::{Case, Casing};
+use oxc_allocator::Allocator;
+use oxc_ast::ast::{ImportDeclarationSpecifier, Statement};
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+use termcolor::{Color, ColorChoice, ColorSpec, StandardStream, WriteColor};
+
+use super::{barrel_export, check_output, is_mongo_project, project_for_file, relocate_import};
+use crate::{
+    CliCommand,
+    core::{
+        command::command,
+        rendered_template::{RenderedTemplate, write_rendered_templates},
+        static_analysis::{
+            EntityAnalyzer, SchemaGenerator, entity_analyzer::EntityDefinition,
+            schema_generator::entity_base_name,
+        },
+        validate::require_manifest,
+    },
+};
+
+/// Named imports of a TypeScript file, by local name.
+fn named_imports(path: &Path) -> Result<BTreeMap<String, String>> {
+    let source =
+        read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))?;
+    let allocator = Allocator::default();
+    let source_type = SourceType::from_path(path)
+        .unwrap_or_default()
+        .with_typescript(true);
+    let program = Parser::new(&allocator, &source, source_type)
+        .parse()
+        .program;
+
+    let mut imports = BTreeMap::new();
+    for statement in &program.body {
+        let Statement::ImportDeclaration(import) = statement else {
+            continue;
+        };
+        for specifier in import.specifiers.iter().flatten() {
+            if let ImportDeclarationSpecifier::ImportSpecifier(spec) = specifier {
+                imports.insert(spec.local.name.to_string(), import.source.value.to_string());
+            }
+        }
+    }
+    Ok(imports)
+}
+
+fn select_entity(
+    mut entities: Vec<EntityDefinition>,
+    name: Option<&str>,
+    file: &Path,
+) -> Result<EntityDefinition> {
+    match name {
+        Some(name) => {
+            let position = entities
+                .iter()
+                .position(|entity| entity.name == name)
+                .with_context(|| format!("Entity '{}' not found in {}", name, file.display()))?;
+            Ok(entities.swap_remove(position))
+        }
+        None if entities.len() == 1 => Ok(entities.remove(0)),
+        None if entities.is_empty() => bail!("No @Entity() classes found in {}", file.display()),
+        None => bail!(
+            "{} declares several entities ({}), pass --entity to choose one",
+            file.display(),
+            entities
+                .iter()
+                .map(|entity| entity.name.as_str())
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct SchemaCommand;
+
+impl SchemaCommand {
+    pub(crate) fn new() -> Self {
+        Self {}
+    }
+}
+
+impl CliCommand for SchemaCommand {
+    fn command(&self) -> Command {
+        command(
+            "schema",
+            "Generate request and response schemas from a MikroORM entity",
+        )
+        .arg(
+            Arg::new("from_entity")
+                .long("from-entity")
+                .help("The entity file to read")
+                .required(true),
+        )
+        .arg(
+            Arg::new("entity")
+                .long("entity")
+                .help("Entity class to use when the file declares several"),
+        )
+        .arg(
+            Arg::new("output")
+                .short('o')
+                .long("output")
+                .help("Schema file to write (defaults to domain/schemas/<name>.schema.ts)"),
+        )
+        .arg(
+            Arg::new("force")
+                .short('f')
+                .long("force")
+                .help("Overwrite the schema file if it exists")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("base_path")
+                .short('p')
+                .long("path")
+                .help("The application path"),
+        )
+        .arg(
+            Arg::new("dryrun")
+                .short('n')
+                .long("dryrun")
+                .help("Dry run the command")
+                .action(ArgAction::SetTrue),
+        )
+    }
+
+    fn handler(&self, matches: &ArgMatches) -> Result<()> {
+        let mut stdout = StandardStream::stdout(ColorChoice::Always);
+        let (app_root_path, manifest_data) = require_manifest(matches)?;
+
+        let entity_file = PathBuf::from(matches.get_one::<String>("from_entity").unwrap());
+        let dryrun = matches.get_flag("dryrun");
+
+        let (project, project_path) =
+            project_for_file(&app_root_path, &manifest_data, &entity_file)?;
+        let entity = select_entity(
+            EntityAnalyzer::parse_entity_file(&entity_file)?,
+            matches.get_one::<String>("entity").map(String::as_str),
+            &entity_file,
+        )?;
+
+        let schemas_path = project_path.join("domain").join("schemas");
+        let module = format!(
+            "{}.schema",
+            entity_base_name(&entity.name).to_case(Case::Camel)
+        );
+        let output = matches
+            .get_one::<String>("output")
+            .map(PathBuf::from)
+            .unwrap_or_else(|| schemas_path.join(format!("{}.ts", module)));
+        check_output(&output, matches.get_flag("force"))?;
+
+        // Non-primitive property types (enums) keep their import, rewritten
+        // relative to the schema file
+        let entity_directory = entity_file
+            .canonicalize()?
+            .parent()
+            .map(Path::to_path_buf)
+            .unwrap_or_default();
+        let output_directory = std::path::absolute(&output)?
+            .parent()
+            .map(Path::to_path_buf)
+            .unwrap_or_default();
+        let type_imports = named_imports(&entity_file)?
+            .into_iter()
+            .filter(|(name, _)| {
+                entity
+                    .properties
+                    .iter()
+                    .any(|property| property.relation_type.is_none() && &property.type_name == name)
+            })
+            .map(|(name, specifier)| {
+                let specifier = relocate_import(&specifier, &entity_directory, &output_directory);
+                (name, specifier)
+            })
+            .collect();
+
+        let entity_name = entity.name.clone();
+        let content = SchemaGenerator::new(
+            entity,
+            manifest_data.app_name.clone(),
+            is_mongo_project(project),
+            type_imports,
+        )
+        .generate_schema_file();
+
+        let mut rendered_templates = vec![RenderedTemplate {
+            path: output.clone(),
+            content,
+            context: None,
+        }];
+        if output.parent() == Some(schemas_path.as_path())
+            && let Some(index) = barrel_export(&schemas_path.join("index.ts"), &module)
+        {
+            rendered_templates.push(index);
+        }
+
+        write_rendered_templates(&rendered_templates, dryrun, &mut stdout)?;
+
+        stdout.set_color(ColorSpec::new().set_fg(Some(Color::Green)))?;
+        writeln!(
+            stdout,
+            "[OK] {} schemas for {} in {}",
+            if dryrun {
+                "Would generate"
+            } else {
+                "Generated"
+            },
+            entity_name,
+            output.display()
+        )?;
+        stdout.reset()?;
+
+        Ok(())
+    }
+}
@@ -808,6 +808,10 @@ impl CliCommand for ApplicationCommand {
             release_version: None,
             release_git_commit: None,
             release_git_branch: None,
+            secrets_recipients: None,
+            env_schema: None,
+            architecture: None,
+            licenses: None,
         };
 
         let mut rendered_templates = Vec::new();
@@ -960,6 +964,10 @@ impl CliCommand for ApplicationCommand {
                 release_version: data.release_version.clone(),
                 release_git_commit: data.release_git_commit.clone(),
                 release_git_branch: data.release_git_branch.clone(),
+                secrets_recipients: data.secrets_recipients.clone(),
+                env_schema: data.env_schema.clone(),
+                architecture: data.architecture.clone(),
+                licenses: data.licenses.clone(),
 
                 is_better_auth: template_dir.module_id == Some(Module::BetterAuthIam),
                 is_stripe: template_dir.module_id == Some(Module::StripeBilling),
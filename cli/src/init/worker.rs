@@ -826,6 +826,10 @@ impl CliCommand for WorkerCommand {
             release_version: manifest_data.release_version.clone(),
             release_git_commit: manifest_data.release_git_commit.clone(),
             release_git_branch: manifest_data.release_git_branch.clone(),
+            secrets_recipients: manifest_data.secrets_recipients.clone(),
+            env_schema: manifest_data.env_schema.clone(),
+            architecture: manifest_data.architecture.clone(),
+            licenses: manifest_data.licenses.clone(),
 
             is_postgres: if let Some(database) = &database {
                 database == &Database::PostgreSQL
@@ -0,0 +1,41 @@
+use anyhow::Result;
+use check::CheckCommand;
+use clap::{ArgMatches, Command};
+use notice::NoticeCommand;
+
+use crate::{CliCommand, core::command::command};
+
+pub(crate) mod check;
+pub(crate) mod notice;
+
+#[derive(Debug)]
+pub(crate) struct LicensesCommand {
+    check: CheckCommand,
+    notice: NoticeCommand,
+}
+
+impl LicensesCommand {
+    pub(crate) fn new() -> Self {
+        Self {
+            check: CheckCommand::new(),
+            notice: NoticeCommand::new(),
+        }
+    }
+}
+
+impl CliCommand for LicensesCommand {
+    fn command(&self) -> Command {
+        command("licenses", "Check the licenses of installed dependencies")
+            .subcommand_required(true)
+            .subcommand(self.check.command())
+            .subcommand(self.notice.command())
+    }
+
+    fn handler(&self, matches: &ArgMatches) -> Result<()> {
+        match matches.subcommand() {
+            Some(("check", sub_matches)) => self.check.handler(sub_matches),
+            Some(("notice", sub_matches)) => self.notice.handler(sub_matches),
+            _ => unreachable!(),
+        }
+    }
+}
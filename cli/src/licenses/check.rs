@@ -0,0 +1,197 @@
+use std::{collections::BTreeMap, io::Write};
+
+use anyhow::{Result, bail};
+use clap::{Arg, ArgAction, ArgMatches, Command};
+use serde_json::json;
+use termcolor::{Color, ColorChoice, ColorSpec, StandardStream, WriteColor};
+
+use crate::{
+    CliCommand,
+    core::{
+        architecture::RuleLevel,
+        command::command,
+        dependency_licenses::{LicenseFinding, check_licenses, collect_installed_packages},
+        validate::require_manifest,
+    },
+};
+
+fn write_findings(findings: &[LicenseFinding], stdout: &mut StandardStream) -> Result<()> {
+    for finding in findings {
+        write!(stdout, "{}@{}: ", finding.package, finding.version)?;
+        let color = match finding.level {
+            RuleLevel::Error => Color::Red,
+            _ => Color::Yellow,
+        };
+        stdout.set_color(ColorSpec::new().set_fg(Some(color)).set_bold(true))?;
+        write!(stdout, "{}", finding.level)?;
+        stdout.reset()?;
+        writeln!(
+            stdout,
+            " [{}] {} (used by {})",
+            finding.rule,
+            finding.message,
+            finding.projects.join(", ")
+        )?;
+    }
+    Ok(())
+}
+
+#[derive(Debug)]
+pub(crate) struct CheckCommand;
+
+impl CheckCommand {
+    pub(crate) fn new() -> Self {
+        Self {}
+    }
+}
+
+impl CliCommand for CheckCommand {
+    fn command(&self) -> Command {
+        command(
+            "check",
+            "Check dependency licenses against the license policy",
+        )
+        .long_about(
+            "Resolves the license of every installed dependency of each project from its package.json in node_modules, and checks it against the [licenses] policy in the manifest and the application's own license. Copyleft licenses that the application's license cannot take in are reported as conflicts. Exits with status 1 when any finding is an error.",
+        )
+        .arg(
+            Arg::new("base_path")
+                .short('p')
+                .long("path")
+                .help("The application path"),
+        )
+        .arg(
+            Arg::new("project")
+                .long("project")
+                .help("Only check the dependencies of this project"),
+        )
+        .arg(
+            Arg::new("dev")
+                .long("dev")
+                .action(ArgAction::SetTrue)
+                .help("Include devDependencies"),
+        )
+        .arg(
+            Arg::new("json")
+                .long("json")
+                .action(ArgAction::SetTrue)
+                .help("Print the packages and findings as JSON"),
+        )
+    }
+
+    fn handler(&self, matches: &ArgMatches) -> Result<()> {
+        let (app_root_path, manifest_data) = require_manifest(matches)?;
+        let policy = manifest_data.licenses.clone().unwrap_or_default();
+        let projects: Vec<String> = match matches.get_one::<String>("project") {
+            Some(project) => {
+                if !manifest_data.projects.iter().any(|p| &p.name == project) {
+                    bail!("Project '{}' not found in manifest", project);
+                }
+                vec![project.clone()]
+            }
+            None => manifest_data
+                .projects
+                .iter()
+                .map(|project| project.name.clone())
+                .collect(),
+        };
+
+        let inventory = collect_installed_packages(
+            &app_root_path,
+            &manifest_data.modules_path,
+            &projects,
+            matches.get_flag("dev"),
+        );
+        let findings = check_licenses(&inventory.packages, &policy, &manifest_data.license);
+        let errors = findings
+            .iter()
+            .filter(|finding| finding.level == RuleLevel::Error)
+            .count();
+
+        if matches.get_flag("json") {
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&json!({
+                    "license": manifest_data.license,
+                    "packages": inventory.packages,
+                    "missing": inventory.missing,
+                    "findings": findings,
+                }))?
+            );
+        } else {
+            let mut stdout = StandardStream::stdout(ColorChoice::Always);
+
+            if !inventory.missing.is_empty() {
+                stdout.set_color(ColorSpec::new().set_fg(Some(Color::Yellow)))?;
+                writeln!(
+                    stdout,
+                    "[WARN] {} direct dependencies are not installed and were not checked. Run your package manager's install first.",
+                    inventory.missing.len()
+                )?;
+                stdout.reset()?;
+                for missing in &inventory.missing {
+                    writeln!(stdout, "  {}", missing)?;
+                }
+                writeln!(stdout)?;
+            }
+
+            let mut by_license: BTreeMap<&str, usize> = BTreeMap::new();
+            for package in &inventory.packages {
+                *by_license
+                    .entry(package.license.as_deref().unwrap_or("UNKNOWN"))
+                    .or_default() += 1;
+            }
+            let mut by_license: Vec<_> = by_license.into_iter().collect();
+            by_license.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+            writeln!(
+                stdout,
+                "[INFO] {} package(s): {}",
+                inventory.packages.len(),
+                by_license
+                    .iter()
+                    .map(|(license, count)| format!("{} {}", license, count))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )?;
+
+            write_findings(&findings, &mut stdout)?;
+            if !findings.is_empty() {
+                writeln!(stdout)?;
+            }
+
+            let warnings = findings.len() - errors;
+            if errors > 0 {
+                stdout.set_color(ColorSpec::new().set_fg(Some(Color::Red)))?;
+                writeln!(
+                    stdout,
+                    "[ERROR] {} error(s), {} warning(s) in {} package(s)",
+                    errors,
+                    warnings,
+                    inventory.packages.len()
+                )?;
+            } else if warnings > 0 {
+                stdout.set_color(ColorSpec::new().set_fg(Some(Color::Yellow)))?;
+                writeln!(
+                    stdout,
+                    "[WARN] {} warning(s) in {} package(s)",
+                    warnings,
+                    inventory.packages.len()
+                )?;
+            } else {
+                stdout.set_color(ColorSpec::new().set_fg(Some(Color::Green)))?;
+                writeln!(
+                    stdout,
+                    "[OK] {} package(s) comply with the license policy",
+                    inventory.packages.len()
+                )?;
+            }
+            stdout.reset()?;
+        }
+
+        if errors > 0 {
+            std::process::exit(1);
+        }
+
+        Ok(())
+    }
+}
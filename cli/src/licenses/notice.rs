@@ -0,0 +1,82 @@
+use std::{fs, io::Write, path::PathBuf};
+
+use anyhow::{Context, Result};
+use clap::{Arg, ArgMatches, Command};
+use termcolor::{Color, ColorChoice, ColorSpec, StandardStream, WriteColor};
+
+use crate::{
+    CliCommand,
+    core::{
+        command::command,
+        dependency_licenses::{collect_installed_packages, render_notice},
+        validate::require_manifest,
+    },
+};
+
+#[derive(Debug)]
+pub(crate) struct NoticeCommand;
+
+impl NoticeCommand {
+    pub(crate) fn new() -> Self {
+        Self {}
+    }
+}
+
+impl CliCommand for NoticeCommand {
+    fn command(&self) -> Command {
+        command(
+            "notice",
+            "Print a third-party notice file for the installed dependencies",
+        )
+        .arg(
+            Arg::new("base_path")
+                .short('p')
+                .long("path")
+                .help("The application path"),
+        )
+        .arg(
+            Arg::new("output")
+                .short('o')
+                .long("output")
+                .help("Write the notice to this file instead of stdout"),
+        )
+    }
+
+    fn handler(&self, matches: &ArgMatches) -> Result<()> {
+        let (app_root_path, manifest_data) = require_manifest(matches)?;
+        let projects: Vec<String> = manifest_data
+            .projects
+            .iter()
+            .map(|project| project.name.clone())
+            .collect();
+
+        let inventory = collect_installed_packages(
+            &app_root_path,
+            &manifest_data.modules_path,
+            &projects,
+            false,
+        );
+        let notice = render_notice(&manifest_data.app_name, &inventory.packages);
+
+        match matches.get_one::<String>("output").map(PathBuf::from) {
+            Some(output) => {
+                fs::write(&output, notice)
+                    .with_context(|| format!("Failed to write {}", output.display()))?;
+
+                let mut stdout = StandardStream::stdout(ColorChoice::Always);
+                stdout.set_color(ColorSpec::new().set_fg(Some(Color::Green)))?;
+                write!(stdout, "[OK]")?;
+                stdout.reset()?;
+                writeln!(
+                    stdout,
+                    " Notices for {} package(s) written to: {}",
+                    inventory.packages.len(),
+                    output.display()
+                )?;
+            }
+            None => print!("{}", notice),
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,35 @@
+use anyhow::Result;
+use architecture::ArchitectureCommand;
+use clap::{ArgMatches, Command};
+
+use crate::{CliCommand, core::command::command};
+
+pub(crate) mod architecture;
+
+#[derive(Debug)]
+pub(crate) struct LintCommand {
+    architecture: ArchitectureCommand,
+}
+
+impl LintCommand {
+    pub(crate) fn new() -> Self {
+        Self {
+            architecture: ArchitectureCommand::new(),
+        }
+    }
+}
+
+impl CliCommand for LintCommand {
+    fn command(&self) -> Command {
+        command("lint", "Check the application against project rules")
+            .subcommand_required(true)
+            .subcommand(self.architecture.command())
+    }
+
+    fn handler(&self, matches: &ArgMatches) -> Result<()> {
+        match matches.subcommand() {
+            Some(("architecture", sub_matches)) => self.architecture.handler(sub_matches),
+            _ => unreachable!(),
+        }
+    }
+}
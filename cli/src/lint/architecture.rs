@@ -0,0 +1,135 @@
+use std::io::Write;
+
+use anyhow::Result;
+use clap::{Arg, ArgAction, ArgMatches, Command};
+use termcolor::{Color, ColorChoice, ColorSpec, StandardStream, WriteColor};
+
+use crate::{
+    CliCommand,
+    core::{
+        architecture::{
+            BUILTIN_RULES, Diagnostic, RuleLevel, check_architecture, scan_project_imports,
+        },
+        command::command,
+        validate::require_manifest,
+    },
+};
+
+fn write_diagnostics(diagnostics: &[Diagnostic], stdout: &mut StandardStream) -> Result<()> {
+    for diagnostic in diagnostics {
+        write!(stdout, "{}:{}: ", diagnostic.file, diagnostic.line)?;
+        let color = match diagnostic.level {
+            RuleLevel::Error => Color::Red,
+            _ => Color::Yellow,
+        };
+        stdout.set_color(ColorSpec::new().set_fg(Some(color)).set_bold(true))?;
+        write!(stdout, "{}", diagnostic.level)?;
+        stdout.reset()?;
+        writeln!(stdout, " [{}] {}", diagnostic.rule, diagnostic.message)?;
+    }
+    Ok(())
+}
+
+#[derive(Debug)]
+pub(crate) struct ArchitectureCommand;
+
+impl ArchitectureCommand {
+    pub(crate) fn new() -> Self {
+        Self {}
+    }
+}
+
+impl CliCommand for ArchitectureCommand {
+    fn command(&self) -> Command {
+        command(
+            "architecture",
+            "Check imports between projects against the architecture rules",
+        )
+        .long_about(format!(
+            "Checks imports between projects against the architecture rules and reports dependency cycles. Exits with status 1 when a rule at level \"error\" is broken.\n\nBuilt-in rules (configure under [architecture.rules] in the manifest):\n{}",
+            BUILTIN_RULES
+                .iter()
+                .map(|(rule, description)| format!("  {:<24}{}", rule, description))
+                .collect::<Vec<_>>()
+                .join("\n")
+        ))
+        .arg(
+            Arg::new("base_path")
+                .short('p')
+                .long("path")
+                .help("The application path"),
+        )
+        .arg(
+            Arg::new("json")
+                .long("json")
+                .action(ArgAction::SetTrue)
+                .help("Print the diagnostics as JSON"),
+        )
+    }
+
+    fn handler(&self, matches: &ArgMatches) -> Result<()> {
+        let (app_root_path, manifest_data) = require_manifest(matches)?;
+        let config = manifest_data.architecture.clone().unwrap_or_default();
+        config.validate()?;
+
+        let imports = scan_project_imports(
+            &app_root_path,
+            &app_root_path.join(&manifest_data.modules_path),
+            &manifest_data.app_name,
+            &manifest_data.projects,
+            &config.ignore,
+        )?;
+        let diagnostics = check_architecture(&imports, &manifest_data.projects, &config);
+        let errors = diagnostics
+            .iter()
+            .filter(|diagnostic| diagnostic.level == RuleLevel::Error)
+            .count();
+
+        if matches.get_flag("json") {
+            println!("{}", serde_json::to_string_pretty(&diagnostics)?);
+        } else {
+            let mut stdout = StandardStream::stdout(ColorChoice::Always);
+            write_diagnostics(&diagnostics, &mut stdout)?;
+            if !diagnostics.is_empty() {
+                writeln!(stdout)?;
+            }
+
+            let warnings = diagnostics.len() - errors;
+            if errors > 0 {
+                stdout.set_color(ColorSpec::new().set_fg(Some(Color::Red)))?;
+                writeln!(
+                    stdout,
+                    "[ERROR] {} error(s), {} warning(s) in {} import(s) across {} project(s)",
+                    errors,
+                    warnings,
+                    imports.len(),
+                    manifest_data.projects.len()
+                )?;
+            } else if warnings > 0 {
+                stdout.set_color(ColorSpec::new().set_fg(Some(Color::Yellow)))?;
+                writeln!(
+                    stdout,
+                    "[WARN] {} warning(s) in {} import(s) across {} project(s)",
+                    warnings,
+                    imports.len(),
+                    manifest_data.projects.len()
+                )?;
+            } else {
+                stdout.set_color(ColorSpec::new().set_fg(Some(Color::Green)))?;
+                writeln!(
+                    stdout,
+                    "[OK] {} import(s) across {} project(s) follow the architecture rules",
+                    imports.len(),
+                    manifest_data.projects.len()
+                )?;
+            }
+            stdout.reset()?;
+        }
+
+        if errors > 0 {
+            std::process::exit(1);
+        }
+
+        Ok(())
+    }
+}
@@ -1,3 +1,4 @@
+use adopt::AdoptCommand;
 use anyhow::Result;
 use change::ChangeCommand;
 use clap::{ArgMatches, Command, command};
@@ -7,18 +8,26 @@ use depcheck::DepcheckCommand;
 use deploy::DeployCommand;
 use eject::EjectCommand;
 use environment::EnvironmentCommand;
+use generate::GenerateCommand;
 use init::InitCommand;
 use integrate::IntegrateCommand;
+use licenses::LicensesCommand;
+use lint::LintCommand;
 use login::LoginCommand;
 use logout::LogoutCommand;
+use migrate::MigrateCommand;
 use openapi::OpenApiCommand;
 use release::ReleaseCommand;
+use sbom::SbomCommand;
+use secrets::SecretsCommand;
+use seed::SeedCommand;
 use version::VersionCommand;
 use whoami::WhoAmICommand;
 use sync::SyncCommand;
 
 use crate::sdk::SdkCommand;
 
+mod adopt;
 mod change;
 mod config;
 mod constants;
@@ -28,14 +37,21 @@ mod depcheck;
 mod deploy;
 mod eject;
 mod environment;
+mod generate;
 mod init;
 mod integrate;
+mod licenses;
+mod lint;
 mod login;
 mod logout;
+mod migrate;
 mod openapi;
 mod prompt;
 mod release;
+mod sbom;
 mod sdk;
+mod secrets;
+mod seed;
 mod version;
 mod whoami;
 mod sync;
@@ -48,6 +64,7 @@ pub(crate) trait CliCommand {
 fn main() -> Result<()> {
     // inject token into init, config
     let init = InitCommand::new();
+    let adopt = AdoptCommand::new();
     let change = ChangeCommand::new();
     let config = ConfigCommand::new();
     let delete = DeleteCommand::new();
@@ -55,12 +72,19 @@ fn main() -> Result<()> {
     let deploy = DeployCommand::new();
     let eject = EjectCommand::new();
     let environment = EnvironmentCommand::new();
+    let generate = GenerateCommand::new();
     let integrate = IntegrateCommand::new();
+    let licenses = LicensesCommand::new();
+    let lint = LintCommand::new();
     let login = LoginCommand::new();
     let logout = LogoutCommand::new();
+    let migrate = MigrateCommand::new();
     let openapi = OpenApiCommand::new();
     let release = ReleaseCommand::new();
+    let sbom = SbomCommand::new();
     let sdk = SdkCommand::new();
+    let secrets = SecretsCommand::new();
+    let seed = SeedCommand::new();
     let whoami = WhoAmICommand::new();
     let version = VersionCommand::new();
     let sync = SyncCommand::new();
@@ -70,6 +94,7 @@ fn main() -> Result<()> {
         .arg_required_else_help(true)
         .subcommand_required(true)
         .subcommand(init.command())
+        .subcommand(adopt.command())
         .subcommand(delete.command())
         .subcommand(change.command())
         .subcommand(eject.command())
@@ -77,12 +102,19 @@ fn main() -> Result<()> {
         .subcommand(config.command())
         .subcommand(deploy.command())
         .subcommand(environment.command())
+        .subcommand(generate.command())
         .subcommand(integrate.command())
+        .subcommand(licenses.command())
+        .subcommand(lint.command())
+        .subcommand(migrate.command())
         .subcommand(openapi.command())
         .subcommand(release.command())
+        .subcommand(sbom.command())
         .subcommand(login.command())
         .subcommand(logout.command())
         .subcommand(sdk.command())
+        .subcommand(secrets.command())
+        .subcommand(seed.command())
         .subcommand(whoami.command())
         .subcommand(version.command())
         .subcommand(sync.command())
@@ -94,6 +126,7 @@ fn main() -> Result<()> {
 
     let result = match matches.subcommand() {
         Some(("init", sub_matches)) => init.handler(sub_matches),
+        Some(("adopt", sub_matches)) => adopt.handler(sub_matches),
         Some(("change", sub_matches)) => change.handler(sub_matches),
         Some(("config", sub_matches)) => config.handler(sub_matches),
         Some(("delete", sub_matches)) => delete.handler(sub_matches),
@@ -101,12 +134,19 @@ fn main() -> Result<()> {
         Some(("deploy", sub_matches)) => deploy.handler(sub_matches),
         Some(("eject", sub_matches)) => eject.handler(sub_matches),
         Some(("environment", sub_matches)) => environment.handler(sub_matches),
+        Some(("generate", sub_matches)) => generate.handler(sub_matches),
         Some(("integrate", sub_matches)) => integrate.handler(sub_matches),
+        Some(("licenses", sub_matches)) => licenses.handler(sub_matches),
+        Some(("lint", sub_matches)) => lint.handler(sub_matches),
+        Some(("migrate", sub_matches)) => migrate.handler(sub_matches),
         Some(("openapi", sub_matches)) => openapi.handler(sub_matches),
         Some(("release", sub_matches)) => release.handler(sub_matches),
+        Some(("sbom", sub_matches)) => sbom.handler(sub_matches),
         Some(("login", sub_matches)) => login.handler(sub_matches),
         Some(("logout", sub_matches)) => logout.handler(sub_matches),
         Some(("sdk", sub_matches)) => sdk.handler(sub_matches),
+        Some(("secrets", sub_matches)) => secrets.handler(sub_matches),
+        Some(("seed", sub_matches)) => seed.handler(sub_matches),
         Some(("whoami", sub_matches)) => whoami.handler(sub_matches),
         Some(("version", sub_matches)) => version.handler(sub_matches),
         Some(("sync", sub_matches)) => sync.handler(sub_matches),
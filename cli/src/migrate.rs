@@ -0,0 +1,284 @@
+use std::{fs::read_dir, io::Write, path::Path};
+
+use anyhow::Result;
+use clap::{Arg, ArgAction, ArgGroup, ArgMatches, Command};
+use termcolor::{ColorChoice, StandardStream};
+
+use crate::{
+    CliCommand,
+    constants::Runtime,
+    core::{
+        command::command,
+        mikro_orm::{
+            DatabaseProject, database_projects, ensure_database_services, mikro_orm_command,
+            print_run_summary, run_command, run_in_dependency_order,
+        },
+        validate::require_manifest,
+    },
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MigrationAction {
+    Create,
+    Up,
+    Down,
+    Status,
+}
+
+impl MigrationAction {
+    fn name(&self) -> &'static str {
+        match self {
+            MigrationAction::Create => "create",
+            MigrationAction::Up => "up",
+            MigrationAction::Down => "down",
+            MigrationAction::Status => "status",
+        }
+    }
+
+    fn about(&self) -> &'static str {
+        match self {
+            MigrationAction::Create => "Create a migration from the current entity definitions",
+            MigrationAction::Up => "Apply pending migrations",
+            MigrationAction::Down => "Revert the last applied migration",
+            MigrationAction::Status => "List pending migrations",
+        }
+    }
+
+    fn mikro_orm_args(&self, matches: &ArgMatches) -> Vec<String> {
+        match self {
+            MigrationAction::Create => {
+                let mut args = vec!["migration:create".to_string()];
+                if matches.get_flag("initial") {
+                    args.push("--initial".to_string());
+                }
+                if matches.get_flag("blank") {
+                    args.push("--blank".to_string());
+                }
+                if let Some(name) = matches.get_one::<String>("name") {
+                    args.push("--name".to_string());
+                    args.push(name.clone());
+                }
+                args
+            }
+            MigrationAction::Up => vec!["migration:up".to_string()],
+            MigrationAction::Down => vec!["migration:down".to_string()],
+            MigrationAction::Status => vec!["migration:pending".to_string()],
+        }
+    }
+}
+
+/// Migrations are written to `migrations-<database>` (see the generated
+/// `mikro-orm.config.ts`).
+fn count_migration_files(project_path: &Path) -> usize {
+    read_dir(project_path)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter(|entry| {
+            entry
+                .file_name()
+                .to_string_lossy()
+                .starts_with("migrations")
+                && entry.path().is_dir()
+        })
+        .flat_map(|directory| read_dir(directory.path()).into_iter().flatten().flatten())
+        .filter(|entry| {
+            let name = entry.file_name();
+            let name = name.to_string_lossy();
+            name.starts_with("Migration") && name.ends_with(".ts")
+        })
+        .count()
+}
+
+#[derive(Debug)]
+struct MigrationSubcommand {
+    action: MigrationAction,
+}
+
+impl MigrationSubcommand {
+    fn new(action: MigrationAction) -> Self {
+        Self { action }
+    }
+}
+
+impl CliCommand for MigrationSubcommand {
+    fn command(&self) -> Command {
+        let command = command(self.action.name(), self.action.about())
+            .arg(
+                Arg::new("project")
+                    .long("project")
+                    .help("Run for a single service or worker"),
+            )
+            .arg(
+                Arg::new("all")
+                    .short('a')
+                    .long("all")
+                    .help("Run for every service and worker with a MikroORM config")
+                    .action(ArgAction::SetTrue),
+            )
+            .group(
+                ArgGroup::new("target")
+                    .args(["project", "all"])
+                    .required(true),
+            )
+            .arg(
+                Arg::new("base_path")
+                    .short('p')
+                    .long("path")
+                    .help("The application path"),
+            );
+
+        match self.action {
+            MigrationAction::Create => command
+                .arg(
+                    Arg::new("name")
+                        .short('n')
+                        .long("name")
+                        .help("Name of the migration"),
+                )
+                .arg(
+                    Arg::new("initial")
+                        .long("initial")
+                        .help("Create the initial migration from the current schema")
+                        .action(ArgAction::SetTrue),
+                )
+                .arg(
+                    Arg::new("blank")
+                        .long("blank")
+                        .help("Create an empty migration")
+                        .action(ArgAction::SetTrue)
+                        .conflicts_with("initial"),
+                ),
+            _ => command,
+        }
+    }
+
+    fn handler(&self, matches: &ArgMatches) -> Result<()> {
+        let mut stdout = StandardStream::stdout(ColorChoice::Always);
+        let (app_root_path, manifest_data) = require_manifest(matches)?;
+        let runtime = manifest_data.runtime.parse::<Runtime>()?;
+
+        let projects = database_projects(
+            &app_root_path,
+            &manifest_data,
+            matches.get_one::<String>("project").map(String::as_str),
+        )?;
+        if projects.is_empty() {
+            writeln!(stdout, "[INFO] No projects with a MikroORM config found")?;
+            return Ok(());
+        }
+
+        ensure_database_services(&app_root_path, &manifest_data, &projects, &mut stdout)?;
+
+        let args = self.action.mikro_orm_args(matches);
+        let results = run_in_dependency_order(
+            &app_root_path,
+            &manifest_data,
+            &projects,
+            self.action == MigrationAction::Down,
+            &mut stdout,
+            |project: &DatabaseProject, stdout| {
+                let before = count_migration_files(&project.path);
+                writeln!(stdout, "[INFO] mikro-orm {}", args.join(" "))?;
+                run_command(mikro_orm_command(&runtime, &project.path, &args))?;
+
+                Ok(match self.action {
+                    MigrationAction::Create => {
+                        let created = count_migration_files(&project.path).saturating_sub(before);
+                        Some(format!("{} migration(s) created", created))
+                    }
+                    MigrationAction::Status => Some(format!("{} migration file(s)", before)),
+                    MigrationAction::Up | MigrationAction::Down => None,
+                })
+            },
+        )?;
+
+        print_run_summary(
+            &format!("Migration summary ({})", self.action.name()),
+            &results,
+            &mut stdout,
+        )
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct MigrateCommand {
+    create: MigrationSubcommand,
+    up: MigrationSubcommand,
+    down: MigrationSubcommand,
+    status: MigrationSubcommand,
+}
+
+impl MigrateCommand {
+    pub(crate) fn new() -> Self {
+        Self {
+            create: MigrationSubcommand::new(MigrationAction::Create),
+            up: MigrationSubcommand::new(MigrationAction::Up),
+            down: MigrationSubcommand::new(MigrationAction::Down),
+            status: MigrationSubcommand::new(MigrationAction::Status),
+        }
+    }
+}
+
+impl CliCommand for MigrateCommand {
+    fn command(&self) -> Command {
+        command(
+            "migrate",
+            "Run MikroORM migrations across the application's services",
+        )
+        .alias("migration")
+        .subcommand(self.create.command())
+        .subcommand(self.up.command())
+        .subcommand(self.down.command())
+        .subcommand(self.status.command())
+        .subcommand_required(true)
+    }
+
+    fn handler(&self, matches: &ArgMatches) -> Result<()> {
+        match matches.subcommand() {
+            Some(("create", sub_matches)) => self.create.handler(sub_matches),
+            Some(("up", sub_matches)) => self.up.handler(sub_matches),
+            Some(("down", sub_matches)) => self.down.handler(sub_matches),
+            Some(("status", sub_matches)) => self.status.handler(sub_matches),
+            _ => unreachable!(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create_args() {
+        // `command` propagates the CLI version, which a standalone subcommand
+        // does not have.
+        let subcommand = MigrationSubcommand::new(MigrationAction::Create);
+        let parse = |args: &[&str]| {
+            subcommand
+                .command()
+                .version("0.0.0")
+                .try_get_matches_from(args)
+        };
+        let matches = parse(&["create", "--all", "--name", "add-invoices"]).unwrap();
+
+        assert_eq!(
+            MigrationAction::Create.mikro_orm_args(&matches),
+            vec!["migration:create", "--name", "add-invoices"]
+        );
+        assert!(parse(&["create", "--name", "x"]).is_err());
+        assert!(parse(&["create", "--all", "--project", "billing"]).is_err());
+    }
+
+    #[test]
+    fn test_count_migration_files() {
+        let project = tempfile::tempdir().unwrap();
+        let migrations = project.path().join("migrations-postgresql");
+        std::fs::create_dir_all(&migrations).unwrap();
+        std::fs::write(migrations.join("Migration20240101000000.ts"), "").unwrap();
+        std::fs::write(migrations.join(".snapshot-postgresql.json"), "").unwrap();
+        std::fs::write(project.path().join("Migration.ts"), "").unwrap();
+
+        assert_eq!(count_migration_files(project.path()), 1);
+    }
+}
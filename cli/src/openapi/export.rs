@@ -1,14 +1,16 @@
 use std::{fs::create_dir_all, io::Write};
 
 use anyhow::{Context, Result};
-use clap::{Arg, ArgMatches, Command};
+use clap::{Arg, ArgAction, ArgMatches, Command, value_parser};
 use termcolor::{Color, ColorChoice, ColorSpec, StandardStream, WriteColor};
 
 use crate::{
     CliCommand,
     core::{
         command::command,
-        openapi_export::export_all_services,
+        openapi_export::{
+            DEFAULT_EXPORT_TIMEOUT_SECS, ExportOptions, ExportStatus, export_all_services,
+        },
     },
 };
 
@@ -37,6 +39,28 @@ impl CliCommand for ExportCommand {
                     .default_value(".forklaunch/openapi")
                     .help("Output directory for OpenAPI specs"),
             )
+            .arg(
+                Arg::new("export-jobs")
+                    .long("jobs")
+                    .short('j')
+                    .value_parser(value_parser!(u64).range(1..))
+                    .help("Number of services to export at once (default: number of CPUs)"),
+            )
+            .arg(
+                Arg::new("export-timeout")
+                    .long("timeout")
+                    .value_parser(value_parser!(u64).range(1..))
+                    .help(format!(
+                        "Seconds each service may take before it is killed (default: {})",
+                        DEFAULT_EXPORT_TIMEOUT_SECS
+                    )),
+            )
+            .arg(
+                Arg::new("continue-on-error")
+                    .long("continue-on-error")
+                    .action(ArgAction::SetTrue)
+                    .help("Export the remaining services when one fails"),
+            )
     }
 
     fn handler(&self, matches: &ArgMatches) -> Result<()> {
@@ -56,24 +80,41 @@ impl CliCommand for ExportCommand {
         stdout.reset()?;
         writeln!(stdout)?;
 
-        let result = export_all_services(&app_root, &manifest, &output_path);
+        let options = ExportOptions::from_matches(matches);
+        let result = export_all_services(&app_root, &manifest, &output_path, &options);
 
         writeln!(stdout)?;
 
         match result {
-            Ok(exported_services) => {
-                stdout.set_color(ColorSpec::new().set_fg(Some(Color::Green)).set_bold(true))?;
-                writeln!(
-                    stdout,
-                    "[OK] Successfully exported {} OpenAPI specification(s)",
-                    exported_services.len()
-                )?;
+            Ok(report) => {
+                let failures = report.failures();
+                if failures.is_empty() {
+                    stdout.set_color(ColorSpec::new().set_fg(Some(Color::Green)).set_bold(true))?;
+                    writeln!(
+                        stdout,
+                        "[OK] Successfully exported {} OpenAPI specification(s)",
+                        report.exported().len()
+                    )?;
+                } else {
+                    stdout.set_color(ColorSpec::new().set_fg(Some(Color::Yellow)).set_bold(true))?;
+                    writeln!(
+                        stdout,
+                        "[WARN] Exported {} of {} OpenAPI specification(s)",
+                        report.exported().len(),
+                        report.services.len()
+                    )?;
+                }
                 stdout.reset()?;
                 writeln!(stdout, "  Output: {}", output_path.display())?;
 
-                for service_name in &exported_services {
-                    stdout.set_color(ColorSpec::new().set_fg(Some(Color::Green)))?;
-                    writeln!(stdout, "  - {}", service_name)?;
+                for service in &report.services {
+                    let color = match service.status {
+                        ExportStatus::Exported => Color::Green,
+                        ExportStatus::Skipped => Color::Yellow,
+                        ExportStatus::Failed(_) | ExportStatus::TimedOut => Color::Red,
+                    };
+                    stdout.set_color(ColorSpec::new().set_fg(Some(color)))?;
+                    writeln!(stdout, "  - {}: {}", service.name, service.describe())?;
                     stdout.reset()?;
                 }
             }
@@ -0,0 +1,436 @@
+use std::{
+    collections::HashMap,
+    fs::{self, create_dir_all},
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result, bail};
+use serde_json::{Value, json};
+use sha2::{Digest, Sha256};
+
+use super::signing::{ReleaseSignature, file_digest};
+
+pub(crate) const BUNDLE_MANIFEST_FILE: &str = "release-manifest.json";
+pub(crate) const BUNDLE_SIGNATURE_FILE: &str = "release-manifest.json.sig";
+pub(crate) const BUNDLE_TARBALL_FILE: &str = "code.tar.gz";
+pub(crate) const BUNDLE_SBOM_FILE: &str = "sbom.cdx.json";
+pub(crate) const BUNDLE_CHECKSUM_FILE: &str = "SHA256SUMS";
+
+pub(crate) const OCI_ARTIFACT_TYPE: &str = "application/vnd.forklaunch.release.v1";
+pub(crate) const OCI_RELEASE_MANIFEST_MEDIA_TYPE: &str =
+    "application/vnd.forklaunch.release.manifest.v1+json";
+const OCI_OPENAPI_MEDIA_TYPE: &str = "application/vnd.forklaunch.openapi.v1+json";
+const OCI_SBOM_MEDIA_TYPE: &str = "application/vnd.cyclonedx+json";
+const OCI_SIGNATURE_MEDIA_TYPE: &str = "application/vnd.forklaunch.release.signature.v1+json";
+const OCI_TARBALL_MEDIA_TYPE: &str = "application/vnd.oci.image.layer.v1.tar+gzip";
+const OCI_IMAGE_MANIFEST_MEDIA_TYPE: &str = "application/vnd.oci.image.manifest.v1+json";
+const OCI_IMAGE_INDEX_MEDIA_TYPE: &str = "application/vnd.oci.image.index.v1+json";
+
+/// On-disk layout for `release create --output`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum BundleFormat {
+    /// Plain directory with the manifest, OpenAPI specs, SBOM, tarball and a SHA256SUMS file
+    Directory,
+    /// OCI image-layout directory (oci-layout, index.json, blobs/sha256/*)
+    Oci,
+}
+
+impl BundleFormat {
+    pub(crate) const VARIANTS: [&'static str; 2] = ["dir", "oci"];
+
+    pub(crate) fn parse(value: &str) -> Result<Self> {
+        match value {
+            "dir" => Ok(Self::Directory),
+            "oci" => Ok(Self::Oci),
+            other => bail!("Unknown bundle format '{}'", other),
+        }
+    }
+}
+
+/// Everything that goes into an offline release bundle.
+pub(crate) struct ReleaseBundle<'a> {
+    pub version: &'a str,
+    pub git_commit: &'a str,
+    pub application_name: &'a str,
+    pub timestamp: &'a str,
+    pub manifest: &'a Value,
+    pub openapi_specs: &'a HashMap<String, Value>,
+    pub tarball: &'a Path,
+    /// CycloneDX JSON SBOM
+    pub sbom: Option<&'a Value>,
+    pub signature: Option<&'a ReleaseSignature>,
+}
+
+/// Write a release bundle to `output_dir`, which must be empty or not yet exist.
+///
+/// Returns the paths written, relative to `output_dir`.
+pub(crate) fn write_release_bundle(
+    output_dir: &Path,
+    format: BundleFormat,
+    bundle: &ReleaseBundle,
+) -> Result<Vec<PathBuf>> {
+    if output_dir.exists()
+        && fs::read_dir(output_dir)
+            .with_context(|| format!("Failed to read {}", output_dir.display()))?
+            .next()
+            .is_some()
+    {
+        bail!(
+            "Output directory {} is not empty. Choose a new directory for each release.",
+            output_dir.display()
+        );
+    }
+    create_dir_all(output_dir)
+        .with_context(|| format!("Failed to create {}", output_dir.display()))?;
+
+    match format {
+        BundleFormat::Directory => write_directory_bundle(output_dir, bundle),
+        BundleFormat::Oci => write_oci_layout(output_dir, bundle),
+    }
+}
+
+fn write_directory_bundle(output_dir: &Path, bundle: &ReleaseBundle) -> Result<Vec<PathBuf>> {
+    let mut written = vec![PathBuf::from(BUNDLE_MANIFEST_FILE)];
+    fs::write(
+        output_dir.join(BUNDLE_MANIFEST_FILE),
+        serde_json::to_string_pretty(bundle.manifest)?,
+    )?;
+
+    if let Some(signature) = bundle.signature {
+        fs::write(
+            output_dir.join(BUNDLE_SIGNATURE_FILE),
+            serde_json::to_string_pretty(signature)?,
+        )?;
+        written.push(PathBuf::from(BUNDLE_SIGNATURE_FILE));
+    }
+
+    let mut services: Vec<_> = bundle.openapi_specs.iter().collect();
+    services.sort_by(|a, b| a.0.cmp(b.0));
+    if !services.is_empty() {
+        create_dir_all(output_dir.join("openapi"))?;
+    }
+    for (service, spec) in services {
+        let relative = Path::new("openapi").join(format!("{}.json", service));
+        fs::write(output_dir.join(&relative), serde_json::to_string_pretty(spec)?)?;
+        written.push(relative);
+    }
+
+    if let Some(sbom) = bundle.sbom {
+        fs::write(
+            output_dir.join(BUNDLE_SBOM_FILE),
+            serde_json::to_string_pretty(sbom)?,
+        )?;
+        written.push(PathBuf::from(BUNDLE_SBOM_FILE));
+    }
+
+    fs::copy(bundle.tarball, output_dir.join(BUNDLE_TARBALL_FILE))
+        .with_context(|| "Failed to copy code tarball into bundle")?;
+    written.push(PathBuf::from(BUNDLE_TARBALL_FILE));
+
+    // sha256sum-compatible: `sha256sum -c SHA256SUMS` from inside the bundle
+    let mut checksums = String::new();
+    for relative in &written {
+        checksums.push_str(&format!(
+            "{}  {}\n",
+            file_digest(&output_dir.join(relative))?,
+            relative.to_string_lossy().replace('\\', "/")
+        ));
+    }
+    fs::write(output_dir.join(BUNDLE_CHECKSUM_FILE), checksums)?;
+    written.push(PathBuf::from(BUNDLE_CHECKSUM_FILE));
+
+    Ok(written)
+}
+
+fn write_oci_layout(output_dir: &Path, bundle: &ReleaseBundle) -> Result<Vec<PathBuf>> {
+    let blobs_dir = output_dir.join("blobs").join("sha256");
+    create_dir_all(&blobs_dir)?;
+    let mut written = Vec::new();
+
+    let write_blob = |bytes: &[u8], written: &mut Vec<PathBuf>| -> Result<(String, u64)> {
+        let digest = format!("{:x}", Sha256::digest(bytes));
+        fs::write(blobs_dir.join(&digest), bytes)?;
+        written.push(Path::new("blobs").join("sha256").join(&digest));
+        Ok((format!("sha256:{}", digest), bytes.len() as u64))
+    };
+
+    let (config_digest, config_size) = write_blob(
+        serde_json::to_string_pretty(bundle.manifest)?.as_bytes(),
+        &mut written,
+    )?;
+
+    let mut layers = Vec::new();
+
+    let tarball_digest = file_digest(bundle.tarball)?;
+    let tarball_size = fs::metadata(bundle.tarball)?.len();
+    fs::copy(bundle.tarball, blobs_dir.join(&tarball_digest))
+        .with_context(|| "Failed to copy code tarball into OCI layout")?;
+    written.push(Path::new("blobs").join("sha256").join(&tarball_digest));
+    layers.push(descriptor(
+        OCI_TARBALL_MEDIA_TYPE,
+        &format!("sha256:{}", tarball_digest),
+        tarball_size,
+        Some(BUNDLE_TARBALL_FILE),
+    ));
+
+    let mut services: Vec<_> = bundle.openapi_specs.iter().collect();
+    services.sort_by(|a, b| a.0.cmp(b.0));
+    for (service, spec) in services {
+        let (digest, size) = write_blob(serde_json::to_string_pretty(spec)?.as_bytes(), &mut written)?;
+        layers.push(descriptor(
+            OCI_OPENAPI_MEDIA_TYPE,
+            &digest,
+            size,
+            Some(&format!("openapi/{}.json", service)),
+        ));
+    }
+
+    if let Some(sbom) = bundle.sbom {
+        let (digest, size) = write_blob(serde_json::to_string_pretty(sbom)?.as_bytes(), &mut written)?;
+        layers.push(descriptor(
+            OCI_SBOM_MEDIA_TYPE,
+            &digest,
+            size,
+            Some(BUNDLE_SBOM_FILE),
+        ));
+    }
+
+    if let Some(signature) = bundle.signature {
+        let (digest, size) = write_blob(
+            serde_json::to_string_pretty(signature)?.as_bytes(),
+            &mut written,
+        )?;
+        layers.push(descriptor(
+            OCI_SIGNATURE_MEDIA_TYPE,
+            &digest,
+            size,
+            Some(BUNDLE_SIGNATURE_FILE),
+        ));
+    }
+
+    let image_manifest = json!({
+        "schemaVersion": 2,
+        "mediaType": OCI_IMAGE_MANIFEST_MEDIA_TYPE,
+        "artifactType": OCI_ARTIFACT_TYPE,
+        "config": descriptor(OCI_RELEASE_MANIFEST_MEDIA_TYPE, &config_digest, config_size, None),
+        "layers": layers,
+        "annotations": {
+            "org.opencontainers.image.title": bundle.application_name,
+            "org.opencontainers.image.version": bundle.version,
+            "org.opencontainers.image.revision": bundle.git_commit,
+            "org.opencontainers.image.created": bundle.timestamp,
+        },
+    });
+    let (manifest_digest, manifest_size) = write_blob(
+        serde_json::to_string_pretty(&image_manifest)?.as_bytes(),
+        &mut written,
+    )?;
+
+    let mut index_entry = descriptor(
+        OCI_IMAGE_MANIFEST_MEDIA_TYPE,
+        &manifest_digest,
+        manifest_size,
+        None,
+    );
+    index_entry["artifactType"] = json!(OCI_ARTIFACT_TYPE);
+    index_entry["annotations"] = json!({ "org.opencontainers.image.ref.name": bundle.version });
+
+    let index = json!({
+        "schemaVersion": 2,
+        "mediaType": OCI_IMAGE_INDEX_MEDIA_TYPE,
+        "manifests": [index_entry],
+    });
+    fs::write(output_dir.join("index.json"), serde_json::to_string_pretty(&index)?)?;
+    written.push(PathBuf::from("index.json"));
+
+    fs::write(
+        output_dir.join("oci-layout"),
+        serde_json::to_string(&json!({ "imageLayoutVersion": "1.0.0" }))?,
+    )?;
+    written.push(PathBuf::from("oci-layout"));
+
+    Ok(written)
+}
+
+/// Read a release manifest from a manifest JSON file, a bundle directory or an OCI layout.
+pub(crate) fn read_release_manifest(path: &Path) -> Result<Value> {
+    let manifest_file = if path.is_dir() {
+        if path.join(BUNDLE_MANIFEST_FILE).is_file() {
+            path.join(BUNDLE_MANIFEST_FILE)
+        } else if path.join("oci-layout").is_file() {
+            oci_release_manifest_blob(path)?
+        } else {
+            bail!(
+                "{} is neither a release bundle nor an OCI layout",
+                path.display()
+            );
+        }
+    } else {
+        path.to_path_buf()
+    };
+
+    let content = fs::read_to_string(&manifest_file)
+        .with_context(|| format!("Failed to read {}", manifest_file.display()))?;
+    serde_json::from_str(&content)
+        .with_context(|| format!("Failed to parse {}", manifest_file.display()))
+}
+
+/// Follow index.json to the release artifact and return the path of its config blob.
+fn oci_release_manifest_blob(layout: &Path) -> Result<PathBuf> {
+    let blob_path = |digest: &str| -> Result<PathBuf> {
+        let hex = digest
+            .strip_prefix("sha256:")
+            .ok_or_else(|| anyhow::anyhow!("Unsupported digest '{}'", digest))?;
+        Ok(layout.join("blobs").join("sha256").join(hex))
+    };
+    let read_json = |path: &Path| -> Result<Value> {
+        serde_json::from_str(
+            &fs::read_to_string(path)
+                .with_context(|| format!("Failed to read {}", path.display()))?,
+        )
+        .with_context(|| format!("Failed to parse {}", path.display()))
+    };
+
+    let index = read_json(&layout.join("index.json"))?;
+    let entry = index["manifests"]
+        .as_array()
+        .and_then(|manifests| {
+            manifests
+                .iter()
+                .find(|m| m["artifactType"] == OCI_ARTIFACT_TYPE)
+                .or_else(|| manifests.first())
+        })
+        .ok_or_else(|| anyhow::anyhow!("No manifests in {}", layout.join("index.json").display()))?;
+
+    let image_manifest = read_json(&blob_path(entry["digest"].as_str().unwrap_or_default())?)?;
+    if image_manifest["config"]["mediaType"] != OCI_RELEASE_MANIFEST_MEDIA_TYPE {
+        bail!(
+            "{} does not contain a forklaunch release (config media type {})",
+            layout.display(),
+            image_manifest["config"]["mediaType"]
+        );
+    }
+    blob_path(image_manifest["config"]["digest"].as_str().unwrap_or_default())
+}
+
+fn descriptor(media_type: &str, digest: &str, size: u64, title: Option<&str>) -> Value {
+    let mut descriptor = json!({
+        "mediaType": media_type,
+        "digest": digest,
+        "size": size,
+    });
+    if let Some(title) = title {
+        descriptor["annotations"] = json!({ "org.opencontainers.image.title": title });
+    }
+    descriptor
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_test_bundle(format: BundleFormat) -> (tempfile::TempDir, Vec<PathBuf>) {
+        let temp = tempfile::tempdir().unwrap();
+        let tarball = temp.path().join("code.tar.gz");
+        fs::write(&tarball, b"not really gzip").unwrap();
+
+        let manifest = json!({ "version": "1.2.0" });
+        let sbom = json!({ "bomFormat": "CycloneDX" });
+        let mut specs = HashMap::new();
+        specs.insert("billing".to_string(), json!({ "openapi": "3.1.0" }));
+
+        let written = write_release_bundle(
+            &temp.path().join("out"),
+            format,
+            &ReleaseBundle {
+                version: "1.2.0",
+                git_commit: "abc123",
+                application_name: "my-app",
+                timestamp: "2025-01-01T00:00:00Z",
+                manifest: &manifest,
+                openapi_specs: &specs,
+                tarball: &tarball,
+                sbom: Some(&sbom),
+                signature: None,
+            },
+        )
+        .unwrap();
+        (temp, written)
+    }
+
+    #[test]
+    fn test_directory_bundle_writes_checksums() {
+        let (temp, written) = write_test_bundle(BundleFormat::Directory);
+        let out = temp.path().join("out");
+
+        assert!(written.contains(&PathBuf::from("openapi/billing.json")));
+        assert!(written.contains(&PathBuf::from(BUNDLE_SBOM_FILE)));
+        let sums = fs::read_to_string(out.join(BUNDLE_CHECKSUM_FILE)).unwrap();
+        let tarball_line = sums
+            .lines()
+            .find(|line| line.ends_with(&format!("  {}", BUNDLE_TARBALL_FILE)))
+            .unwrap();
+        assert!(tarball_line.starts_with(&file_digest(&out.join(BUNDLE_TARBALL_FILE)).unwrap()));
+        assert_eq!(sums.lines().count(), 4);
+    }
+
+    #[test]
+    fn test_oci_layout_blobs_match_digests() {
+        let (temp, _) = write_test_bundle(BundleFormat::Oci);
+        let out = temp.path().join("out");
+
+        let index: Value =
+            serde_json::from_str(&fs::read_to_string(out.join("index.json")).unwrap()).unwrap();
+        let manifest_digest = index["manifests"][0]["digest"].as_str().unwrap();
+        assert_eq!(
+            index["manifests"][0]["annotations"]["org.opencontainers.image.ref.name"],
+            "1.2.0"
+        );
+
+        let blob = |digest: &str| out.join("blobs/sha256").join(digest.trim_start_matches("sha256:"));
+        let image_manifest: Value =
+            serde_json::from_str(&fs::read_to_string(blob(manifest_digest)).unwrap()).unwrap();
+        assert_eq!(image_manifest["config"]["mediaType"], OCI_RELEASE_MANIFEST_MEDIA_TYPE);
+        assert_eq!(image_manifest["layers"].as_array().unwrap().len(), 3);
+
+        for layer in image_manifest["layers"].as_array().unwrap() {
+            let digest = layer["digest"].as_str().unwrap();
+            assert_eq!(
+                format!("sha256:{}", file_digest(&blob(digest)).unwrap()),
+                digest
+            );
+        }
+    }
+
+    #[test]
+    fn test_read_release_manifest_from_every_layout() {
+        for format in [BundleFormat::Directory, BundleFormat::Oci] {
+            let (temp, _) = write_test_bundle(format);
+            let manifest = read_release_manifest(&temp.path().join("out")).unwrap();
+            assert_eq!(manifest["version"], "1.2.0");
+        }
+    }
+
+    #[test]
+    fn test_refuses_non_empty_output_dir() {
+        let temp = tempfile::tempdir().unwrap();
+        fs::write(temp.path().join("stale"), "x").unwrap();
+        let manifest = json!({});
+        let result = write_release_bundle(
+            temp.path(),
+            BundleFormat::Directory,
+            &ReleaseBundle {
+                version: "1.0.0",
+                git_commit: "abc",
+                application_name: "app",
+                timestamp: "",
+                manifest: &manifest,
+                openapi_specs: &HashMap::new(),
+                tarball: &temp.path().join("missing"),
+                sbom: None,
+                signature: None,
+            },
+        );
+        assert!(result.is_err());
+    }
+}
@@ -2,7 +2,7 @@ use std::{
     collections::{HashMap, hash_map::Entry},
     fs::{self, create_dir_all, read_to_string},
     io::Write,
-    path::Path,
+    path::{Path, PathBuf},
 };
 
 use anyhow::{Context, Result, bail};
@@ -13,22 +13,29 @@ use termcolor::{Color, ColorChoice, ColorSpec, StandardStream, WriteColor};
 use toml::to_string_pretty;
 
 use super::{
-    git::{get_git_branch, get_git_commit, is_git_repo},
+    git::{create_annotated_tag, get_git_branch, get_git_commit, is_git_repo, tag_exists},
+    bundle::{BundleFormat, ReleaseBundle, write_release_bundle},
     manifest_generator::{
         EnvironmentVariableComponent, EnvironmentVariableComponentProperty,
         EnvironmentVariableComponentType, EnvironmentVariableRequirement, EnvironmentVariableScope,
         ReleaseManifest, generate_release_manifest,
     },
+    signing::{ReleaseSignature, file_digest, key_id, load_signing_key, sign_release},
+    versioning::{
+        AUTO_VERSION, Bump, RELEASE_TAG_PREFIX, find_openapi_breaking_changes,
+        plan_next_version, render_changelog_section, write_changelog,
+    },
 };
 use crate::{
     CliCommand,
     constants::get_platform_management_api_url,
     core::{
-        ast::infrastructure::{
-            env::find_all_env_vars,
-            integrations::find_all_integrations,
-            runtime_deps::{find_all_runtime_deps, get_unique_resource_types},
-            service_dependencies::find_all_service_dependencies,
+        ast::{
+            analysis::{AnalysisScope, ModulesAnalysis},
+            infrastructure::{
+                integrations::Integration, runtime_deps::get_unique_resource_types,
+                worker_config::WorkerConfig,
+            },
         },
         command::command,
         docker::{DockerCompose, find_docker_compose_path},
@@ -36,8 +43,9 @@ use crate::{
         env_scope::determine_env_var_scopes,
         hmac::AuthMode,
         manifest::{ProjectType, application::ApplicationManifestData},
-        openapi_export::export_all_services,
+        openapi_export::{DEFAULT_EXPORT_TIMEOUT_SECS, ExportOptions, export_all_services},
         rendered_template::RenderedTemplatesCache,
+        sbom::{SbomFormat, build_sbom},
     },
 };
 
@@ -48,6 +56,11 @@ struct CreateReleaseRequest {
     manifest: ReleaseManifest,
     #[serde(rename = "releasedBy", skip_serializing_if = "Option::is_none")]
     released_by: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    signature: Option<ReleaseSignature>,
+    /// CycloneDX JSON SBOM
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sbom: Option<Value>,
 }
 
 #[derive(Debug)]
@@ -68,7 +81,7 @@ impl CliCommand for CreateCommand {
                     .long("version")
                     .short('v')
                     .required(true)
-                    .help("Release version (e.g., 1.0.0)"),
+                    .help("Release version (e.g., 1.0.0), or 'auto' to derive it from conventional commits"),
             )
             .arg(
                 Arg::new("notes")
@@ -100,24 +113,120 @@ impl CliCommand for CreateCommand {
                     .action(clap::ArgAction::SetTrue)
                     .help("Skip automatic sync of projects with manifest before creating release"),
             )
+            .arg(
+                Arg::new("no-changelog")
+                    .long("no-changelog")
+                    .action(clap::ArgAction::SetTrue)
+                    .help("Don't update CHANGELOG.md when using --version auto"),
+            )
+            .arg(
+                Arg::new("tag")
+                    .long("tag")
+                    .action(clap::ArgAction::SetTrue)
+                    .help("Create an annotated git tag (v<version>) for the release"),
+            )
+            .arg(
+                Arg::new("sign")
+                    .long("sign")
+                    .action(clap::ArgAction::SetTrue)
+                    .help("Sign the release manifest and code tarball with an ed25519 key"),
+            )
+            .arg(
+                Arg::new("signing-key")
+                    .long("signing-key")
+                    .help("Path to the ed25519 private key used by --sign (defaults to $FORKLAUNCH_SIGNING_KEY)"),
+            )
+            .arg(
+                Arg::new("output")
+                    .long("output")
+                    .short('o')
+                    .conflicts_with("local")
+                    .help("Write the release bundle to this directory instead of uploading (fully offline)"),
+            )
+            .arg(
+                Arg::new("output-format")
+                    .long("output-format")
+                    .requires("output")
+                    .value_parser(BundleFormat::VARIANTS)
+                    .default_value("dir")
+                    .help("Layout of the --output directory: plain files or an OCI image layout"),
+            )
+            .arg(
+                Arg::new("export-jobs")
+                    .long("export-jobs")
+                    .value_parser(clap::value_parser!(u64).range(1..))
+                    .help("Number of services whose OpenAPI specs are exported at once (default: number of CPUs)"),
+            )
+            .arg(
+                Arg::new("export-timeout")
+                    .long("export-timeout")
+                    .value_parser(clap::value_parser!(u64).range(1..))
+                    .help(format!(
+                        "Seconds each service's OpenAPI export may take before it is killed (default: {})",
+                        DEFAULT_EXPORT_TIMEOUT_SECS
+                    )),
+            )
+            .arg(
+                Arg::new("continue-on-error")
+                    .long("continue-on-error")
+                    .action(clap::ArgAction::SetTrue)
+                    .help("Release without the OpenAPI specs of services that fail to export"),
+            )
     }
 
     fn handler(&self, matches: &ArgMatches) -> Result<()> {
         let mut stdout = StandardStream::stdout(ColorChoice::Always);
 
-        // Upfront validation
-        let auth_mode = crate::core::validate::resolve_auth()?;
+        let output_dir = matches.get_one::<String>("output").map(PathBuf::from);
+
+        // Upfront validation. Offline bundles need neither credentials nor an integrated app.
+        let auth_mode = if output_dir.is_some() {
+            AuthMode::detect()
+        } else {
+            crate::core::validate::resolve_auth()?
+        };
         let (app_root, manifest) = crate::core::validate::require_manifest(matches)?;
-        let application_id = crate::core::validate::require_integration(&manifest)?;
+        let application_id = if output_dir.is_some() {
+            manifest
+                .platform_application_id
+                .clone()
+                .unwrap_or_else(|| manifest.app_name.clone())
+        } else {
+            crate::core::validate::require_integration(&manifest)?
+        };
 
-        // Get version
-        let version = matches
+        // Get version ("auto" is resolved once git history has been read)
+        let requested_version = matches
             .get_one::<String>("release_version")
             .ok_or_else(|| anyhow::anyhow!("Version is required"))?;
+        let auto_version = requested_version == AUTO_VERSION;
+        let mut version = requested_version.clone();
+        let update_changelog = !matches.get_flag("no-changelog");
+        let create_tag = matches.get_flag("tag");
 
         let dry_run = matches.get_flag("dry-run");
         let local_mode = matches.get_flag("local");
         let skip_sync = matches.get_flag("skip-sync");
+        let output_format = BundleFormat::parse(matches.get_one::<String>("output-format").unwrap())?;
+        // Code is packaged from the working tree rather than referenced by git repository
+        let package_code = local_mode || output_dir.is_some();
+
+        // Load the signing key before doing any work so a bad path fails fast
+        let signing_key = if matches.get_flag("sign") {
+            let key_path = matches
+                .get_one::<String>("signing-key")
+                .cloned()
+                .or_else(|| std::env::var("FORKLAUNCH_SIGNING_KEY").ok())
+                .filter(|path| !path.is_empty())
+                .ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "--sign requires --signing-key <path> or FORKLAUNCH_SIGNING_KEY to be set"
+                    )
+                })?;
+            Some(load_signing_key(Path::new(&key_path))?)
+        } else {
+            None
+        };
 
         let manifest_path = app_root.join(".forklaunch").join("manifest.toml");
         let mut manifest = manifest;
@@ -178,8 +287,8 @@ impl CliCommand for CreateCommand {
             writeln!(stdout)?;
         }
 
-        // Skip git repository check if using local mode
-        if !local_mode && manifest.git_repository.is_none() {
+        // Skip git repository check if packaging code directly
+        if !package_code && manifest.git_repository.is_none() {
             stdout.set_color(ColorSpec::new().set_fg(Some(Color::Yellow)))?;
             writeln!(stdout, "[INFO] Git repository URL not set in manifest")?;
             stdout.reset()?;
@@ -211,6 +320,21 @@ impl CliCommand for CreateCommand {
             stdout.reset()?;
         }
 
+        let mut version_plan = if auto_version {
+            if !is_git_repo() {
+                bail!("--version auto requires a git repository");
+            }
+            let plan = plan_next_version(&app_root, &manifest)?;
+            version = plan.next.to_string();
+
+            stdout.set_color(ColorSpec::new().set_fg(Some(Color::Cyan)))?;
+            writeln!(stdout, "[INFO] Version: {}", plan.describe())?;
+            stdout.reset()?;
+            Some(plan)
+        } else {
+            None
+        };
+
         stdout.set_color(ColorSpec::new().set_fg(Some(Color::Cyan)).set_bold(true))?;
         writeln!(stdout, "[INFO] Creating release {}...", version)?;
         stdout.reset()?;
@@ -229,7 +353,7 @@ impl CliCommand for CreateCommand {
             writeln!(stdout, " [OK]")?;
             stdout.reset()?;
             (commit, branch)
-        } else if local_mode {
+        } else if package_code {
             stdout.set_color(ColorSpec::new().set_fg(Some(Color::Yellow)))?;
             writeln!(
                 stdout,
@@ -255,314 +379,44 @@ impl CliCommand for CreateCommand {
             git_branch.as_deref().unwrap_or("unknown")
         )?;
 
-        // Step 2: Export OpenAPI specs
-        stdout.set_color(ColorSpec::new().set_fg(Some(Color::Cyan)))?;
-        write!(stdout, "[INFO] Exporting OpenAPI specifications...")?;
-        stdout.flush()?;
-        stdout.reset()?;
-
-        let openapi_path = app_root.join(".forklaunch").join("openapi");
-        create_dir_all(&openapi_path).with_context(|| "Failed to create openapi directory")?;
-
-        let exported_services = export_all_services(&app_root, &manifest, &openapi_path)?;
-
-        stdout.set_color(ColorSpec::new().set_fg(Some(Color::Green)))?;
-        writeln!(stdout, " [OK] ({} services)", exported_services.len())?;
-        stdout.reset()?;
-
-        let mut openapi_specs = HashMap::new();
-        for project in &manifest.projects {
-            let openapi_file = openapi_path.join(&project.name).join("openapi.json");
-            if openapi_file.exists() {
-                let content = read_to_string(&openapi_file)?;
-                let spec: Value = serde_json::from_str(&content)?;
-                openapi_specs.insert(project.name.clone(), spec);
-            }
-        }
-
-        stdout.set_color(ColorSpec::new().set_fg(Some(Color::Cyan)))?;
-        write!(stdout, "[INFO] Detecting required environment variables...")?;
-        stdout.flush()?;
-        stdout.reset()?;
-
-        let workspace_root = find_workspace_root(&app_root)?;
-        let modules_path = get_modules_path(&workspace_root)?;
-
-        let rendered_templates_cache = RenderedTemplatesCache::new();
-        let project_env_vars = find_all_env_vars(&modules_path, &rendered_templates_cache)?;
-
-        let mut scoped_env_vars = determine_env_var_scopes(&project_env_vars, &manifest)?;
-
-        let (mut env_var_components, docker_compose_env_vars) =
-            build_env_var_component_map(app_root.as_path(), &manifest);
-
-        // Add all env vars from docker-compose for each service/worker
-        let mut existing_vars: std::collections::HashSet<(String, Option<String>)> =
-            scoped_env_vars
-                .iter()
-                .map(|v| (v.name.clone(), v.scope_id.clone()))
-                .collect();
-
-        for (service_name, env_vars) in docker_compose_env_vars {
-            let project_types: HashMap<String, ProjectType> = manifest
-                .projects
-                .iter()
-                .map(|p| (p.name.clone(), p.r#type.clone()))
-                .collect();
-
-            let worker_alias_info = classify_worker_alias(&service_name, &project_types);
-
-            let (scope, scope_id) =
-                if let Some((component_type, base_worker_name)) = worker_alias_info {
-                    match component_type {
-                        EnvironmentVariableComponentType::Service => (
-                            crate::core::env_scope::EnvironmentVariableScope::Service,
-                            Some(format!("{}-service", base_worker_name)),
-                        ),
-                        EnvironmentVariableComponentType::Worker => (
-                            crate::core::env_scope::EnvironmentVariableScope::Worker,
-                            Some(format!("{}-worker", base_worker_name)),
-                        ),
-                        _ => continue,
-                    }
-                } else {
-                    // Check if it's a direct project match
-                    let project_type = manifest
-                        .projects
-                        .iter()
-                        .find(|p| p.name == service_name)
-                        .map(|p| &p.r#type);
-
-                    match project_type {
-                        Some(crate::core::manifest::ProjectType::Service) => (
-                            crate::core::env_scope::EnvironmentVariableScope::Service,
-                            Some(service_name.clone()),
-                        ),
-                        Some(crate::core::manifest::ProjectType::Worker) => (
-                            crate::core::env_scope::EnvironmentVariableScope::Worker,
-                            Some(format!("{}-worker", service_name)),
-                        ),
-                        _ => continue, // Skip if not a service or worker
-                    }
-                };
-
-            for (key, _value) in env_vars {
-                // Skip if already exists
-                if existing_vars.contains(&(key.clone(), scope_id.clone())) {
-                    continue;
-                }
-
-                // Handle PORT specially - ensure passthrough is set to "8000" in component map
-                let key_upper = key.to_ascii_uppercase();
-                if key_upper == "PORT" {
-                    // Update or insert PORT with passthrough "8000"
-                    let property = infer_component_property(&key_upper).unwrap_or_else(|| {
-                        default_component_property(
-                            &EnvironmentVariableComponentType::Service,
-                            &key_upper,
-                        )
-                    });
-                    env_var_components.insert(
-                        key.clone(),
-                        (
-                            EnvironmentVariableComponentType::Service,
-                            property,
-                            None,
-                            None,
-                            Some("8000".to_string()),
-                        ),
-                    );
-                }
-
-                scoped_env_vars.push(crate::core::env_scope::ScopedEnvVar {
-                    name: key.clone(),
-                    scope: scope.clone(),
-                    scope_id: scope_id.clone(),
-                    used_by: vec![service_name.clone()],
-                });
-
-                existing_vars.insert((key, scope_id.clone()));
-            }
-        }
+        let ReleaseAnalysis {
+            openapi_specs,
+            required_env_vars,
+            project_runtime_deps,
+            all_integrations,
+            all_worker_configs,
+            all_service_deps,
+        } = analyze_release(
+            &app_root,
+            &manifest,
+            &ExportOptions::from_matches(matches),
+            &mut stdout,
+        )?;
 
-        // Fallback: infer service/worker URL component from env var name
-        // For _URL vars not already in env_var_components, check if the name
-        // (minus _URL, converted to kebab-case) matches a project Service or Worker
+        // Removed operations or newly required parameters force a major bump
+        if let Some(plan) = &mut version_plan
+            && let Some(since) = plan.since.clone()
         {
-            let project_types: HashMap<String, ProjectType> = manifest
-                .projects
-                .iter()
-                .map(|p| (p.name.clone(), p.r#type.clone()))
-                .collect();
-
-            for scoped_var in &scoped_env_vars {
-                if env_var_components.contains_key(&scoped_var.name) {
-                    continue;
+            let breaking_changes = find_openapi_breaking_changes(&app_root, &since, &openapi_specs);
+            if !breaking_changes.is_empty() {
+                stdout.set_color(ColorSpec::new().set_fg(Some(Color::Yellow)))?;
+                writeln!(stdout, "[WARN] OpenAPI breaking changes since {}:", since)?;
+                stdout.reset()?;
+                for change in &breaking_changes {
+                    writeln!(stdout, "  - {}", change)?;
                 }
 
-                let upper = scoped_var.name.to_ascii_uppercase();
-                if !upper.ends_with("_URL") {
-                    continue;
+                plan.escalate(Bump::Major);
+                if plan.next.to_string() != version {
+                    version = plan.next.to_string();
+                    writeln!(stdout, "[INFO] Version bumped to {}", version)?;
                 }
-
-                // BILLING_URL -> billing, PLATFORM_MANAGEMENT_URL -> platform-management
-                let stripped = upper.trim_end_matches("_URL");
-                let kebab = stripped.to_ascii_lowercase().replace('_', "-");
-
-                if let Some(project_type) = project_types.get(&kebab) {
-                    let component_type = match project_type {
-                        ProjectType::Service => EnvironmentVariableComponentType::Service,
-                        ProjectType::Worker => EnvironmentVariableComponentType::Worker,
-                        _ => continue,
-                    };
-                    env_var_components.insert(
-                        scoped_var.name.clone(),
-                        (
-                            component_type,
-                            EnvironmentVariableComponentProperty::Url,
-                            Some(kebab),
-                            None,
-                            None,
-                        ),
-                    );
-                }
-            }
-        }
-
-        // Only keep application-level variables if they match the allowed criteria
-        scoped_env_vars.retain(|v| {
-            if v.scope != crate::core::env_scope::EnvironmentVariableScope::Application {
-                return true;
-            }
-
-            is_allowed_application_var(&v.name, &env_var_components)
-        });
-
-        stdout.set_color(ColorSpec::new().set_fg(Some(Color::Green)))?;
-        writeln!(stdout, " [OK] ({} variables)", scoped_env_vars.len())?;
-        stdout.reset()?;
-
-        // Detect runtime dependencies
-        stdout.set_color(ColorSpec::new().set_fg(Some(Color::Cyan)))?;
-        write!(stdout, "[INFO] Detecting runtime dependencies...")?;
-        stdout.flush()?;
-        stdout.reset()?;
-
-        let all_runtime_deps = find_all_runtime_deps(&modules_path, &rendered_templates_cache)?;
-
-        // Convert to resource types per project
-        let mut project_runtime_deps: HashMap<String, Vec<String>> = HashMap::new();
-        for (project_name, deps) in &all_runtime_deps {
-            let resource_types = get_unique_resource_types(deps);
-            // Filter out "monitoring" as it's not a provisionable resource
-            let filtered_types: Vec<String> = resource_types
-                .into_iter()
-                .filter(|t| t != "monitoring")
-                .collect();
-            if !filtered_types.is_empty() {
-                project_runtime_deps.insert(project_name.clone(), filtered_types);
             }
         }
 
-        let total_resources: usize = project_runtime_deps.values().map(|v| v.len()).sum();
-        stdout.set_color(ColorSpec::new().set_fg(Some(Color::Green)))?;
-        writeln!(stdout, " [OK] ({} resources)", total_resources)?;
-        stdout.reset()?;
-
-        // Detect integrations
-        stdout.set_color(ColorSpec::new().set_fg(Some(Color::Cyan)))?;
-        write!(stdout, "[INFO] Detecting integrations...")?;
-        stdout.flush()?;
-        stdout.reset()?;
-
-        let all_integrations = find_all_integrations(&modules_path, &rendered_templates_cache)?;
-
-        let total_integrations: usize = all_integrations.values().map(|v| v.len()).sum();
-        stdout.set_color(ColorSpec::new().set_fg(Some(Color::Green)))?;
-        writeln!(stdout, " [OK] ({} integrations)", total_integrations)?;
-        stdout.reset()?;
-
-        // Detect worker configurations
-        stdout.set_color(ColorSpec::new().set_fg(Some(Color::Cyan)))?;
-        write!(stdout, "[INFO] Detecting worker configurations...")?;
-        stdout.flush()?;
-        stdout.reset()?;
-
-        let all_worker_configs =
-            crate::core::ast::infrastructure::worker_config::find_all_worker_configs(
-                &modules_path,
-                &rendered_templates_cache,
-            )?;
-
-        let total_worker_configs = all_worker_configs.len();
-        stdout.set_color(ColorSpec::new().set_fg(Some(Color::Green)))?;
-        writeln!(stdout, " [OK] ({} workers)", total_worker_configs)?;
-        stdout.reset()?;
-
-        // Detect service mesh dependencies (SDK client imports between services)
-        stdout.set_color(ColorSpec::new().set_fg(Some(Color::Cyan)))?;
-        write!(stdout, "[INFO] Detecting service mesh connections...")?;
-        stdout.flush()?;
-        stdout.reset()?;
-
-        let all_service_deps =
-            find_all_service_dependencies(&modules_path, &rendered_templates_cache)?;
-
-        let total_service_deps: usize = all_service_deps.values().map(|v| v.len()).sum();
-        stdout.set_color(ColorSpec::new().set_fg(Some(Color::Green)))?;
-        writeln!(stdout, " [OK] ({} connections)", total_service_deps)?;
-        stdout.reset()?;
-
-        let required_env_vars: Vec<EnvironmentVariableRequirement> = scoped_env_vars
-            .iter()
-            .map(|v| EnvironmentVariableRequirement {
-                name: v.name.clone(),
-                scope: match v.scope {
-                    crate::core::env_scope::EnvironmentVariableScope::Application => {
-                        EnvironmentVariableScope::Application
-                    }
-                    crate::core::env_scope::EnvironmentVariableScope::Service => {
-                        EnvironmentVariableScope::Service
-                    }
-                    crate::core::env_scope::EnvironmentVariableScope::Worker => {
-                        EnvironmentVariableScope::Worker
-                    }
-                },
-                scope_id: v.scope_id.clone(),
-                component: env_var_components.get(&v.name).map(
-                    |(component_type, property, target, path, passthrough)| {
-                        EnvironmentVariableComponent {
-                            r#type: component_type.clone(),
-                            property: property.clone(),
-                            target: target.clone(),
-                            path: path.clone(),
-                            passthrough: passthrough.clone(),
-                        }
-                    },
-                ),
-            })
-            .collect();
-
-        let app_vars = scoped_env_vars
-            .iter()
-            .filter(|v| v.scope == crate::core::env_scope::EnvironmentVariableScope::Application)
-            .count();
-        let service_vars = scoped_env_vars
-            .iter()
-            .filter(|v| v.scope == crate::core::env_scope::EnvironmentVariableScope::Service)
-            .count();
-        let worker_vars = scoped_env_vars
-            .iter()
-            .filter(|v| v.scope == crate::core::env_scope::EnvironmentVariableScope::Worker)
-            .count();
-        if app_vars > 0 {
-            writeln!(stdout, "[INFO] Application-level: {}", app_vars)?;
-        }
-        if service_vars > 0 {
-            writeln!(stdout, "[INFO] Service-level: {}", service_vars)?;
-        }
-        if worker_vars > 0 {
-            writeln!(stdout, "[INFO] Worker-level: {}", worker_vars)?;
+        let release_tag = format!("{}{}", RELEASE_TAG_PREFIX, version);
+        if create_tag && !dry_run && tag_exists(&app_root, &release_tag) {
+            bail!("Tag {} already exists", release_tag);
         }
 
         stdout.set_color(ColorSpec::new().set_fg(Some(Color::Cyan)))?;
@@ -570,19 +424,28 @@ impl CliCommand for CreateCommand {
         stdout.flush()?;
         stdout.reset()?;
 
-        // Handle local mode: create tarball and upload to S3
-        let code_source_url = if local_mode && !dry_run {
+        // Create the code tarball when it will be uploaded or signed
+        let tarball_path = app_root.join(".forklaunch").join("release-code.tar.gz");
+        let upload_code = local_mode && !dry_run;
+        if upload_code || signing_key.is_some() || output_dir.is_some() {
             stdout.set_color(ColorSpec::new().set_fg(Some(Color::Cyan)))?;
             writeln!(stdout, "\n[INFO] Packaging local code...")?;
             stdout.reset()?;
 
-            // Create tarball
-            let tarball_path = app_root.join(".forklaunch").join("release-code.tar.gz");
             super::s3_upload::create_app_tarball(&app_root, &tarball_path)?;
 
             stdout.set_color(ColorSpec::new().set_fg(Some(Color::Green)))?;
             writeln!(stdout, "[INFO] Tarball created")?;
             stdout.reset()?;
+        }
+        let tarball_sha256 = if signing_key.is_some() {
+            Some(file_digest(&tarball_path)?)
+        } else {
+            None
+        };
+
+        // Handle local mode: upload tarball to S3
+        let code_source_url = if upload_code {
 
             // Get presigned upload URL from platform
             stdout.set_color(ColorSpec::new().set_fg(Some(Color::Cyan)))?;
@@ -591,7 +454,7 @@ impl CliCommand for CreateCommand {
             stdout.reset()?;
 
             let upload_response =
-                super::s3_upload::get_presigned_upload_url(&application_id, version, &auth_mode)?;
+                super::s3_upload::get_presigned_upload_url(&application_id, &version, &auth_mode)?;
 
             stdout.set_color(ColorSpec::new().set_fg(Some(Color::Green)))?;
             writeln!(stdout, " [OK]")?;
@@ -609,15 +472,13 @@ impl CliCommand for CreateCommand {
             writeln!(stdout, " [OK]")?;
             stdout.reset()?;
 
-            // Clean up tarball
-            std::fs::remove_file(&tarball_path).ok();
 
             Some(upload_response.code_source_url)
         } else {
             None
         };
 
-        let release_manifest = generate_release_manifest(
+        let mut release_manifest = generate_release_manifest(
             &app_root,
             application_id.clone(),
             version.clone(),
@@ -637,12 +498,85 @@ impl CliCommand for CreateCommand {
         writeln!(stdout, " [OK]")?;
         stdout.reset()?;
 
-        if dry_run {
+        // The SBOM is attached to every release; failing to build it only warns
+        let sbom = match build_sbom(&app_root, &manifest, Some(&version), None) {
+            Ok(sbom) => {
+                writeln!(
+                    stdout,
+                    "[INFO] SBOM generated with {} components",
+                    sbom.components.len()
+                )?;
+                Some(sbom.render(SbomFormat::CycloneDxJson, &release_manifest.timestamp))
+            }
+            Err(error) => {
+                stdout.set_color(ColorSpec::new().set_fg(Some(Color::Yellow)))?;
+                writeln!(stdout, "[WARN] Could not generate SBOM: {:#}", error)?;
+                stdout.reset()?;
+                None
+            }
+        };
+
+        let manifest_file = app_root.join(".forklaunch").join("release-manifest.json");
+        let signature = if let Some(signing_key) = &signing_key {
+            release_manifest.signing_key_id = Some(key_id(&signing_key.verifying_key()));
+            let signature = sign_release(
+                signing_key,
+                &serde_json::to_value(&release_manifest)?,
+                tarball_sha256,
+            );
+
+            let mut signature_file = manifest_file.clone().into_os_string();
+            signature_file.push(".sig");
+            std::fs::write(
+                &manifest_file,
+                serde_json::to_string_pretty(&release_manifest)?,
+            )?;
+            std::fs::write(&signature_file, serde_json::to_string_pretty(&signature)?)?;
+
+            stdout.set_color(ColorSpec::new().set_fg(Some(Color::Green)))?;
+            writeln!(stdout, "[OK] Release signed with key {}", signature.key_id)?;
+            stdout.reset()?;
+            writeln!(
+                stdout,
+                "[INFO] Signature written to: {}",
+                Path::new(&signature_file).display()
+            )?;
+            Some(signature)
+        } else {
+            None
+        };
+
+        if let Some(output_dir) = &output_dir {
+            stdout.set_color(ColorSpec::new().set_fg(Some(Color::Cyan)))?;
+            write!(stdout, "[INFO] Writing release bundle...")?;
+            stdout.flush()?;
+            stdout.reset()?;
+
+            let written = write_release_bundle(
+                output_dir,
+                output_format,
+                &ReleaseBundle {
+                    version: &version,
+                    git_commit: &git_commit,
+                    application_name: &manifest.app_name,
+                    timestamp: &release_manifest.timestamp,
+                    manifest: &serde_json::to_value(&release_manifest)?,
+                    openapi_specs: &openapi_specs,
+                    tarball: &tarball_path,
+                    sbom: sbom.as_ref(),
+                    signature: signature.as_ref(),
+                },
+            )?;
+
+            stdout.set_color(ColorSpec::new().set_fg(Some(Color::Green)))?;
+            writeln!(stdout, " [OK] ({} files)", written.len())?;
+            stdout.reset()?;
+            writeln!(stdout, "[INFO] Bundle written to: {}", output_dir.display())?;
+        } else if dry_run {
             stdout.set_color(ColorSpec::new().set_fg(Some(Color::Yellow)))?;
             writeln!(stdout, "\n  [DRY RUN] Skipping upload to platform")?;
             stdout.reset()?;
 
-            let manifest_file = app_root.join(".forklaunch").join("release-manifest.json");
             std::fs::write(
                 &manifest_file,
                 serde_json::to_string_pretty(&release_manifest)?,
@@ -652,13 +586,24 @@ impl CliCommand for CreateCommand {
                 "[INFO] Manifest written to: {}",
                 manifest_file.display()
             )?;
+            if let Some(sbom) = &sbom {
+                let sbom_file = app_root.join(".forklaunch").join("release-sbom.cdx.json");
+                std::fs::write(&sbom_file, serde_json::to_string_pretty(sbom)?)?;
+                writeln!(stdout, "[INFO] SBOM written to: {}", sbom_file.display())?;
+            }
         } else {
             stdout.set_color(ColorSpec::new().set_fg(Some(Color::Cyan)))?;
             write!(stdout, "[INFO] Uploading release to platform...")?;
             stdout.flush()?;
             stdout.reset()?;
 
-            upload_release(&application_id, release_manifest, &auth_mode)?;
+            upload_release(
+                &application_id,
+                release_manifest,
+                signature,
+                sbom,
+                &auth_mode,
+            )?;
 
             stdout.set_color(ColorSpec::new().set_fg(Some(Color::Green)))?;
             writeln!(stdout, " [OK]")?;
@@ -675,12 +620,54 @@ impl CliCommand for CreateCommand {
                 .with_context(|| "Failed to write updated manifest")?;
         }
 
+        if let Some(plan) = &version_plan
+            && update_changelog
+        {
+            let project_names: Vec<String> =
+                manifest.projects.iter().map(|p| p.name.clone()).collect();
+            let section = render_changelog_section(
+                &plan.next,
+                &chrono::Utc::now().format("%Y-%m-%d").to_string(),
+                &plan.commits,
+                &manifest.modules_path,
+                &project_names,
+            );
+
+            if dry_run {
+                stdout.set_color(ColorSpec::new().set_fg(Some(Color::Yellow)))?;
+                writeln!(stdout, "\n  [DRY RUN] Changelog entry (not written):")?;
+                stdout.reset()?;
+                writeln!(stdout, "{}", section)?;
+            } else {
+                let changelog_path = write_changelog(&app_root, &section)?;
+                writeln!(stdout, "[INFO] Changelog updated: {}", changelog_path.display())?;
+            }
+        }
+
+        if create_tag && !dry_run {
+            let message = match matches.get_one::<String>("notes") {
+                Some(notes) => format!("Release {}\n\n{}", version, notes),
+                None => format!("Release {}", version),
+            };
+            create_annotated_tag(&app_root, &release_tag, &message)?;
+
+            stdout.set_color(ColorSpec::new().set_fg(Some(Color::Green)))?;
+            writeln!(stdout, "[OK] Created tag {}", release_tag)?;
+            stdout.reset()?;
+            writeln!(stdout, "[INFO] Push it with: git push origin {}", release_tag)?;
+        }
+
+        // Clean up tarball (kept when signing without a bundle so it can be verified later)
+        if signing_key.is_none() || output_dir.is_some() {
+            std::fs::remove_file(&tarball_path).ok();
+        }
+
         writeln!(stdout)?;
         stdout.set_color(ColorSpec::new().set_fg(Some(Color::Green)).set_bold(true))?;
         writeln!(stdout, "[OK] Release {} created successfully!", version)?;
         stdout.reset()?;
 
-        if !dry_run {
+        if !dry_run && output_dir.is_none() {
             stdout.set_color(ColorSpec::new().set_fg(Some(Color::Cyan)))?;
             writeln!(stdout, "\n[INFO] Next steps:")?;
             stdout.reset()?;
@@ -696,15 +683,370 @@ impl CliCommand for CreateCommand {
     }
 }
 
+/// Everything `generate_release_manifest` needs that is discovered from the application source.
+pub(crate) struct ReleaseAnalysis {
+    pub openapi_specs: HashMap<String, Value>,
+    pub required_env_vars: Vec<EnvironmentVariableRequirement>,
+    pub project_runtime_deps: HashMap<String, Vec<String>>,
+    pub all_integrations: HashMap<String, Vec<Integration>>,
+    pub all_worker_configs: HashMap<String, WorkerConfig>,
+    pub all_service_deps: HashMap<String, Vec<(String, String)>>,
+}
+
+/// Export OpenAPI specs and detect env vars, runtime dependencies, integrations,
+/// worker configs and service connections, reporting progress as it goes.
+pub(crate) fn analyze_release(
+    app_root: &Path,
+    manifest: &ApplicationManifestData,
+    export_options: &ExportOptions,
+    stdout: &mut StandardStream,
+) -> Result<ReleaseAnalysis> {
+    // Step 2: Export OpenAPI specs
+    stdout.set_color(ColorSpec::new().set_fg(Some(Color::Cyan)))?;
+    write!(stdout, "[INFO] Exporting OpenAPI specifications...")?;
+    stdout.flush()?;
+    stdout.reset()?;
+
+    let openapi_path = app_root.join(".forklaunch").join("openapi");
+    create_dir_all(&openapi_path).with_context(|| "Failed to create openapi directory")?;
+
+    let export_report = export_all_services(app_root, manifest, &openapi_path, export_options)?;
+
+    stdout.set_color(ColorSpec::new().set_fg(Some(Color::Green)))?;
+    writeln!(stdout, " [OK] ({} services)", export_report.exported().len())?;
+    stdout.reset()?;
+
+    for failure in export_report.failures() {
+        stdout.set_color(ColorSpec::new().set_fg(Some(Color::Yellow)))?;
+        writeln!(
+            stdout,
+            "[WARN] No OpenAPI spec for {}: {}",
+            failure.name,
+            failure.describe()
+        )?;
+        stdout.reset()?;
+    }
+
+    let mut openapi_specs = HashMap::new();
+    for project in &manifest.projects {
+        let openapi_file = openapi_path.join(&project.name).join("openapi.json");
+        if openapi_file.exists() {
+            let content = read_to_string(&openapi_file)?;
+            let spec: Value = serde_json::from_str(&content)?;
+            openapi_specs.insert(project.name.clone(), spec);
+        }
+    }
+
+    stdout.set_color(ColorSpec::new().set_fg(Some(Color::Cyan)))?;
+    write!(stdout, "[INFO] Detecting required environment variables...")?;
+    stdout.flush()?;
+    stdout.reset()?;
+
+    let workspace_root = find_workspace_root(app_root)?;
+    let modules_path = get_modules_path(&workspace_root)?;
+
+    // One parse per source file feeds every detection step below; unchanged
+    // files are served from the analysis cache in .forklaunch/cache.
+    let rendered_templates_cache = RenderedTemplatesCache::new();
+    let analysis = ModulesAnalysis::analyze_cached(
+        &modules_path,
+        &rendered_templates_cache,
+        AnalysisScope::AllSources,
+    )?;
+    let project_env_vars = analysis.env_vars();
+
+    let mut scoped_env_vars = determine_env_var_scopes(&project_env_vars, manifest)?;
+
+    let (mut env_var_components, docker_compose_env_vars) =
+        build_env_var_component_map(app_root, manifest);
+
+    // Add all env vars from docker-compose for each service/worker
+    let mut existing_vars: std::collections::HashSet<(String, Option<String>)> =
+        scoped_env_vars
+            .iter()
+            .map(|v| (v.name.clone(), v.scope_id.clone()))
+            .collect();
+
+    for (service_name, env_vars) in docker_compose_env_vars {
+        let project_types: HashMap<String, ProjectType> = manifest
+            .projects
+            .iter()
+            .map(|p| (p.name.clone(), p.r#type.clone()))
+            .collect();
+
+        let worker_alias_info = classify_worker_alias(&service_name, &project_types);
+
+        let (scope, scope_id) =
+            if let Some((component_type, base_worker_name)) = worker_alias_info {
+                match component_type {
+                    EnvironmentVariableComponentType::Service => (
+                        crate::core::env_scope::EnvironmentVariableScope::Service,
+                        Some(format!("{}-service", base_worker_name)),
+                    ),
+                    EnvironmentVariableComponentType::Worker => (
+                        crate::core::env_scope::EnvironmentVariableScope::Worker,
+                        Some(format!("{}-worker", base_worker_name)),
+                    ),
+                    _ => continue,
+                }
+            } else {
+                // Check if it's a direct project match
+                let project_type = manifest
+                    .projects
+                    .iter()
+                    .find(|p| p.name == service_name)
+                    .map(|p| &p.r#type);
+
+                match project_type {
+                    Some(crate::core::manifest::ProjectType::Service) => (
+                        crate::core::env_scope::EnvironmentVariableScope::Service,
+                        Some(service_name.clone()),
+                    ),
+                    Some(crate::core::manifest::ProjectType::Worker) => (
+                        crate::core::env_scope::EnvironmentVariableScope::Worker,
+                        Some(format!("{}-worker", service_name)),
+                    ),
+                    _ => continue, // Skip if not a service or worker
+                }
+            };
+
+        for (key, _value) in env_vars {
+            // Skip if already exists
+            if existing_vars.contains(&(key.clone(), scope_id.clone())) {
+                continue;
+            }
+
+            // Handle PORT specially - ensure passthrough is set to "8000" in component map
+            let key_upper = key.to_ascii_uppercase();
+            if key_upper == "PORT" {
+                // Update or insert PORT with passthrough "8000"
+                let property = infer_component_property(&key_upper).unwrap_or_else(|| {
+                    default_component_property(
+                        &EnvironmentVariableComponentType::Service,
+                        &key_upper,
+                    )
+                });
+                env_var_components.insert(
+                    key.clone(),
+                    (
+                        EnvironmentVariableComponentType::Service,
+                        property,
+                        None,
+                        None,
+                        Some("8000".to_string()),
+                    ),
+                );
+            }
+
+            scoped_env_vars.push(crate::core::env_scope::ScopedEnvVar {
+                name: key.clone(),
+                scope: scope.clone(),
+                scope_id: scope_id.clone(),
+                used_by: vec![service_name.clone()],
+            });
+
+            existing_vars.insert((key, scope_id.clone()));
+        }
+    }
+
+    // Fallback: infer service/worker URL component from env var name
+    // For _URL vars not already in env_var_components, check if the name
+    // (minus _URL, converted to kebab-case) matches a project Service or Worker
+    {
+        let project_types: HashMap<String, ProjectType> = manifest
+            .projects
+            .iter()
+            .map(|p| (p.name.clone(), p.r#type.clone()))
+            .collect();
+
+        for scoped_var in &scoped_env_vars {
+            if env_var_components.contains_key(&scoped_var.name) {
+                continue;
+            }
+
+            let upper = scoped_var.name.to_ascii_uppercase();
+            if !upper.ends_with("_URL") {
+                continue;
+            }
+
+            // BILLING_URL -> billing, PLATFORM_MANAGEMENT_URL -> platform-management
+            let stripped = upper.trim_end_matches("_URL");
+            let kebab = stripped.to_ascii_lowercase().replace('_', "-");
+
+            if let Some(project_type) = project_types.get(&kebab) {
+                let component_type = match project_type {
+                    ProjectType::Service => EnvironmentVariableComponentType::Service,
+                    ProjectType::Worker => EnvironmentVariableComponentType::Worker,
+                    _ => continue,
+                };
+                env_var_components.insert(
+                    scoped_var.name.clone(),
+                    (
+                        component_type,
+                        EnvironmentVariableComponentProperty::Url,
+                        Some(kebab),
+                        None,
+                        None,
+                    ),
+                );
+            }
+        }
+    }
+
+    // Only keep application-level variables if they match the allowed criteria
+    scoped_env_vars.retain(|v| {
+        if v.scope != crate::core::env_scope::EnvironmentVariableScope::Application {
+            return true;
+        }
+
+        is_allowed_application_var(&v.name, &env_var_components)
+    });
+
+    stdout.set_color(ColorSpec::new().set_fg(Some(Color::Green)))?;
+    writeln!(stdout, " [OK] ({} variables)", scoped_env_vars.len())?;
+    stdout.reset()?;
+
+    // Detect runtime dependencies
+    stdout.set_color(ColorSpec::new().set_fg(Some(Color::Cyan)))?;
+    write!(stdout, "[INFO] Detecting runtime dependencies...")?;
+    stdout.flush()?;
+    stdout.reset()?;
+
+    let all_runtime_deps = analysis.runtime_deps();
+
+    // Convert to resource types per project
+    let mut project_runtime_deps: HashMap<String, Vec<String>> = HashMap::new();
+    for (project_name, deps) in &all_runtime_deps {
+        let resource_types = get_unique_resource_types(deps);
+        // Filter out "monitoring" as it's not a provisionable resource
+        let filtered_types: Vec<String> = resource_types
+            .into_iter()
+            .filter(|t| t != "monitoring")
+            .collect();
+        if !filtered_types.is_empty() {
+            project_runtime_deps.insert(project_name.clone(), filtered_types);
+        }
+    }
+
+    let total_resources: usize = project_runtime_deps.values().map(|v| v.len()).sum();
+    stdout.set_color(ColorSpec::new().set_fg(Some(Color::Green)))?;
+    writeln!(stdout, " [OK] ({} resources)", total_resources)?;
+    stdout.reset()?;
+
+    // Detect integrations
+    stdout.set_color(ColorSpec::new().set_fg(Some(Color::Cyan)))?;
+    write!(stdout, "[INFO] Detecting integrations...")?;
+    stdout.flush()?;
+    stdout.reset()?;
+
+    let all_integrations = analysis.integrations();
+
+    let total_integrations: usize = all_integrations.values().map(|v| v.len()).sum();
+    stdout.set_color(ColorSpec::new().set_fg(Some(Color::Green)))?;
+    writeln!(stdout, " [OK] ({} integrations)", total_integrations)?;
+    stdout.reset()?;
+
+    // Detect worker configurations
+    stdout.set_color(ColorSpec::new().set_fg(Some(Color::Cyan)))?;
+    write!(stdout, "[INFO] Detecting worker configurations...")?;
+    stdout.flush()?;
+    stdout.reset()?;
+
+    let all_worker_configs = analysis.worker_configs();
+
+    let total_worker_configs = all_worker_configs.len();
+    stdout.set_color(ColorSpec::new().set_fg(Some(Color::Green)))?;
+    writeln!(stdout, " [OK] ({} workers)", total_worker_configs)?;
+    stdout.reset()?;
+
+    // Detect service mesh dependencies (SDK client imports between services)
+    stdout.set_color(ColorSpec::new().set_fg(Some(Color::Cyan)))?;
+    write!(stdout, "[INFO] Detecting service mesh connections...")?;
+    stdout.flush()?;
+    stdout.reset()?;
+
+    let all_service_deps = analysis.service_dependencies();
+
+    let total_service_deps: usize = all_service_deps.values().map(|v| v.len()).sum();
+    stdout.set_color(ColorSpec::new().set_fg(Some(Color::Green)))?;
+    writeln!(stdout, " [OK] ({} connections)", total_service_deps)?;
+    stdout.reset()?;
+
+    let required_env_vars: Vec<EnvironmentVariableRequirement> = scoped_env_vars
+        .iter()
+        .map(|v| EnvironmentVariableRequirement {
+            name: v.name.clone(),
+            scope: match v.scope {
+                crate::core::env_scope::EnvironmentVariableScope::Application => {
+                    EnvironmentVariableScope::Application
+                }
+                crate::core::env_scope::EnvironmentVariableScope::Service => {
+                    EnvironmentVariableScope::Service
+                }
+                crate::core::env_scope::EnvironmentVariableScope::Worker => {
+                    EnvironmentVariableScope::Worker
+                }
+            },
+            scope_id: v.scope_id.clone(),
+            component: env_var_components.get(&v.name).map(
+                |(component_type, property, target, path, passthrough)| {
+                    EnvironmentVariableComponent {
+                        r#type: component_type.clone(),
+                        property: property.clone(),
+                        target: target.clone(),
+                        path: path.clone(),
+                        passthrough: passthrough.clone(),
+                    }
+                },
+            ),
+        })
+        .collect();
+
+    let app_vars = scoped_env_vars
+        .iter()
+        .filter(|v| v.scope == crate::core::env_scope::EnvironmentVariableScope::Application)
+        .count();
+    let service_vars = scoped_env_vars
+        .iter()
+        .filter(|v| v.scope == crate::core::env_scope::EnvironmentVariableScope::Service)
+        .count();
+    let worker_vars = scoped_env_vars
+        .iter()
+        .filter(|v| v.scope == crate::core::env_scope::EnvironmentVariableScope::Worker)
+        .count();
+    if app_vars > 0 {
+        writeln!(stdout, "[INFO] Application-level: {}", app_vars)?;
+    }
+    if service_vars > 0 {
+        writeln!(stdout, "[INFO] Service-level: {}", service_vars)?;
+    }
+    if worker_vars > 0 {
+        writeln!(stdout, "[INFO] Worker-level: {}", worker_vars)?;
+    }
+
+    Ok(ReleaseAnalysis {
+        openapi_specs,
+        required_env_vars,
+        project_runtime_deps,
+        all_integrations,
+        all_worker_configs,
+        all_service_deps,
+    })
+}
+
 fn upload_release(
     application_id: &str,
     manifest: ReleaseManifest,
+    signature: Option<ReleaseSignature>,
+    sbom: Option<Value>,
     auth_mode: &AuthMode,
 ) -> Result<()> {
     let request_body = CreateReleaseRequest {
         application_id: application_id.to_string(),
         manifest,
         released_by: None, // TODO: Get from token
+        signature,
+        sbom,
     };
 
     let url = if auth_mode.is_hmac() {
@@ -1069,7 +1411,7 @@ fn infer_component_details(
     None
 }
 
-fn infer_component_property(key_upper: &str) -> Option<EnvironmentVariableComponentProperty> {
+pub(crate) fn infer_component_property(key_upper: &str) -> Option<EnvironmentVariableComponentProperty> {
     let tokens: Vec<&str> = key_upper
         .split('_')
         .filter(|segment| !segment.is_empty())
@@ -1390,6 +1732,31 @@ fn is_url_like(value: &str) -> bool {
     parse_url(value).is_some()
 }
 
+fn url_has_credentials(value: &str) -> bool {
+    value
+        .split_once("://")
+        .map(|(_, rest)| rest.split(['/', '?', '#']).next().unwrap_or_default())
+        .is_some_and(|authority| authority.contains('@'))
+}
+
+/// Whether a variable belongs in a secret store rather than plain config,
+/// using the same key inference as the release manifest.
+pub(crate) fn is_secret_env_var(key: &str, value: &str) -> bool {
+    let key_upper = key.to_ascii_uppercase();
+
+    if key_upper.ends_with("_PATH") {
+        return false;
+    }
+    if is_url_like(value) {
+        return url_has_credentials(value);
+    }
+    if let Some(property) = infer_key_component_property(&key_upper, value) {
+        return property != EnvironmentVariableComponentProperty::PublicPem;
+    }
+
+    infer_component_property(&key_upper) == Some(EnvironmentVariableComponentProperty::Password)
+}
+
 fn should_passthrough(key: &str, value: &str) -> bool {
     if is_url_like(value) {
         return false;
@@ -1427,7 +1794,7 @@ const CLI_GENERATED_KEY_VARS: &[&str] = &[
     "PASSWORD_ENCRYPTION_SECRET",
 ];
 
-fn is_cli_generated_key_var(key_upper: &str) -> bool {
+pub(crate) fn is_cli_generated_key_var(key_upper: &str) -> bool {
     CLI_GENERATED_KEY_VARS
         .iter()
         .any(|allowed| allowed == &key_upper)
@@ -1511,18 +1878,18 @@ fn extract_path_from_value(value: &str) -> Option<String> {
     parse_url(value).and_then(|parsed| parsed.path)
 }
 
-fn looks_pem(value: &str) -> bool {
+pub(crate) fn looks_pem(value: &str) -> bool {
     let trimmed = value.trim();
     trimmed.starts_with("-----BEGIN ") && trimmed.contains("-----END ")
 }
 
-fn looks_hex_key(value: &str) -> bool {
+pub(crate) fn looks_hex_key(value: &str) -> bool {
     let trimmed = value.trim();
     let hex_chars = trimmed.chars().all(|c| c.is_ascii_hexdigit());
     hex_chars && (trimmed.len() >= 32)
 }
 
-fn looks_base64(value: &str) -> bool {
+pub(crate) fn looks_base64(value: &str) -> bool {
     let trimmed = value.trim();
     if trimmed.len() < 16 {
         return false;
@@ -1608,6 +1975,10 @@ mod tests {
             release_version: None,
             release_git_commit: None,
             release_git_branch: None,
+            secrets_recipients: None,
+            env_schema: None,
+            architecture: None,
+            licenses: None,
         }
     }
 
@@ -1626,6 +1997,28 @@ mod tests {
         yaml
     }
 
+    #[test]
+    fn test_is_secret_env_var() {
+        assert!(is_secret_env_var("HMAC_SECRET_KEY", "c2VjcmV0"));
+        assert!(is_secret_env_var("DB_PASSWORD", "postgresql"));
+        assert!(is_secret_env_var("STRIPE_API_KEY", "sk_test_123"));
+        assert!(is_secret_env_var(
+            "DATABASE_URL",
+            "postgres://user:pw@db:5432/app"
+        ));
+        assert!(!is_secret_env_var("REDIS_URL", "redis://redis:6379"));
+        assert!(!is_secret_env_var(
+            "JWKS_PUBLIC_KEY",
+            "-----BEGIN PUBLIC KEY-----\nMFkw\n-----END PUBLIC KEY-----"
+        ));
+        assert!(!is_secret_env_var(
+            "PASSWORD_ENCRYPTION_PUBLIC_KEY_PATH",
+            "./public.pem"
+        ));
+        assert!(!is_secret_env_var("PORT", "8000"));
+        assert!(!is_secret_env_var("OTEL_LEVEL", "info"));
+    }
+
     #[test]
     fn test_build_env_var_component_map_collects_docker_compose_env_vars() {
         let temp_dir = TempDir::new().unwrap();
@@ -0,0 +1,693 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    io::Write,
+    path::Path,
+};
+
+use anyhow::{Context, Result};
+use clap::{Arg, ArgMatches, Command};
+use serde::Serialize;
+use termcolor::{Color, ColorChoice, ColorSpec, StandardStream, WriteColor};
+
+use super::{
+    bundle::read_release_manifest,
+    create::{ReleaseAnalysis, analyze_release},
+    git::{get_git_branch, get_git_commit, is_git_repo},
+    manifest_generator::{
+        ControllerDefinition, EnvironmentVariableRequirement, ReleaseManifest, ServiceConfigEnum,
+        ServiceDefinition, generate_release_manifest,
+    },
+};
+use crate::{
+    CliCommand,
+    core::{command::command, openapi_export::ExportOptions},
+};
+
+const WORKING_TREE_LABEL: &str = "working tree";
+
+#[derive(Debug)]
+pub(crate) struct DiffCommand;
+
+impl DiffCommand {
+    pub(crate) fn new() -> Self {
+        Self {}
+    }
+}
+
+impl CliCommand for DiffCommand {
+    fn command(&self) -> Command {
+        command("diff", "Show what changed between two release manifests")
+            .arg(
+                Arg::new("from")
+                    .required(true)
+                    .help("Release manifest JSON, release bundle directory or OCI layout"),
+            )
+            .arg(
+                Arg::new("to")
+                    .help("Release to compare against (defaults to the current working tree)"),
+            )
+            .arg(
+                Arg::new("base_path")
+                    .long("path")
+                    .short('p')
+                    .help("Path to application root, used when comparing against the working tree"),
+            )
+            .arg(
+                Arg::new("json")
+                    .long("json")
+                    .action(clap::ArgAction::SetTrue)
+                    .help("Print the diff as JSON"),
+            )
+    }
+
+    fn handler(&self, matches: &ArgMatches) -> Result<()> {
+        let json = matches.get_flag("json");
+        let from_path = matches.get_one::<String>("from").unwrap();
+        let from = load_manifest(Path::new(from_path))?;
+
+        let (to, to_label) = match matches.get_one::<String>("to") {
+            Some(to_path) => (load_manifest(Path::new(to_path))?, to_path.clone()),
+            None => {
+                // Keep progress output off stdout when it carries JSON
+                let mut progress = if json {
+                    StandardStream::stderr(ColorChoice::Always)
+                } else {
+                    StandardStream::stdout(ColorChoice::Always)
+                };
+                (
+                    working_tree_manifest(matches, &mut progress)?,
+                    WORKING_TREE_LABEL.to_string(),
+                )
+            }
+        };
+
+        let diff = diff_release_manifests(&from, &to);
+
+        if json {
+            println!("{}", serde_json::to_string_pretty(&diff)?);
+            return Ok(());
+        }
+
+        let mut stdout = StandardStream::stdout(ColorChoice::Always);
+        stdout.set_color(ColorSpec::new().set_fg(Some(Color::Cyan)).set_bold(true))?;
+        writeln!(
+            stdout,
+            "[INFO] Release {} ({}) -> {} ({})",
+            from.version, from_path, to.version, to_label
+        )?;
+        stdout.reset()?;
+
+        display_release_diff(&diff, &mut stdout)
+    }
+}
+
+fn load_manifest(path: &Path) -> Result<ReleaseManifest> {
+    serde_json::from_value(read_release_manifest(path)?)
+        .with_context(|| format!("{} is not a valid release manifest", path.display()))
+}
+
+/// Build a release manifest from the current source without exporting or uploading anything else.
+fn working_tree_manifest(
+    matches: &ArgMatches,
+    stdout: &mut StandardStream,
+) -> Result<ReleaseManifest> {
+    let (app_root, manifest) = crate::core::validate::require_manifest(matches)?;
+
+    stdout.set_color(ColorSpec::new().set_fg(Some(Color::Cyan)).set_bold(true))?;
+    writeln!(stdout, "[INFO] Analyzing working tree...")?;
+    stdout.reset()?;
+
+    let (git_commit, git_branch) = if is_git_repo() {
+        (get_git_commit()?, get_git_branch().ok())
+    } else {
+        (WORKING_TREE_LABEL.to_string(), None)
+    };
+
+    let ReleaseAnalysis {
+        openapi_specs,
+        required_env_vars,
+        project_runtime_deps,
+        all_integrations,
+        all_worker_configs,
+        all_service_deps,
+    } = analyze_release(&app_root, &manifest, &ExportOptions::default(), stdout)?;
+
+    writeln!(stdout)?;
+
+    generate_release_manifest(
+        &app_root,
+        manifest
+            .platform_application_id
+            .clone()
+            .unwrap_or_else(|| manifest.app_name.clone()),
+        WORKING_TREE_LABEL.to_string(),
+        git_commit,
+        git_branch,
+        None,
+        &manifest,
+        &openapi_specs,
+        required_env_vars,
+        &project_runtime_deps,
+        &all_integrations,
+        &all_worker_configs,
+        &all_service_deps,
+    )
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum ChangeKind {
+    Added,
+    Removed,
+    Changed,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub(crate) struct Change {
+    pub kind: ChangeKind,
+    pub subject: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+}
+
+impl Change {
+    fn added(subject: impl Into<String>) -> Self {
+        Self {
+            kind: ChangeKind::Added,
+            subject: subject.into(),
+            detail: None,
+        }
+    }
+
+    fn removed(subject: impl Into<String>) -> Self {
+        Self {
+            kind: ChangeKind::Removed,
+            subject: subject.into(),
+            detail: None,
+        }
+    }
+
+    fn changed(subject: impl Into<String>, detail: impl Into<String>) -> Self {
+        Self {
+            kind: ChangeKind::Changed,
+            subject: subject.into(),
+            detail: Some(detail.into()),
+        }
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub(crate) struct ReleaseDiff {
+    pub services: Vec<Change>,
+    #[serde(rename = "runtimeDependencies")]
+    pub runtime_dependencies: Vec<Change>,
+    pub integrations: Vec<Change>,
+    pub routes: Vec<Change>,
+    #[serde(rename = "environmentVariables")]
+    pub environment_variables: Vec<Change>,
+    pub libraries: Vec<Change>,
+    pub infrastructure: Vec<Change>,
+}
+
+impl ReleaseDiff {
+    fn sections(&self) -> [(&'static str, &Vec<Change>); 7] {
+        [
+            ("Services and workers", &self.services),
+            ("Runtime dependencies", &self.runtime_dependencies),
+            ("Integrations", &self.integrations),
+            ("Routes", &self.routes),
+            ("Environment variables", &self.environment_variables),
+            ("Libraries", &self.libraries),
+            ("Infrastructure", &self.infrastructure),
+        ]
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.sections().iter().all(|(_, changes)| changes.is_empty())
+    }
+}
+
+/// Push added/removed entries for two sets, in sorted order.
+fn diff_sets(
+    before: &BTreeSet<String>,
+    after: &BTreeSet<String>,
+    subject: impl Fn(&str) -> String,
+    changes: &mut Vec<Change>,
+) {
+    for removed in before.difference(after) {
+        changes.push(Change::removed(subject(removed)));
+    }
+    for added in after.difference(before) {
+        changes.push(Change::added(subject(added)));
+    }
+}
+
+fn describe<T: std::fmt::Debug>(value: &Option<T>) -> String {
+    match value {
+        Some(value) => format!("{:?}", value).trim_matches('"').to_string(),
+        None => "(none)".to_string(),
+    }
+}
+
+fn service_kind(service: &ServiceDefinition) -> &'static str {
+    let is_worker = match &service.config {
+        ServiceConfigEnum::Service(config) => {
+            config.service_type == super::manifest_generator::ConfigType::Worker
+                || config.is_worker_service == Some(true)
+        }
+        ServiceConfigEnum::Worker(_) => true,
+    };
+    if is_worker { "worker" } else { "service" }
+}
+
+fn runtime_dependencies(service: &ServiceDefinition) -> BTreeSet<String> {
+    let deps = match &service.config {
+        ServiceConfigEnum::Service(config) => &config.runtime_dependencies,
+        ServiceConfigEnum::Worker(config) => &config.runtime_dependencies,
+    };
+    deps.iter().flatten().cloned().collect()
+}
+
+fn instance_size(service: &ServiceDefinition) -> &Option<String> {
+    match &service.config {
+        ServiceConfigEnum::Service(config) => &config.instance_size,
+        ServiceConfigEnum::Worker(config) => &config.instance_size,
+    }
+}
+
+fn integrations(service: &ServiceDefinition) -> BTreeMap<String, String> {
+    match &service.config {
+        ServiceConfigEnum::Service(config) => config
+            .integrations
+            .iter()
+            .flatten()
+            .map(|integration| {
+                (
+                    format!("{} ({})", integration.integration_type, integration.id),
+                    serde_json::to_string(&integration.config).unwrap_or_default(),
+                )
+            })
+            .collect(),
+        ServiceConfigEnum::Worker(_) => BTreeMap::new(),
+    }
+}
+
+fn controllers(service: &ServiceDefinition) -> BTreeMap<String, &ControllerDefinition> {
+    match &service.config {
+        ServiceConfigEnum::Service(config) => config
+            .controllers
+            .iter()
+            .flatten()
+            .map(|controller| (controller.name.clone(), controller))
+            .collect(),
+        ServiceConfigEnum::Worker(_) => BTreeMap::new(),
+    }
+}
+
+fn routes(controller: Option<&&ControllerDefinition>) -> BTreeSet<String> {
+    controller
+        .map(|controller| {
+            controller
+                .routes
+                .iter()
+                .map(|route| format!("{} {}", route.method.to_uppercase(), route.path))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn env_var_scopes(
+    requirements: &Option<Vec<EnvironmentVariableRequirement>>,
+) -> BTreeMap<String, (BTreeSet<String>, BTreeSet<String>)> {
+    let mut scopes: BTreeMap<String, (BTreeSet<String>, BTreeSet<String>)> = BTreeMap::new();
+    for requirement in requirements.iter().flatten() {
+        let scope = serde_json::to_value(&requirement.scope)
+            .ok()
+            .and_then(|v| v.as_str().map(str::to_string))
+            .unwrap_or_default();
+        let entry = scopes.entry(requirement.name.clone()).or_default();
+        entry.0.insert(match &requirement.scope_id {
+            Some(scope_id) => format!("{}:{}", scope, scope_id),
+            None => scope,
+        });
+        if let Some(component) = &requirement.component {
+            entry.1.insert(
+                serde_json::to_string(component).unwrap_or_default(),
+            );
+        }
+    }
+    scopes
+}
+
+fn join(values: &BTreeSet<String>) -> String {
+    values.iter().cloned().collect::<Vec<_>>().join(", ")
+}
+
+/// Compare two release manifests section by section.
+pub(crate) fn diff_release_manifests(from: &ReleaseManifest, to: &ReleaseManifest) -> ReleaseDiff {
+    let mut diff = ReleaseDiff::default();
+
+    let from_services: BTreeMap<_, _> = from.services.iter().map(|s| (s.name.clone(), s)).collect();
+    let to_services: BTreeMap<_, _> = to.services.iter().map(|s| (s.name.clone(), s)).collect();
+    let names: BTreeSet<_> = from_services.keys().chain(to_services.keys()).cloned().collect();
+
+    for name in &names {
+        match (from_services.get(name), to_services.get(name)) {
+            (Some(service), None) => diff
+                .services
+                .push(Change::removed(format!("{} {}", service_kind(service), name))),
+            (None, Some(service)) => diff
+                .services
+                .push(Change::added(format!("{} {}", service_kind(service), name))),
+            (Some(before), Some(after)) => {
+                if service_kind(before) != service_kind(after) {
+                    diff.services.push(Change::changed(
+                        name.clone(),
+                        format!("{} -> {}", service_kind(before), service_kind(after)),
+                    ));
+                }
+                if instance_size(before) != instance_size(after) {
+                    diff.infrastructure.push(Change::changed(
+                        format!("{} instance size", name),
+                        format!(
+                            "{} -> {}",
+                            describe(instance_size(before)),
+                            describe(instance_size(after))
+                        ),
+                    ));
+                }
+                diff_service_contents(name, before, after, &mut diff);
+            }
+            (None, None) => unreachable!(),
+        }
+    }
+
+    // Env vars: presence, then scope and component changes for vars in both releases
+    let from_vars = env_var_scopes(&from.required_environment_variables);
+    let to_vars = env_var_scopes(&to.required_environment_variables);
+    for (name, (scopes, _)) in &from_vars {
+        if !to_vars.contains_key(name) {
+            diff.environment_variables
+                .push(Change::removed(format!("{} [{}]", name, join(scopes))));
+        }
+    }
+    for (name, (scopes, components)) in &to_vars {
+        match from_vars.get(name) {
+            None => diff
+                .environment_variables
+                .push(Change::added(format!("{} [{}]", name, join(scopes)))),
+            Some((before_scopes, before_components)) => {
+                if before_scopes != scopes {
+                    diff.environment_variables.push(Change::changed(
+                        name.clone(),
+                        format!("scope [{}] -> [{}]", join(before_scopes), join(scopes)),
+                    ));
+                }
+                if before_components != components {
+                    diff.environment_variables.push(Change::changed(
+                        name.clone(),
+                        format!("component {} -> {}", join(before_components), join(components)),
+                    ));
+                }
+            }
+        }
+    }
+
+    // Libraries
+    let from_libs: BTreeMap<_, _> = from
+        .libraries
+        .iter()
+        .flatten()
+        .map(|l| (l.name.clone(), l.version.clone()))
+        .collect();
+    let to_libs: BTreeMap<_, _> = to
+        .libraries
+        .iter()
+        .flatten()
+        .map(|l| (l.name.clone(), l.version.clone()))
+        .collect();
+    for (name, version) in &from_libs {
+        if !to_libs.contains_key(name) {
+            diff.libraries.push(Change::removed(format!("{}@{}", name, version)));
+        }
+    }
+    for (name, version) in &to_libs {
+        match from_libs.get(name) {
+            None => diff.libraries.push(Change::added(format!("{}@{}", name, version))),
+            Some(before) if before != version => diff
+                .libraries
+                .push(Change::changed(name.clone(), format!("{} -> {}", before, version))),
+            _ => {}
+        }
+    }
+
+    diff_infrastructure(from, to, &mut diff);
+
+    diff
+}
+
+fn diff_service_contents(
+    name: &str,
+    before: &ServiceDefinition,
+    after: &ServiceDefinition,
+    diff: &mut ReleaseDiff,
+) {
+    diff_sets(
+        &runtime_dependencies(before),
+        &runtime_dependencies(after),
+        |dep| format!("{}: {}", name, dep),
+        &mut diff.runtime_dependencies,
+    );
+
+    let before_integrations = integrations(before);
+    let after_integrations = integrations(after);
+    diff_sets(
+        &before_integrations.keys().cloned().collect(),
+        &after_integrations.keys().cloned().collect(),
+        |integration| format!("{}: {}", name, integration),
+        &mut diff.integrations,
+    );
+    for (key, config) in &after_integrations {
+        if let Some(before_config) = before_integrations.get(key)
+            && before_config != config
+        {
+            diff.integrations
+                .push(Change::changed(format!("{}: {}", name, key), "configuration changed"));
+        }
+    }
+
+    let before_controllers = controllers(before);
+    let after_controllers = controllers(after);
+    let controller_names: BTreeSet<_> = before_controllers
+        .keys()
+        .chain(after_controllers.keys())
+        .cloned()
+        .collect();
+    for controller in &controller_names {
+        diff_sets(
+            &routes(before_controllers.get(controller)),
+            &routes(after_controllers.get(controller)),
+            |route| format!("{}/{}: {}", name, controller, route),
+            &mut diff.routes,
+        );
+    }
+}
+
+fn diff_infrastructure(from: &ReleaseManifest, to: &ReleaseManifest, diff: &mut ReleaseDiff) {
+    let before = &from.infrastructure;
+    let after = &to.infrastructure;
+
+    diff_sets(
+        &before.regions.iter().cloned().collect(),
+        &after.regions.iter().cloned().collect(),
+        |region| format!("region {}", region),
+        &mut diff.infrastructure,
+    );
+
+    for (label, before_value, after_value) in [
+        ("cloud provider", &before.cloud_provider, &after.cloud_provider),
+        ("VPC", &before.vpc_id, &after.vpc_id),
+        (
+            "default instance size",
+            &before.default_instance_size,
+            &after.default_instance_size,
+        ),
+        ("build context", &before.build_context, &after.build_context),
+    ] {
+        if before_value != after_value {
+            diff.infrastructure.push(Change::changed(
+                label,
+                format!("{} -> {}", describe(before_value), describe(after_value)),
+            ));
+        }
+    }
+
+    let resources = |manifest: &ReleaseManifest| -> BTreeMap<String, String> {
+        manifest
+            .infrastructure
+            .resources
+            .iter()
+            .flatten()
+            .map(|resource| {
+                (
+                    format!("{} {}", resource.resource_type, resource.name),
+                    serde_json::to_string(&(
+                        &resource.region,
+                        &resource.config,
+                        &resource.service_name,
+                    ))
+                    .unwrap_or_default(),
+                )
+            })
+            .collect()
+    };
+    let before_resources = resources(from);
+    let after_resources = resources(to);
+    diff_sets(
+        &before_resources.keys().cloned().collect(),
+        &after_resources.keys().cloned().collect(),
+        |resource| format!("resource {}", resource),
+        &mut diff.infrastructure,
+    );
+    for (key, config) in &after_resources {
+        if let Some(before_config) = before_resources.get(key)
+            && before_config != config
+        {
+            diff.infrastructure
+                .push(Change::changed(format!("resource {}", key), "configuration changed"));
+        }
+    }
+}
+
+fn display_release_diff(diff: &ReleaseDiff, stdout: &mut StandardStream) -> Result<()> {
+    if diff.is_empty() {
+        stdout.set_color(ColorSpec::new().set_fg(Some(Color::Green)))?;
+        writeln!(stdout, "[OK] No differences")?;
+        stdout.reset()?;
+        return Ok(());
+    }
+
+    for (title, changes) in diff.sections() {
+        if changes.is_empty() {
+            continue;
+        }
+
+        writeln!(stdout)?;
+        stdout.set_color(ColorSpec::new().set_bold(true))?;
+        writeln!(stdout, "{}:", title)?;
+        stdout.reset()?;
+
+        for change in changes {
+            let (marker, color) = match change.kind {
+                ChangeKind::Added => ("+", Color::Green),
+                ChangeKind::Removed => ("-", Color::Red),
+                ChangeKind::Changed => ("~", Color::Yellow),
+            };
+            stdout.set_color(ColorSpec::new().set_fg(Some(color)))?;
+            write!(stdout, "  {} {}", marker, change.subject)?;
+            stdout.reset()?;
+            match &change.detail {
+                Some(detail) => writeln!(stdout, ": {}", detail)?,
+                None => writeln!(stdout)?,
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn manifest(value: serde_json::Value) -> ReleaseManifest {
+        serde_json::from_value(value).unwrap()
+    }
+
+    fn base() -> serde_json::Value {
+        json!({
+            "applicationId": "app",
+            "version": "1.0.0",
+            "gitCommit": "abc",
+            "timestamp": "2025-01-01T00:00:00Z",
+            "services": [
+                {
+                    "id": "billing",
+                    "name": "billing",
+                    "config": {
+                        "type": "service",
+                        "runtimeDependencies": ["database"],
+                        "controllers": [{
+                            "id": "c1",
+                            "name": "InvoiceController",
+                            "path": "invoice",
+                            "routes": [
+                                {"id": "r1", "method": "get", "path": "/invoices", "handler": "list"}
+                            ]
+                        }]
+                    }
+                },
+                {
+                    "id": "email",
+                    "name": "email",
+                    "config": {"type": "worker", "workerType": "bullmq"}
+                }
+            ],
+            "infrastructure": {"regions": ["us-east-1"]},
+            "requiredEnvironmentVariables": [
+                {"name": "DB_HOST", "scope": "service", "scopeId": "billing"},
+                {"name": "OLD_VAR", "scope": "application"}
+            ],
+            "libraries": [
+                {"name": "@forklaunch/core", "version": "1.0.0"}
+            ]
+        })
+    }
+
+    #[test]
+    fn test_identical_manifests_have_no_diff() {
+        assert!(diff_release_manifests(&manifest(base()), &manifest(base())).is_empty());
+    }
+
+    #[test]
+    fn test_diff_reports_each_section() {
+        let mut next = base();
+        next["version"] = json!("1.1.0");
+        next["services"][0]["config"]["runtimeDependencies"] = json!(["database", "cache"]);
+        next["services"][0]["config"]["controllers"][0]["routes"]
+            .as_array_mut()
+            .unwrap()
+            .push(json!({"id": "r2", "method": "post", "path": "/invoices", "handler": "create"}));
+        next["services"].as_array_mut().unwrap().remove(1);
+        next["requiredEnvironmentVariables"] = json!([
+            {"name": "DB_HOST", "scope": "application"},
+            {"name": "NEW_VAR", "scope": "application"}
+        ]);
+        next["libraries"] = json!([{"name": "@forklaunch/core", "version": "1.1.0"}]);
+        next["infrastructure"]["regions"] = json!(["us-east-1", "eu-west-1"]);
+
+        let diff = diff_release_manifests(&manifest(base()), &manifest(next));
+
+        assert_eq!(diff.services, vec![Change::removed("worker email")]);
+        assert_eq!(diff.runtime_dependencies, vec![Change::added("billing: cache")]);
+        assert_eq!(
+            diff.routes,
+            vec![Change::added("billing/InvoiceController: POST /invoices")]
+        );
+        assert_eq!(
+            diff.environment_variables,
+            vec![
+                Change::removed("OLD_VAR [application]"),
+                Change::changed("DB_HOST", "scope [service:billing] -> [application]"),
+                Change::added("NEW_VAR [application]"),
+            ]
+        );
+        assert_eq!(
+            diff.libraries,
+            vec![Change::changed("@forklaunch/core", "1.0.0 -> 1.1.0")]
+        );
+        assert_eq!(diff.infrastructure, vec![Change::added("region eu-west-1")]);
+    }
+}
@@ -1,4 +1,4 @@
-use std::process::Command;
+use std::{path::Path, process::Command};
 
 use anyhow::{Context, Result};
 
@@ -64,3 +64,150 @@ pub(crate) fn is_git_repo() -> bool {
         .map(|output| output.status.success())
         .unwrap_or(false)
 }
+
+/// A commit as reported by `git log`, with the files it touched.
+#[derive(Debug, Clone)]
+pub(crate) struct GitCommit {
+    pub sha: String,
+    pub subject: String,
+    pub body: String,
+    /// Paths relative to the directory the log was run in
+    pub files: Vec<String>,
+}
+
+fn run_git(dir: &Path, args: &[&str]) -> Result<String> {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .with_context(|| "Failed to execute git command. Is git installed?")?;
+
+    if !output.status.success() {
+        anyhow::bail!(
+            "git {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+
+    String::from_utf8(output.stdout).with_context(|| "Invalid UTF-8 in git output")
+}
+
+/// List tags reachable from HEAD that match `pattern` (e.g. `v*`)
+pub(crate) fn get_merged_tags(dir: &Path, pattern: &str) -> Result<Vec<String>> {
+    Ok(run_git(dir, &["tag", "--list", pattern, "--merged", "HEAD"])?
+        .lines()
+        .map(|line| line.trim().to_string())
+        .filter(|line| !line.is_empty())
+        .collect())
+}
+
+/// Get commits in `since..HEAD` (or the whole history) that touch `dir`, newest first.
+pub(crate) fn get_commits_since(dir: &Path, since: Option<&str>) -> Result<Vec<GitCommit>> {
+    let range = since.map(|since| format!("{}..HEAD", since));
+    let mut args = vec![
+        "log",
+        "--no-merges",
+        "--relative",
+        "--name-only",
+        "--format=%x1e%H%x1f%s%x1f%b%x1f",
+    ];
+    if let Some(range) = &range {
+        args.push(range);
+    }
+    args.extend(["--", "."]);
+
+    Ok(parse_git_log(&run_git(dir, &args)?))
+}
+
+fn parse_git_log(output: &str) -> Vec<GitCommit> {
+    output
+        .split('\x1e')
+        .filter_map(|record| {
+            let mut fields = record.splitn(4, '\x1f');
+            let sha = fields.next()?.trim().to_string();
+            if sha.is_empty() {
+                return None;
+            }
+            Some(GitCommit {
+                sha,
+                subject: fields.next()?.trim().to_string(),
+                body: fields.next()?.trim().to_string(),
+                files: fields
+                    .next()
+                    .unwrap_or_default()
+                    .lines()
+                    .map(str::trim)
+                    .filter(|line| !line.is_empty())
+                    .map(str::to_string)
+                    .collect(),
+            })
+        })
+        .collect()
+}
+
+/// Read a file as it was at `revision`, or `None` if it was not tracked there
+pub(crate) fn show_file_at(dir: &Path, revision: &str, path: &str) -> Option<String> {
+    run_git(dir, &["show", &format!("{}:./{}", revision, path)]).ok()
+}
+
+pub(crate) fn tag_exists(dir: &Path, tag: &str) -> bool {
+    run_git(dir, &["rev-parse", "--quiet", "--verify", &format!("refs/tags/{}", tag)]).is_ok()
+}
+
+/// Create an annotated tag at HEAD
+pub(crate) fn create_annotated_tag(dir: &Path, tag: &str, message: &str) -> Result<()> {
+    run_git(dir, &["tag", "--annotate", tag, "--message", message]).map(|_| ())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_git_log_records() {
+        let output = "\x1eaaa\x1ffeat(billing): add invoices\x1fBREAKING CHANGE: x\x1f\n\nsrc/modules/billing/a.ts\nsrc/modules/billing/b.ts\n\x1ebbb\x1ffix: typo\x1f\x1f\n\nREADME.md\n";
+        let commits = parse_git_log(output);
+
+        assert_eq!(commits.len(), 2);
+        assert_eq!(commits[0].sha, "aaa");
+        assert_eq!(commits[0].subject, "feat(billing): add invoices");
+        assert_eq!(commits[0].body, "BREAKING CHANGE: x");
+        assert_eq!(commits[0].files.len(), 2);
+        assert_eq!(commits[1].files, vec!["README.md"]);
+    }
+
+    #[test]
+    fn test_commits_since_tag_in_subdirectory() {
+        let temp = tempfile::tempdir().unwrap();
+        let repo = temp.path();
+        let app = repo.join("app");
+        std::fs::create_dir_all(app.join("src")).unwrap();
+        let git = |args: &[&str]| run_git(repo, args).unwrap();
+        git(&["init", "--quiet"]);
+        git(&["config", "user.email", "dev@example.com"]);
+        git(&["config", "user.name", "Dev"]);
+
+        std::fs::write(app.join("src/a.ts"), "a").unwrap();
+        git(&["add", "."]);
+        git(&["commit", "--quiet", "-m", "feat: initial"]);
+        create_annotated_tag(repo, "v1.0.0", "Release 1.0.0").unwrap();
+
+        std::fs::write(app.join("src/b.ts"), "b").unwrap();
+        std::fs::write(repo.join("outside.txt"), "x").unwrap();
+        git(&["add", "."]);
+        git(&["commit", "--quiet", "-m", "fix(api): handle b"]);
+        std::fs::write(repo.join("outside.txt"), "y").unwrap();
+        git(&["commit", "--quiet", "-am", "docs: outside the app"]);
+
+        assert_eq!(get_merged_tags(repo, "v*").unwrap(), vec!["v1.0.0"]);
+        assert!(tag_exists(repo, "v1.0.0"));
+
+        let commits = get_commits_since(&app, Some("v1.0.0")).unwrap();
+        assert_eq!(commits.len(), 1);
+        assert_eq!(commits[0].subject, "fix(api): handle b");
+        assert_eq!(commits[0].files, vec!["src/b.ts"]);
+        assert_eq!(show_file_at(&app, "v1.0.0", "src/a.ts").as_deref(), Some("a"));
+        assert!(show_file_at(&app, "v1.0.0", "src/b.ts").is_none());
+    }
+}
@@ -151,6 +151,8 @@ pub(crate) struct ReleaseManifest {
     pub required_environment_variables: Option<Vec<EnvironmentVariableRequirement>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub libraries: Option<Vec<LibraryDefinition>>,
+    #[serde(rename = "signingKeyId", skip_serializing_if = "Option::is_none")]
+    pub signing_key_id: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -718,6 +720,7 @@ pub(crate) fn generate_release_manifest(
             Some(required_env_vars)
         },
         libraries: scan_project_libraries(&app_root.join("package.json")).ok(),
+        signing_key_id: None,
     })
 }
 
@@ -1,35 +1,51 @@
 use anyhow::Result;
 use clap::{ArgMatches, Command};
 use create::CreateCommand;
+use diff::DiffCommand;
+use verify::VerifyCommand;
 
 use crate::{CliCommand, core::command::command};
 
-mod create;
+mod bundle;
+pub(crate) mod create;
+mod diff;
 mod git;
-mod manifest_generator;
+pub(crate) mod manifest_generator;
 pub(crate) mod s3_upload;
+mod signing;
+mod verify;
+mod versioning;
 
 #[derive(Debug)]
 pub(crate) struct ReleaseCommand {
     create: CreateCommand,
+    diff: DiffCommand,
+    verify: VerifyCommand,
 }
 
 impl ReleaseCommand {
     pub(crate) fn new() -> Self {
         Self {
             create: CreateCommand::new(),
+            diff: DiffCommand::new(),
+            verify: VerifyCommand::new(),
         }
     }
 }
 
 impl CliCommand for ReleaseCommand {
     fn command(&self) -> Command {
-        command("release", "Release management").subcommand(self.create.command())
+        command("release", "Release management")
+            .subcommand(self.create.command())
+            .subcommand(self.verify.command())
+            .subcommand(self.diff.command())
     }
 
     fn handler(&self, matches: &ArgMatches) -> Result<()> {
         match matches.subcommand() {
             Some(("create", sub_matches)) => self.create.handler(sub_matches),
+            Some(("verify", sub_matches)) => self.verify.handler(sub_matches),
+            Some(("diff", sub_matches)) => self.diff.handler(sub_matches),
             // Default to create for convenience
             None => self.create.handler(matches),
             _ => unreachable!(),
@@ -0,0 +1,117 @@
+use std::{fs, io::Write, path::PathBuf};
+
+use anyhow::{Context, Result};
+use clap::{Arg, ArgMatches, Command};
+use serde_json::Value;
+use termcolor::{Color, ColorChoice, ColorSpec, StandardStream, WriteColor};
+
+use super::signing::{ReleaseSignature, file_digest, load_verifying_key, verify_release};
+use crate::{CliCommand, core::command::command};
+
+#[derive(Debug)]
+pub(crate) struct VerifyCommand;
+
+impl VerifyCommand {
+    pub(crate) fn new() -> Self {
+        Self {}
+    }
+}
+
+impl CliCommand for VerifyCommand {
+    fn command(&self) -> Command {
+        command("verify", "Verify a signed release manifest")
+            .arg(
+                Arg::new("manifest")
+                    .required(true)
+                    .help("Path to the release manifest JSON"),
+            )
+            .arg(
+                Arg::new("tarball")
+                    .long("tarball")
+                    .short('t')
+                    .help("Code tarball to check against the signature (optional)"),
+            )
+            .arg(
+                Arg::new("signature")
+                    .long("signature")
+                    .short('s')
+                    .help("Detached signature file (defaults to <manifest>.sig)"),
+            )
+            .arg(
+                Arg::new("public-key")
+                    .long("public-key")
+                    .short('k')
+                    .help("Trusted ed25519 public key (PEM, hex or base64). Without it only integrity is checked"),
+            )
+    }
+
+    fn handler(&self, matches: &ArgMatches) -> Result<()> {
+        let mut stdout = StandardStream::stdout(ColorChoice::Always);
+
+        let manifest_path = PathBuf::from(matches.get_one::<String>("manifest").unwrap());
+        let signature_path = matches
+            .get_one::<String>("signature")
+            .map(PathBuf::from)
+            .unwrap_or_else(|| {
+                let mut path = manifest_path.clone().into_os_string();
+                path.push(".sig");
+                PathBuf::from(path)
+            });
+
+        let manifest: Value = serde_json::from_str(
+            &fs::read_to_string(&manifest_path)
+                .with_context(|| format!("Failed to read {}", manifest_path.display()))?,
+        )
+        .with_context(|| format!("Failed to parse {}", manifest_path.display()))?;
+        let signature: ReleaseSignature = serde_json::from_str(
+            &fs::read_to_string(&signature_path)
+                .with_context(|| format!("Failed to read {}", signature_path.display()))?,
+        )
+        .with_context(|| format!("Failed to parse {}", signature_path.display()))?;
+
+        let trusted_key = matches
+            .get_one::<String>("public-key")
+            .map(|path| load_verifying_key(&PathBuf::from(path)))
+            .transpose()?;
+        let tarball_sha256 = matches
+            .get_one::<String>("tarball")
+            .map(|path| file_digest(&PathBuf::from(path)))
+            .transpose()?;
+
+        verify_release(
+            &signature,
+            &manifest,
+            tarball_sha256.as_deref(),
+            trusted_key.as_ref(),
+        )?;
+
+        stdout.set_color(ColorSpec::new().set_fg(Some(Color::Green)).set_bold(true))?;
+        writeln!(stdout, "[OK] Release signature is valid")?;
+        stdout.reset()?;
+        writeln!(stdout, "[INFO] Key id: {}", signature.key_id)?;
+        writeln!(stdout, "[INFO] Manifest sha256: {}", signature.manifest_sha256)?;
+        match (&signature.tarball_sha256, &tarball_sha256) {
+            (Some(digest), Some(_)) => writeln!(stdout, "[INFO] Tarball sha256: {}", digest)?,
+            (Some(_), None) => {
+                stdout.set_color(ColorSpec::new().set_fg(Some(Color::Yellow)))?;
+                writeln!(
+                    stdout,
+                    "[WARN] Signature covers a tarball; pass --tarball to check it"
+                )?;
+                stdout.reset()?;
+            }
+            _ => {}
+        }
+
+        if trusted_key.is_none() {
+            stdout.set_color(ColorSpec::new().set_fg(Some(Color::Yellow)))?;
+            writeln!(
+                stdout,
+                "[WARN] No --public-key given: verified against the key embedded in the signature, which does not prove who signed it"
+            )?;
+            stdout.reset()?;
+        }
+
+        Ok(())
+    }
+}
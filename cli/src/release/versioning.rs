@@ -0,0 +1,490 @@
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result, bail};
+use semver::Version;
+use serde_json::Value;
+
+use super::git::{GitCommit, get_commits_since, get_merged_tags, show_file_at};
+use crate::core::manifest::application::ApplicationManifestData;
+
+pub(crate) const AUTO_VERSION: &str = "auto";
+pub(crate) const RELEASE_TAG_PREFIX: &str = "v";
+const CHANGELOG_FILE: &str = "CHANGELOG.md";
+const CHANGELOG_HEADER: &str = "# Changelog";
+/// Changelog group for commits that touch no project under the modules path
+const APPLICATION_GROUP: &str = "Application";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum Bump {
+    Patch,
+    Minor,
+    Major,
+}
+
+impl Bump {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Bump::Patch => "patch",
+            Bump::Minor => "minor",
+            Bump::Major => "major",
+        }
+    }
+}
+
+/// A commit message parsed per the Conventional Commits spec: `type(scope)!: description`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ConventionalCommit {
+    pub kind: String,
+    pub scope: Option<String>,
+    pub breaking: bool,
+    pub description: String,
+}
+
+pub(crate) fn parse_conventional_commit(subject: &str, body: &str) -> Option<ConventionalCommit> {
+    let (header, description) = subject.split_once(": ")?;
+    let (header, bang) = match header.strip_suffix('!') {
+        Some(header) => (header, true),
+        None => (header, false),
+    };
+    let (kind, scope) = match header.split_once('(') {
+        Some((kind, scope)) => (kind, Some(scope.strip_suffix(')')?.to_string())),
+        None => (header, None),
+    };
+    if kind.is_empty() || !kind.chars().all(|c| c.is_ascii_alphanumeric()) {
+        return None;
+    }
+
+    let breaking = bang
+        || body.lines().any(|line| {
+            line.starts_with("BREAKING CHANGE:") || line.starts_with("BREAKING-CHANGE:")
+        });
+
+    Some(ConventionalCommit {
+        kind: kind.to_ascii_lowercase(),
+        scope,
+        breaking,
+        description: description.trim().to_string(),
+    })
+}
+
+fn commit_bump(commit: &GitCommit) -> Bump {
+    match parse_conventional_commit(&commit.subject, &commit.body) {
+        Some(parsed) if parsed.breaking => Bump::Major,
+        Some(parsed) if parsed.kind == "feat" => Bump::Minor,
+        _ => Bump::Patch,
+    }
+}
+
+/// Apply a bump. While the major version is 0, breaking changes bump the minor version.
+pub(crate) fn apply_bump(version: &Version, bump: Bump) -> Version {
+    match bump {
+        Bump::Major if version.major == 0 => Version::new(0, version.minor + 1, 0),
+        Bump::Major => Version::new(version.major + 1, 0, 0),
+        Bump::Minor => Version::new(version.major, version.minor + 1, 0),
+        Bump::Patch if !version.pre.is_empty() => {
+            Version::new(version.major, version.minor, version.patch)
+        }
+        Bump::Patch => Version::new(version.major, version.minor, version.patch + 1),
+    }
+}
+
+/// The next release version, derived from commits since the previous release.
+#[derive(Debug)]
+pub(crate) struct VersionPlan {
+    pub previous: Option<Version>,
+    /// Git revision of the previous release (a tag, or the commit recorded in the manifest)
+    pub since: Option<String>,
+    pub bump: Bump,
+    pub next: Version,
+    pub commits: Vec<GitCommit>,
+}
+
+impl VersionPlan {
+    /// Raise the bump (never lower it), e.g. when OpenAPI breaking changes are found.
+    pub(crate) fn escalate(&mut self, bump: Bump) {
+        if bump > self.bump {
+            self.bump = bump;
+            self.next = apply_bump(self.previous.as_ref().unwrap_or(&Version::new(0, 0, 0)), bump);
+        }
+    }
+
+    pub(crate) fn describe(&self) -> String {
+        format!(
+            "{} -> {} ({} bump, {} commits since {})",
+            self.previous
+                .as_ref()
+                .map(|v| v.to_string())
+                .unwrap_or_else(|| "none".to_string()),
+            self.next,
+            self.bump.as_str(),
+            self.commits.len(),
+            self.since.as_deref().unwrap_or("the first commit")
+        )
+    }
+}
+
+/// Find the highest `v<semver>` tag reachable from HEAD.
+fn latest_release_tag(app_root: &Path) -> Result<Option<(String, Version)>> {
+    Ok(get_merged_tags(app_root, &format!("{}*", RELEASE_TAG_PREFIX))?
+        .into_iter()
+        .filter_map(|tag| {
+            let version = Version::parse(tag.strip_prefix(RELEASE_TAG_PREFIX)?).ok()?;
+            Some((tag, version))
+        })
+        .max_by(|a, b| a.1.cmp(&b.1)))
+}
+
+/// Compute the next version from the last release tag, falling back to the release
+/// recorded in the manifest, and the conventional commits made since.
+pub(crate) fn plan_next_version(
+    app_root: &Path,
+    manifest: &ApplicationManifestData,
+) -> Result<VersionPlan> {
+    let (previous, since) = match latest_release_tag(app_root)? {
+        Some((tag, version)) => (Some(version), Some(tag)),
+        None => match &manifest.release_version {
+            Some(version) => (
+                Some(Version::parse(version.trim_start_matches(RELEASE_TAG_PREFIX)).with_context(
+                    || format!("Release version '{}' in manifest is not semver", version),
+                )?),
+                manifest.release_git_commit.clone(),
+            ),
+            None => (None, None),
+        },
+    };
+
+    let commits = get_commits_since(app_root, since.as_deref())?;
+    if previous.is_some() && commits.is_empty() {
+        bail!(
+            "No commits since {}; nothing to release",
+            since.as_deref().unwrap_or("the last release")
+        );
+    }
+
+    let (bump, next) = match &previous {
+        Some(previous) => {
+            let bump = commits.iter().map(commit_bump).max().unwrap_or(Bump::Patch);
+            (bump, apply_bump(previous, bump))
+        }
+        // First release
+        None => (Bump::Minor, Version::new(0, 1, 0)),
+    };
+
+    Ok(VersionPlan {
+        previous,
+        since,
+        bump,
+        next,
+        commits,
+    })
+}
+
+/// Operations removed or made stricter between two OpenAPI documents.
+pub(crate) fn openapi_breaking_changes(previous: &Value, current: &Value) -> Vec<String> {
+    const METHODS: [&str; 8] = [
+        "get", "put", "post", "delete", "options", "head", "patch", "trace",
+    ];
+    let mut changes = Vec::new();
+
+    let Some(previous_paths) = previous.get("paths").and_then(Value::as_object) else {
+        return changes;
+    };
+    let current_paths = current.get("paths").and_then(Value::as_object);
+
+    for (path, previous_item) in previous_paths {
+        for method in METHODS {
+            let Some(previous_op) = previous_item.get(method) else {
+                continue;
+            };
+            let Some(current_op) = current_paths
+                .and_then(|paths| paths.get(path))
+                .and_then(|item| item.get(method))
+            else {
+                changes.push(format!("removed {} {}", method.to_uppercase(), path));
+                continue;
+            };
+
+            let required_params = |op: &Value| -> BTreeSet<String> {
+                op.get("parameters")
+                    .and_then(Value::as_array)
+                    .into_iter()
+                    .flatten()
+                    .filter(|p| p.get("required").and_then(Value::as_bool) == Some(true))
+                    .filter_map(|p| {
+                        Some(format!("{} '{}'", p.get("in")?.as_str()?, p.get("name")?.as_str()?))
+                    })
+                    .collect()
+            };
+            for param in required_params(current_op).difference(&required_params(previous_op)) {
+                changes.push(format!(
+                    "{} {} now requires {} parameter",
+                    method.to_uppercase(),
+                    path,
+                    param
+                ));
+            }
+        }
+    }
+
+    changes
+}
+
+/// Compare each current spec against the same file at the previous release, where tracked.
+pub(crate) fn find_openapi_breaking_changes(
+    app_root: &Path,
+    since: &str,
+    openapi_specs: &HashMap<String, Value>,
+) -> Vec<String> {
+    let mut services: Vec<_> = openapi_specs.iter().collect();
+    services.sort_by(|a, b| a.0.cmp(b.0));
+
+    services
+        .into_iter()
+        .filter_map(|(service, current)| {
+            let previous = show_file_at(
+                app_root,
+                since,
+                &format!(".forklaunch/openapi/{}/openapi.json", service),
+            )?;
+            let previous: Value = serde_json::from_str(&previous).ok()?;
+            Some(
+                openapi_breaking_changes(&previous, current)
+                    .into_iter()
+                    .map(|change| format!("{}: {}", service, change))
+                    .collect::<Vec<_>>(),
+            )
+        })
+        .flatten()
+        .collect()
+}
+
+/// Render a changelog section, grouped by the projects each commit touched.
+pub(crate) fn render_changelog_section(
+    version: &Version,
+    date: &str,
+    commits: &[GitCommit],
+    modules_path: &str,
+    project_names: &[String],
+) -> String {
+    const GROUPS: [&str; 5] = [
+        "Breaking Changes",
+        "Features",
+        "Bug Fixes",
+        "Performance",
+        "Other Changes",
+    ];
+
+    let modules_path = modules_path.trim_start_matches("./").trim_end_matches('/');
+    let mut projects: BTreeMap<String, BTreeMap<usize, Vec<String>>> = BTreeMap::new();
+
+    for commit in commits {
+        let parsed = parse_conventional_commit(&commit.subject, &commit.body);
+        // Release bookkeeping commits are noise in the changelog
+        if parsed
+            .as_ref()
+            .is_some_and(|p| p.kind == "chore" && p.scope.as_deref() == Some("release"))
+        {
+            continue;
+        }
+
+        let (group, entry) = match &parsed {
+            Some(parsed) => {
+                let group = if parsed.breaking {
+                    0
+                } else {
+                    match parsed.kind.as_str() {
+                        "feat" => 1,
+                        "fix" => 2,
+                        "perf" => 3,
+                        _ => 4,
+                    }
+                };
+                let entry = match &parsed.scope {
+                    Some(scope) => format!("**{}:** {}", scope, parsed.description),
+                    None => parsed.description.clone(),
+                };
+                (group, entry)
+            }
+            None => (4, commit.subject.clone()),
+        };
+        let entry = format!("- {} ({})", entry, &commit.sha[..commit.sha.len().min(7)]);
+
+        let mut touched: BTreeSet<String> = commit
+            .files
+            .iter()
+            .filter_map(|file| {
+                let rest = file.strip_prefix(modules_path)?.strip_prefix('/')?;
+                let project = rest.split('/').next()?;
+                project_names
+                    .iter()
+                    .any(|name| name == project)
+                    .then(|| project.to_string())
+            })
+            .collect();
+        if touched.is_empty() {
+            touched.insert(APPLICATION_GROUP.to_string());
+        }
+
+        for project in touched {
+            projects
+                .entry(project)
+                .or_default()
+                .entry(group)
+                .or_default()
+                .push(entry.clone());
+        }
+    }
+
+    let mut section = format!("## {} ({})\n", version, date);
+    // Projects alphabetically, with application-wide changes last
+    let mut order: Vec<&str> = projects
+        .keys()
+        .map(String::as_str)
+        .filter(|p| *p != APPLICATION_GROUP)
+        .collect();
+    if projects.contains_key(APPLICATION_GROUP) {
+        order.push(APPLICATION_GROUP);
+    }
+    for project in order {
+        section.push_str(&format!("\n### {}\n", project));
+        for (group, entries) in &projects[project] {
+            section.push_str(&format!("\n#### {}\n\n", GROUPS[*group]));
+            for entry in entries {
+                section.push_str(entry);
+                section.push('\n');
+            }
+        }
+    }
+
+    section
+}
+
+/// Insert a section at the top of CHANGELOG.md (below its title), creating the file if needed.
+pub(crate) fn write_changelog(app_root: &Path, section: &str) -> Result<PathBuf> {
+    let path = app_root.join(CHANGELOG_FILE);
+    let existing = if path.exists() {
+        fs::read_to_string(&path).with_context(|| format!("Failed to read {}", path.display()))?
+    } else {
+        String::new()
+    };
+
+    let body = existing
+        .strip_prefix(CHANGELOG_HEADER)
+        .unwrap_or(&existing)
+        .trim_start_matches('\n');
+    let mut content = format!("{}\n\n{}", CHANGELOG_HEADER, section);
+    if !body.is_empty() {
+        content.push('\n');
+        content.push_str(body);
+    }
+
+    fs::write(&path, content).with_context(|| format!("Failed to write {}", path.display()))?;
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_conventional_commit() {
+        let parsed = parse_conventional_commit("feat(billing)!: drop v1 invoices", "").unwrap();
+        assert_eq!(parsed.kind, "feat");
+        assert_eq!(parsed.scope.as_deref(), Some("billing"));
+        assert!(parsed.breaking);
+        assert_eq!(parsed.description, "drop v1 invoices");
+
+        let footer = parse_conventional_commit("fix: handle nulls", "BREAKING CHANGE: nulls rejected");
+        assert!(footer.unwrap().breaking);
+
+        assert!(parse_conventional_commit("Merge branch 'main'", "").is_none());
+        assert!(parse_conventional_commit("update readme: typos", "").is_none());
+    }
+
+    #[test]
+    fn test_apply_bump() {
+        let v = |s: &str| Version::parse(s).unwrap();
+        assert_eq!(apply_bump(&v("1.2.3"), Bump::Patch), v("1.2.4"));
+        assert_eq!(apply_bump(&v("1.2.3"), Bump::Minor), v("1.3.0"));
+        assert_eq!(apply_bump(&v("1.2.3"), Bump::Major), v("2.0.0"));
+        assert_eq!(apply_bump(&v("0.4.1"), Bump::Major), v("0.5.0"));
+        assert_eq!(apply_bump(&v("1.3.0-rc.1"), Bump::Patch), v("1.3.0"));
+    }
+
+    #[test]
+    fn test_openapi_breaking_changes() {
+        let previous = json!({"paths": {
+            "/invoices": {"get": {}, "post": {}},
+            "/users": {"get": {"parameters": [{"in": "query", "name": "page"}]}}
+        }});
+        let current = json!({"paths": {
+            "/invoices": {"get": {}},
+            "/users": {"get": {"parameters": [{"in": "query", "name": "page", "required": true}]}},
+            "/new": {"get": {}}
+        }});
+
+        assert_eq!(
+            openapi_breaking_changes(&previous, &current),
+            vec![
+                "removed POST /invoices".to_string(),
+                "GET /users now requires query 'page' parameter".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_render_changelog_groups_by_project() {
+        let commit = |sha: &str, subject: &str, files: &[&str]| GitCommit {
+            sha: sha.to_string(),
+            subject: subject.to_string(),
+            body: String::new(),
+            files: files.iter().map(|f| f.to_string()).collect(),
+        };
+        let commits = vec![
+            commit("aaaaaaaaa", "feat(invoices): add PDF export", &["src/modules/billing/a.ts"]),
+            commit(
+                "bbbbbbbbb",
+                "fix: shared retry",
+                &["src/modules/billing/b.ts", "src/modules/iam/c.ts"],
+            ),
+            commit("ccccccccc", "Update README", &["README.md"]),
+            commit("ddddddddd", "chore(release): 1.0.0", &["CHANGELOG.md"]),
+        ];
+
+        let section = render_changelog_section(
+            &Version::new(1, 1, 0),
+            "2025-02-01",
+            &commits,
+            "src/modules",
+            &["billing".to_string(), "iam".to_string()],
+        );
+
+        assert_eq!(
+            section,
+            "## 1.1.0 (2025-02-01)\n\
+             \n### billing\n\n#### Features\n\n- **invoices:** add PDF export (aaaaaaa)\n\
+             \n#### Bug Fixes\n\n- shared retry (bbbbbbb)\n\
+             \n### iam\n\n#### Bug Fixes\n\n- shared retry (bbbbbbb)\n\
+             \n### Application\n\n#### Other Changes\n\n- Update README (ccccccc)\n"
+        );
+    }
+
+    #[test]
+    fn test_write_changelog_prepends_section() {
+        let temp = tempfile::tempdir().unwrap();
+        write_changelog(temp.path(), "## 1.0.0 (2025-01-01)\n").unwrap();
+        write_changelog(temp.path(), "## 1.1.0 (2025-02-01)\n").unwrap();
+
+        let content = fs::read_to_string(temp.path().join(CHANGELOG_FILE)).unwrap();
+        assert_eq!(
+            content,
+            "# Changelog\n\n## 1.1.0 (2025-02-01)\n\n## 1.0.0 (2025-01-01)\n"
+        );
+    }
+}
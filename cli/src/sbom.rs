@@ -0,0 +1,94 @@
+use std::{fs, io::Write, path::PathBuf};
+
+use anyhow::{Context, Result};
+use clap::{Arg, ArgMatches, Command};
+use termcolor::{Color, ColorChoice, ColorSpec, StandardStream, WriteColor};
+
+use crate::{
+    CliCommand,
+    core::{
+        command::command,
+        sbom::{SbomFormat, build_sbom},
+        validate::require_manifest,
+    },
+};
+
+#[derive(Debug)]
+pub(crate) struct SbomCommand;
+
+impl SbomCommand {
+    pub(crate) fn new() -> Self {
+        Self {}
+    }
+}
+
+impl CliCommand for SbomCommand {
+    fn command(&self) -> Command {
+        command(
+            "sbom",
+            "Generate a software bill of materials for the application",
+        )
+        .long_about(
+            "Generates a CycloneDX or SPDX software bill of materials. Components carry package URLs, versions resolved from the lockfile and licenses read from installed packages; workspace projects are linked by their dependencies.",
+        )
+        .arg(
+            Arg::new("format")
+                .long("format")
+                .value_parser(SbomFormat::VARIANTS)
+                .default_value("cyclonedx-json")
+                .help("SBOM format"),
+        )
+        .arg(
+            Arg::new("project")
+                .long("project")
+                .help("Only include this project and the workspace projects it depends on"),
+        )
+        .arg(
+            Arg::new("output")
+                .short('o')
+                .long("output")
+                .help("Write the SBOM to this file instead of stdout"),
+        )
+        .arg(
+            Arg::new("base_path")
+                .short('p')
+                .long("path")
+                .help("The application path"),
+        )
+    }
+
+    fn handler(&self, matches: &ArgMatches) -> Result<()> {
+        let (app_root_path, manifest_data) = require_manifest(matches)?;
+        let format = SbomFormat::parse(matches.get_one::<String>("format").unwrap())?;
+
+        let sbom = build_sbom(
+            &app_root_path,
+            &manifest_data,
+            manifest_data.release_version.as_deref(),
+            matches.get_one::<String>("project").map(String::as_str),
+        )?;
+        let document =
+            serde_json::to_string_pretty(&sbom.render(format, &chrono::Utc::now().to_rfc3339()))?;
+
+        match matches.get_one::<String>("output").map(PathBuf::from) {
+            Some(output) => {
+                fs::write(&output, document)
+                    .with_context(|| format!("Failed to write {}", output.display()))?;
+
+                let mut stdout = StandardStream::stdout(ColorChoice::Always);
+                stdout.set_color(ColorSpec::new().set_fg(Some(Color::Green)))?;
+                write!(stdout, "[OK]")?;
+                stdout.reset()?;
+                writeln!(
+                    stdout,
+                    " SBOM with {} components written to: {}",
+                    sbom.components.len(),
+                    output.display()
+                )?;
+            }
+            None => println!("{}", document),
+        }
+
+        Ok(())
+    }
+}
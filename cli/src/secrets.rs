@@ -0,0 +1,137 @@
+use std::{
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Result, bail};
+use clap::{Arg, ArgMatches, Command};
+use decrypt::DecryptCommand;
+use edit::EditCommand;
+use encrypt::EncryptCommand;
+use keygen::KeygenCommand;
+use rekey::RekeyCommand;
+use termcolor::{Color, ColorSpec, StandardStream, WriteColor};
+
+use crate::{
+    CliCommand,
+    core::{
+        command::command,
+        manifest::application::ApplicationManifestData,
+        secrets::{ENCRYPTED_ENV_FILE_NAME, RekeyReport},
+    },
+};
+
+pub(crate) mod decrypt;
+pub(crate) mod edit;
+pub(crate) mod encrypt;
+pub(crate) mod keygen;
+pub(crate) mod rekey;
+
+#[derive(Debug)]
+pub(crate) struct SecretsCommand {
+    encrypt: EncryptCommand,
+    decrypt: DecryptCommand,
+    edit: EditCommand,
+    rekey: RekeyCommand,
+    keygen: KeygenCommand,
+}
+
+impl SecretsCommand {
+    pub(crate) fn new() -> Self {
+        Self {
+            encrypt: EncryptCommand::new(),
+            decrypt: DecryptCommand::new(),
+            edit: EditCommand::new(),
+            rekey: RekeyCommand::new(),
+            keygen: KeygenCommand::new(),
+        }
+    }
+}
+
+impl CliCommand for SecretsCommand {
+    fn command(&self) -> Command {
+        command(
+            "secrets",
+            "Manage encrypted environment values committed in .env.enc",
+        )
+        .subcommand_required(true)
+        .subcommand(self.encrypt.command())
+        .subcommand(self.decrypt.command())
+        .subcommand(self.edit.command())
+        .subcommand(self.rekey.command())
+        .subcommand(self.keygen.command())
+    }
+
+    fn handler(&self, matches: &ArgMatches) -> Result<()> {
+        match matches.subcommand() {
+            Some(("encrypt", sub_matches)) => self.encrypt.handler(sub_matches),
+            Some(("decrypt", sub_matches)) => self.decrypt.handler(sub_matches),
+            Some(("edit", sub_matches)) => self.edit.handler(sub_matches),
+            Some(("rekey", sub_matches)) => self.rekey.handler(sub_matches),
+            Some(("keygen", sub_matches)) => self.keygen.handler(sub_matches),
+            _ => unreachable!(),
+        }
+    }
+}
+
+pub(crate) fn base_path_arg() -> Arg {
+    Arg::new("base_path")
+        .short('p')
+        .long("path")
+        .help("The application path")
+}
+
+pub(crate) fn file_arg() -> Arg {
+    Arg::new("file")
+        .short('f')
+        .long("file")
+        .help("Encrypted env file (defaults to .env.enc at the application root)")
+}
+
+pub(crate) fn encrypted_file_path(matches: &ArgMatches, app_root: &Path) -> PathBuf {
+    matches
+        .get_one::<String>("file")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| app_root.join(ENCRYPTED_ENV_FILE_NAME))
+}
+
+pub(crate) fn require_recipients(manifest: &ApplicationManifestData) -> Result<Vec<String>> {
+    match &manifest.secrets_recipients {
+        Some(recipients) if !recipients.is_empty() => Ok(recipients.clone()),
+        _ => bail!(
+            "No secrets recipients configured.\nRun: forklaunch secrets keygen, then forklaunch secrets rekey --add <public-key>"
+        ),
+    }
+}
+
+/// Renders decrypted values as a plain env file, quoting values that would
+/// not survive a round trip through the env parser.
+pub(crate) fn render_plain_env(vars: &[(String, String)]) -> String {
+    vars.iter()
+        .map(|(key, value)| {
+            if value.trim() != value || value.starts_with('"') || value.starts_with('\'') {
+                format!("{}=\"{}\"\n", key, value)
+            } else {
+                format!("{}={}\n", key, value)
+            }
+        })
+        .collect()
+}
+
+pub(crate) fn print_rekey_report(stdout: &mut StandardStream, report: &RekeyReport) -> Result<()> {
+    for recipient in &report.added {
+        writeln!(stdout, "[INFO] Granted access to {}", recipient)?;
+    }
+    for recipient in &report.removed {
+        writeln!(stdout, "[INFO] Revoked access for {}", recipient)?;
+    }
+    if report.rotated {
+        stdout.set_color(ColorSpec::new().set_fg(Some(Color::Yellow)))?;
+        writeln!(
+            stdout,
+            "[WARN] Data key rotated; removed recipients can still read older commits, so rotate the underlying secrets too"
+        )?;
+        stdout.reset()?;
+    }
+    Ok(())
+}
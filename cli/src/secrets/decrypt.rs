@@ -0,0 +1,76 @@
+use std::{fs, io::Write, path::PathBuf};
+
+use anyhow::{Context, Result};
+use clap::{Arg, ArgMatches, Command};
+use termcolor::{Color, ColorChoice, ColorSpec, StandardStream, WriteColor};
+
+use super::{base_path_arg, encrypted_file_path, file_arg, render_plain_env};
+use crate::{
+    CliCommand,
+    core::{
+        command::command,
+        secrets::{EncryptedEnvFile, load_identities},
+        validate::require_manifest,
+    },
+};
+
+#[derive(Debug)]
+pub(crate) struct DecryptCommand;
+
+impl DecryptCommand {
+    pub(crate) fn new() -> Self {
+        Self {}
+    }
+}
+
+impl CliCommand for DecryptCommand {
+    fn command(&self) -> Command {
+        command("decrypt", "Print the decrypted contents of .env.enc")
+            .arg(base_path_arg())
+            .arg(file_arg())
+            .arg(
+                Arg::new("output")
+                    .short('o')
+                    .long("output")
+                    .help("Write the plaintext to a file instead of stdout"),
+            )
+    }
+
+    fn handler(&self, matches: &ArgMatches) -> Result<()> {
+        let (app_root, _manifest) = require_manifest(matches)?;
+        let target = encrypted_file_path(matches, &app_root);
+
+        let vars = EncryptedEnvFile::read(&target)?
+            .decrypt(&load_identities()?)?
+            .with_context(|| {
+                format!(
+                    "None of the local age identities can decrypt {}",
+                    target.display()
+                )
+            })?;
+        let plaintext = render_plain_env(&vars);
+
+        match matches.get_one::<String>("output").map(PathBuf::from) {
+            Some(output) => {
+                fs::write(&output, plaintext)
+                    .with_context(|| format!("Failed to write {}", output.display()))?;
+
+                let mut stdout = StandardStream::stdout(ColorChoice::Always);
+                stdout.set_color(ColorSpec::new().set_fg(Some(Color::Green)))?;
+                writeln!(
+                    stdout,
+                    "[OK] Decrypted {} value(s) into {}",
+                    vars.len(),
+                    output.display()
+                )?;
+                stdout.reset()?;
+                stdout.set_color(ColorSpec::new().set_fg(Some(Color::Yellow)))?;
+                writeln!(stdout, "[WARN] Do not commit {}", output.display())?;
+                stdout.reset()?;
+            }
+            None => print!("{}", plaintext),
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,124 @@
+use std::{env::var, fs, io::Write, process::Command as ProcessCommand};
+
+use anyhow::{Context, Result, bail};
+use clap::{ArgMatches, Command};
+use termcolor::{Color, ColorChoice, ColorSpec, StandardStream, WriteColor};
+
+use super::{
+    base_path_arg, encrypted_file_path, file_arg, print_rekey_report, render_plain_env,
+    require_recipients,
+};
+use crate::{
+    CliCommand,
+    core::{
+        command::command,
+        env::parse_env_entries,
+        secrets::{EncryptedEnvFile, encrypt_env, load_identities},
+        validate::require_manifest,
+    },
+};
+
+#[derive(Debug)]
+pub(crate) struct EditCommand;
+
+impl EditCommand {
+    pub(crate) fn new() -> Self {
+        Self {}
+    }
+}
+
+fn editor() -> String {
+    ["VISUAL", "EDITOR"]
+        .iter()
+        .filter_map(|name| var(name).ok())
+        .find(|value| !value.trim().is_empty())
+        .unwrap_or_else(|| "vi".to_string())
+}
+
+impl CliCommand for EditCommand {
+    fn command(&self) -> Command {
+        command(
+            "edit",
+            "Decrypt .env.enc into $EDITOR and re-encrypt it on save",
+        )
+        .arg(base_path_arg())
+        .arg(file_arg())
+    }
+
+    fn handler(&self, matches: &ArgMatches) -> Result<()> {
+        let mut stdout = StandardStream::stdout(ColorChoice::Always);
+
+        let (app_root, manifest) = require_manifest(matches)?;
+        let recipients = require_recipients(&manifest)?;
+        let target = encrypted_file_path(matches, &app_root);
+
+        let identities = load_identities()?;
+        let previous = if target.exists() {
+            Some(EncryptedEnvFile::read(&target)?)
+        } else {
+            None
+        };
+        let vars = match &previous {
+            Some(previous) => previous.decrypt(&identities)?.with_context(|| {
+                format!(
+                    "None of the local age identities can decrypt {}",
+                    target.display()
+                )
+            })?,
+            None => Vec::new(),
+        };
+        let original = render_plain_env(&vars);
+
+        // The plaintext only lives in a private temp file for the duration of the edit.
+        let mut scratch = tempfile::Builder::new()
+            .prefix("forklaunch-secrets-")
+            .suffix(".env")
+            .tempfile()
+            .context("Failed to create temporary file")?;
+        scratch.write_all(original.as_bytes())?;
+        scratch.flush()?;
+
+        let editor = editor();
+        let mut parts = editor.split_whitespace();
+        let program = parts.next().unwrap_or("vi");
+        let status = ProcessCommand::new(program)
+            .args(parts)
+            .arg(scratch.path())
+            .status()
+            .with_context(|| format!("Failed to launch editor '{}'", editor))?;
+        if !status.success() {
+            bail!(
+                "Editor exited with {}; {} was not changed",
+                status,
+                target.display()
+            );
+        }
+
+        let edited = fs::read_to_string(scratch.path()).context("Failed to read edited file")?;
+        let edited_vars = parse_env_entries(&edited);
+        let recipients_changed = previous
+            .as_ref()
+            .is_some_and(|previous| previous.recipients != recipients);
+
+        if edited_vars == vars && previous.is_some() && !recipients_changed {
+            writeln!(stdout, "[INFO] No changes made to {}", target.display())?;
+            return Ok(());
+        }
+
+        let (encrypted, report) =
+            encrypt_env(&edited_vars, &recipients, previous.as_ref(), &identities)?;
+        encrypted.write(&target)?;
+
+        print_rekey_report(&mut stdout, &report)?;
+        stdout.set_color(ColorSpec::new().set_fg(Some(Color::Green)))?;
+        writeln!(
+            stdout,
+            "[OK] Saved {} value(s) to {}",
+            edited_vars.len(),
+            target.display()
+        )?;
+        stdout.reset()?;
+
+        Ok(())
+    }
+}
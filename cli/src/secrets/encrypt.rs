@@ -0,0 +1,103 @@
+use std::{fs, io::Write, path::PathBuf};
+
+use anyhow::{Context, Result};
+use clap::{Arg, ArgAction, ArgMatches, Command};
+use termcolor::{Color, ColorChoice, ColorSpec, StandardStream, WriteColor};
+
+use super::{base_path_arg, encrypted_file_path, file_arg, print_rekey_report, require_recipients};
+use crate::{
+    CliCommand,
+    core::{
+        command::command,
+        env::parse_env_entries,
+        secrets::{EncryptedEnvFile, encrypt_env, load_identities},
+        validate::require_manifest,
+    },
+};
+
+#[derive(Debug)]
+pub(crate) struct EncryptCommand;
+
+impl EncryptCommand {
+    pub(crate) fn new() -> Self {
+        Self {}
+    }
+}
+
+impl CliCommand for EncryptCommand {
+    fn command(&self) -> Command {
+        command(
+            "encrypt",
+            "Encrypt a plaintext env file into .env.enc, adding or updating its keys",
+        )
+        .arg(
+            Arg::new("input")
+                .required(true)
+                .help("Plaintext env file to encrypt"),
+        )
+        .arg(base_path_arg())
+        .arg(file_arg())
+        .arg(
+            Arg::new("remove-plaintext")
+                .long("remove-plaintext")
+                .action(ArgAction::SetTrue)
+                .help("Delete the plaintext input once it has been encrypted"),
+        )
+    }
+
+    fn handler(&self, matches: &ArgMatches) -> Result<()> {
+        let mut stdout = StandardStream::stdout(ColorChoice::Always);
+
+        let (app_root, manifest) = require_manifest(matches)?;
+        let recipients = require_recipients(&manifest)?;
+        let target = encrypted_file_path(matches, &app_root);
+        let input = PathBuf::from(matches.get_one::<String>("input").unwrap());
+
+        let plaintext = fs::read_to_string(&input)
+            .with_context(|| format!("Failed to read {}", input.display()))?;
+        let updates = parse_env_entries(&plaintext);
+
+        let identities = load_identities()?;
+        let previous = if target.exists() {
+            Some(EncryptedEnvFile::read(&target)?)
+        } else {
+            None
+        };
+
+        let mut vars = match &previous {
+            Some(previous) => previous.decrypt(&identities)?.with_context(|| {
+                format!(
+                    "None of the local age identities can decrypt {}",
+                    target.display()
+                )
+            })?,
+            None => Vec::new(),
+        };
+        for (key, value) in &updates {
+            match vars.iter_mut().find(|(existing, _)| existing == key) {
+                Some(entry) => entry.1 = value.clone(),
+                None => vars.push((key.clone(), value.clone())),
+            }
+        }
+
+        let (encrypted, report) = encrypt_env(&vars, &recipients, previous.as_ref(), &identities)?;
+        encrypted.write(&target)?;
+
+        if matches.get_flag("remove-plaintext") {
+            fs::remove_file(&input)
+                .with_context(|| format!("Failed to remove {}", input.display()))?;
+        }
+
+        print_rekey_report(&mut stdout, &report)?;
+        stdout.set_color(ColorSpec::new().set_fg(Some(Color::Green)))?;
+        writeln!(
+            stdout,
+            "[OK] Encrypted {} value(s) into {}",
+            updates.len(),
+            target.display()
+        )?;
+        stdout.reset()?;
+
+        Ok(())
+    }
+}
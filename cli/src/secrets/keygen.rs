@@ -0,0 +1,92 @@
+#[cfg(not(unix))]
+use std::fs::create_dir_all;
+#[cfg(unix)]
+use std::os::unix::fs::{DirBuilderExt, OpenOptionsExt};
+use std::{fs::OpenOptions, io::Write};
+
+use age::{secrecy::ExposeSecret, x25519::Identity};
+use anyhow::{Context, Result, bail};
+use clap::{Arg, ArgAction, ArgMatches, Command};
+use termcolor::{Color, ColorChoice, ColorSpec, StandardStream, WriteColor};
+
+use crate::{
+    CliCommand,
+    core::{command::command, secrets::get_identity_path},
+};
+
+#[derive(Debug)]
+pub(crate) struct KeygenCommand;
+
+impl KeygenCommand {
+    pub(crate) fn new() -> Self {
+        Self {}
+    }
+}
+
+impl CliCommand for KeygenCommand {
+    fn command(&self) -> Command {
+        command(
+            "keygen",
+            "Generate a local age identity for decrypting .env.enc",
+        )
+        .arg(
+            Arg::new("force")
+                .long("force")
+                .action(ArgAction::SetTrue)
+                .help("Overwrite an existing identity file"),
+        )
+    }
+
+    fn handler(&self, matches: &ArgMatches) -> Result<()> {
+        let mut stdout = StandardStream::stdout(ColorChoice::Always);
+
+        let identity_path = get_identity_path()?;
+        if identity_path.exists() && !matches.get_flag("force") {
+            bail!(
+                "An identity already exists at {}. Pass --force to replace it",
+                identity_path.display()
+            );
+        }
+
+        if let Some(parent) = identity_path.parent() {
+            #[cfg(unix)]
+            {
+                use std::fs::DirBuilder;
+                DirBuilder::new()
+                    .recursive(true)
+                    .mode(0o700)
+                    .create(parent)?;
+            }
+
+            #[cfg(not(unix))]
+            {
+                create_dir_all(parent)?;
+            }
+        }
+
+        let identity = Identity::generate();
+        let public_key = identity.to_public().to_string();
+
+        let mut options = OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+        #[cfg(unix)]
+        options.mode(0o600);
+        let mut file = options
+            .open(&identity_path)
+            .with_context(|| format!("Failed to create {}", identity_path.display()))?;
+        writeln!(file, "# public key: {}", public_key)?;
+        writeln!(file, "{}", identity.to_string().expose_secret())?;
+
+        stdout.set_color(ColorSpec::new().set_fg(Some(Color::Green)))?;
+        writeln!(stdout, "[OK] Wrote identity to {}", identity_path.display())?;
+        stdout.reset()?;
+        writeln!(stdout, "[INFO] Public key: {}", public_key)?;
+        writeln!(
+            stdout,
+            "[INFO] Share it with a teammate who can run: forklaunch secrets rekey --add {}",
+            public_key
+        )?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,122 @@
+use std::{fs::write, io::Write};
+
+use anyhow::{Context, Result};
+use clap::{Arg, ArgAction, ArgMatches, Command};
+use termcolor::{Color, ColorChoice, ColorSpec, StandardStream, WriteColor};
+use toml::to_string_pretty;
+
+use super::{base_path_arg, encrypted_file_path, file_arg, print_rekey_report, require_recipients};
+use crate::{
+    CliCommand,
+    core::{
+        command::command,
+        secrets::{EncryptedEnvFile, encrypt_env, load_identities, parse_recipients},
+        validate::require_manifest,
+    },
+};
+
+#[derive(Debug)]
+pub(crate) struct RekeyCommand;
+
+impl RekeyCommand {
+    pub(crate) fn new() -> Self {
+        Self {}
+    }
+}
+
+impl CliCommand for RekeyCommand {
+    fn command(&self) -> Command {
+        command(
+            "rekey",
+            "Re-encrypt .env.enc for the recipients listed in the manifest",
+        )
+        .arg(base_path_arg())
+        .arg(file_arg())
+        .arg(
+            Arg::new("add")
+                .long("add")
+                .action(ArgAction::Append)
+                .help("Add an age public key to secrets_recipients before re-keying"),
+        )
+        .arg(
+            Arg::new("remove")
+                .long("remove")
+                .action(ArgAction::Append)
+                .help("Remove an age public key from secrets_recipients before re-keying"),
+        )
+    }
+
+    fn handler(&self, matches: &ArgMatches) -> Result<()> {
+        let mut stdout = StandardStream::stdout(ColorChoice::Always);
+
+        let (app_root, mut manifest) = require_manifest(matches)?;
+        let target = encrypted_file_path(matches, &app_root);
+
+        let additions: Vec<String> = matches
+            .get_many::<String>("add")
+            .map(|values| values.map(|value| value.trim().to_string()).collect())
+            .unwrap_or_default();
+        let removals: Vec<String> = matches
+            .get_many::<String>("remove")
+            .map(|values| values.map(|value| value.trim().to_string()).collect())
+            .unwrap_or_default();
+
+        if !additions.is_empty() || !removals.is_empty() {
+            parse_recipients(&additions)?;
+
+            let mut recipients = manifest.secrets_recipients.clone().unwrap_or_default();
+            for recipient in additions {
+                if !recipients.contains(&recipient) {
+                    recipients.push(recipient);
+                }
+            }
+            recipients.retain(|recipient| !removals.contains(recipient));
+            manifest.secrets_recipients = Some(recipients);
+
+            let manifest_path = app_root.join(".forklaunch").join("manifest.toml");
+            let updated_manifest = to_string_pretty(&manifest)
+                .with_context(|| "Failed to serialize updated manifest")?;
+            write(&manifest_path, updated_manifest)
+                .with_context(|| format!("Failed to write manifest at {:?}", manifest_path))?;
+            writeln!(stdout, "[INFO] Updated secrets_recipients in manifest.toml")?;
+        }
+
+        let recipients = require_recipients(&manifest)?;
+
+        if !target.exists() {
+            writeln!(
+                stdout,
+                "[INFO] {} does not exist yet; nothing to re-key",
+                target.display()
+            )?;
+            return Ok(());
+        }
+
+        let identities = load_identities()?;
+        let previous = EncryptedEnvFile::read(&target)?;
+        let vars = previous.decrypt(&identities)?.with_context(|| {
+            format!(
+                "None of the local age identities can decrypt {}",
+                target.display()
+            )
+        })?;
+
+        let (encrypted, report) = encrypt_env(&vars, &recipients, Some(&previous), &identities)?;
+        if report.is_empty() {
+            writeln!(
+                stdout,
+                "[INFO] Recipients of {} are up to date",
+                target.display()
+            )?;
+            return Ok(());
+        }
+        encrypted.write(&target)?;
+
+        print_rekey_report(&mut stdout, &report)?;
+        stdout.set_color(ColorSpec::new().set_fg(Some(Color::Green)))?;
+        writeln!(stdout, "[OK] Re-keyed {}", target.display())?;
+        stdout.reset()?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,41 @@
+use anyhow::Result;
+use clap::{ArgMatches, Command};
+use generate::GenerateCommand;
+use run::RunCommand;
+
+use crate::{CliCommand, core::command::command};
+
+pub(crate) mod generate;
+pub(crate) mod run;
+
+#[derive(Debug)]
+pub(crate) struct SeedCommand {
+    generate: GenerateCommand,
+    run: RunCommand,
+}
+
+impl SeedCommand {
+    pub(crate) fn new() -> Self {
+        Self {
+            generate: GenerateCommand::new(),
+            run: RunCommand::new(),
+        }
+    }
+}
+
+impl CliCommand for SeedCommand {
+    fn command(&self) -> Command {
+        command("seed", "Generate and run database seed data")
+            .subcommand_required(true)
+            .subcommand(self.generate.command())
+            .subcommand(self.run.command())
+    }
+
+    fn handler(&self, matches: &ArgMatches) -> Result<()> {
+        match matches.subcommand() {
+            Some(("generate", sub_matches)) => self.generate.handler(sub_matches),
+            Some(("run", sub_matches)) => self.run.handler(sub_matches),
+            _ => unreachable!(),
+        }
+    }
+}
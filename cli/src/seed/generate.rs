@@ -0,0 +1,329 @@
+use std::{
+    fs::{read_dir, read_to_string},
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result, bail};
+use clap::{Arg, ArgAction, ArgGroup, ArgMatches, Command, value_parser};
+use convert_case::{Case, Casing};
+use termcolor::{Color, ColorChoice, ColorSpec, StandardStream, WriteColor};
+
+use crate::{
+    CliCommand,
+    constants::Database,
+    core::{
+        command::command,
+        manifest::{ProjectEntry, application::ApplicationManifestData},
+        rendered_template::{RenderedTemplate, write_rendered_templates},
+        seed_data::{IdStyle, generate_seed_data, select_entities},
+        static_analysis::{EntityAnalyzer, entity_analyzer::EntityDefinition},
+        validate::require_manifest,
+    },
+};
+
+const ENTITY_FILE_SUFFIX: &str = ".entity.ts";
+
+/// Finds the project that owns `router`, or checks that `project` does.
+fn resolve_project<'a>(
+    manifest_data: &'a ApplicationManifestData,
+    project: Option<&str>,
+    router: Option<&str>,
+) -> Result<&'a ProjectEntry> {
+    let candidates: Vec<&ProjectEntry> = manifest_data
+        .projects
+        .iter()
+        .filter(|p| project.is_none_or(|name| p.name == name))
+        .filter(|p| {
+            router.is_none_or(|router| {
+                p.routers
+                    .as_ref()
+                    .is_some_and(|routers| routers.iter().any(|r| r == router))
+            })
+        })
+        .collect();
+
+    match (candidates.as_slice(), project, router) {
+        ([entry], _, _) => Ok(entry),
+        ([], Some(project), Some(router)) => {
+            bail!("Router '{}' not found in project '{}'", router, project)
+        }
+        ([], Some(project), None) => bail!("Project '{}' not found in manifest", project),
+        ([], None, Some(router)) => bail!("Router '{}' not found in manifest", router),
+        (_, _, Some(router)) => bail!(
+            "Router '{}' exists in several projects, pass --project to choose one",
+            router
+        ),
+        _ => unreachable!(),
+    }
+}
+
+/// Entity files of a router are named `<router>Record.entity.ts` (services)
+/// or `<router>EventRecord.entity.ts` (workers).
+fn is_router_entity_file(file_name: &str, router: &str) -> bool {
+    let camel_case_name = router.to_case(Case::Camel);
+    file_name
+        .strip_suffix(ENTITY_FILE_SUFFIX)
+        .is_some_and(|stem| {
+            stem == camel_case_name
+                || stem == format!("{}Record", camel_case_name)
+                || stem == format!("{}EventRecord", camel_case_name)
+        })
+}
+
+/// Parses every entity file of the project. Returns all entities and the
+/// names of those declared in the router's files.
+fn load_entities(
+    entities_path: &Path,
+    router: Option<&str>,
+) -> Result<(Vec<EntityDefinition>, Vec<String>)> {
+    let mut files: Vec<PathBuf> = read_dir(entities_path)
+        .with_context(|| format!("Failed to read {}", entities_path.display()))?
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.to_string_lossy().ends_with(ENTITY_FILE_SUFFIX))
+        .collect();
+    files.sort();
+
+    let mut entities = vec![];
+    let mut roots = vec![];
+    for file in files {
+        let parsed = EntityAnalyzer::parse_entity_file(&file)?;
+        let file_name = file.file_name().unwrap().to_string_lossy().to_string();
+        if router.is_none_or(|router| is_router_entity_file(&file_name, router)) {
+            roots.extend(parsed.iter().map(|entity| entity.name.clone()));
+        }
+        entities.extend(parsed);
+    }
+
+    Ok((entities, roots))
+}
+
+/// Adds an export for the generated seeder so `DatabaseSeeder` picks it up.
+fn add_seeder_export(seeders_index: &str, seeder_class: &str, seeder_file: &str) -> Option<String> {
+    if seeders_index.contains(seeder_class) {
+        return None;
+    }
+    let mut content = seeders_index.trim_end().to_string();
+    if !content.is_empty() {
+        content.push('\n');
+    }
+    content.push_str(&format!(
+        "export {{ {} }} from \"./{}\";\n",
+        seeder_class, seeder_file
+    ));
+    Some(content)
+}
+
+#[derive(Debug)]
+pub(crate) struct GenerateCommand;
+
+impl GenerateCommand {
+    pub(crate) fn new() -> Self {
+        Self {}
+    }
+}
+
+impl CliCommand for GenerateCommand {
+    fn command(&self) -> Command {
+        command(
+            "generate",
+            "Generate seed data from a router's entity definitions",
+        )
+        .arg(
+            Arg::new("router")
+                .short('r')
+                .long("router")
+                .help("Router whose entities to seed, along with the entities they reference"),
+        )
+        .arg(
+            Arg::new("project")
+                .long("project")
+                .help("Project to seed; required when no router is given"),
+        )
+        .group(
+            ArgGroup::new("target")
+                .args(["router", "project"])
+                .multiple(true)
+                .required(true),
+        )
+        .arg(
+            Arg::new("count")
+                .short('c')
+                .long("count")
+                .help("Records to generate per entity")
+                .value_parser(value_parser!(usize))
+                .default_value("10"),
+        )
+        .arg(
+            Arg::new("seed")
+                .short('s')
+                .long("seed")
+                .help("Seed value; the same seed produces the same data")
+                .value_parser(value_parser!(u64))
+                .default_value("1"),
+        )
+        .arg(
+            Arg::new("base_path")
+                .short('p')
+                .long("path")
+                .help("The application path"),
+        )
+        .arg(
+            Arg::new("dryrun")
+                .short('n')
+                .long("dryrun")
+                .help("Dry run the command")
+                .action(ArgAction::SetTrue),
+        )
+    }
+
+    fn handler(&self, matches: &ArgMatches) -> Result<()> {
+        let mut stdout = StandardStream::stdout(ColorChoice::Always);
+        let (app_root_path, manifest_data) = require_manifest(matches)?;
+
+        let router = matches.get_one::<String>("router").map(String::as_str);
+        let count = *matches.get_one::<usize>("count").unwrap();
+        let seed = *matches.get_one::<u64>("seed").unwrap();
+        let dryrun = matches.get_flag("dryrun");
+
+        if count == 0 {
+            bail!("--count must be at least 1");
+        }
+
+        let project = resolve_project(
+            &manifest_data,
+            matches.get_one::<String>("project").map(String::as_str),
+            router,
+        )?;
+        let persistence_path = app_root_path
+            .join(&manifest_data.modules_path)
+            .join(&project.name)
+            .join("persistence");
+
+        let (entities, roots) = load_entities(&persistence_path.join("entities"), router)?;
+        if roots.is_empty() {
+            bail!(
+                "No entities found for {} in {}",
+                router.unwrap_or(&project.name),
+                persistence_path.join("entities").display()
+            );
+        }
+        let selected = select_entities(&entities, &roots);
+
+        let is_mongo = project
+            .resources
+            .as_ref()
+            .and_then(|resources| resources.database.as_ref())
+            .and_then(|database| database.parse::<Database>().ok())
+            .is_some_and(|database| database == Database::MongoDB);
+        let id_style = if is_mongo {
+            IdStyle::ObjectId
+        } else {
+            IdStyle::Uuid
+        };
+
+        let key = router.map_or("all".to_string(), |router| router.to_case(Case::Camel));
+        let command_line = format!(
+            "forklaunch seed generate {}--project {} --count {} --seed {}",
+            router.map_or(String::new(), |router| format!("--router {} ", router)),
+            project.name,
+            count,
+            seed
+        );
+        let generated = generate_seed_data(&selected, &key, count, seed, id_style, &command_line);
+
+        let seeder_file = format!("{}Generated.seeder", key);
+        let seeders_index_path = persistence_path.join("seeders").join("index.ts");
+        let mut rendered_templates = vec![
+            RenderedTemplate {
+                path: persistence_path.join(format!("{}.seed.data.ts", key)),
+                content: generated.data_ts,
+                context: None,
+            },
+            RenderedTemplate {
+                path: persistence_path
+                    .join("seeders")
+                    .join(format!("{}.ts", seeder_file)),
+                content: generated.seeder_ts,
+                context: None,
+            },
+        ];
+        let seeders_index = read_to_string(&seeders_index_path).unwrap_or_default();
+        if let Some(content) =
+            add_seeder_export(&seeders_index, &generated.seeder_class, &seeder_file)
+        {
+            rendered_templates.push(RenderedTemplate {
+                path: seeders_index_path,
+                content,
+                context: None,
+            });
+        }
+
+        write_rendered_templates(&rendered_templates, dryrun, &mut stdout)?;
+
+        for warning in &generated.warnings {
+            stdout.set_color(ColorSpec::new().set_fg(Some(Color::Yellow)))?;
+            writeln!(stdout, "[WARN] {}", warning)?;
+            stdout.reset()?;
+        }
+
+        stdout.set_color(ColorSpec::new().set_fg(Some(Color::Green)))?;
+        writeln!(
+            stdout,
+            "[OK] {} {} record(s) for {} in {}",
+            if dryrun {
+                "Would generate"
+            } else {
+                "Generated"
+            },
+            count * generated.entity_order.len(),
+            generated.entity_order.join(", "),
+            project.name
+        )?;
+        stdout.reset()?;
+        if !dryrun {
+            writeln!(
+                stdout,
+                "[INFO] Run `forklaunch seed run --project {} --class {}` to insert it",
+                project.name, generated.seeder_class
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_router_entity_files_and_seeder_export() {
+        assert!(is_router_entity_file("userRecord.entity.ts", "user"));
+        assert!(is_router_entity_file(
+            "paymentEventRecord.entity.ts",
+            "payment"
+        ));
+        assert!(is_router_entity_file(
+            "orderItemRecord.entity.ts",
+            "order-item"
+        ));
+        assert!(!is_router_entity_file("userRoleRecord.entity.ts", "user"));
+
+        let index = "export { UserRecordSeeder } from \"./userRecord.seeder\";";
+        assert_eq!(
+            add_seeder_export(index, "UserGeneratedSeeder", "userGenerated.seeder").unwrap(),
+            "export { UserRecordSeeder } from \"./userRecord.seeder\";\n\
+             export { UserGeneratedSeeder } from \"./userGenerated.seeder\";\n"
+        );
+        assert!(
+            add_seeder_export(
+                "export { UserGeneratedSeeder } from \"./userGenerated.seeder\";\n",
+                "UserGeneratedSeeder",
+                "userGenerated.seeder"
+            )
+            .is_none()
+        );
+    }
+}
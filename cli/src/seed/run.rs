@@ -0,0 +1,138 @@
+use std::io::Write;
+
+use anyhow::Result;
+use clap::{Arg, ArgAction, ArgGroup, ArgMatches, Command};
+use termcolor::{ColorChoice, StandardStream};
+
+use crate::{
+    CliCommand,
+    constants::Runtime,
+    core::{
+        command::command,
+        mikro_orm::{
+            DatabaseProject, database_projects, ensure_database_services, mikro_orm_command,
+            print_run_summary, run_command, run_in_dependency_order,
+        },
+        validate::require_manifest,
+    },
+};
+
+fn seeder_args(matches: &ArgMatches) -> Vec<String> {
+    let mut args = vec!["seeder:run".to_string()];
+    if let Some(class) = matches.get_one::<String>("class") {
+        args.push("--class".to_string());
+        args.push(class.clone());
+    }
+    args
+}
+
+#[derive(Debug)]
+pub(crate) struct RunCommand;
+
+impl RunCommand {
+    pub(crate) fn new() -> Self {
+        Self {}
+    }
+}
+
+impl CliCommand for RunCommand {
+    fn command(&self) -> Command {
+        command("run", "Run database seeders")
+            .arg(
+                Arg::new("project")
+                    .long("project")
+                    .help("Seed a single service or worker"),
+            )
+            .arg(
+                Arg::new("all")
+                    .short('a')
+                    .long("all")
+                    .help("Seed every service and worker with a MikroORM config")
+                    .action(ArgAction::SetTrue),
+            )
+            .group(
+                ArgGroup::new("target")
+                    .args(["project", "all"])
+                    .required(true),
+            )
+            .arg(
+                Arg::new("class")
+                    .short('c')
+                    .long("class")
+                    .help("Seeder class to run instead of DatabaseSeeder"),
+            )
+            .arg(
+                Arg::new("base_path")
+                    .short('p')
+                    .long("path")
+                    .help("The application path"),
+            )
+    }
+
+    fn handler(&self, matches: &ArgMatches) -> Result<()> {
+        let mut stdout = StandardStream::stdout(ColorChoice::Always);
+        let (app_root_path, manifest_data) = require_manifest(matches)?;
+        let runtime = manifest_data.runtime.parse::<Runtime>()?;
+
+        let projects = database_projects(
+            &app_root_path,
+            &manifest_data,
+            matches.get_one::<String>("project").map(String::as_str),
+        )?;
+        if projects.is_empty() {
+            writeln!(stdout, "[INFO] No projects with a MikroORM config found")?;
+            return Ok(());
+        }
+
+        ensure_database_services(&app_root_path, &manifest_data, &projects, &mut stdout)?;
+
+        let args = seeder_args(matches);
+        let results = run_in_dependency_order(
+            &app_root_path,
+            &manifest_data,
+            &projects,
+            false,
+            &mut stdout,
+            |project: &DatabaseProject, stdout| {
+                writeln!(stdout, "[INFO] mikro-orm {}", args.join(" "))?;
+                run_command(mikro_orm_command(&runtime, &project.path, &args))?;
+                Ok(None)
+            },
+        )?;
+
+        print_run_summary("Seed summary", &results, &mut stdout)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_seeder_args() {
+        let parse = |args: &[&str]| {
+            RunCommand::new()
+                .command()
+                .version("0.0.0")
+                .try_get_matches_from(args)
+        };
+
+        let matches = parse(&[
+            "run",
+            "--project",
+            "billing",
+            "--class",
+            "UserGeneratedSeeder",
+        ])
+        .unwrap();
+        assert_eq!(
+            seeder_args(&matches),
+            vec!["seeder:run", "--class", "UserGeneratedSeeder"]
+        );
+        assert_eq!(
+            seeder_args(&parse(&["run", "--all"]).unwrap()),
+            vec!["seeder:run"]
+        );
+        assert!(parse(&["run"]).is_err());
+    }
+}
@@ -10,6 +10,7 @@ use crate::{CliCommand, core::command::command};
 pub(crate) mod all;
 pub(crate) mod library;
 pub(crate) mod service;
+pub(crate) mod watch;
 pub(crate) mod worker;
 
 #[derive(Debug)]
@@ -21,6 +21,7 @@ use crate::{
         },
     },
     prompt::{ArrayCompleter, prompt_for_confirmation, prompt_with_validation_with_answers},
+    sync::watch::watch_projects,
 };
 
 /// Performs a full sync of all projects in the modules directory with the manifest.
@@ -111,29 +112,11 @@ pub fn sync_all_projects(
 
         if should_cleanup {
             for project_name in &orphaned_projects {
-                writeln!(stdout, "[INFO] Removing '{}'...", project_name)?;
-
-                let project = manifest_data
-                    .projects
-                    .iter()
-                    .find(|p| &p.name == project_name);
-                let project_type = project
-                    .map(|p| p.r#type.clone())
-                    .unwrap_or(crate::core::manifest::ProjectType::Library);
-
-                remove_project_from_artifacts(
-                    rendered_templates_cache,
-                    manifest_data,
+                remove_orphaned_project(
                     project_name,
-                    project_type,
-                    &[
-                        ArtifactType::Manifest,
-                        ArtifactType::DockerCompose,
-                        ArtifactType::Runtime,
-                        ArtifactType::ClientSdk,
-                    ],
                     app_root_path,
-                    &modules_path,
+                    manifest_data,
+                    rendered_templates_cache,
                     stdout,
                 )?;
                 changes_made = true;
@@ -172,178 +155,289 @@ pub fn sync_all_projects(
             continue;
         }
 
-        writeln!(stdout)?;
-        stdout.set_color(ColorSpec::new().set_fg(Some(Color::Cyan)))?;
-        writeln!(stdout, "[INFO] Processing: {}", project_name)?;
-        stdout.reset()?;
+        if sync_project(
+            &project_name,
+            app_root_path,
+            manifest_data,
+            rendered_templates_cache,
+            confirm_all,
+            prompts_map,
+            stdout,
+        )? {
+            changes_made = true;
+        }
+    }
 
-        let detected_type = detect_project_type(&project_path)?;
+    Ok(changes_made)
+}
 
-        let project_type = if let Some(detected) = detected_type {
-            writeln!(stdout, "[INFO] Detected as: {}", detected.to_string())?;
-            detected
-        } else {
-            stdout.set_color(ColorSpec::new().set_fg(Some(Color::Yellow)))?;
-            writeln!(stdout, "[WARN] Could not auto-detect project type")?;
-            stdout.reset()?;
+/// Detects the type of a single project folder and syncs it to the
+/// application artifacts. Returns `Ok(true)` if its manifest entry changed.
+pub(crate) fn sync_project(
+    project_name: &str,
+    app_root_path: &Path,
+    manifest_data: &mut ApplicationManifestData,
+    rendered_templates_cache: &mut RenderedTemplatesCache,
+    confirm_all: bool,
+    prompts_map: &std::collections::HashMap<String, std::collections::HashMap<String, String>>,
+    stdout: &mut StandardStream,
+) -> Result<bool> {
+    writeln!(stdout)?;
+    stdout.set_color(ColorSpec::new().set_fg(Some(Color::Cyan)))?;
+    writeln!(stdout, "[INFO] Processing: {}", project_name)?;
+    stdout.reset()?;
 
-            if confirm_all {
-                stdout.set_color(ColorSpec::new().set_fg(Some(Color::Yellow)))?;
-                writeln!(
-                    stdout,
-                    "[WARN] Skipping '{}' (cannot auto-detect and no interaction allowed)",
-                    project_name
-                )?;
-                stdout.reset()?;
-                continue;
-            }
+    let project_path = app_root_path
+        .join(&manifest_data.modules_path)
+        .join(project_name);
+    let detected_type = detect_project_type(&project_path)?;
 
-            let mut line_editor = Editor::<ArrayCompleter, DefaultHistory>::new()?;
-            let type_str = prompt_with_validation_with_answers(
-                &mut line_editor,
+    let project_type = if let Some(detected) = detected_type {
+        writeln!(stdout, "[INFO] Detected as: {}", detected.to_string())?;
+        detected
+    } else {
+        stdout.set_color(ColorSpec::new().set_fg(Some(Color::Yellow)))?;
+        writeln!(stdout, "[WARN] Could not auto-detect project type")?;
+        stdout.reset()?;
+
+        if confirm_all {
+            stdout.set_color(ColorSpec::new().set_fg(Some(Color::Yellow)))?;
+            writeln!(
                 stdout,
-                "category",
-                &ArgMatches::default(),
-                &format!("Project type for '{}'", project_name),
-                Some(&InitializeType::VARIANTS),
-                |input| InitializeType::VARIANTS.contains(&input),
-                |_| "Invalid project type. Please try again.".to_string(),
-                &project_name,
-                prompts_map,
+                "[WARN] Skipping '{}' (cannot auto-detect and no interaction allowed)",
+                project_name
             )?;
-            type_str.parse()?
-        };
-
-        let should_sync = if confirm_all {
-            true
-        } else {
-            let mut line_editor = Editor::<ArrayCompleter, DefaultHistory>::new()?;
-            prompt_for_confirmation(
-                &mut line_editor,
-                &format!(
-                    "Sync '{}' as {}? (y/N) ",
-                    project_name,
-                    project_type.to_string()
-                ),
-            )?
-        };
-
-        if !should_sync {
-            writeln!(stdout, "[INFO] Skipped")?;
-            continue;
+            stdout.reset()?;
+            return Ok(false);
         }
 
-        // Take a snapshot of the project state before syncing to detect changes
-        let project_snapshot_before = manifest_data
-            .projects
-            .iter()
-            .find(|p| p.name == project_name)
-            .map(|p| toml::to_string(p).unwrap_or_default());
-
-        match project_type {
-            InitializeType::Service => {
-                writeln!(stdout, "[INFO] Syncing service...")?;
+        let mut line_editor = Editor::<ArrayCompleter, DefaultHistory>::new()?;
+        let type_str = prompt_with_validation_with_answers(
+            &mut line_editor,
+            stdout,
+            "category",
+            &ArgMatches::default(),
+            &format!("Project type for '{}'", project_name),
+            Some(&InitializeType::VARIANTS),
+            |input| InitializeType::VARIANTS.contains(&input),
+            |_| "Invalid project type. Please try again.".to_string(),
+            project_name,
+            prompts_map,
+        )?;
+        type_str.parse()?
+    };
+
+    let should_sync = if confirm_all {
+        true
+    } else {
+        let mut line_editor = Editor::<ArrayCompleter, DefaultHistory>::new()?;
+        prompt_for_confirmation(
+            &mut line_editor,
+            &format!(
+                "Sync '{}' as {}? (y/N) ",
+                project_name,
+                project_type.to_string()
+            ),
+        )?
+    };
+
+    if !should_sync {
+        writeln!(stdout, "[INFO] Skipped")?;
+        return Ok(false);
+    }
 
-                match crate::sync::service::sync_service_with_cache(
-                    &project_name,
-                    app_root_path,
-                    manifest_data,
-                    &ArgMatches::default(),
-                    prompts_map,
-                    rendered_templates_cache,
-                    stdout,
-                ) {
-                    Ok(_) => {
-                        // Compare snapshot to detect changes
-                        let project_snapshot_after = manifest_data
-                            .projects
-                            .iter()
-                            .find(|p| p.name == project_name)
-                            .map(|p| toml::to_string(p).unwrap_or_default());
-
-                        if project_snapshot_before != project_snapshot_after {
-                            changes_made = true;
-                        }
-                    }
-                    Err(e) => {
-                        stdout.set_color(ColorSpec::new().set_fg(Some(Color::Red)))?;
-                        writeln!(stdout, "[ERROR] {}", e)?;
-                        stdout.reset()?;
+    // Take a snapshot of the project state before syncing to detect changes
+    let project_snapshot_before = manifest_data
+        .projects
+        .iter()
+        .find(|p| p.name == project_name)
+        .map(|p| toml::to_string(p).unwrap_or_default());
+
+    match project_type {
+        InitializeType::Service => {
+            writeln!(stdout, "[INFO] Syncing service...")?;
+
+            match crate::sync::service::sync_service_with_cache(
+                project_name,
+                app_root_path,
+                manifest_data,
+                &ArgMatches::default(),
+                prompts_map,
+                rendered_templates_cache,
+                stdout,
+            ) {
+                Ok(_) => {
+                    // Compare snapshot to detect changes
+                    let project_snapshot_after = manifest_data
+                        .projects
+                        .iter()
+                        .find(|p| p.name == project_name)
+                        .map(|p| toml::to_string(p).unwrap_or_default());
+
+                    if project_snapshot_before != project_snapshot_after {
+                        return Ok(true);
                     }
                 }
+                Err(e) => {
+                    stdout.set_color(ColorSpec::new().set_fg(Some(Color::Red)))?;
+                    writeln!(stdout, "[ERROR] {}", e)?;
+                    stdout.reset()?;
+                }
             }
-            InitializeType::Worker => {
-                writeln!(stdout, "[INFO] Syncing worker...")?;
+        }
+        InitializeType::Worker => {
+            writeln!(stdout, "[INFO] Syncing worker...")?;
 
-                match crate::sync::worker::sync_worker_with_cache(
-                    &project_name,
-                    app_root_path,
-                    manifest_data,
-                    &ArgMatches::default(),
-                    prompts_map,
-                    rendered_templates_cache,
-                    stdout,
-                ) {
-                    Ok(_) => {
-                        // Compare snapshot to detect changes
-                        let project_snapshot_after = manifest_data
-                            .projects
-                            .iter()
-                            .find(|p| p.name == project_name)
-                            .map(|p| toml::to_string(p).unwrap_or_default());
-
-                        if project_snapshot_before != project_snapshot_after {
-                            changes_made = true;
-                        }
-                    }
-                    Err(e) => {
-                        stdout.set_color(ColorSpec::new().set_fg(Some(Color::Red)))?;
-                        writeln!(stdout, "[ERROR] {}", e)?;
-                        stdout.reset()?;
+            match crate::sync::worker::sync_worker_with_cache(
+                project_name,
+                app_root_path,
+                manifest_data,
+                &ArgMatches::default(),
+                prompts_map,
+                rendered_templates_cache,
+                stdout,
+            ) {
+                Ok(_) => {
+                    // Compare snapshot to detect changes
+                    let project_snapshot_after = manifest_data
+                        .projects
+                        .iter()
+                        .find(|p| p.name == project_name)
+                        .map(|p| toml::to_string(p).unwrap_or_default());
+
+                    if project_snapshot_before != project_snapshot_after {
+                        return Ok(true);
                     }
                 }
+                Err(e) => {
+                    stdout.set_color(ColorSpec::new().set_fg(Some(Color::Red)))?;
+                    writeln!(stdout, "[ERROR] {}", e)?;
+                    stdout.reset()?;
+                }
             }
-            InitializeType::Library => {
-                writeln!(stdout, "[INFO] Syncing library...")?;
+        }
+        InitializeType::Library => {
+            writeln!(stdout, "[INFO] Syncing library...")?;
 
-                match crate::sync::library::sync_library_with_cache(
-                    &project_name,
-                    app_root_path,
-                    manifest_data,
-                    &ArgMatches::default(),
-                    prompts_map,
-                    rendered_templates_cache,
-                    stdout,
-                ) {
-                    Ok(_) => {
-                        // Compare snapshot to detect changes
-                        let project_snapshot_after = manifest_data
-                            .projects
-                            .iter()
-                            .find(|p| p.name == project_name)
-                            .map(|p| toml::to_string(p).unwrap_or_default());
-
-                        if project_snapshot_before != project_snapshot_after {
-                            changes_made = true;
-                        }
-                    }
-                    Err(e) => {
-                        stdout.set_color(ColorSpec::new().set_fg(Some(Color::Red)))?;
-                        writeln!(stdout, "[ERROR] {}", e)?;
-                        stdout.reset()?;
+            match crate::sync::library::sync_library_with_cache(
+                project_name,
+                app_root_path,
+                manifest_data,
+                &ArgMatches::default(),
+                prompts_map,
+                rendered_templates_cache,
+                stdout,
+            ) {
+                Ok(_) => {
+                    // Compare snapshot to detect changes
+                    let project_snapshot_after = manifest_data
+                        .projects
+                        .iter()
+                        .find(|p| p.name == project_name)
+                        .map(|p| toml::to_string(p).unwrap_or_default());
+
+                    if project_snapshot_before != project_snapshot_after {
+                        return Ok(true);
                     }
                 }
+                Err(e) => {
+                    stdout.set_color(ColorSpec::new().set_fg(Some(Color::Red)))?;
+                    writeln!(stdout, "[ERROR] {}", e)?;
+                    stdout.reset()?;
+                }
             }
-            InitializeType::Router | InitializeType::Module => {
-                writeln!(
-                    stdout,
-                    "[INFO] Skipped (routers and modules are synced as part of their parent service)"
-                )?;
-            }
+        }
+        InitializeType::Router | InitializeType::Module => {
+            writeln!(
+                stdout,
+                "[INFO] Skipped (routers and modules are synced as part of their parent service)"
+            )?;
         }
     }
 
-    Ok(changes_made)
+    Ok(false)
+}
+
+/// Removes a project whose folder no longer exists from all artifacts.
+pub(crate) fn remove_orphaned_project(
+    project_name: &str,
+    app_root_path: &Path,
+    manifest_data: &mut ApplicationManifestData,
+    rendered_templates_cache: &mut RenderedTemplatesCache,
+    stdout: &mut StandardStream,
+) -> Result<()> {
+    writeln!(stdout, "[INFO] Removing '{}'...", project_name)?;
+
+    let modules_path = app_root_path.join(&manifest_data.modules_path);
+    let project = manifest_data
+        .projects
+        .iter()
+        .find(|p| p.name == project_name);
+    let project_type = project
+        .map(|p| p.r#type.clone())
+        .unwrap_or(crate::core::manifest::ProjectType::Library);
+
+    remove_project_from_artifacts(
+        rendered_templates_cache,
+        manifest_data,
+        project_name,
+        project_type,
+        &[
+            ArtifactType::Manifest,
+            ArtifactType::DockerCompose,
+            ArtifactType::Runtime,
+            ArtifactType::ClientSdk,
+        ],
+        app_root_path,
+        &modules_path,
+        stdout,
+    )
+}
+
+/// Regenerates env templates and writes the manifest and every artifact
+/// touched during a sync.
+pub(crate) fn write_sync_results(
+    app_root_path: &Path,
+    manifest_data: &ApplicationManifestData,
+    rendered_templates_cache: &mut RenderedTemplatesCache,
+    stdout: &mut StandardStream,
+) -> Result<()> {
+    let manifest_path = app_root_path.join(".forklaunch").join("manifest.toml");
+
+    // Generate .env.template files and sync .env.local
+    let modules_path = app_root_path.join(&manifest_data.modules_path);
+    crate::core::env_template::generate_env_templates(
+        &modules_path,
+        manifest_data,
+        rendered_templates_cache,
+        stdout,
+    )?;
+    crate::core::env_template::sync_env_local_files(
+        &modules_path,
+        manifest_data,
+        stdout,
+    )?;
+
+    // Write the updated manifest back to cache
+    rendered_templates_cache.insert(
+        manifest_path.to_string_lossy().to_string(),
+        crate::core::rendered_template::RenderedTemplate {
+            path: manifest_path.clone(),
+            content: toml::to_string_pretty(manifest_data)
+                .context("Failed to serialize manifest")?,
+            context: Some("Failed to write manifest".to_string()),
+        },
+    );
+
+    // Collect and write all rendered templates (including manifest)
+    let rendered_templates: Vec<_> = rendered_templates_cache
+        .drain()
+        .map(|(_, template)| template)
+        .collect();
+
+    write_rendered_templates(&rendered_templates, false, stdout)?;
+
+    Ok(())
 }
 
 #[derive(Debug)]
@@ -381,6 +475,13 @@ impl CliCommand for SyncAllCommand {
                 .help("JSON object with pre-provided answers for prompts")
                 .value_name("JSON"),
         )
+        .arg(
+            Arg::new("watch")
+                .short('w')
+                .long("watch")
+                .action(ArgAction::SetTrue)
+                .help("Keep watching the modules directory and re-sync projects as they change (implies --confirm)"),
+        )
     }
 
     fn handler(&self, matches: &ArgMatches) -> Result<()> {
@@ -406,7 +507,8 @@ impl CliCommand for SyncAllCommand {
             toml_from_str::<ApplicationManifestData>(&manifest_template.content)
                 .with_context(|| ERROR_FAILED_TO_PARSE_MANIFEST)?;
 
-        let confirm_all = matches.get_flag("confirm");
+        let watch = matches.get_flag("watch");
+        let confirm_all = matches.get_flag("confirm") || watch;
 
         // Call the reusable sync function
         let _changes_made = sync_all_projects(
@@ -418,44 +520,22 @@ impl CliCommand for SyncAllCommand {
             &mut stdout,
         )?;
 
-        // Generate .env.template files and sync .env.local
-        let modules_path = app_root_path.join(&manifest_data.modules_path);
-        crate::core::env_template::generate_env_templates(
-            &modules_path,
+        write_sync_results(
+            &app_root_path,
             &manifest_data,
             &mut rendered_templates_cache,
             &mut stdout,
         )?;
-        crate::core::env_template::sync_env_local_files(
-            &modules_path,
-            &manifest_data,
-            &mut stdout,
-        )?;
-
-        // Write the updated manifest back to cache
-        rendered_templates_cache.insert(
-            manifest_path.to_string_lossy().to_string(),
-            crate::core::rendered_template::RenderedTemplate {
-                path: manifest_path.clone(),
-                content: toml::to_string_pretty(&manifest_data)
-                    .context("Failed to serialize manifest")?,
-                context: Some("Failed to write manifest".to_string()),
-            },
-        );
-
-        // Collect and write all rendered templates (including manifest)
-        let rendered_templates: Vec<_> = rendered_templates_cache
-            .drain()
-            .map(|(_, template)| template)
-            .collect();
-
-        write_rendered_templates(&rendered_templates, false, &mut stdout)?;
 
         writeln!(stdout)?;
         stdout.set_color(ColorSpec::new().set_fg(Some(Color::Green)).set_bold(true))?;
         writeln!(stdout, "[OK] Sync all completed")?;
         stdout.reset()?;
 
+        if watch {
+            watch_projects(&app_root_path, &prompts_map, &mut stdout)?;
+        }
+
         Ok(())
     }
 }
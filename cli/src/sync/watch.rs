@@ -0,0 +1,363 @@
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    fs,
+    io::Write,
+    path::{Path, PathBuf},
+    thread::sleep,
+    time::{Duration, Instant, SystemTime},
+};
+
+use anyhow::{Context, Result};
+use termcolor::{Color, ColorSpec, StandardStream, WriteColor};
+use toml::from_str as toml_from_str;
+
+use super::all::{remove_orphaned_project, sync_project, write_sync_results};
+use crate::{
+    constants::{DIRS_TO_IGNORE, ERROR_FAILED_TO_PARSE_MANIFEST, ERROR_FAILED_TO_READ_MANIFEST},
+    core::{
+        manifest::application::ApplicationManifestData, rendered_template::RenderedTemplatesCache,
+        sync::artifacts::refresh_project_in_manifest,
+    },
+};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(300);
+const DEBOUNCE_PERIOD: Duration = Duration::from_millis(750);
+
+/// Files in a project folder that project detection reads.
+const WATCHED_PROJECT_FILES: &[&str] = &[
+    "package.json",
+    "server.ts",
+    "worker.ts",
+    "registrations.ts",
+    "mikro-orm.config.ts",
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct FileStamp {
+    modified: Option<SystemTime>,
+    len: u64,
+}
+
+/// Watched files of one project, keyed by path relative to the project.
+pub(crate) type ProjectFingerprint = BTreeMap<PathBuf, FileStamp>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum ProjectChange {
+    Added(String),
+    Removed(String),
+    Modified {
+        project: String,
+        files: Vec<PathBuf>,
+    },
+}
+
+fn stamp(path: &Path) -> Option<FileStamp> {
+    let metadata = fs::metadata(path).ok()?;
+    metadata.is_file().then(|| FileStamp {
+        modified: metadata.modified().ok(),
+        len: metadata.len(),
+    })
+}
+
+fn fingerprint_project(project_path: &Path) -> ProjectFingerprint {
+    let mut fingerprint = BTreeMap::new();
+
+    for file_name in WATCHED_PROJECT_FILES {
+        if let Some(file_stamp) = stamp(&project_path.join(file_name)) {
+            fingerprint.insert(PathBuf::from(file_name), file_stamp);
+        }
+    }
+
+    let routes_path = Path::new("api").join("routes");
+    if let Ok(entries) = fs::read_dir(project_path.join(&routes_path)) {
+        for entry in entries.flatten() {
+            let file_name = entry.file_name();
+            if let Some(file_stamp) = stamp(&entry.path()) {
+                fingerprint.insert(routes_path.join(file_name), file_stamp);
+            }
+        }
+    }
+
+    fingerprint
+}
+
+/// Fingerprints every project folder directly under the modules path.
+pub(crate) fn snapshot_projects(
+    modules_path: &Path,
+) -> Result<BTreeMap<String, ProjectFingerprint>> {
+    let mut snapshot = BTreeMap::new();
+
+    for entry in fs::read_dir(modules_path)
+        .with_context(|| format!("Failed to read {}", modules_path.display()))?
+    {
+        let entry = entry?;
+        let project_path = entry.path();
+        if !project_path.is_dir() {
+            continue;
+        }
+        let project_name = entry.file_name().to_string_lossy().to_string();
+        if DIRS_TO_IGNORE.contains(&project_name.as_str()) {
+            continue;
+        }
+        snapshot.insert(project_name, fingerprint_project(&project_path));
+    }
+
+    Ok(snapshot)
+}
+
+/// Compares two snapshots. Removals come first so that a renamed folder is
+/// cleaned up before it is added under its new name.
+pub(crate) fn diff_snapshots(
+    before: &BTreeMap<String, ProjectFingerprint>,
+    after: &BTreeMap<String, ProjectFingerprint>,
+) -> Vec<ProjectChange> {
+    let mut changes: Vec<ProjectChange> = before
+        .keys()
+        .filter(|project| !after.contains_key(*project))
+        .map(|project| ProjectChange::Removed(project.clone()))
+        .collect();
+
+    changes.extend(
+        after
+            .keys()
+            .filter(|project| !before.contains_key(*project))
+            .map(|project| ProjectChange::Added(project.clone())),
+    );
+
+    for (project, fingerprint) in after {
+        let Some(previous) = before.get(project) else {
+            continue;
+        };
+        let files: BTreeSet<&PathBuf> = previous.keys().chain(fingerprint.keys()).collect();
+        let files: Vec<PathBuf> = files
+            .into_iter()
+            .filter(|file| previous.get(*file) != fingerprint.get(*file))
+            .cloned()
+            .collect();
+        if !files.is_empty() {
+            changes.push(ProjectChange::Modified {
+                project: project.clone(),
+                files,
+            });
+        }
+    }
+
+    changes
+}
+
+fn sync_changes(
+    app_root_path: &Path,
+    changes: &[ProjectChange],
+    prompts_map: &HashMap<String, HashMap<String, String>>,
+    stdout: &mut StandardStream,
+) -> Result<()> {
+    let manifest_path = app_root_path.join(".forklaunch").join("manifest.toml");
+    let mut rendered_templates_cache = RenderedTemplatesCache::new();
+    let manifest_template = rendered_templates_cache
+        .get(&manifest_path)?
+        .context(ERROR_FAILED_TO_READ_MANIFEST)?;
+    let mut manifest_data = toml_from_str::<ApplicationManifestData>(&manifest_template.content)
+        .context(ERROR_FAILED_TO_PARSE_MANIFEST)?;
+    let modules_path = app_root_path.join(&manifest_data.modules_path);
+
+    for change in changes {
+        match change {
+            ProjectChange::Removed(project) => {
+                writeln!(stdout)?;
+                stdout.set_color(ColorSpec::new().set_fg(Some(Color::Yellow)))?;
+                writeln!(stdout, "[INFO] Project folder removed: {}", project)?;
+                stdout.reset()?;
+                if manifest_data.projects.iter().any(|p| &p.name == project) {
+                    remove_orphaned_project(
+                        project,
+                        app_root_path,
+                        &mut manifest_data,
+                        &mut rendered_templates_cache,
+                        stdout,
+                    )?;
+                }
+            }
+            ProjectChange::Added(project) => {
+                stdout.set_color(ColorSpec::new().set_fg(Some(Color::Cyan)))?;
+                writeln!(stdout, "[INFO] Project folder added: {}", project)?;
+                stdout.reset()?;
+                sync_project(
+                    project,
+                    app_root_path,
+                    &mut manifest_data,
+                    &mut rendered_templates_cache,
+                    true,
+                    prompts_map,
+                    stdout,
+                )?;
+            }
+            ProjectChange::Modified { project, files } => {
+                let files: Vec<String> = files.iter().map(|f| f.display().to_string()).collect();
+                writeln!(stdout)?;
+                stdout.set_color(ColorSpec::new().set_fg(Some(Color::Cyan)))?;
+                writeln!(stdout, "[INFO] {} changed: {}", project, files.join(", "))?;
+                stdout.reset()?;
+
+                if !manifest_data.projects.iter().any(|p| &p.name == project) {
+                    sync_project(
+                        project,
+                        app_root_path,
+                        &mut manifest_data,
+                        &mut rendered_templates_cache,
+                        true,
+                        prompts_map,
+                        stdout,
+                    )?;
+                    continue;
+                }
+
+                let updates =
+                    refresh_project_in_manifest(&mut manifest_data, project, &modules_path)?;
+                if updates.is_empty() {
+                    writeln!(stdout, "[INFO] No manifest changes for {}", project)?;
+                }
+                for update in updates {
+                    stdout.set_color(ColorSpec::new().set_fg(Some(Color::Green)))?;
+                    writeln!(stdout, "[OK] {} {}", project, update)?;
+                    stdout.reset()?;
+                }
+            }
+        }
+    }
+
+    write_sync_results(
+        app_root_path,
+        &manifest_data,
+        &mut rendered_templates_cache,
+        stdout,
+    )
+}
+
+/// Polls the modules directory and incrementally re-syncs the projects that
+/// changed once the filesystem has been quiet for the debounce period. Runs
+/// until interrupted.
+pub(crate) fn watch_projects(
+    app_root_path: &Path,
+    prompts_map: &HashMap<String, HashMap<String, String>>,
+    stdout: &mut StandardStream,
+) -> Result<()> {
+    let manifest_path = app_root_path.join(".forklaunch").join("manifest.toml");
+    let manifest_content =
+        fs::read_to_string(&manifest_path).context(ERROR_FAILED_TO_READ_MANIFEST)?;
+    let manifest_data = toml_from_str::<ApplicationManifestData>(&manifest_content)
+        .context(ERROR_FAILED_TO_PARSE_MANIFEST)?;
+    let modules_path = app_root_path.join(&manifest_data.modules_path);
+
+    writeln!(stdout)?;
+    stdout.set_color(ColorSpec::new().set_fg(Some(Color::Cyan)))?;
+    writeln!(
+        stdout,
+        "[INFO] Watching {} for changes (Ctrl+C to stop)",
+        modules_path.display()
+    )?;
+    stdout.reset()?;
+
+    let mut synced = snapshot_projects(&modules_path)?;
+    let mut latest = synced.clone();
+    let mut last_change: Option<Instant> = None;
+
+    loop {
+        sleep(POLL_INTERVAL);
+
+        let current = snapshot_projects(&modules_path)?;
+        if current != latest {
+            latest = current;
+            last_change = Some(Instant::now());
+            continue;
+        }
+
+        let Some(changed_at) = last_change else {
+            continue;
+        };
+        if changed_at.elapsed() < DEBOUNCE_PERIOD {
+            continue;
+        }
+        last_change = None;
+
+        let changes = diff_snapshots(&synced, &latest);
+        if !changes.is_empty()
+            && let Err(error) = sync_changes(app_root_path, &changes, prompts_map, stdout)
+        {
+            stdout.set_color(ColorSpec::new().set_fg(Some(Color::Red)))?;
+            writeln!(stdout, "[ERROR] {:#}", error)?;
+            stdout.reset()?;
+        }
+
+        // Re-snapshot so files written by the sync itself are not picked up
+        // as new changes.
+        synced = snapshot_projects(&modules_path)?;
+        latest = synced.clone();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+
+    #[test]
+    fn test_snapshot_projects_tracks_detection_files() {
+        let temp_dir = TempDir::new().unwrap();
+        let modules = temp_dir.path();
+        fs::create_dir_all(modules.join("billing/api/routes")).unwrap();
+        fs::create_dir_all(modules.join("node_modules/pkg")).unwrap();
+        fs::write(modules.join("billing/server.ts"), "").unwrap();
+        fs::write(modules.join("billing/README.md"), "").unwrap();
+        fs::write(modules.join("billing/api/routes/payment.routes.ts"), "").unwrap();
+
+        let snapshot = snapshot_projects(modules).unwrap();
+
+        assert_eq!(snapshot.keys().collect::<Vec<_>>(), vec!["billing"]);
+        assert_eq!(
+            snapshot["billing"].keys().cloned().collect::<Vec<_>>(),
+            vec![
+                PathBuf::from("api/routes/payment.routes.ts"),
+                PathBuf::from("server.ts"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_diff_snapshots() {
+        let temp_dir = TempDir::new().unwrap();
+        let modules = temp_dir.path();
+        fs::create_dir_all(modules.join("billing/api/routes")).unwrap();
+        fs::create_dir_all(modules.join("iam")).unwrap();
+        fs::write(modules.join("billing/registrations.ts"), "").unwrap();
+        fs::write(modules.join("billing/api/routes/payment.routes.ts"), "").unwrap();
+        fs::write(modules.join("iam/package.json"), "{}").unwrap();
+        let before = snapshot_projects(modules).unwrap();
+
+        fs::rename(modules.join("iam"), modules.join("auth")).unwrap();
+        fs::write(modules.join("billing/registrations.ts"), "RedisTtlCache").unwrap();
+        fs::rename(
+            modules.join("billing/api/routes/payment.routes.ts"),
+            modules.join("billing/api/routes/checkout.routes.ts"),
+        )
+        .unwrap();
+        let after = snapshot_projects(modules).unwrap();
+
+        assert_eq!(
+            diff_snapshots(&before, &after),
+            vec![
+                ProjectChange::Removed("iam".to_string()),
+                ProjectChange::Added("auth".to_string()),
+                ProjectChange::Modified {
+                    project: "billing".to_string(),
+                    files: vec![
+                        PathBuf::from("api/routes/checkout.routes.ts"),
+                        PathBuf::from("api/routes/payment.routes.ts"),
+                        PathBuf::from("registrations.ts"),
+                    ],
+                },
+            ]
+        );
+        assert!(diff_snapshots(&after, &after).is_empty());
+    }
+}